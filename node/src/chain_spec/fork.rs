@@ -266,7 +266,7 @@ pub fn dev_collator_set() -> Vec<CollatorTuple> {
 ///
 /// # Field correspondence
 ///
-/// All 12 `GenesisConfig` fields are populated.  See `testnet_genesis` in
+/// All 14 `GenesisConfig` fields are populated.  See `testnet_genesis` in
 /// `node/src/chain_spec/testnet/mod.rs` for the canonical reference.
 pub fn assemble_general_fork_genesis(
     wasm_binary: &[u8],
@@ -318,7 +318,11 @@ pub fn assemble_general_fork_genesis(
         transaction_payment: Default::default(),
         // 11. assets
         assets: Default::default(),
-        // 12. sudo
+        // 12. council — members are appointed after launch.
+        council: Default::default(),
+        // 13. technical_committee
+        technical_committee: Default::default(),
+        // 14. sudo
         sudo: general_runtime::SudoConfig { key: root_key },
     }
 }
@@ -569,11 +573,11 @@ mod tests {
     }
 
     // -----------------------------------------------------------------------
-    // Test T2-2: assemble_general_fork_genesis populates all 12 fields without
+    // Test T2-2: assemble_general_fork_genesis populates all 14 fields without
     // panic and produces structurally correct output
     // -----------------------------------------------------------------------
     #[test]
-    fn assemble_general_fork_genesis_populates_all_12_fields_without_panic() {
+    fn assemble_general_fork_genesis_populates_all_14_fields_without_panic() {
         use cumulus_primitives_core::ParaId;
 
         let wasm = vec![0x00u8, 0x61, 0x73, 0x6d]; // minimal wasm magic bytes
@@ -616,7 +620,14 @@ mod tests {
         // Field 5 (session): 2 key entries.
         assert_eq!(genesis.session.keys.len(), 2, "session.keys must have one entry per collator");
 
-        // Field 12 (sudo): root_key is present.
+        // Fields 12-13 (council, technical_committee): no members at genesis.
+        assert!(genesis.council.members.is_empty(), "council must start without members");
+        assert!(
+            genesis.technical_committee.members.is_empty(),
+            "technical_committee must start without members"
+        );
+
+        // Field 14 (sudo): root_key is present.
         assert_eq!(genesis.sudo.key, Some(root_key), "sudo.key must match the supplied root_key");
     }
 
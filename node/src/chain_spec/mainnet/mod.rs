@@ -57,6 +57,8 @@ fn mainnet_genesis(
         },
         transaction_payment: Default::default(),
         assets: Default::default(),
+        council: Default::default(),
+        technical_committee: Default::default(),
         sudo: general_runtime::SudoConfig { key: root_key },
     }
 }
@@ -8,7 +8,7 @@
 use std::sync::Arc;
 
 use general_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Index as Nonce};
-use sc_client_api::AuxStore;
+use sc_client_api::{AuxStore, BlockchainEvents};
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -26,6 +26,8 @@ pub struct FullDeps<C, P> {
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
+    /// Executor for the tasks driving RPC subscriptions.
+    pub subscription_executor: SubscriptionTaskExecutor,
 }
 
 /// Instantiate all RPC extensions.
@@ -37,11 +39,13 @@ where
         + HeaderBackend<Block>
         + AuxStore
         + HeaderMetadata<Block, Error = BlockChainError>
+        + BlockchainEvents<Block>
         + Send
         + Sync
         + 'static,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+    C::Api: assets_runtime_api::AssetsApi<Block, AccountId, Balance, u32>,
     C::Api: pallet_rwa_runtime_api::RwaApi<Block, AccountId, Balance, BlockNumber, u32>,
     C::Api: pallet_crowdfunding_runtime_api::CrowdfundingApi<
         Block,
@@ -53,17 +57,19 @@ where
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + Sync + Send + 'static,
 {
+    use assets_rpc::{AssetsRpcHandler, AssetsRpcServer};
     use pallet_crowdfunding_rpc::{CrowdfundingRpcHandler, CrowdfundingRpcServer};
     use pallet_rwa_rpc::{RwaRpcHandler, RwaRpcServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcExtension::new(());
-    let FullDeps { client, pool, deny_unsafe } = deps;
+    let FullDeps { client, pool, deny_unsafe, subscription_executor } = deps;
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
-    module.merge(RwaRpcHandler::new(client.clone()).into_rpc())?;
-    module.merge(CrowdfundingRpcHandler::new(client).into_rpc())?;
+    module.merge(AssetsRpcHandler::new(client.clone()).into_rpc())?;
+    module.merge(RwaRpcHandler::new(client.clone(), subscription_executor.clone()).into_rpc())?;
+    module.merge(CrowdfundingRpcHandler::new(client, subscription_executor).into_rpc())?;
     Ok(module)
 }
@@ -194,11 +194,12 @@ async fn start_node_impl(
         let client = client.clone();
         let transaction_pool = transaction_pool.clone();
 
-        Box::new(move |deny_unsafe, _| {
+        Box::new(move |deny_unsafe, subscription_executor| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: transaction_pool.clone(),
                 deny_unsafe,
+                subscription_executor,
             };
 
             crate::rpc::create_full(deps).map_err(Into::into)
@@ -0,0 +1,109 @@
+//! XCM executor adapters backed by the registry.
+
+use core::marker::PhantomData;
+
+use frame_support::weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight};
+use sp_std::borrow::Borrow;
+use xcm::latest::prelude::*;
+use xcm_builder::TakeRevenue;
+use xcm_executor::{
+    traits::{Convert, WeightTrader},
+    Assets,
+};
+
+use crate::{Config, LocationToAssetId, Pallet, RegisteredAssets};
+
+/// Converts between registered locations and `pallet_assets` ids, as the
+/// id converter of a `ConvertedConcreteId`.
+pub struct AssetIdForLocation<T>(PhantomData<T>);
+
+impl<T: Config> Convert<MultiLocation, T::AssetId> for AssetIdForLocation<T> {
+    fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<T::AssetId, ()> {
+        LocationToAssetId::<T>::get(location.borrow()).ok_or(())
+    }
+
+    fn reverse_ref(asset_id: impl Borrow<T::AssetId>) -> Result<MultiLocation, ()> {
+        RegisteredAssets::<T>::get(asset_id.borrow()).map(|asset| asset.location).ok_or(())
+    }
+}
+
+/// Charges XCM execution in the first payment asset with a registered fee
+/// rate.  Every later purchase in the same message must use that asset too.
+/// Whatever is left unrefunded when the trader is dropped goes to `R`.
+pub struct RegistryTrader<T: Config, R: TakeRevenue> {
+    weight: Weight,
+    consumed: u128,
+    /// Location and rate of the asset paying for this message.
+    paid_in: Option<(MultiLocation, u128)>,
+    _marker: PhantomData<(T, R)>,
+}
+
+impl<T: Config, R: TakeRevenue> RegistryTrader<T, R> {
+    fn fee(units_per_second: u128, weight: Weight) -> u128 {
+        units_per_second.saturating_mul(weight.ref_time() as u128)
+            / (WEIGHT_REF_TIME_PER_SECOND as u128)
+    }
+}
+
+impl<T: Config, R: TakeRevenue> WeightTrader for RegistryTrader<T, R> {
+    fn new() -> Self {
+        Self { weight: Weight::zero(), consumed: 0, paid_in: None, _marker: PhantomData }
+    }
+
+    fn buy_weight(&mut self, weight: Weight, payment: Assets) -> Result<Assets, XcmError> {
+        log::trace!(
+            target: "xcm::weight",
+            "RegistryTrader::buy_weight weight: {:?}, payment: {:?}",
+            weight,
+            payment,
+        );
+        let (location, units_per_second) = match self.paid_in {
+            Some(rate) => rate,
+            None => payment
+                .fungible
+                .keys()
+                .find_map(|id| match id {
+                    Concrete(location) => {
+                        Pallet::<T>::units_per_second(location).map(|rate| (*location, rate))
+                    }
+                    Abstract(_) => None,
+                })
+                .ok_or(XcmError::TooExpensive)?,
+        };
+
+        let amount = Self::fee(units_per_second, weight);
+        let unused = if amount == 0 {
+            payment
+        } else {
+            payment.checked_sub((location, amount).into()).map_err(|_| XcmError::TooExpensive)?
+        };
+        self.weight = self.weight.saturating_add(weight);
+        self.consumed = self.consumed.saturating_add(amount);
+        self.paid_in = Some((location, units_per_second));
+        Ok(unused)
+    }
+
+    fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
+        log::trace!(target: "xcm::weight", "RegistryTrader::refund_weight weight: {:?}", weight);
+        let (location, units_per_second) = self.paid_in?;
+        let weight = weight.min(self.weight);
+        let amount = Self::fee(units_per_second, weight).min(self.consumed);
+        self.weight = self.weight.saturating_sub(weight);
+        self.consumed = self.consumed.saturating_sub(amount);
+        if amount > 0 {
+            Some((location, amount).into())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Config, R: TakeRevenue> Drop for RegistryTrader<T, R> {
+    fn drop(&mut self) {
+        if let Some((location, _)) = self.paid_in {
+            if self.consumed > 0 {
+                R::take_revenue((location, self.consumed).into());
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::benchmarks;
+use frame_support::{assert_ok, pallet_prelude::Get};
+use frame_system::RawOrigin;
+use sp_std::{boxed::Box, vec};
+use xcm::latest::prelude::*;
+
+use super::*;
+use crate::pallet::{self as pallet_asset_registry};
+
+/// A foreign asset id in runtimes that reserve the top bit of `u32` ids
+/// for the registry.
+const ASSET: u32 = (1 << 31) | 100;
+
+fn sibling_location(index: u128) -> MultiLocation {
+    MultiLocation::new(1, X2(Parachain(2000), GeneralIndex(index)))
+}
+
+/// Register `ASSET` at `sibling_location(0)` with names of the maximum
+/// length.
+fn setup_registered_asset<T: Config>() -> T::AssetId {
+    let asset_id = T::AssetId::from(ASSET);
+    let name = vec![b'n'; T::StringLimit::get() as usize];
+    assert_ok!(Pallet::<T>::register_asset(
+        RawOrigin::Root.into(),
+        asset_id,
+        Box::new(sibling_location(0).into()),
+        name.clone(),
+        name,
+        12,
+        Some(1_000_000),
+        1u32.into(),
+    ));
+    asset_id
+}
+
+benchmarks! {
+    register_asset {
+        let asset_id = T::AssetId::from(ASSET);
+        let name = vec![b'n'; T::StringLimit::get() as usize];
+        let location = Box::new(sibling_location(0).into());
+    }: _(RawOrigin::Root, asset_id, location, name.clone(), name, 12, Some(1_000_000), 1u32.into())
+    verify {
+        assert!(pallet_asset_registry::RegisteredAssets::<T>::contains_key(asset_id));
+    }
+
+    update_location {
+        let asset_id = setup_registered_asset::<T>();
+        let location = Box::new(sibling_location(1).into());
+    }: _(RawOrigin::Root, asset_id, location)
+    verify {
+        assert_eq!(Pallet::<T>::asset_id(&sibling_location(1)), Some(asset_id));
+    }
+
+    update_metadata {
+        let asset_id = setup_registered_asset::<T>();
+        let name = vec![b'm'; T::StringLimit::get() as usize];
+    }: _(RawOrigin::Root, asset_id, name.clone(), name, 6)
+    verify {
+        let asset = pallet_asset_registry::RegisteredAssets::<T>::get(asset_id).unwrap();
+        assert_eq!(asset.metadata.decimals, 6);
+    }
+
+    set_units_per_second {
+        let asset_id = setup_registered_asset::<T>();
+    }: _(RawOrigin::Root, asset_id, Some(2_000_000))
+    verify {
+        assert_eq!(Pallet::<T>::units_per_second(&sibling_location(0)), Some(2_000_000));
+    }
+
+    deregister_asset {
+        let asset_id = setup_registered_asset::<T>();
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(!pallet_asset_registry::RegisteredAssets::<T>::contains_key(asset_id));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
+}
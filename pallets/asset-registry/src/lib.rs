@@ -0,0 +1,328 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Registry of `pallet_assets` assets reachable over XCM.
+//!
+//! Each registered asset is keyed by its `pallet_assets` id and records the
+//! `MultiLocation` other chains use for it, display metadata and the rate at
+//! which it pays for XCM execution.  The registry creates the asset itself,
+//! owned by its pallet account and under an id reserved for foreign assets,
+//! so nobody can mint a registered asset outside of XCM.  The [`adapters`] plug
+//! the registry into the XCM executor: [`AssetIdForLocation`] for a
+//! `FungiblesAdapter` and [`RegistryTrader`] for `Trader`.
+
+pub mod adapters;
+pub mod types;
+pub mod weights;
+
+pub use adapters::{AssetIdForLocation, RegistryTrader};
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{tokens::fungibles, Contains},
+        PalletId,
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{AccountIdConversion, Zero};
+    use sp_std::{boxed::Box, vec::Vec};
+    use xcm::{latest::MultiLocation, VersionedMultiLocation};
+
+    use super::*;
+
+    pub type AssetMetadataOf<T> = AssetMetadata<<T as Config>::StringLimit>;
+
+    pub type AssetRegistrationOf<T> = AssetRegistration<<T as Config>::StringLimit>;
+
+    pub type BalanceOf<T> = <<T as Config>::Fungibles as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type AssetId: Member + Parameter + Copy + MaxEncodedLen + From<u32>;
+        /// The assets pallet holding the balances of registered assets.
+        type Fungibles: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId>
+            + fungibles::Create<Self::AccountId>;
+        /// Ids under which the registry may create assets.  They must be
+        /// out of reach of the assets pallet's other creators.
+        type ForeignAssetIds: Contains<Self::AssetId>;
+        /// Origin allowed to register assets and change their entries.
+        type RegistryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Id of the account owning the assets the registry creates.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+        #[pallet::constant]
+        type StringLimit: Get<u32>;
+        type WeightInfo: WeightInfo;
+    }
+
+    // ── Storage ──────────────────────────────────────────────────────────
+
+    #[pallet::storage]
+    pub type RegisteredAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, AssetRegistrationOf<T>>;
+
+    /// Reverse index of `RegisteredAssets` by location.
+    #[pallet::storage]
+    pub type LocationToAssetId<T: Config> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, T::AssetId>;
+
+    // ── Events ───────────────────────────────────────────────────────────
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        #[codec(index = 0)]
+        AssetRegistered { asset_id: T::AssetId, location: MultiLocation },
+        #[codec(index = 1)]
+        LocationUpdated {
+            asset_id: T::AssetId,
+            old_location: MultiLocation,
+            new_location: MultiLocation,
+        },
+        #[codec(index = 2)]
+        MetadataUpdated { asset_id: T::AssetId },
+        #[codec(index = 3)]
+        UnitsPerSecondSet { asset_id: T::AssetId, units_per_second: Option<u128> },
+        #[codec(index = 4)]
+        AssetDeregistered { asset_id: T::AssetId, location: MultiLocation },
+    }
+
+    // ── Errors ───────────────────────────────────────────────────────────
+
+    #[pallet::error]
+    pub enum Error<T> {
+        #[codec(index = 1)]
+        AssetAlreadyRegistered,
+        #[codec(index = 2)]
+        AssetNotRegistered,
+        /// Another asset is already registered at this location.
+        #[codec(index = 3)]
+        LocationAlreadyRegistered,
+        /// The location cannot be converted to the latest XCM version.
+        #[codec(index = 4)]
+        BadLocation,
+        #[codec(index = 5)]
+        MetadataTooLong,
+        /// The asset id is outside the range reserved for foreign assets.
+        #[codec(index = 6)]
+        NotForeignAssetId,
+        /// The asset already exists in the assets pallet.
+        #[codec(index = 7)]
+        AssetAlreadyExists,
+        /// A fee rate of zero would make XCM execution free.
+        #[codec(index = 8)]
+        ZeroUnitsPerSecond,
+    }
+
+    // ── Dispatchables ────────────────────────────────────────────────────
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a sufficient `pallet_assets` asset with `min_balance`,
+        /// owned by the registry, and register it at `location`.
+        ///
+        /// `asset_id` must be one of `ForeignAssetIds` and not exist yet.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register_asset())]
+        pub fn register_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            location: Box<VersionedMultiLocation>,
+            name: Vec<u8>,
+            symbol: Vec<u8>,
+            decimals: u8,
+            units_per_second: Option<u128>,
+            min_balance: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+            let location = Self::latest_location(location)?;
+            let metadata = Self::bounded_metadata(name, symbol, decimals)?;
+            Self::ensure_rate(units_per_second)?;
+
+            ensure!(T::ForeignAssetIds::contains(&asset_id), Error::<T>::NotForeignAssetId);
+            ensure!(
+                !<T::Fungibles as fungibles::Inspect<T::AccountId>>::asset_exists(asset_id),
+                Error::<T>::AssetAlreadyExists
+            );
+            ensure!(
+                !RegisteredAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetAlreadyRegistered
+            );
+            ensure!(
+                !LocationToAssetId::<T>::contains_key(location),
+                Error::<T>::LocationAlreadyRegistered
+            );
+
+            <T::Fungibles as fungibles::Create<T::AccountId>>::create(
+                asset_id,
+                Self::account_id(),
+                true,
+                min_balance,
+            )?;
+            RegisteredAssets::<T>::insert(
+                asset_id,
+                AssetRegistration { location, metadata, units_per_second },
+            );
+            LocationToAssetId::<T>::insert(location, asset_id);
+
+            Self::deposit_event(Event::AssetRegistered { asset_id, location });
+            Ok(())
+        }
+
+        /// Move a registered asset to a new location.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::update_location())]
+        pub fn update_location(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            location: Box<VersionedMultiLocation>,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+            let new_location = Self::latest_location(location)?;
+            ensure!(
+                !LocationToAssetId::<T>::contains_key(new_location),
+                Error::<T>::LocationAlreadyRegistered
+            );
+
+            let old_location = RegisteredAssets::<T>::try_mutate(
+                asset_id,
+                |maybe_asset| -> Result<_, DispatchError> {
+                    let asset = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotRegistered)?;
+                    Ok(sp_std::mem::replace(&mut asset.location, new_location))
+                },
+            )?;
+            LocationToAssetId::<T>::remove(old_location);
+            LocationToAssetId::<T>::insert(new_location, asset_id);
+
+            Self::deposit_event(Event::LocationUpdated { asset_id, old_location, new_location });
+            Ok(())
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::update_metadata())]
+        pub fn update_metadata(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            name: Vec<u8>,
+            symbol: Vec<u8>,
+            decimals: u8,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+            let metadata = Self::bounded_metadata(name, symbol, decimals)?;
+
+            RegisteredAssets::<T>::try_mutate(asset_id, |maybe_asset| -> DispatchResult {
+                let asset = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotRegistered)?;
+                asset.metadata = metadata;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::MetadataUpdated { asset_id });
+            Ok(())
+        }
+
+        /// Set the XCM fee rate of a registered asset.  `None` stops the
+        /// asset from being accepted as fee payment.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_units_per_second())]
+        pub fn set_units_per_second(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            units_per_second: Option<u128>,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+            Self::ensure_rate(units_per_second)?;
+
+            RegisteredAssets::<T>::try_mutate(asset_id, |maybe_asset| -> DispatchResult {
+                let asset = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotRegistered)?;
+                asset.units_per_second = units_per_second;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::UnitsPerSecondSet { asset_id, units_per_second });
+            Ok(())
+        }
+
+        /// Remove an asset from the registry.  Balances held in the assets
+        /// pallet are untouched; the asset just stops transacting over XCM.
+        /// Its id stays taken, so it cannot be registered again.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::deregister_asset())]
+        pub fn deregister_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+
+            let asset =
+                RegisteredAssets::<T>::take(asset_id).ok_or(Error::<T>::AssetNotRegistered)?;
+            LocationToAssetId::<T>::remove(asset.location);
+
+            Self::deposit_event(Event::AssetDeregistered { asset_id, location: asset.location });
+            Ok(())
+        }
+    }
+
+    // ── Helpers ──────────────────────────────────────────────────────────
+
+    impl<T: Config> Pallet<T> {
+        /// Account owning the assets the registry creates.
+        pub fn account_id() -> T::AccountId { T::PalletId::get().into_account_truncating() }
+
+        /// Asset registered at `location`, if any.
+        pub fn asset_id(location: &MultiLocation) -> Option<T::AssetId> {
+            LocationToAssetId::<T>::get(location)
+        }
+
+        /// Location of a registered asset.
+        pub fn location(asset_id: &T::AssetId) -> Option<MultiLocation> {
+            RegisteredAssets::<T>::get(asset_id).map(|asset| asset.location)
+        }
+
+        /// XCM fee rate of the asset registered at `location`, if it is
+        /// accepted as fee payment.
+        pub fn units_per_second(location: &MultiLocation) -> Option<u128> {
+            let asset_id = LocationToAssetId::<T>::get(location)?;
+            RegisteredAssets::<T>::get(asset_id)?.units_per_second
+        }
+
+        fn latest_location(
+            location: Box<VersionedMultiLocation>,
+        ) -> Result<MultiLocation, DispatchError> {
+            MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadLocation.into())
+        }
+
+        fn ensure_rate(units_per_second: Option<u128>) -> DispatchResult {
+            ensure!(
+                units_per_second.map_or(true, |rate| !rate.is_zero()),
+                Error::<T>::ZeroUnitsPerSecond
+            );
+            Ok(())
+        }
+
+        fn bounded_metadata(
+            name: Vec<u8>,
+            symbol: Vec<u8>,
+            decimals: u8,
+        ) -> Result<AssetMetadataOf<T>, DispatchError> {
+            Ok(AssetMetadata {
+                name: name.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+                symbol: symbol.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+                decimals,
+            })
+        }
+    }
+}
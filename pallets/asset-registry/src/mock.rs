@@ -0,0 +1,177 @@
+use std::cell::RefCell;
+
+use frame_support::{
+    parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, Contains},
+    PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use xcm::latest::prelude::*;
+
+use crate as pallet_asset_registry;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        AssetRegistry: pallet_asset_registry,
+    }
+);
+
+impl frame_system::Config for Test {
+    type AccountData = pallet_balances::AccountData<u128>;
+    type AccountId = u64;
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockHashCount = ConstU64<250>;
+    type BlockLength = ();
+    type BlockNumber = u64;
+    type BlockWeights = ();
+    type DbWeight = ();
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type Header = Header;
+    type Index = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type MaxConsumers = ConstU32<16>;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type OnSetCode = ();
+    type PalletInfo = PalletInfo;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SS58Prefix = ConstU16<42>;
+    type SystemWeightInfo = ();
+    type Version = ();
+}
+
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+    type Balance = u128;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+}
+
+impl pallet_assets::Config for Test {
+    type ApprovalDeposit = ConstU128<1>;
+    type AssetAccountDeposit = ConstU128<1>;
+    type AssetDeposit = ConstU128<1>;
+    type AssetId = u32;
+    type AssetIdParameter = codec::Compact<u32>;
+    type Balance = u128;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+    type CallbackHandle = ();
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+    type Currency = Balances;
+    type Extra = ();
+    type ForceOrigin = EnsureRoot<u64>;
+    type Freezer = ();
+    type MetadataDepositBase = ConstU128<1>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type RuntimeEvent = RuntimeEvent;
+    type StringLimit = ConstU32<50>;
+    type WeightInfo = ();
+}
+
+/// Ids with this bit set are reserved for registry assets.
+pub const FOREIGN_ASSET_ID_FLAG: u32 = 1 << 31;
+
+pub struct ForeignAssetIds;
+
+impl Contains<u32> for ForeignAssetIds {
+    fn contains(id: &u32) -> bool { id & FOREIGN_ASSET_ID_FLAG != 0 }
+}
+
+parameter_types! {
+    pub const AssetRegistryPalletId: PalletId = PalletId(*b"py/asreg");
+}
+
+impl pallet_asset_registry::Config for Test {
+    type AssetId = u32;
+    type ForeignAssetIds = ForeignAssetIds;
+    type Fungibles = Assets;
+    type PalletId = AssetRegistryPalletId;
+    type RegistryOrigin = EnsureRoot<u64>;
+    type RuntimeEvent = RuntimeEvent;
+    type StringLimit = ConstU32<16>;
+    type WeightInfo = ();
+}
+
+// ── RecordRevenue ───────────────────────────────────────────────────────
+//
+// `TakeRevenue` that keeps whatever a trader hands over, so tests can check
+// what was charged once the trader is dropped.
+
+pub struct RecordRevenue;
+
+thread_local! {
+    static REVENUE: RefCell<Vec<MultiAsset>> = RefCell::new(Vec::new());
+}
+
+impl RecordRevenue {
+    /// Revenue taken so far, in order.
+    pub fn taken() -> Vec<MultiAsset> { REVENUE.with(|r| r.borrow().clone()) }
+}
+
+impl xcm_builder::TakeRevenue for RecordRevenue {
+    fn take_revenue(revenue: MultiAsset) { REVENUE.with(|r| r.borrow_mut().push(revenue)); }
+}
+
+pub const ALICE: u64 = 1;
+
+/// Foreign asset ids; `UNREGISTERED` stays out of the registry.
+pub const USDT: u32 = FOREIGN_ASSET_ID_FLAG | 1;
+pub const DOT: u32 = FOREIGN_ASSET_ID_FLAG | 2;
+pub const UNREGISTERED: u32 = FOREIGN_ASSET_ID_FLAG | 3;
+/// Local asset created in genesis by `ALICE`.
+pub const LOCAL: u32 = 1;
+
+pub fn location(index: u128) -> MultiLocation {
+    MultiLocation::new(1, X2(Parachain(2000), GeneralIndex(index)))
+}
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+    fn default() -> Self { Self }
+}
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        REVENUE.with(|r| r.borrow_mut().clear());
+        let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        pallet_balances::GenesisConfig::<Test> { balances: vec![(ALICE, 10_000)] }
+            .assimilate_storage(&mut t)
+            .unwrap();
+        pallet_assets::GenesisConfig::<Test> {
+            assets: vec![(LOCAL, ALICE, true, 1)],
+            metadata: vec![],
+            accounts: vec![],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
@@ -0,0 +1,376 @@
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::tokens::fungibles,
+    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+};
+use sp_runtime::DispatchError;
+use xcm::{latest::prelude::*, VersionedMultiLocation};
+use xcm_executor::{
+    traits::{Convert, WeightTrader},
+    Assets,
+};
+
+use super::{mock::*, *};
+
+fn register(asset_id: u32, index: u128, units_per_second: Option<u128>) {
+    assert_ok!(AssetRegistry::register_asset(
+        RuntimeOrigin::root(),
+        asset_id,
+        Box::new(location(index).into()),
+        b"Tether".to_vec(),
+        b"USDT".to_vec(),
+        6,
+        units_per_second,
+        1,
+    ));
+}
+
+/// Payment of `amount` units of the asset at `location(index)`.
+fn payment(index: u128, amount: u128) -> Assets {
+    MultiAsset::from((location(index), amount)).into()
+}
+
+// ── register_asset ──────────────────────────────────────────────────────
+
+mod register_asset {
+    use super::*;
+
+    #[test]
+    fn happy_path() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, Some(1_000));
+            let asset = pallet::RegisteredAssets::<Test>::get(USDT).unwrap();
+            assert_eq!(asset.location, location(1));
+            assert_eq!(asset.metadata.symbol.to_vec(), b"USDT".to_vec());
+            assert_eq!(asset.metadata.decimals, 6);
+            assert_eq!(asset.units_per_second, Some(1_000));
+            assert_eq!(AssetRegistry::asset_id(&location(1)), Some(USDT));
+            System::assert_last_event(
+                Event::<Test>::AssetRegistered { asset_id: USDT, location: location(1) }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn requires_registry_origin() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_noop!(
+                AssetRegistry::register_asset(
+                    RuntimeOrigin::signed(ALICE),
+                    USDT,
+                    Box::new(location(1).into()),
+                    vec![],
+                    vec![],
+                    6,
+                    None,
+                    1,
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn creates_asset_owned_by_registry() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert!(!<crate::mock::Assets as fungibles::Inspect<u64>>::asset_exists(USDT));
+            register(USDT, 1, None);
+            assert!(<crate::mock::Assets as fungibles::Inspect<u64>>::asset_exists(USDT));
+            assert_eq!(
+                <crate::mock::Assets as fungibles::roles::Inspect<u64>>::owner(USDT),
+                Some(AssetRegistry::account_id())
+            );
+        });
+    }
+
+    #[test]
+    fn asset_id_must_be_foreign_and_unused() {
+        ExtBuilder::default().build().execute_with(|| {
+            let register_as = |asset_id| {
+                AssetRegistry::register_asset(
+                    RuntimeOrigin::root(),
+                    asset_id,
+                    Box::new(location(1).into()),
+                    vec![],
+                    vec![],
+                    6,
+                    None,
+                    1,
+                )
+            };
+            assert_noop!(register_as(LOCAL), Error::<Test>::NotForeignAssetId);
+            assert_noop!(register_as(7), Error::<Test>::NotForeignAssetId);
+
+            // A foreign id created outside the registry has another owner.
+            assert_ok!(crate::mock::Assets::force_create(
+                RuntimeOrigin::root(),
+                codec::Compact(USDT),
+                ALICE,
+                true,
+                1
+            ));
+            assert_noop!(register_as(USDT), Error::<Test>::AssetAlreadyExists);
+        });
+    }
+
+    #[test]
+    fn rejects_zero_rate() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_noop!(
+                AssetRegistry::register_asset(
+                    RuntimeOrigin::root(),
+                    USDT,
+                    Box::new(location(1).into()),
+                    vec![],
+                    vec![],
+                    6,
+                    Some(0),
+                    1,
+                ),
+                Error::<Test>::ZeroUnitsPerSecond
+            );
+            register(USDT, 1, Some(1_000));
+            assert_noop!(
+                AssetRegistry::set_units_per_second(RuntimeOrigin::root(), USDT, Some(0)),
+                Error::<Test>::ZeroUnitsPerSecond
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_duplicates() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, None);
+            assert_noop!(
+                AssetRegistry::register_asset(
+                    RuntimeOrigin::root(),
+                    USDT,
+                    Box::new(location(2).into()),
+                    vec![],
+                    vec![],
+                    6,
+                    None,
+                    1,
+                ),
+                Error::<Test>::AssetAlreadyRegistered
+            );
+            assert_noop!(
+                AssetRegistry::register_asset(
+                    RuntimeOrigin::root(),
+                    DOT,
+                    Box::new(location(1).into()),
+                    vec![],
+                    vec![],
+                    10,
+                    None,
+                    1,
+                ),
+                Error::<Test>::LocationAlreadyRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn accepts_older_xcm_versions() {
+        ExtBuilder::default().build().execute_with(|| {
+            let v2 = xcm::v2::MultiLocation::new(
+                1,
+                xcm::v2::Junctions::X2(
+                    xcm::v2::Junction::Parachain(2000),
+                    xcm::v2::Junction::GeneralIndex(1),
+                ),
+            );
+            assert_ok!(AssetRegistry::register_asset(
+                RuntimeOrigin::root(),
+                USDT,
+                Box::new(VersionedMultiLocation::V2(v2)),
+                vec![],
+                vec![],
+                6,
+                None,
+                1,
+            ));
+            assert_eq!(AssetRegistry::asset_id(&location(1)), Some(USDT));
+        });
+    }
+
+    #[test]
+    fn metadata_overflow() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_noop!(
+                AssetRegistry::register_asset(
+                    RuntimeOrigin::root(),
+                    USDT,
+                    Box::new(location(1).into()),
+                    vec![b'n'; 17],
+                    vec![],
+                    6,
+                    None,
+                    1,
+                ),
+                Error::<Test>::MetadataTooLong
+            );
+        });
+    }
+}
+
+// ── updates ─────────────────────────────────────────────────────────────
+
+mod updates {
+    use super::*;
+
+    #[test]
+    fn update_location_moves_index() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, None);
+            assert_ok!(AssetRegistry::update_location(
+                RuntimeOrigin::root(),
+                USDT,
+                Box::new(location(5).into()),
+            ));
+            assert_eq!(AssetRegistry::asset_id(&location(1)), None);
+            assert_eq!(AssetRegistry::asset_id(&location(5)), Some(USDT));
+            assert_eq!(AssetRegistry::location(&USDT), Some(location(5)));
+        });
+    }
+
+    #[test]
+    fn update_location_rejects_taken_location() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, None);
+            register(DOT, 2, None);
+            assert_noop!(
+                AssetRegistry::update_location(
+                    RuntimeOrigin::root(),
+                    USDT,
+                    Box::new(location(2).into()),
+                ),
+                Error::<Test>::LocationAlreadyRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn update_metadata_and_rate() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, None);
+            assert_ok!(AssetRegistry::update_metadata(
+                RuntimeOrigin::root(),
+                USDT,
+                b"Tether USD".to_vec(),
+                b"USDt".to_vec(),
+                6,
+            ));
+            assert_ok!(AssetRegistry::set_units_per_second(RuntimeOrigin::root(), USDT, Some(7)));
+            let asset = pallet::RegisteredAssets::<Test>::get(USDT).unwrap();
+            assert_eq!(asset.metadata.name.to_vec(), b"Tether USD".to_vec());
+            assert_eq!(AssetRegistry::units_per_second(&location(1)), Some(7));
+        });
+    }
+
+    #[test]
+    fn unregistered_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_noop!(
+                AssetRegistry::set_units_per_second(RuntimeOrigin::root(), UNREGISTERED, Some(1)),
+                Error::<Test>::AssetNotRegistered
+            );
+            assert_noop!(
+                AssetRegistry::deregister_asset(RuntimeOrigin::root(), UNREGISTERED),
+                Error::<Test>::AssetNotRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn deregister_clears_both_maps() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, Some(1_000));
+            assert_ok!(AssetRegistry::deregister_asset(RuntimeOrigin::root(), USDT));
+            assert!(!pallet::RegisteredAssets::<Test>::contains_key(USDT));
+            assert_eq!(AssetRegistry::asset_id(&location(1)), None);
+            // The asset itself stays in the assets pallet.
+            assert!(pallet_assets::Asset::<Test>::contains_key(USDT));
+        });
+    }
+}
+
+// ── adapters ────────────────────────────────────────────────────────────
+
+mod adapters {
+    use super::*;
+
+    type Trader = RegistryTrader<Test, RecordRevenue>;
+
+    /// `n`-th part of one second of execution weight.
+    fn seconds_part(n: u64) -> Weight { Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / n, 0) }
+
+    fn second() -> Weight { seconds_part(1) }
+
+    #[test]
+    fn converts_registered_locations() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, None);
+            assert_eq!(AssetIdForLocation::<Test>::convert_ref(location(1)), Ok(USDT));
+            assert_eq!(AssetIdForLocation::<Test>::reverse_ref(USDT), Ok(location(1)));
+            assert_eq!(AssetIdForLocation::<Test>::convert_ref(location(2)), Err(()));
+            assert_eq!(AssetIdForLocation::<Test>::reverse_ref(DOT), Err(()));
+        });
+    }
+
+    #[test]
+    fn charges_registered_rate() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, Some(1_000));
+            let mut trader = Trader::new();
+            let unused = trader.buy_weight(second(), payment(1, 1_500)).unwrap();
+            assert_eq!(unused, payment(1, 500));
+            drop(trader);
+            assert_eq!(RecordRevenue::taken(), vec![MultiAsset::from((location(1), 1_000))]);
+        });
+    }
+
+    #[test]
+    fn refunds_unused_weight() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, Some(1_000));
+            let mut trader = Trader::new();
+            assert_ok!(trader.buy_weight(second(), payment(1, 1_000)));
+            assert_eq!(trader.refund_weight(seconds_part(4)), Some((location(1), 250).into()));
+            drop(trader);
+            assert_eq!(RecordRevenue::taken(), vec![MultiAsset::from((location(1), 750))]);
+        });
+    }
+
+    #[test]
+    fn rejects_assets_without_rate() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, None);
+            let mut trader = Trader::new();
+            assert_eq!(trader.buy_weight(second(), payment(1, 1_000)), Err(XcmError::TooExpensive));
+            assert_eq!(trader.buy_weight(second(), payment(9, 1_000)), Err(XcmError::TooExpensive));
+        });
+    }
+
+    #[test]
+    fn rejects_insufficient_payment() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, Some(1_000));
+            let mut trader = Trader::new();
+            assert_eq!(trader.buy_weight(second(), payment(1, 999)), Err(XcmError::TooExpensive));
+            drop(trader);
+            assert!(RecordRevenue::taken().is_empty());
+        });
+    }
+
+    #[test]
+    fn later_purchases_use_the_same_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            register(USDT, 1, Some(1_000));
+            register(DOT, 2, Some(10));
+            let mut trader = Trader::new();
+            assert_ok!(trader.buy_weight(second(), payment(1, 1_000)));
+            assert_eq!(trader.buy_weight(second(), payment(2, 1_000)), Err(XcmError::TooExpensive));
+        });
+    }
+}
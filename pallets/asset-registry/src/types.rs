@@ -0,0 +1,26 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use xcm::latest::MultiLocation;
+
+/// Display metadata of a registered asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(StringLimit))]
+pub struct AssetMetadata<StringLimit: Get<u32>> {
+    pub name: BoundedVec<u8, StringLimit>,
+    pub symbol: BoundedVec<u8, StringLimit>,
+    pub decimals: u8,
+}
+
+/// Registry entry of a `pallet_assets` asset reachable over XCM.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(StringLimit))]
+pub struct AssetRegistration<StringLimit: Get<u32>> {
+    /// Location of the asset, relative to this chain.
+    pub location: MultiLocation,
+    pub metadata: AssetMetadata<StringLimit>,
+    /// Amount of the asset charged per second of execution weight
+    /// (None = not accepted as XCM fee payment).
+    pub units_per_second: Option<u128>,
+}
@@ -0,0 +1,101 @@
+//! Weights for `pallet_asset_registry`
+//!
+//! Hand-estimated from the storage accesses of each call, pending a run of
+//! the benchmark CLI on reference hardware:
+//!
+//! ./target/release/thxnet-leafchain benchmark pallet --chain thx-testnet \
+//!     --pallet pallet_asset_registry --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/asset-registry/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+pub trait WeightInfo {
+    fn register_asset() -> Weight;
+    fn update_location() -> Weight;
+    fn update_metadata() -> Weight;
+    fn set_units_per_second() -> Weight;
+    fn deregister_asset() -> Weight;
+}
+
+/// Weight functions for `pallet_asset_registry`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: Assets Asset (r:1 w:1)
+    /// Storage: AssetRegistry RegisteredAssets (r:1 w:1)
+    /// Storage: AssetRegistry LocationToAssetId (r:1 w:1)
+    fn register_asset() -> Weight {
+        // Estimated: asset creation plus both registry entries
+        Weight::from_parts(26_000_000, 8762)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: AssetRegistry LocationToAssetId (r:1 w:2)
+    /// Storage: AssetRegistry RegisteredAssets (r:1 w:1)
+    fn update_location() -> Weight {
+        // Estimated: mirrors register_asset without the assets pallet read
+        Weight::from_parts(20_000_000, 7228)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: AssetRegistry RegisteredAssets (r:1 w:1)
+    fn update_metadata() -> Weight {
+        // Estimated: single registry entry rewrite
+        Weight::from_parts(13_000_000, 3694)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: AssetRegistry RegisteredAssets (r:1 w:1)
+    fn set_units_per_second() -> Weight {
+        // Estimated: mirrors update_metadata
+        Weight::from_parts(13_000_000, 3694)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: AssetRegistry RegisteredAssets (r:1 w:1)
+    /// Storage: AssetRegistry LocationToAssetId (r:0 w:1)
+    fn deregister_asset() -> Weight {
+        // Estimated: registry entry take plus index removal
+        Weight::from_parts(15_000_000, 3694)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+}
+
+/// Fallback weights for testing / development.
+impl WeightInfo for () {
+    fn register_asset() -> Weight {
+        Weight::from_parts(26_000_000, 8762)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn update_location() -> Weight {
+        Weight::from_parts(20_000_000, 7228)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn update_metadata() -> Weight {
+        Weight::from_parts(13_000_000, 3694)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn set_units_per_second() -> Weight {
+        Weight::from_parts(13_000_000, 3694)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn deregister_asset() -> Weight {
+        Weight::from_parts(15_000_000, 3694)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+}
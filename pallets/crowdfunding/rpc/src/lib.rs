@@ -1,25 +1,61 @@
 use std::sync::Arc;
 
 use codec::Codec;
+use futures::{future, stream, FutureExt, StreamExt};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     proc_macros::rpc,
+    types::SubscriptionResult,
+    SubscriptionSink,
+};
+use pallet_crowdfunding::{
+    CampaignSummary, EligibilityError, EligibilityFailure, Investment, VestingStatus,
+    WhitelistProof, WithdrawalPreview,
 };
-use pallet_crowdfunding::{CampaignSummary, EligibilityError, Investment, WithdrawalPreview};
 use pallet_crowdfunding_runtime_api::CrowdfundingApi as CrowdfundingRuntimeApi;
-use sp_api::ProvideRuntimeApi;
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+
+/// Which campaign changes a `crowdfunding_subscribeCampaign` subscriber
+/// receives.  Unset fields match everything.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CampaignSubscription<AccountId> {
+    pub campaign_id: Option<u32>,
+    /// Only campaigns this account created or has (or had before the block)
+    /// invested in.
+    pub account: Option<AccountId>,
+    /// Follow finalized blocks instead of new best blocks.
+    pub finalized: bool,
+}
+
+/// A campaign named by a block's events, with its summary after the block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignUpdate<BlockHash, Balance, BlockNumber> {
+    pub block_hash: BlockHash,
+    pub campaign_id: u32,
+    /// `None` once the campaign has been removed.
+    pub summary: Option<CampaignSummary<Balance, BlockNumber>>,
+}
 
 #[rpc(client, server)]
 pub trait CrowdfundingRpc<BlockHash, AccountId, Balance, BlockNumber, AssetId> {
+    /// Whether `who` may invest in `campaign_id`.  Blocks from before
+    /// `CrowdfundingApi` version 2 ignore `proof` and do not say which rule
+    /// failed; such failures are reported as `Rule { index: 0, .. }`.
     #[method(name = "crowdfunding_checkEligibility")]
     fn check_eligibility(
         &self,
         campaign_id: u32,
         who: AccountId,
+        proof: Option<WhitelistProof<Balance>>,
         at: Option<BlockHash>,
-    ) -> RpcResult<Result<(), EligibilityError>>;
+    ) -> RpcResult<Result<(), EligibilityFailure>>;
 
     #[method(name = "crowdfunding_previewWithdrawal")]
     fn preview_withdrawal(
@@ -37,6 +73,13 @@ pub trait CrowdfundingRpc<BlockHash, AccountId, Balance, BlockNumber, AssetId> {
         at: Option<BlockHash>,
     ) -> RpcResult<Option<CampaignSummary<Balance, BlockNumber>>>;
 
+    #[method(name = "crowdfunding_vestingStatus")]
+    fn vesting_status(
+        &self,
+        campaign_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<VestingStatus<Balance, BlockNumber>>>;
+
     #[method(name = "crowdfunding_campaignsByCreator")]
     fn campaigns_by_creator(
         &self,
@@ -61,15 +104,113 @@ pub trait CrowdfundingRpc<BlockHash, AccountId, Balance, BlockNumber, AssetId> {
 
     #[method(name = "crowdfunding_getProtocolConfig")]
     fn get_protocol_config(&self, at: Option<BlockHash>) -> RpcResult<(u16, AccountId)>;
+
+    /// Push the campaign changes of each new best (or finalized) block that
+    /// match `filter`.
+    #[subscription(
+        name = "crowdfunding_subscribeCampaign" => "crowdfunding_campaign",
+        unsubscribe = "crowdfunding_unsubscribeCampaign",
+        item = CampaignUpdate<BlockHash, Balance, BlockNumber>,
+    )]
+    fn subscribe_campaign(&self, filter: CampaignSubscription<AccountId>);
 }
 
 pub struct CrowdfundingRpcHandler<C, Block> {
     client: Arc<C>,
+    executor: SubscriptionTaskExecutor,
     _marker: std::marker::PhantomData<Block>,
 }
 
 impl<C, Block> CrowdfundingRpcHandler<C, Block> {
-    pub fn new(client: Arc<C>) -> Self { Self { client, _marker: Default::default() } }
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+        Self { client, executor, _marker: Default::default() }
+    }
+}
+
+impl<C, Block> CrowdfundingRpcHandler<C, Block>
+where
+    Block: BlockT,
+    C: BlockchainEvents<Block>,
+{
+    /// Hashes of new best blocks, or of every newly finalized block in order.
+    fn block_hashes(&self, finalized: bool) -> stream::BoxStream<'static, Block::Hash> {
+        if finalized {
+            self.client
+                .finality_notification_stream()
+                .flat_map(|n| {
+                    let hashes: Vec<_> = n.tree_route.iter().copied().chain([n.hash]).collect();
+                    stream::iter(hashes)
+                })
+                .boxed()
+        } else {
+            self.client
+                .import_notification_stream()
+                .filter(|n| future::ready(n.is_new_best))
+                .map(|n| n.hash)
+                .boxed()
+        }
+    }
+}
+
+/// The campaign changes in block `hash` that match `filter`.
+fn campaign_updates<C, Block, AccountId, Balance, BlockNumber, AssetId>(
+    client: &C,
+    hash: Block::Hash,
+    filter: &CampaignSubscription<AccountId>,
+) -> Vec<CampaignUpdate<Block::Hash, Balance, BlockNumber>>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: CrowdfundingRuntimeApi<Block, AccountId, Balance, BlockNumber, AssetId>,
+    AccountId: Codec + Clone,
+    Balance: Codec,
+    BlockNumber: Codec,
+    AssetId: Codec,
+{
+    let api = client.runtime_api();
+    let touched = match api.campaign_updates(hash) {
+        Ok(touched) => touched,
+        Err(e) => {
+            log::debug!(target: "crowdfunding-rpc", "campaign_updates failed: {:?}", e);
+            return Vec::new();
+        }
+    };
+    let related = |at: Block::Hash| match &filter.account {
+        Some(account) => {
+            let mut ids = api.campaigns_by_creator(at, account.clone()).unwrap_or_default();
+            ids.extend(api.campaigns_by_investor(at, account.clone()).unwrap_or_default());
+            ids
+        }
+        None => Vec::new(),
+    };
+    let (mut related_after, mut related_before) = (None, None);
+
+    let mut updates = Vec::new();
+    for campaign_id in touched {
+        if filter.campaign_id.map_or(false, |id| id != campaign_id) {
+            continue;
+        }
+        if filter.account.is_some() {
+            let after = related_after.get_or_insert_with(|| related(hash));
+            if !after.contains(&campaign_id) {
+                let before = related_before.get_or_insert_with(|| {
+                    match client.header(hash).ok().flatten() {
+                        Some(header) => related(*header.parent_hash()),
+                        None => Vec::new(),
+                    }
+                });
+                if !before.contains(&campaign_id) {
+                    continue;
+                }
+            }
+        }
+        let summary = match api.campaign_summary(hash, campaign_id) {
+            Ok(summary) => summary,
+            Err(_) => continue,
+        };
+        updates.push(CampaignUpdate { block_hash: hash, campaign_id, summary });
+    }
+    updates
 }
 
 /// L-7: map a runtime API error to an RPC error, logging the debug format
@@ -85,22 +226,46 @@ impl<C, Block, AccountId, Balance, BlockNumber, AssetId>
     for CrowdfundingRpcHandler<C, Block>
 where
     Block: BlockT,
-    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C: Send
+        + Sync
+        + 'static
+        + ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockchainEvents<Block>,
     C::Api: CrowdfundingRuntimeApi<Block, AccountId, Balance, BlockNumber, AssetId>,
-    AccountId: Codec + Send + Sync + 'static,
-    Balance: Codec + Send + Sync + 'static,
-    BlockNumber: Codec + Send + Sync + 'static,
+    AccountId: Codec + Clone + Send + Sync + 'static,
+    Balance: Codec + Serialize + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
     AssetId: Codec + Send + Sync + 'static,
 {
     fn check_eligibility(
         &self,
         campaign_id: u32,
         who: AccountId,
+        proof: Option<WhitelistProof<Balance>>,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Result<(), EligibilityError>> {
+    ) -> RpcResult<Result<(), EligibilityFailure>> {
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
-        api.check_eligibility(at_hash, campaign_id, who).map_err(runtime_api_error)
+        let version = api
+            .api_version::<dyn CrowdfundingRuntimeApi<Block, AccountId, Balance, BlockNumber, AssetId>>(
+                at_hash,
+            )
+            .map_err(runtime_api_error)?;
+        if version.map_or(false, |v| v >= 2) {
+            return api
+                .check_eligibility(at_hash, campaign_id, who, proof)
+                .map_err(runtime_api_error);
+        }
+
+        #[allow(deprecated)]
+        let result = api
+            .check_eligibility_before_version_2(at_hash, campaign_id, who)
+            .map_err(runtime_api_error)?;
+        Ok(result.map_err(|error| match error {
+            EligibilityError::CampaignNotFound => EligibilityFailure::CampaignNotFound,
+            error => EligibilityFailure::Rule { index: 0, error },
+        }))
     }
 
     fn preview_withdrawal(
@@ -125,6 +290,26 @@ where
         api.campaign_summary(at_hash, campaign_id).map_err(runtime_api_error)
     }
 
+    fn vesting_status(
+        &self,
+        campaign_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<VestingStatus<Balance, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        // Older runtimes have no vesting schedules.
+        let supported = api
+            .has_api_with::<dyn CrowdfundingRuntimeApi<Block, AccountId, Balance, BlockNumber, AssetId>, _>(
+                at_hash,
+                |v| v >= 2,
+            )
+            .map_err(runtime_api_error)?;
+        if !supported {
+            return Ok(None);
+        }
+        api.vesting_status(at_hash, campaign_id).map_err(runtime_api_error)
+    }
+
     fn campaigns_by_creator(
         &self,
         creator: AccountId,
@@ -164,4 +349,21 @@ where
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
         api.get_protocol_config(at_hash).map_err(runtime_api_error)
     }
+
+    fn subscribe_campaign(
+        &self,
+        sink: SubscriptionSink,
+        filter: CampaignSubscription<AccountId>,
+    ) -> SubscriptionResult {
+        let client = self.client.clone();
+        let updates = self
+            .block_hashes(filter.finalized)
+            .flat_map(move |hash| stream::iter(campaign_updates(&*client, hash, &filter)))
+            .boxed();
+        let fut = async move {
+            sink.pipe_from_stream(updates).await;
+        };
+        self.executor.spawn("crowdfunding-campaign-subscription", Some("rpc"), fut.boxed());
+        Ok(())
+    }
 }
@@ -1,10 +1,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-use pallet_crowdfunding::{CampaignSummary, EligibilityError, Investment, WithdrawalPreview};
+use pallet_crowdfunding::{
+    CampaignSummary, EligibilityError, EligibilityFailure, Investment, VestingStatus,
+    WhitelistProof, WithdrawalPreview,
+};
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait CrowdfundingApi<AccountId, Balance, BlockNumber, AssetId>
     where
         AccountId: Codec,
@@ -12,11 +16,18 @@ sp_api::decl_runtime_apis! {
         BlockNumber: Codec,
         AssetId: Codec,
     {
+        #[changed_in(2)]
         fn check_eligibility(
             campaign_id: u32,
             who: AccountId,
         ) -> Result<(), EligibilityError>;
 
+        fn check_eligibility(
+            campaign_id: u32,
+            who: AccountId,
+            proof: Option<WhitelistProof<Balance>>,
+        ) -> Result<(), EligibilityFailure>;
+
         fn preview_withdrawal(
             campaign_id: u32,
             investor: AccountId,
@@ -25,6 +36,9 @@ sp_api::decl_runtime_apis! {
 
         fn campaign_summary(campaign_id: u32) -> Option<CampaignSummary<Balance, BlockNumber>>;
 
+        #[api_version(2)]
+        fn vesting_status(campaign_id: u32) -> Option<VestingStatus<Balance, BlockNumber>>;
+
         fn campaigns_by_creator(creator: AccountId) -> Vec<u32>;
 
         fn campaigns_by_investor(investor: AccountId) -> Vec<u32>;
@@ -32,5 +46,9 @@ sp_api::decl_runtime_apis! {
         fn get_investment(campaign_id: u32, investor: AccountId) -> Option<Investment<Balance>>;
 
         fn get_protocol_config() -> (u16, AccountId);
+
+        /// Campaigns named by this block's events, without duplicates.
+        #[api_version(2)]
+        fn campaign_updates() -> Vec<u32>;
     }
 }
@@ -1,18 +1,27 @@
 #![cfg(feature = "runtime-benchmarks")]
 
+use codec::{Decode, Encode};
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::{
-    traits::{Currency, Get},
+    traits::{tokens::fungibles, Currency, Get},
     BoundedVec,
 };
 use frame_system::RawOrigin;
-use sp_runtime::{traits::Bounded, Saturating};
-use sp_std::vec;
+use sp_runtime::{
+    traits::{Bounded, TrailingZeroInput, Zero},
+    Perbill, Saturating,
+};
+use sp_std::{vec, vec::Vec};
 
 use super::*;
 use crate::pallet::{
-    BalanceOf, CampaignConfigOf, CampaignWhitelist, Campaigns, EligibilityRuleOf, Investments,
-    MilestoneStatuses, NextCampaignId,
+    AcceptedCurrencies, ArchivedCampaigns, AssetOfferings, AssetShares, BalanceOf,
+    CampaignConfigOf, CampaignWhitelist, Campaigns, CommittedAssetFraction, CreatorBondBps,
+    EligibilityExpressionOf, EligibilityExpressions, EligibilityRuleOf, FeeSponsorships,
+    FinalizationKeys, FundingRoundOf, FundingRounds, Investments, InvestorTiers,
+    IssuedAssetFraction, MilestoneStatuses, NextCampaignId, PenaltySchedules, PenaltyShareClaims,
+    PendingCleanups, RegistrationPhases, Registrations, RewardTierOf, RewardTiers, StretchGoals,
+    TransferLockups, VestingSchedules, WhitelistAllowances, WhitelistRoots,
 };
 
 /// Investment/goal amount large enough to exceed existential deposit on any
@@ -33,6 +42,7 @@ fn campaign_config<T: Config>(deadline: T::BlockNumber) -> CampaignConfigOf<T> {
         max_investment_per_investor: None,
         metadata_hash: [0u8; 32],
         early_withdrawal_penalty_bps: None,
+        early_bird_bonus: None,
     }
 }
 
@@ -54,6 +64,18 @@ fn milestone_campaign_config<T: Config>(deadline: T::BlockNumber) -> CampaignCon
         max_investment_per_investor: None,
         metadata_hash: [0u8; 32],
         early_withdrawal_penalty_bps: None,
+        early_bird_bonus: None,
+    }
+}
+
+// Helper to create a reward tier without an NFT reward
+fn reward_tier<T: Config>() -> RewardTierOf<T> {
+    RewardTier {
+        min_amount: benchmark_amount::<T>() / 4u32.into(),
+        quantity: 100,
+        remaining: 100,
+        description_hash: [3u8; 32],
+        nft_collection: None,
     }
 }
 
@@ -187,6 +209,8 @@ benchmarks! {
     approve_milestone {
         let caller: T::AccountId = whitelisted_caller();
         T::NativeCurrency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+        // Worst case: the milestone releases part of a creator bond.
+        CreatorBondBps::<T>::put(1_000);
         let now = frame_system::Pallet::<T>::block_number();
         let deadline = now + T::MinCampaignDuration::get() + 1u32.into();
         let config = milestone_campaign_config::<T>(deadline);
@@ -348,6 +372,12 @@ benchmarks! {
         let recipient: T::AccountId = account("recipient", 0, 0);
     }: _(RawOrigin::Root, 500u16, recipient)
 
+    set_creator_bond_bps {
+    }: _(RawOrigin::Root, 1_000u16)
+    verify {
+        assert_eq!(CreatorBondBps::<T>::get(), 1_000);
+    }
+
     report_license_revoked {
         // Setup: create a campaign, then mutate it to have a linked license
         // and mark the license as revoked so the extrinsic succeeds.
@@ -376,5 +406,409 @@ benchmarks! {
         assert!(matches!(c.status, CampaignStatus::Cancelled));
     }
 
+    set_reward_tiers {
+        let t in 0 .. T::MaxRewardTiers::get();
+        let (id, creator) = setup_funded_campaign::<T>();
+        let tiers: BoundedVec<RewardTierOf<T>, T::MaxRewardTiers> = (0..t)
+            .map(|_| reward_tier::<T>())
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("within max reward tiers");
+    }: _(RawOrigin::Signed(creator), id, tiers)
+    verify {
+        assert_eq!(RewardTiers::<T>::get(id).map_or(0, |tiers| tiers.len() as u32), t);
+    }
+
+    invest_with_tier {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let tiers: BoundedVec<RewardTierOf<T>, T::MaxRewardTiers> =
+            vec![reward_tier::<T>()].try_into().expect("within max reward tiers");
+        Pallet::<T>::set_reward_tiers(RawOrigin::Signed(creator).into(), id, tiers)
+            .expect("set_reward_tiers failed");
+        let investor: T::AccountId = account("investor", 0, 0);
+        T::NativeCurrency::make_free_balance_be(&investor, BalanceOf::<T>::max_value() / 2u32.into());
+        let invest_amount = benchmark_amount::<T>() / 2u32.into();
+    }: _(RawOrigin::Signed(investor.clone()), id, invest_amount, 0u8)
+    verify {
+        assert!(InvestorTiers::<T>::contains_key(id, &investor));
+    }
+
+    claim_reward_nft {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let mut tier = reward_tier::<T>();
+        tier.nft_collection = Some(T::BenchmarkHelper::reward_collection(&creator));
+        let tiers: BoundedVec<RewardTierOf<T>, T::MaxRewardTiers> =
+            vec![tier].try_into().expect("within max reward tiers");
+        Pallet::<T>::set_reward_tiers(RawOrigin::Signed(creator.clone()).into(), id, tiers)
+            .expect("set_reward_tiers failed");
+        let investor: T::AccountId = account("investor", 0, 0);
+        T::NativeCurrency::make_free_balance_be(&investor, BalanceOf::<T>::max_value() / 2u32.into());
+        Pallet::<T>::invest_with_tier(
+            RawOrigin::Signed(investor.clone()).into(),
+            id,
+            benchmark_amount::<T>(),
+            0u8,
+        )
+        .expect("invest_with_tier failed");
+        let campaign = Campaigns::<T>::get(id).unwrap();
+        frame_system::Pallet::<T>::set_block_number(campaign.config.deadline + 1u32.into());
+        Pallet::<T>::finalize_campaign(RawOrigin::Signed(creator).into(), id)
+            .expect("finalize failed");
+    }: _(RawOrigin::Signed(investor.clone()), id)
+    verify {
+        assert!(InvestorTiers::<T>::get(id, &investor).map_or(false, |alloc| alloc.reward_minted));
+    }
+
+    process_refunds {
+        let n in 1 .. T::MaxRefundsPerCall::get();
+        let (id, _creator) = setup_funded_campaign::<T>();
+        let invest_amount = benchmark_amount::<T>() / 10u32.into();
+        for i in 0..n {
+            let investor: T::AccountId = account("investor", i, 0);
+            T::NativeCurrency::make_free_balance_be(&investor, BalanceOf::<T>::max_value() / 4u32.into());
+            Pallet::<T>::invest(RawOrigin::Signed(investor).into(), id, invest_amount)
+                .expect("invest failed");
+        }
+        Pallet::<T>::cancel_campaign(RawOrigin::Root.into(), id).expect("cancel failed");
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), id, n)
+    verify {
+        assert_eq!(Investments::<T>::iter_prefix(id).count(), 0);
+    }
+
+    reap_campaign {
+        let n in 1 .. T::MaxReapsPerCall::get();
+        let (id, creator) = setup_funded_campaign::<T>();
+        let invest_amount = benchmark_amount::<T>();
+        for i in 0..n {
+            let investor: T::AccountId = account("investor", i, 0);
+            T::NativeCurrency::make_free_balance_be(&investor, BalanceOf::<T>::max_value() / 4u32.into());
+            Pallet::<T>::invest(RawOrigin::Signed(investor).into(), id, invest_amount)
+                .expect("invest failed");
+        }
+        let campaign = Campaigns::<T>::get(id).unwrap();
+        frame_system::Pallet::<T>::set_block_number(campaign.config.deadline + 1u32.into());
+        Pallet::<T>::finalize_campaign(RawOrigin::Signed(creator.clone()).into(), id)
+            .expect("finalize failed");
+        Pallet::<T>::claim_funds(RawOrigin::Signed(creator.clone()).into(), id)
+            .expect("claim_funds failed");
+        Pallet::<T>::claim_creation_deposit(RawOrigin::Signed(creator).into(), id)
+            .expect("claim_creation_deposit failed");
+        let caller: T::AccountId = account("reaper", 0, 0);
+    }: _(RawOrigin::Signed(caller), id, n)
+    verify {
+        assert!(ArchivedCampaigns::<T>::contains_key(id));
+        assert!(!Campaigns::<T>::contains_key(id));
+    }
+
+    set_eligibility_expression {
+        let n in 1 .. T::MaxEligibilityNodes::get();
+        let (id, creator) = setup_funded_campaign::<T>();
+        // A leaf under a chain of `Not` nodes: the deepest valid tree.
+        let mut nodes = vec![EligibilityNode::Rule(EligibilityRule::AccountWhitelist)];
+        for i in 1..n {
+            nodes.push(EligibilityNode::Not((i - 1) as u8));
+        }
+        let expression: EligibilityExpressionOf<T> =
+            nodes.try_into().expect("within max eligibility nodes");
+    }: _(RawOrigin::Signed(creator), id, expression)
+    verify {
+        assert_eq!(EligibilityExpressions::<T>::get(id).map_or(0, |e| e.len() as u32), n);
+    }
+
+    set_registration_phase {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let opens_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+    }: _(RawOrigin::Signed(creator), id, opens_at)
+    verify {
+        assert_eq!(RegistrationPhases::<T>::get(id), Some(opens_at));
+    }
+
+    register_interest {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let opens_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        Pallet::<T>::set_registration_phase(RawOrigin::Signed(creator).into(), id, opens_at)
+            .expect("set_registration_phase failed");
+        let caller: T::AccountId = account("registrant", 0, 0);
+        T::NativeCurrency::make_free_balance_be(&caller, benchmark_amount::<T>());
+    }: _(RawOrigin::Signed(caller.clone()), id)
+    verify {
+        assert!(Registrations::<T>::contains_key(id, &caller));
+    }
+
+    set_whitelist_root {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let root = [7u8; 32];
+    }: _(RawOrigin::Signed(creator), id, Some(root))
+    verify {
+        assert_eq!(WhitelistRoots::<T>::get(id), Some(root));
+    }
+
+    invest_with_proof {
+        let n in 0 .. T::MaxWhitelistProofDepth::get();
+        let (id, creator) = setup_funded_campaign::<T>();
+        let investor: T::AccountId = account("investor", 0, 0);
+        T::NativeCurrency::make_free_balance_be(&investor, BalanceOf::<T>::max_value() / 2u32.into());
+        let proof = WhitelistProof {
+            cap: None,
+            siblings: (0..n).map(|i| [i as u8; 32]).collect::<Vec<_>>(),
+        };
+        let root = Pallet::<T>::whitelist_proof_root(&investor, &proof);
+        Pallet::<T>::set_whitelist_root(RawOrigin::Signed(creator).into(), id, Some(root))
+            .expect("set_whitelist_root failed");
+        let invest_amount = benchmark_amount::<T>();
+    }: _(RawOrigin::Signed(investor.clone()), id, invest_amount, proof)
+    verify {
+        assert!(WhitelistAllowances::<T>::contains_key(id, &investor));
+    }
+
+    set_accepted_currencies {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let currencies: Vec<PaymentCurrency<T::AssetId>> = (0..T::MaxAcceptedCurrencies::get())
+            .map(|i| {
+                let asset_id = T::AssetId::decode(&mut TrailingZeroInput::new(&i.encode()))
+                    .expect("infinite input; qed");
+                PaymentCurrency::Asset(asset_id)
+            })
+            .collect();
+        let currencies: BoundedVec<_, T::MaxAcceptedCurrencies> =
+            currencies.try_into().expect("within max accepted currencies");
+    }: _(RawOrigin::Signed(creator), id, currencies)
+    verify {
+        assert!(AcceptedCurrencies::<T>::contains_key(id));
+    }
+
+    invest_in_currency {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let asset_id = T::BenchmarkHelper::payment_asset();
+        let currency = PaymentCurrency::Asset(asset_id);
+        let currencies: BoundedVec<_, T::MaxAcceptedCurrencies> =
+            vec![currency.clone()].try_into().expect("within max accepted currencies");
+        Pallet::<T>::set_accepted_currencies(RawOrigin::Signed(creator).into(), id, currencies)
+            .expect("set_accepted_currencies failed");
+        let investor: T::AccountId = account("investor", 0, 0);
+        T::NativeCurrency::make_free_balance_be(&investor, BalanceOf::<T>::max_value() / 2u32.into());
+        <T::Fungibles as fungibles::Mutate<T::AccountId>>::mint_into(
+            asset_id,
+            &investor,
+            benchmark_amount::<T>(),
+        )
+        .expect("mint failed");
+        let invest_amount = benchmark_amount::<T>() / 2u32.into();
+    }: _(RawOrigin::Signed(investor.clone()), id, currency, invest_amount)
+    verify {
+        assert!(Investments::<T>::contains_key(id, &investor));
+    }
+
+    configure_asset_offering {
+        let (id, creator) = setup_funded_campaign::<T>();
+        Campaigns::<T>::mutate(id, |c| {
+            if let Some(c) = c {
+                c.rwa_asset_id = Some(0);
+            }
+        });
+        // Valued in another currency, so the valuation goes through
+        // `PriceFeed`.
+        let asset_id = T::BenchmarkHelper::payment_asset();
+        T::BenchmarkHelper::set_valuation(
+            0,
+            PaymentCurrency::Asset(asset_id),
+            benchmark_amount::<T>().saturating_mul(2u32.into()),
+        );
+    }: _(RawOrigin::Signed(creator), id, Perbill::from_percent(50))
+    verify {
+        assert!(AssetOfferings::<T>::contains_key(id));
+    }
+
+    claim_asset_share {
+        let (id, _creator, investor) = setup_invested_campaign::<T>();
+        Campaigns::<T>::mutate(id, |c| {
+            if let Some(c) = c {
+                c.status = CampaignStatus::Succeeded;
+            }
+        });
+        let fraction = Perbill::from_percent(50);
+        AssetOfferings::<T>::insert(
+            id,
+            AssetOffering {
+                rwa_asset_id: 0,
+                fraction,
+                valuation: benchmark_amount::<T>().saturating_mul(2u32.into()),
+                issued: Perbill::zero(),
+            },
+        );
+        CommittedAssetFraction::<T>::insert(0, fraction);
+    }: _(RawOrigin::Signed(investor.clone()), id)
+    verify {
+        assert!(AssetShares::<T>::contains_key(0, &investor));
+    }
+
+    surrender_asset_share {
+        let holder: T::AccountId = whitelisted_caller();
+        AssetShares::<T>::insert(0, &holder, Perbill::from_percent(10));
+        IssuedAssetFraction::<T>::insert(0, Perbill::from_percent(10));
+    }: _(RawOrigin::Signed(holder.clone()), 0)
+    verify {
+        assert!(!AssetShares::<T>::contains_key(0, &holder));
+    }
+
+    set_stretch_goals {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let goals: Vec<StretchGoal<BalanceOf<T>>> = (0..T::MaxStretchGoals::get())
+            .map(|i| StretchGoal {
+                threshold: benchmark_amount::<T>().saturating_mul((i + 2).into()),
+                description_hash: [4u8; 32],
+            })
+            .collect();
+        let goals: BoundedVec<_, T::MaxStretchGoals> =
+            goals.try_into().expect("within max stretch goals");
+    }: _(RawOrigin::Signed(creator), id, goals)
+    verify {
+        assert!(StretchGoals::<T>::contains_key(id));
+    }
+
+    set_vesting_schedule {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let schedule = VestingSchedule { cliff: 10u32.into(), duration: 100u32.into() };
+    }: _(RawOrigin::Signed(creator), id, Some(schedule))
+    verify {
+        assert!(VestingSchedules::<T>::contains_key(id));
+    }
+
+    set_funding_rounds {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let now = frame_system::Pallet::<T>::block_number();
+        let deadline = Campaigns::<T>::get(id).expect("campaign exists").config.deadline;
+        let count = T::MaxFundingRounds::get();
+        let rounds: Vec<FundingRoundOf<T>> = (0..count)
+            .map(|i| FundingRound {
+                deadline: if i + 1 == count { deadline } else { now + (i + 1).into() },
+                cap: benchmark_amount::<T>().saturating_mul((i + 1).into()),
+                min_investment: None,
+            })
+            .collect();
+        let rounds: BoundedVec<_, T::MaxFundingRounds> =
+            rounds.try_into().expect("within max funding rounds");
+    }: _(RawOrigin::Signed(creator), id, rounds)
+    verify {
+        assert!(FundingRounds::<T>::contains_key(id));
+    }
+
+    set_withdrawal_penalty {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let steps: Vec<PenaltyStep<T::BlockNumber>> = (0..T::MaxPenaltySteps::get())
+            .map(|i| PenaltyStep {
+                within: (T::MaxPenaltySteps::get() - i).into(),
+                penalty_bps: 500u16.saturating_mul(i as u16 + 1),
+            })
+            .collect();
+        let schedule = PenaltySchedule {
+            cooling_off: 1u32.into(),
+            steps: steps.try_into().expect("within max penalty steps"),
+        };
+    }: _(RawOrigin::Signed(creator), id, Some(schedule), PenaltyDestination::RemainingInvestors)
+    verify {
+        assert!(PenaltySchedules::<T>::contains_key(id));
+    }
+
+    claim_penalty_share {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let deadline = Campaigns::<T>::get(id).expect("campaign exists").config.deadline;
+        let step = PenaltyStep { within: deadline, penalty_bps: 5_000 };
+        let schedule = PenaltySchedule {
+            cooling_off: Zero::zero(),
+            steps: vec![step].try_into().expect("within max penalty steps"),
+        };
+        Pallet::<T>::set_withdrawal_penalty(
+            RawOrigin::Signed(creator).into(),
+            id,
+            Some(schedule),
+            PenaltyDestination::RemainingInvestors,
+        ).expect("set_withdrawal_penalty failed");
+        let investor: T::AccountId = account("investor", 0, 0);
+        let leaver: T::AccountId = account("investor", 1, 0);
+        for who in [&investor, &leaver] {
+            T::NativeCurrency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+            Pallet::<T>::invest(RawOrigin::Signed(who.clone()).into(), id, benchmark_amount::<T>())
+                .expect("invest failed");
+        }
+        Pallet::<T>::withdraw_investment(
+            RawOrigin::Signed(leaver).into(),
+            id,
+            benchmark_amount::<T>(),
+        ).expect("withdraw failed");
+        frame_system::Pallet::<T>::set_block_number(deadline + 1u32.into());
+        Campaigns::<T>::mutate(id, |c| {
+            if let Some(c) = c {
+                c.status = CampaignStatus::Succeeded;
+            }
+        });
+    }: _(RawOrigin::Signed(investor.clone()), id)
+    verify {
+        assert!(PenaltyShareClaims::<T>::contains_key(id, &investor));
+    }
+
+    transfer_investment {
+        let (id, _creator, investor) = setup_invested_campaign::<T>();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let amount = benchmark_amount::<T>() / 2u32.into();
+    }: _(RawOrigin::Signed(investor), id, recipient.clone(), amount)
+    verify {
+        assert!(Investments::<T>::contains_key(id, &recipient));
+    }
+
+    set_transfer_lockup {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let until = frame_system::Pallet::<T>::block_number() + 100u32.into();
+    }: _(RawOrigin::Signed(creator), id, Some(until))
+    verify {
+        assert!(TransferLockups::<T>::contains_key(id));
+    }
+
+    fund_fee_sponsorship {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let amount = T::NativeCurrency::minimum_balance().saturating_mul(100u32.into());
+    }: _(RawOrigin::Signed(creator), id, amount, 10)
+    verify {
+        assert!(FeeSponsorships::<T>::contains_key(id));
+    }
+
+    end_fee_sponsorship {
+        let (id, creator) = setup_funded_campaign::<T>();
+        let amount = T::NativeCurrency::minimum_balance().saturating_mul(100u32.into());
+        Pallet::<T>::fund_fee_sponsorship(
+            RawOrigin::Signed(creator.clone()).into(),
+            id,
+            amount,
+            10,
+        )
+        .expect("fund_fee_sponsorship failed");
+    }: _(RawOrigin::Signed(creator), id)
+    verify {
+        assert!(!FeeSponsorships::<T>::contains_key(id));
+    }
+
+    set_finalization_key {
+        let key: T::AccountId = account("key", 0, 0);
+    }: _(RawOrigin::Root, key.clone(), true)
+    verify {
+        assert!(FinalizationKeys::<T>::contains_key(&key));
+    }
+
+    idle_cleanup {
+        let n in 1 .. T::MaxIdleCleanups::get();
+        let (id, _creator) = setup_funded_campaign::<T>();
+        for i in 0..n {
+            let account: T::AccountId = account("whitelisted", i, 0);
+            CampaignWhitelist::<T>::insert(id, &account, true);
+        }
+        PendingCleanups::<T>::insert(id, ());
+    }: {
+        Pallet::<T>::do_cleanup(id, n);
+    }
+    verify {
+        assert_eq!(CampaignWhitelist::<T>::iter_prefix(id).count(), 0);
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
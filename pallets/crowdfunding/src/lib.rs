@@ -4,6 +4,7 @@ pub mod migrations;
 pub mod types;
 pub mod weights;
 
+pub use migrations::stepped::SteppedMigration;
 pub use pallet::*;
 pub use types::*;
 pub use weights::WeightInfo;
@@ -18,21 +19,64 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 
+use frame_system::offchain::{SignedPayload, SigningTypes};
+use sp_core::crypto::KeyTypeId;
+
+/// Key type of the keys signing the offchain worker's finalization payloads.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"cfnd");
+
+/// sr25519 keys under [`KEY_TYPE`], for the offchain worker's signed payloads.
+pub mod crypto {
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    use super::KEY_TYPE;
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    pub struct FinalizationAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for FinalizationAuthId {
+        type GenericPublic = sp_core::sr25519::Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type RuntimeAppPublic = Public;
+    }
+}
+
+impl<T: SigningTypes> SignedPayload<T> for FinalizationPayload<T::Public, T::BlockNumber> {
+    fn public(&self) -> T::Public { self.public.clone() }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         pallet_prelude::*,
+        storage,
         traits::{
             tokens::{fungibles, nonfungibles_v2},
-            Currency, ExistenceRequirement, WithdrawReasons,
+            Currency, ExistenceRequirement, Imbalance, WithdrawReasons,
         },
         PalletId,
     };
-    use frame_system::pallet_prelude::*;
+    use frame_system::{
+        offchain::{AppCrypto, SendTransactionTypes, SendUnsignedTransaction, Signer},
+        pallet_prelude::*,
+    };
     use sp_runtime::{
-        traits::{AccountIdConversion, Saturating, Zero},
-        Permill,
+        helpers_128bit::multiply_by_rational_with_rounding,
+        offchain::storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+        traits::{
+            AccountIdConversion, IdentifyAccount, One, Saturating, UniqueSaturatedInto, Zero,
+        },
+        transaction_validity::{
+            InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
+            ValidTransaction,
+        },
+        Perbill, Permill, Rounding, RuntimeAppPublic,
     };
+    use sp_std::{vec, vec::Vec};
 
     use super::*;
 
@@ -40,6 +84,10 @@ pub mod pallet {
         <T as frame_system::Config>::AccountId,
     >>::Balance;
 
+    pub type NegativeImbalanceOf<T> = <<T as Config>::NativeCurrency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+
     pub type CampaignOf<T> = Campaign<
         <T as frame_system::Config>::AccountId,
         BalanceOf<T>,
@@ -69,14 +117,56 @@ pub mod pallet {
         <T as Config>::MaxNftsPerSet,
     >;
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    pub type RewardTierOf<T> = RewardTier<BalanceOf<T>, <T as Config>::CollectionId>;
+
+    pub type FundingRoundOf<T> =
+        FundingRound<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+    pub type VestingScheduleOf<T> = VestingSchedule<<T as frame_system::Config>::BlockNumber>;
+
+    pub type PenaltyScheduleOf<T> =
+        PenaltySchedule<<T as frame_system::Config>::BlockNumber, <T as Config>::MaxPenaltySteps>;
+
+    pub type RegistrationSnapshotOf<T> = RegistrationSnapshot<
+        BalanceOf<T>,
+        <T as Config>::AssetId,
+        <T as Config>::MaxSnapshotAssets,
+    >;
+
+    pub type CurrencyHoldingOf<T> = CurrencyHolding<<T as Config>::AssetId, BalanceOf<T>>;
+
+    pub type CurrencyHoldingsOf<T> =
+        BoundedVec<CurrencyHoldingOf<T>, <T as Config>::MaxAcceptedCurrencies>;
+
+    pub type EligibilityExpressionOf<T> =
+        BoundedVec<EligibilityNode<EligibilityRuleOf<T>>, <T as Config>::MaxEligibilityNodes>;
+
+    pub type ArchivedCampaignOf<T> = ArchivedCampaign<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+        <T as Config>::AssetId,
+    >;
+
+    pub type MigrationCursorOf<T> = MigrationCursor<<T as frame_system::Config>::BlockNumber>;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
+    /// Taken serials `do_mint_reward` skips before giving up on a mint.
+    const MAX_REWARD_SERIAL_SKIPS: u32 = 16;
+
+    /// Full deadline slots `resume_campaign` rolls a deadline past before
+    /// giving up.
+    const MAX_DEADLINE_ROLLOVERS: u32 = 16;
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config:
+        frame_system::Config + SendTransactionTypes<Call<Self>> + SigningTypes
+    {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type AssetId: Member + Parameter + Copy + MaxEncodedLen;
         type CollectionId: Member + Parameter + Copy + MaxEncodedLen;
@@ -93,6 +183,8 @@ pub mod pallet {
         type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         type MilestoneApprover: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to pause and resume campaigns.
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         #[pallet::constant]
         type PalletId: Get<PalletId>;
         #[pallet::constant]
@@ -123,10 +215,113 @@ pub mod pallet {
         /// unbounded growth from a malicious campaign creator.
         #[pallet::constant]
         type MaxWhitelistSize: Get<u32>;
+        /// Maximum number of sibling hashes in a whitelist Merkle proof.
+        #[pallet::constant]
+        type MaxWhitelistProofDepth: Get<u32>;
         /// Optional license/RWA participation verifier.
         /// Set to `()` if no license requirement is needed.
         type LicenseVerifier: LicenseVerifier<Self::AccountId, Self::BlockNumber>;
+        /// Source of attestations and account ages for eligibility rules.
+        /// Set to `()` if neither is available.
+        type EligibilityProvider: EligibilityProvider<Self::AccountId, Self::BlockNumber>;
+        /// Maximum number of nodes in a campaign's eligibility expression
+        /// (at most 256, as nodes are referenced by `u8` index).
+        #[pallet::constant]
+        type MaxEligibilityNodes: Get<u32>;
+        /// Maximum number of distinct assets a registration snapshot records.
+        #[pallet::constant]
+        type MaxSnapshotAssets: Get<u32>;
+        /// Maximum number of accounts that can register interest in a
+        /// campaign.
+        #[pallet::constant]
+        type MaxRegistrations: Get<u32>;
+        /// Conversion rates used to value contributions in a campaign's
+        /// alternative currencies.  Set to `()` if none are available.
+        type PriceFeed: PriceFeed<Self::AssetId, BalanceOf<Self>>;
+        /// Maximum number of alternative currencies a campaign accepts.
+        #[pallet::constant]
+        type MaxAcceptedCurrencies: Get<u32>;
+        /// Valuations of linked RWA assets, used to size asset-backed
+        /// offerings.  Set to `()` if none are available.
+        type ValuationProvider: ValuationProvider<Self::AssetId, BalanceOf<Self>>;
+        /// Maximum number of stretch goals a campaign can announce.
+        #[pallet::constant]
+        type MaxStretchGoals: Get<u32>;
+        /// Maximum number of funding rounds a campaign can run.
+        #[pallet::constant]
+        type MaxFundingRounds: Get<u32>;
+        /// Maximum number of steps in a campaign's early-withdrawal penalty
+        /// schedule.
+        #[pallet::constant]
+        type MaxPenaltySteps: Get<u32>;
+        /// Maximum number of reward tiers a campaign can declare.
+        #[pallet::constant]
+        type MaxRewardTiers: Get<u32>;
+        /// Mints the NFT reward of a tier once its campaign has succeeded.
+        /// Set to `()` if NFT rewards are not supported.
+        type RewardMinter: RewardMinter<Self::AccountId, Self::CollectionId>;
+        /// Maximum number of campaigns whose deadline index entry can fall in
+        /// the same block.
+        #[pallet::constant]
+        type MaxDeadlinesPerBlock: Get<u32>;
+        /// Maximum number of campaigns finalized in `on_initialize`; the
+        /// remainder of a block's deadline entries is finalized in `on_idle`.
+        #[pallet::constant]
+        type MaxAutoFinalizationsPerBlock: Get<u32>;
+        /// Maximum number of investors refunded by a single `process_refunds`
+        /// call.
+        #[pallet::constant]
+        type MaxRefundsPerCall: Get<u32>;
+        /// Maximum number of investors refunded in `on_idle` per block
+        /// (0 = no automatic refunds).
+        #[pallet::constant]
+        type MaxIdleRefunds: Get<u32>;
+        /// Maximum number of per-account records of ended campaigns removed
+        /// in `on_idle` per block.
+        #[pallet::constant]
+        type MaxIdleCleanups: Get<u32>;
+        /// Native deposit taken at campaign creation and paid to whoever reaps
+        /// the campaign once it is fully settled.  Must be zero or at least
+        /// the existential deposit.
+        #[pallet::constant]
+        type ReapDeposit: Get<BalanceOf<Self>>;
+        /// Maximum number of investment records removed by a single
+        /// `reap_campaign` call.
+        #[pallet::constant]
+        type MaxReapsPerCall: Get<u32>;
+        /// Multi-block migration started on upgrade when its target version is
+        /// above the on-chain storage version.  `()` when none is pending.
+        type SteppedMigration: SteppedMigration;
+        /// Maximum number of items a stepped migration processes per block.
+        #[pallet::constant]
+        type MigrationItemsPerBlock: Get<u32>;
+        /// Window over which a fee sponsorship's per-account call limit
+        /// applies.
+        #[pallet::constant]
+        type SponsorshipPeriod: Get<Self::BlockNumber>;
+        /// Keys the offchain worker signs finalization payloads with.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+        /// Priority of the offchain worker's finalization transactions.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+        /// Maximum number of campaigns finalized by one offchain worker
+        /// transaction.
+        #[pallet::constant]
+        type MaxFinalizationsPerTx: Get<u32>;
+        /// Blocks the offchain worker waits between submissions, which is
+        /// also how long a submitted transaction stays valid.
+        #[pallet::constant]
+        type UnsignedInterval: Get<Self::BlockNumber>;
         type WeightInfo: WeightInfo;
+        /// Sets up NFT collections, assets, prices and valuations for
+        /// benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<
+            Self::AccountId,
+            Self::CollectionId,
+            Self::AssetId,
+            BalanceOf<Self>,
+        >;
     }
 
     // ── Hooks ───────────────────────────────────────────────────────
@@ -135,16 +330,191 @@ pub mod pallet {
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         #[cfg(feature = "try-runtime")]
         fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> { Self::do_try_state() }
+
+        /// Finalize the campaigns whose deadline passed in the previous block.
+        /// At most `MaxAutoFinalizationsPerBlock` are finalized here; the rest
+        /// are queued in `PendingFinalizations` for `on_idle`.
+        ///
+        /// While a stepped migration is active it is advanced instead, and all
+        /// due campaigns are queued.
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let mut weight = T::DbWeight::get().reads(2);
+            let migration = ActiveMigration::<T>::get();
+            let limit = match migration {
+                Some(_) => 0,
+                None => T::MaxAutoFinalizationsPerBlock::get() as usize,
+            };
+            if let Some(cursor) = migration {
+                weight = weight.saturating_add(Self::step_migration(cursor));
+            }
+            let campaign_ids = CampaignDeadlines::<T>::take(n);
+            if campaign_ids.is_empty() {
+                return weight;
+            }
+            weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            for (i, campaign_id) in campaign_ids.into_iter().enumerate() {
+                if i < limit {
+                    Self::auto_finalize(campaign_id, n);
+                    weight = weight.saturating_add(Self::auto_finalize_weight());
+                } else {
+                    PendingFinalizations::<T>::insert(campaign_id, ());
+                    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+                }
+            }
+            weight
+        }
+
+        /// Finalize campaigns queued in `PendingFinalizations` with the
+        /// block's leftover weight.
+        ///
+        /// Leftover weight is then spent on removing the records of a
+        /// campaign queued in `PendingCleanups` (up to `MaxIdleCleanups`) and
+        /// on refunding investors of a campaign queued in `PendingRefunds`
+        /// (up to `MaxIdleRefunds`).  All queues wait while a stepped
+        /// migration is active.
+        fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            let per_item =
+                Self::auto_finalize_weight().saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            let mut used = T::DbWeight::get().reads(2);
+            if remaining_weight.any_lt(used) {
+                return Weight::zero();
+            }
+            if Self::migration_ongoing() {
+                return used;
+            }
+            let mut iter = PendingFinalizations::<T>::iter_keys();
+            loop {
+                if remaining_weight.any_lt(used.saturating_add(per_item)) {
+                    break;
+                }
+                let campaign_id = match iter.next() {
+                    Some(id) => id,
+                    None => break,
+                };
+                PendingFinalizations::<T>::remove(campaign_id);
+                Self::auto_finalize(campaign_id, n);
+                used = used.saturating_add(per_item);
+            }
+
+            used = Self::idle_cleanup(remaining_weight, used);
+
+            let max_refunds = T::MaxIdleRefunds::get();
+            let queue_read = T::DbWeight::get().reads(2);
+            if max_refunds == 0 || remaining_weight.any_lt(used.saturating_add(queue_read)) {
+                return used;
+            }
+            used = used.saturating_add(queue_read);
+            let campaign_id = match PendingRefunds::<T>::iter_keys().next() {
+                Some(id) => id,
+                None => return used,
+            };
+            let refund_weight = T::WeightInfo::process_refunds(1);
+            let mut count = 0u32;
+            while count < max_refunds
+                && !remaining_weight
+                    .any_lt(used.saturating_add(refund_weight.saturating_mul(count as u64 + 1)))
+            {
+                count += 1;
+            }
+            if count == 0 {
+                return used;
+            }
+            match Campaigns::<T>::get(campaign_id) {
+                Some(c)
+                    if matches!(c.status, CampaignStatus::Failed | CampaignStatus::Cancelled) =>
+                {
+                    Self::do_process_refunds(campaign_id, &c, count);
+                }
+                _ => PendingRefunds::<T>::remove(campaign_id),
+            }
+            used.saturating_add(refund_weight.saturating_mul(count as u64))
+        }
+
+        /// Submit the campaigns still `Funding` past their deadline for
+        /// finalization, at most once every `UnsignedInterval` blocks.  These
+        /// are the campaigns the block hooks have not reached yet.
+        fn offchain_worker(n: T::BlockNumber) {
+            if Self::migration_ongoing() {
+                return;
+            }
+            let campaign_ids = Self::finalizable_campaigns(T::MaxFinalizationsPerTx::get());
+            if campaign_ids.is_empty() || !Self::acquire_offchain_slot(n) {
+                return;
+            }
+            let result = Signer::<T, T::AuthorityId>::any_account()
+                .with_filter(Self::local_finalization_keys())
+                .send_unsigned_transaction(
+                    |account| FinalizationPayload {
+                        block_number: n,
+                        campaign_ids: campaign_ids.clone(),
+                        public: account.public.clone(),
+                    },
+                    |payload, signature| Call::finalize_campaigns_unsigned { payload, signature },
+                );
+            match result {
+                Some((_, Ok(()))) => {}
+                Some((_, Err(()))) => frame_support::log::warn!(
+                    target: "runtime::crowdfunding",
+                    "failed to submit finalization at {:?}",
+                    n,
+                ),
+                None => frame_support::log::debug!(
+                    target: "runtime::crowdfunding",
+                    "no allowed finalization key in keystore",
+                ),
+            }
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// Accept a finalization payload signed by an allowed finalization
+        /// key whose campaigns can all be finalized.  Every campaign is
+        /// provided as a tag, so two transactions finalizing the same one
+        /// never both enter the pool.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let (payload, signature) = match call {
+                Call::finalize_campaigns_unsigned { payload, signature } => (payload, signature),
+                _ => return InvalidTransaction::Call.into(),
+            };
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone())
+                || !Self::is_finalization_key(&payload.public)
+            {
+                return InvalidTransaction::BadProof.into();
+            }
+            if payload.campaign_ids.is_empty()
+                || payload.campaign_ids.len() > T::MaxFinalizationsPerTx::get() as usize
+            {
+                return InvalidTransaction::Call.into();
+            }
+            if payload.block_number > frame_system::Pallet::<T>::block_number() {
+                return InvalidTransaction::Future.into();
+            }
+            let mut valid = ValidTransaction::with_tag_prefix("CrowdfundingFinalization")
+                .priority(T::UnsignedPriority::get())
+                .longevity(T::UnsignedInterval::get().unique_saturated_into())
+                .propagate(true);
+            for campaign_id in payload.campaign_ids.iter() {
+                if !Self::is_finalizable(*campaign_id) {
+                    return InvalidTransaction::Stale.into();
+                }
+                valid = valid.and_provides(campaign_id);
+            }
+            valid.build()
+        }
     }
 
     // ── Storage ──────────────────────────────────────────────────────
 
     /// All campaign records, keyed by campaign ID.
     ///
-    /// Campaign records are intentionally retained in storage after a campaign
-    /// reaches a terminal state (Completed, Failed, Cancelled).  Deletion would
-    /// break on-chain auditability: historical records must remain accessible
-    /// to investors, indexers, and on-chain governance tooling.
+    /// Campaign records are retained in storage after a campaign reaches a
+    /// terminal state (Completed, Failed, Cancelled) until it is fully settled
+    /// and reaped via `reap_campaign`.  Reaping moves a compact summary to
+    /// `ArchivedCampaigns` so historical records remain accessible to
+    /// investors, indexers, and on-chain governance tooling.
     #[pallet::storage]
     pub type Campaigns<T: Config> = StorageMap<_, Blake2_128Concat, u32, CampaignOf<T>>;
 
@@ -161,6 +531,12 @@ pub mod pallet {
         Investment<BalanceOf<T>>,
     >;
 
+    /// Sum of the weighted allocations of a campaign's investments, net of
+    /// withdrawals.  An asset-backed offering splits its fraction by it.
+    #[pallet::storage]
+    pub type TotalWeightedAllocations<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BalanceOf<T>, ValueQuery>;
+
     #[pallet::storage]
     pub type MilestoneStatuses<T: Config> =
         StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u8, MilestoneStatus>;
@@ -205,6 +581,24 @@ pub mod pallet {
     pub type CampaignWhitelistCount<T: Config> =
         StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery>;
 
+    /// Merkle root of a campaign's large whitelist.  Accounts prove
+    /// membership once via `invest_with_proof`; the explicit
+    /// `CampaignWhitelist` remains available for small lists.
+    #[pallet::storage]
+    pub type WhitelistRoots<T: Config> = StorageMap<_, Blake2_128Concat, u32, [u8; 32]>;
+
+    /// First successful whitelist proof per (campaign, account), so later
+    /// investments need no proof.
+    #[pallet::storage]
+    pub type WhitelistAllowances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        WhitelistAllowance<BalanceOf<T>>,
+    >;
+
     /// Override for the compile-time `ProtocolFeeBps` constant, set via
     /// `set_protocol_config`.  When `None`, the Config constant is used.
     #[pallet::storage]
@@ -215,6 +609,258 @@ pub mod pallet {
     #[pallet::storage]
     pub type ProtocolFeeRecipientOverride<T: Config> = StorageValue<_, T::AccountId>;
 
+    /// Creator bond, in basis points of the goal, locked by new
+    /// milestone-based campaigns.  Zero disables the bond.
+    #[pallet::storage]
+    pub type CreatorBondBps<T: Config> = StorageValue<_, u16, ValueQuery>;
+
+    /// Creator bonds of milestone-based campaigns, keyed by campaign ID.
+    #[pallet::storage]
+    pub type CreatorBonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, CreatorBond<BalanceOf<T>>>;
+
+    /// Vesting schedules for the funds of successful campaigns, keyed by
+    /// campaign ID.
+    #[pallet::storage]
+    pub type VestingSchedules<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, VestingScheduleOf<T>>;
+
+    /// Early-withdrawal penalty schedules, keyed by campaign ID.  Campaigns
+    /// without one use their flat penalty.
+    #[pallet::storage]
+    pub type PenaltySchedules<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, PenaltyScheduleOf<T>>;
+
+    /// Where each campaign's early-withdrawal penalties go.
+    #[pallet::storage]
+    pub type PenaltyDestinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, PenaltyDestination, ValueQuery>;
+
+    /// Penalties retained by campaigns whose destination is `CampaignPot`
+    /// or `RemainingInvestors`.
+    #[pallet::storage]
+    pub type PenaltyPots<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, PenaltyPot<BalanceOf<T>>>;
+
+    /// Investors who claimed their share of a campaign's retained
+    /// penalties.
+    #[pallet::storage]
+    pub type PenaltyShareClaims<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Block of an investor's first investment in a campaign.  A penalty
+    /// schedule's cooling-off period runs from it; positions older than
+    /// this index count from the campaign's creation.
+    #[pallet::storage]
+    pub type InvestedAt<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+    /// Block until which investments in a campaign cannot be transferred,
+    /// keyed by campaign ID.
+    #[pallet::storage]
+    pub type TransferLockups<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::BlockNumber>;
+
+    /// Reward tiers declared by the campaign creator, keyed by campaign ID.
+    #[pallet::storage]
+    pub type RewardTiers<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BoundedVec<RewardTierOf<T>, T::MaxRewardTiers>>;
+
+    /// Reward tier held by each investor of a campaign.
+    #[pallet::storage]
+    pub type InvestorTiers<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, TierAllocation>;
+
+    /// Next serial passed to `RewardMinter` for each reward collection.
+    #[pallet::storage]
+    pub type NextRewardSerial<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CollectionId, u32, ValueQuery>;
+
+    /// Deadline index: campaigns due for automatic finalization, keyed by
+    /// the first block after their deadline.
+    #[pallet::storage]
+    pub type CampaignDeadlines<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        BoundedVec<u32, T::MaxDeadlinesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Campaigns past their deadline that did not fit in `on_initialize`'s
+    /// finalization budget and are finalized in `on_idle`.
+    #[pallet::storage]
+    pub type PendingFinalizations<T: Config> = StorageMap<_, Blake2_128Concat, u32, ()>;
+
+    /// Non-terminal campaigns created under an RWA license, keyed by the
+    /// license's `(rwa_asset_id, participation_id)`, so the RWA lifecycle
+    /// guard can find them without scanning `Campaigns`.
+    #[pallet::storage]
+    pub type LicensedCampaigns<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, u32>,
+            NMapKey<Blake2_128Concat, u32>,
+            NMapKey<Blake2_128Concat, u32>,
+        ),
+        (),
+    >;
+
+    /// Failed/Cancelled campaigns with refunds left for `on_idle` to push.
+    #[pallet::storage]
+    pub type PendingRefunds<T: Config> = StorageMap<_, Blake2_128Concat, u32, ()>;
+
+    /// Ended campaigns whose whitelist, registration and sponsored-call
+    /// records are left for `on_idle` to remove, a bounded number per block.
+    #[pallet::storage]
+    pub type PendingCleanups<T: Config> = StorageMap<_, Blake2_128Concat, u32, ()>;
+
+    /// Last investor visited by bulk refund processing, per campaign.  The
+    /// next batch resumes after this investor.
+    #[pallet::storage]
+    pub type RefundCursor<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId>;
+
+    /// Reap deposit held in the campaign sub-account, paid to the account
+    /// that reaps the campaign.
+    #[pallet::storage]
+    pub type ReapDeposits<T: Config> = StorageMap<_, Blake2_128Concat, u32, BalanceOf<T>>;
+
+    /// Optional AND/OR/NOT eligibility expression of a campaign, checked in
+    /// addition to its flat `eligibility_rules`.
+    #[pallet::storage]
+    pub type EligibilityExpressions<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, EligibilityExpressionOf<T>>;
+
+    /// Block at which funding opens for campaigns with a registration
+    /// phase.  Until then only `register_interest` is accepted.
+    #[pallet::storage]
+    pub type RegistrationPhases<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::BlockNumber>;
+
+    /// Balance snapshots of the accounts that registered interest.
+    #[pallet::storage]
+    pub type Registrations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        RegistrationSnapshotOf<T>,
+    >;
+
+    /// Number of registrations per campaign, bounded by `MaxRegistrations`.
+    #[pallet::storage]
+    pub type RegistrationCount<T: Config> = StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery>;
+
+    /// Alternative currencies a campaign accepts besides its funding
+    /// currency.
+    #[pallet::storage]
+    pub type AcceptedCurrencies<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<PaymentCurrency<T::AssetId>, T::MaxAcceptedCurrencies>,
+    >;
+
+    /// Amounts a campaign account holds in alternative currencies.  The
+    /// rest of `total_raised - total_disbursed` is held in the funding
+    /// currency.
+    #[pallet::storage]
+    pub type CampaignHoldings<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, CurrencyHoldingsOf<T>>;
+
+    /// Amounts each investor contributed in a campaign's alternative
+    /// currencies, paid back in those currencies on withdrawal or refund.
+    #[pallet::storage]
+    pub type InvestorHoldings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        CurrencyHoldingsOf<T>,
+    >;
+
+    /// Asset-backed offering terms, keyed by campaign ID.
+    #[pallet::storage]
+    pub type AssetOfferings<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, AssetOffering<BalanceOf<T>>>;
+
+    /// Investors who already claimed their share of an asset-backed
+    /// offering.
+    #[pallet::storage]
+    pub type OfferingShareClaims<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Fraction of each RWA asset promised by offerings whose shares have
+    /// not been issued yet.
+    #[pallet::storage]
+    pub type CommittedAssetFraction<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, Perbill, ValueQuery>;
+
+    /// Fraction of each RWA asset held by investors as asset shares.
+    #[pallet::storage]
+    pub type IssuedAssetFraction<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, Perbill, ValueQuery>;
+
+    /// Ownership share each account holds in an RWA asset through
+    /// asset-backed offerings.
+    #[pallet::storage]
+    pub type AssetShares<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, Perbill>;
+
+    /// Stretch goals announced by the campaign creator, in ascending
+    /// threshold order.
+    #[pallet::storage]
+    pub type StretchGoals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<StretchGoal<BalanceOf<T>>, T::MaxStretchGoals>,
+    >;
+
+    /// Number of a campaign's stretch goals reached so far.  Goals are
+    /// reached in order and stay reached after withdrawals.
+    #[pallet::storage]
+    pub type StretchGoalsReached<T: Config> = StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery>;
+
+    /// Sequential funding rounds of a campaign, in deadline order.
+    #[pallet::storage]
+    pub type FundingRounds<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, BoundedVec<FundingRoundOf<T>, T::MaxFundingRounds>>;
+
+    /// Compact summaries of reaped campaigns, keyed by campaign ID.
+    #[pallet::storage]
+    pub type ArchivedCampaigns<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, ArchivedCampaignOf<T>>;
+
+    /// Cursor of the stepped migration in progress, if any.  While set, the
+    /// runtime call filter rejects the pallet's extrinsics and automatic
+    /// finalization and refunds are deferred.
+    #[pallet::storage]
+    pub type ActiveMigration<T: Config> = StorageValue<_, MigrationCursorOf<T>>;
+
+    /// Fee sponsorships by campaign.
+    #[pallet::storage]
+    pub type FeeSponsorships<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, FeeSponsorship<T::AccountId>>;
+
+    /// Sponsored transactions per campaign and account in the current
+    /// period.
+    #[pallet::storage]
+    pub type SponsoredCalls<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        SponsoredUsage<T::BlockNumber>,
+        ValueQuery,
+    >;
+
+    /// Accounts of the keys allowed to sign the offchain worker's
+    /// finalization payloads.
+    #[pallet::storage]
+    pub type FinalizationKeys<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
     // ── Events ───────────────────────────────────────────────────────
 
     #[pallet::event]
@@ -272,32 +918,261 @@ pub mod pallet {
         /// check.
         #[codec(index = 19)]
         CampaignForceFinalized { campaign_id: u32, status: CampaignStatus },
-    }
-
-    // ── Errors ───────────────────────────────────────────────────────
-
-    #[pallet::error]
-    pub enum Error<T> {
-        #[codec(index = 0)]
-        CampaignNotFound,
-        #[codec(index = 1)]
-        NotCampaignCreator,
-        #[codec(index = 2)]
-        InvalidCampaignStatus,
-        #[codec(index = 3)]
-        CampaignStillFunding,
-        #[codec(index = 4)]
-        DeadlinePassed,
-        #[codec(index = 5)]
-        DeadlineInPast,
-        #[codec(index = 6)]
-        DurationTooShort,
-        #[codec(index = 7)]
-        DurationTooLong,
-        #[codec(index = 8)]
-        GoalNotMet,
-        #[codec(index = 9)]
-        HardCapExceeded,
+        /// The creator declared the campaign's reward tiers.
+        #[codec(index = 20)]
+        RewardTiersSet { campaign_id: u32, tier_count: u32 },
+        /// An investor was allocated a reward tier.
+        #[codec(index = 21)]
+        RewardTierSelected { campaign_id: u32, investor: T::AccountId, tier_index: u8 },
+        /// An investor's reward tier allocation was returned to the tier
+        /// supply.
+        #[codec(index = 22)]
+        RewardTierReleased { campaign_id: u32, investor: T::AccountId, tier_index: u8 },
+        /// The NFT reward of a tier was minted to the investor.
+        #[codec(index = 23)]
+        RewardMinted {
+            campaign_id: u32,
+            investor: T::AccountId,
+            tier_index: u8,
+            collection: T::CollectionId,
+            serial: u32,
+        },
+        /// A batch of bulk refunds was processed.  `completed` is set once
+        /// every investment record of the campaign has been visited.
+        #[codec(index = 24)]
+        RefundsProcessed { campaign_id: u32, refunded: u32, completed: bool },
+        /// A batch of investment records of a settled campaign was removed.
+        /// `completed` is set once the campaign has been archived.
+        #[codec(index = 25)]
+        CampaignReaped { campaign_id: u32, removed: u32, completed: bool },
+        /// The reap deposit was paid to the account that archived the
+        /// campaign.
+        #[codec(index = 26)]
+        ReapRewardPaid { campaign_id: u32, who: T::AccountId, amount: BalanceOf<T> },
+        /// The campaign's eligibility expression was set (`node_count` = 0
+        /// when cleared).
+        #[codec(index = 27)]
+        EligibilityExpressionSet { campaign_id: u32, node_count: u32 },
+        /// A registration phase was set; funding opens at `funding_opens_at`.
+        #[codec(index = 28)]
+        RegistrationPhaseSet { campaign_id: u32, funding_opens_at: T::BlockNumber },
+        /// An account registered interest and its balances were recorded.
+        #[codec(index = 29)]
+        InterestRegistered { campaign_id: u32, who: T::AccountId },
+        /// A campaign's whitelist Merkle root was set or cleared.
+        #[codec(index = 30)]
+        WhitelistRootSet { campaign_id: u32, root: Option<[u8; 32]> },
+        /// An account proved membership of a campaign's whitelist root.
+        #[codec(index = 31)]
+        WhitelistProofVerified { campaign_id: u32, who: T::AccountId },
+        /// A campaign's alternative currencies were set or cleared.
+        #[codec(index = 32)]
+        AcceptedCurrenciesSet { campaign_id: u32, currencies: Vec<PaymentCurrency<T::AssetId>> },
+        /// An investment was paid in an alternative currency.  `normalized`
+        /// is its value in the funding currency.
+        #[codec(index = 33)]
+        InvestedInCurrency {
+            campaign_id: u32,
+            investor: T::AccountId,
+            currency: PaymentCurrency<T::AssetId>,
+            amount: BalanceOf<T>,
+            normalized: BalanceOf<T>,
+        },
+        /// A campaign was turned into an offering of `fraction` of its
+        /// linked RWA asset, with goal and hard cap set to `goal`.
+        #[codec(index = 34)]
+        AssetOfferingConfigured {
+            campaign_id: u32,
+            rwa_asset_id: u32,
+            fraction: Perbill,
+            goal: BalanceOf<T>,
+        },
+        /// An investor of a successful asset-backed offering received their
+        /// share of the RWA asset.
+        #[codec(index = 35)]
+        AssetShareIssued { campaign_id: u32, rwa_asset_id: u32, who: T::AccountId, share: Perbill },
+        /// A holder gave up their share of an RWA asset.
+        #[codec(index = 36)]
+        AssetShareSurrendered { rwa_asset_id: u32, who: T::AccountId, share: Perbill },
+        /// The campaign's stretch goals were replaced.
+        #[codec(index = 37)]
+        StretchGoalsSet { campaign_id: u32, count: u32 },
+        /// `total_raised` reached a stretch goal.
+        #[codec(index = 38)]
+        StretchGoalReached { campaign_id: u32, index: u32, threshold: BalanceOf<T> },
+        /// The campaign's funding rounds were replaced.
+        #[codec(index = 39)]
+        FundingRoundsSet { campaign_id: u32, count: u32 },
+        /// The creator bond required of new milestone-based campaigns was
+        /// changed.
+        #[codec(index = 40)]
+        CreatorBondBpsSet { bond_bps: u16 },
+        /// A creator bond was locked in the campaign sub-account.
+        #[codec(index = 41)]
+        CreatorBondLocked { campaign_id: u32, amount: BalanceOf<T> },
+        /// Part of the creator bond was returned to the creator.
+        #[codec(index = 42)]
+        CreatorBondReleased { campaign_id: u32, amount: BalanceOf<T> },
+        /// The locked creator bond was slashed for investors.
+        #[codec(index = 43)]
+        CreatorBondSlashed { campaign_id: u32, amount: BalanceOf<T> },
+        /// An investor was paid their share of a slashed creator bond.
+        #[codec(index = 44)]
+        BondShareRefunded { campaign_id: u32, investor: T::AccountId, amount: BalanceOf<T> },
+        /// The campaign's vesting schedule was set (`None` = cleared).
+        #[codec(index = 45)]
+        VestingScheduleSet { campaign_id: u32, schedule: Option<VestingScheduleOf<T>> },
+        /// The campaign's early-withdrawal penalty terms were set.
+        #[codec(index = 46)]
+        WithdrawalPenaltySet { campaign_id: u32, destination: PenaltyDestination },
+        /// An early-withdrawal penalty was charged.
+        #[codec(index = 47)]
+        WithdrawalPenaltyApplied {
+            campaign_id: u32,
+            investor: T::AccountId,
+            amount: BalanceOf<T>,
+            destination: PenaltyDestination,
+        },
+        /// An investor was paid their share of retained penalties.
+        #[codec(index = 48)]
+        PenaltySharePaid { campaign_id: u32, investor: T::AccountId, amount: BalanceOf<T> },
+        /// Part or all of an investment changed hands.
+        #[codec(index = 49)]
+        InvestmentTransferred {
+            campaign_id: u32,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The campaign's transfer lockup was set or cleared.
+        #[codec(index = 50)]
+        TransferLockupSet { campaign_id: u32, until: Option<T::BlockNumber> },
+        /// A stepped storage migration started.
+        #[codec(index = 51)]
+        MigrationStarted { target_version: u16 },
+        /// The stepped storage migration finished and the target storage
+        /// version was stamped.
+        #[codec(index = 52)]
+        MigrationCompleted { target_version: u16, migrated: u32 },
+        /// A campaign's fee sponsorship was funded or its limit changed.
+        #[codec(index = 53)]
+        FeeSponsorshipFunded {
+            campaign_id: u32,
+            sponsor: T::AccountId,
+            amount: BalanceOf<T>,
+            max_calls_per_period: u32,
+        },
+        /// A campaign's fee sponsorship ended and its budget was refunded.
+        #[codec(index = 54)]
+        FeeSponsorshipEnded { campaign_id: u32, sponsor: T::AccountId, refunded: BalanceOf<T> },
+        /// A campaign's fee sponsorship paid a transaction fee for `who`.
+        #[codec(index = 55)]
+        FeeSponsored { campaign_id: u32, who: T::AccountId, fee: BalanceOf<T> },
+        /// The unclaimed NFT reward of a reaped investment could not be
+        /// minted and was dropped.
+        #[codec(index = 56)]
+        RewardMintFailed { campaign_id: u32, investor: T::AccountId, tier_index: u8 },
+        /// A key was allowed or disallowed to sign finalization payloads.
+        #[codec(index = 57)]
+        FinalizationKeySet { key: T::AccountId, allowed: bool },
+        /// The unclaimed penalty share of a reaped investment could not be
+        /// paid and was left in the pot.
+        #[codec(index = 58)]
+        PenaltySharePaymentFailed { campaign_id: u32, investor: T::AccountId },
+    }
+
+    impl<T: Config> Event<T> {
+        /// The campaign this event concerns, if any.
+        pub fn campaign_id(&self) -> Option<u32> {
+            match self {
+                Event::CampaignCreated { campaign_id, .. }
+                | Event::CampaignFinalized { campaign_id, .. }
+                | Event::CampaignCancelled { campaign_id, .. }
+                | Event::CreationDepositClaimed { campaign_id, .. }
+                | Event::Invested { campaign_id, .. }
+                | Event::InvestmentWithdrawn { campaign_id, .. }
+                | Event::RefundClaimed { campaign_id, .. }
+                | Event::FundsClaimed { campaign_id, .. }
+                | Event::MilestoneSubmitted { campaign_id, .. }
+                | Event::MilestoneApproved { campaign_id, .. }
+                | Event::MilestoneRejected { campaign_id, .. }
+                | Event::MilestoneFundsClaimed { campaign_id, .. }
+                | Event::ProtocolFeeCollected { campaign_id, .. }
+                | Event::CampaignPaused { campaign_id, .. }
+                | Event::CampaignResumed { campaign_id, .. }
+                | Event::HardCapReached { campaign_id, .. }
+                | Event::CampaignLicenseReported { campaign_id, .. }
+                | Event::CampaignForceFinalized { campaign_id, .. }
+                | Event::RewardTiersSet { campaign_id, .. }
+                | Event::RewardTierSelected { campaign_id, .. }
+                | Event::RewardTierReleased { campaign_id, .. }
+                | Event::RewardMinted { campaign_id, .. }
+                | Event::RewardMintFailed { campaign_id, .. }
+                | Event::PenaltySharePaymentFailed { campaign_id, .. }
+                | Event::RefundsProcessed { campaign_id, .. }
+                | Event::CampaignReaped { campaign_id, .. }
+                | Event::ReapRewardPaid { campaign_id, .. }
+                | Event::EligibilityExpressionSet { campaign_id, .. }
+                | Event::RegistrationPhaseSet { campaign_id, .. }
+                | Event::InterestRegistered { campaign_id, .. }
+                | Event::WhitelistRootSet { campaign_id, .. }
+                | Event::WhitelistProofVerified { campaign_id, .. }
+                | Event::AcceptedCurrenciesSet { campaign_id, .. }
+                | Event::InvestedInCurrency { campaign_id, .. }
+                | Event::AssetOfferingConfigured { campaign_id, .. }
+                | Event::AssetShareIssued { campaign_id, .. }
+                | Event::StretchGoalsSet { campaign_id, .. }
+                | Event::StretchGoalReached { campaign_id, .. }
+                | Event::FundingRoundsSet { campaign_id, .. }
+                | Event::CreatorBondLocked { campaign_id, .. }
+                | Event::CreatorBondReleased { campaign_id, .. }
+                | Event::CreatorBondSlashed { campaign_id, .. }
+                | Event::BondShareRefunded { campaign_id, .. }
+                | Event::VestingScheduleSet { campaign_id, .. }
+                | Event::WithdrawalPenaltySet { campaign_id, .. }
+                | Event::WithdrawalPenaltyApplied { campaign_id, .. }
+                | Event::PenaltySharePaid { campaign_id, .. }
+                | Event::InvestmentTransferred { campaign_id, .. }
+                | Event::TransferLockupSet { campaign_id, .. }
+                | Event::FeeSponsorshipFunded { campaign_id, .. }
+                | Event::FeeSponsorshipEnded { campaign_id, .. }
+                | Event::FeeSponsored { campaign_id, .. } => Some(*campaign_id),
+                Event::DefaultEligibilitySet
+                | Event::ProtocolConfigUpdated { .. }
+                | Event::AssetShareSurrendered { .. }
+                | Event::CreatorBondBpsSet { .. }
+                | Event::MigrationStarted { .. }
+                | Event::MigrationCompleted { .. }
+                | Event::FinalizationKeySet { .. }
+                | Event::__Ignore(..) => None,
+            }
+        }
+    }
+
+    // ── Errors ───────────────────────────────────────────────────────
+
+    #[pallet::error]
+    pub enum Error<T> {
+        #[codec(index = 0)]
+        CampaignNotFound,
+        #[codec(index = 1)]
+        NotCampaignCreator,
+        #[codec(index = 2)]
+        InvalidCampaignStatus,
+        #[codec(index = 3)]
+        CampaignStillFunding,
+        #[codec(index = 4)]
+        DeadlinePassed,
+        #[codec(index = 5)]
+        DeadlineInPast,
+        #[codec(index = 6)]
+        DurationTooShort,
+        #[codec(index = 7)]
+        DurationTooLong,
+        #[codec(index = 8)]
+        GoalNotMet,
+        #[codec(index = 9)]
+        HardCapExceeded,
         #[codec(index = 10)]
         InvestmentBelowMinimum,
         #[codec(index = 11)]
@@ -357,6 +1232,180 @@ pub mod pallet {
         /// Campaign deadline exceeds the license expiry block (V2 fix).
         #[codec(index = 35)]
         CampaignExceedsLicenseExpiry,
+        /// A reward tier has zero quantity or an NFT collection not owned by
+        /// the creator.
+        #[codec(index = 36)]
+        InvalidRewardTier,
+        /// Reward tiers can only be set before the first investment.
+        #[codec(index = 37)]
+        RewardTiersLocked,
+        /// The requested reward tier does not exist.
+        #[codec(index = 38)]
+        RewardTierNotFound,
+        /// The requested reward tier has no remaining allocations.
+        #[codec(index = 39)]
+        RewardTierSoldOut,
+        /// The investor's net investment is below the tier's minimum amount.
+        #[codec(index = 40)]
+        BelowTierMinimum,
+        /// The investor holds no tier with an unminted NFT reward.
+        #[codec(index = 41)]
+        NoRewardToMint,
+        /// The early-bird bonus exceeds 10 000 bps, is zero, or has an empty
+        /// bonus window.
+        #[codec(index = 42)]
+        InvalidBonusSchedule,
+        /// The deadline index slot for this campaign's deadline is full
+        /// (`MaxDeadlinesPerBlock` reached).
+        #[codec(index = 43)]
+        DeadlineSlotsFull,
+        /// `max_count` is zero.
+        #[codec(index = 44)]
+        InvalidRefundBatch,
+        /// The campaign is not terminal or still has a creation deposit or
+        /// investor refunds to claim.
+        #[codec(index = 45)]
+        CampaignNotSettled,
+        /// A node refers to a child that does not precede it or that is
+        /// already used by another node, or a node is left unused.
+        #[codec(index = 46)]
+        InvalidEligibilityExpression,
+        /// The eligibility expression can only change before the first
+        /// investment.
+        #[codec(index = 47)]
+        EligibilityLocked,
+        /// Funding must open after the current block and before the
+        /// campaign deadline.
+        #[codec(index = 48)]
+        InvalidRegistrationPhase,
+        /// The campaign has no registration phase or it has ended.
+        #[codec(index = 49)]
+        RegistrationClosed,
+        /// The campaign is still in its registration phase.
+        #[codec(index = 50)]
+        FundingNotOpen,
+        /// The investor did not register interest during the registration
+        /// phase.
+        #[codec(index = 51)]
+        NotRegistered,
+        /// The campaign's rules reference more assets than a registration
+        /// snapshot can record (`MaxSnapshotAssets`).
+        #[codec(index = 52)]
+        TooManySnapshotAssets,
+        /// `MaxRegistrations` reached for this campaign.
+        #[codec(index = 53)]
+        MaxRegistrationsReached,
+        /// The campaign has no whitelist root or the proof does not match it.
+        #[codec(index = 54)]
+        InvalidWhitelistProof,
+        /// The investment would exceed the cap committed to in the
+        /// account's whitelist leaf.
+        #[codec(index = 55)]
+        WhitelistCapExceeded,
+        /// The campaign does not accept this currency.
+        #[codec(index = 56)]
+        CurrencyNotAccepted,
+        /// The price feed has no rate for this currency, or the amount is
+        /// worth nothing in the funding currency.
+        #[codec(index = 57)]
+        PriceUnavailable,
+        /// Accepted currencies must be distinct and exclude the funding
+        /// currency.
+        #[codec(index = 58)]
+        InvalidAcceptedCurrencies,
+        /// Accepted currencies can only change before the first investment.
+        #[codec(index = 59)]
+        CurrenciesLocked,
+        /// Asset-backed offerings need a linked RWA asset, a goal-based
+        /// funding model and a non-zero goal.
+        #[codec(index = 60)]
+        InvalidAssetOffering,
+        /// The linked RWA asset has no valuation in the funding currency.
+        #[codec(index = 61)]
+        ValuationUnavailable,
+        /// The offering would promise more than the whole RWA asset.
+        #[codec(index = 62)]
+        AssetFractionExceeded,
+        /// Offering terms can only change before the first investment.
+        #[codec(index = 63)]
+        OfferingLocked,
+        /// The campaign is not an asset-backed offering.
+        #[codec(index = 64)]
+        NotAnAssetOffering,
+        /// No asset share to claim or surrender.
+        #[codec(index = 65)]
+        NoAssetShare,
+        /// Stretch goal thresholds must be strictly ascending, above the
+        /// campaign goal and within the hard cap; reached goals cannot
+        /// change.
+        #[codec(index = 66)]
+        InvalidStretchGoals,
+        /// Round deadlines and caps must be strictly ascending, the last
+        /// round must end at the campaign deadline, and caps must be
+        /// non-zero and within the hard cap.
+        #[codec(index = 67)]
+        InvalidFundingRounds,
+        /// Funding rounds can only change before the first investment.
+        #[codec(index = 68)]
+        FundingRoundsLocked,
+        /// The investment would exceed the cap of the active funding round.
+        #[codec(index = 69)]
+        RoundCapExceeded,
+        /// Creator bond exceeds 10 000 bps (100 %) of the goal.
+        #[codec(index = 70)]
+        InvalidBondBps,
+        /// Vesting needs a non-zero duration no shorter than the cliff, and
+        /// is not available to milestone-based campaigns.
+        #[codec(index = 71)]
+        InvalidVestingSchedule,
+        /// The vesting schedule can only change before the first investment.
+        #[codec(index = 72)]
+        VestingLocked,
+        /// Penalty steps must be in strictly descending `within` order with
+        /// penalties of at most 10 000 bps.
+        #[codec(index = 73)]
+        InvalidPenaltySchedule,
+        /// Penalty terms can only change before the first investment.
+        #[codec(index = 74)]
+        PenaltyLocked,
+        /// Retained penalties are only supported for campaigns without
+        /// alternative currencies.
+        #[codec(index = 75)]
+        PenaltyDestinationUnsupported,
+        /// The investor has no unclaimed share of retained penalties.
+        #[codec(index = 76)]
+        NoPenaltyShare,
+        /// Investments in this campaign cannot be transferred yet.
+        #[codec(index = 77)]
+        TransferLocked,
+        /// The transfer lockup can only change before the first investment.
+        #[codec(index = 78)]
+        TransferLockupLocked,
+        /// An investment cannot be transferred to its own holder.
+        #[codec(index = 79)]
+        InvalidTransferRecipient,
+        /// The sender or recipient already claimed their asset or penalty
+        /// share, so the position can no longer change hands.
+        #[codec(index = 80)]
+        PositionAlreadyClaimed,
+        /// The campaign's fee sponsorship was funded by another account.
+        #[codec(index = 81)]
+        SponsoredByAnother,
+        /// The campaign has no fee sponsorship.
+        #[codec(index = 82)]
+        NoFeeSponsorship,
+        /// A fee sponsorship must allow at least one call per period.
+        #[codec(index = 83)]
+        InvalidSponsorshipLimit,
+        /// Too many serials following the reward collection's next serial
+        /// are already taken.
+        #[codec(index = 84)]
+        RewardSerialsExhausted,
+        /// The campaign can still be cancelled and refunded (milestone phase
+        /// or funds still vesting), so rewards and shares cannot be claimed
+        /// yet.
+        #[codec(index = 85)]
+        CampaignStillCancellable,
     }
 
     // ── Dispatchables ────────────────────────────────────────────────
@@ -404,6 +1453,17 @@ pub mod pallet {
                 ensure!(penalty_bps <= 10_000, Error::<T>::InvalidPenaltyBps);
             }
 
+            if let Some(bonus) = &config.early_bird_bonus {
+                ensure!(
+                    bonus.bonus_bps > 0 && bonus.bonus_bps <= 10_000,
+                    Error::<T>::InvalidBonusSchedule
+                );
+                ensure!(
+                    !bonus.full_bonus_blocks.saturating_add(bonus.decay_blocks).is_zero(),
+                    Error::<T>::InvalidBonusSchedule
+                );
+            }
+
             // M-4: validate funding model — goal must be non-zero; milestone bps must sum
             // to 10_000
             match &config.funding_model {
@@ -436,6 +1496,10 @@ pub mod pallet {
                 ensure!(*min <= *max, Error::<T>::InvalidFundingModel);
             }
 
+            // Pre-flight check on the deadline index slot, before any state
+            // changes (same pattern as `SunsettingAssets` in pallet-rwa).
+            Self::ensure_deadline_slot(config.deadline)?;
+
             // creator campaign limit
             let current_campaigns = CreatorCampaigns::<T>::get(&creator);
             ensure!(
@@ -454,6 +1518,13 @@ pub mod pallet {
                 deposit >= T::NativeCurrency::minimum_balance(),
                 Error::<T>::InsufficientBalance
             );
+            // The reap deposit is all that remains in the sub-account once the
+            // creation deposit is returned, so it must keep the account alive.
+            let reap_deposit = T::ReapDeposit::get();
+            ensure!(
+                reap_deposit.is_zero() || reap_deposit >= T::NativeCurrency::minimum_balance(),
+                Error::<T>::InsufficientBalance
+            );
 
             let campaign_id = NextCampaignId::<T>::get();
             let next_id = campaign_id.checked_add(1).ok_or(Error::<T>::CampaignIdOverflow)?;
@@ -468,6 +1539,41 @@ pub mod pallet {
                 deposit,
                 ExistenceRequirement::KeepAlive,
             )?;
+            if !reap_deposit.is_zero() {
+                T::NativeCurrency::transfer(
+                    &creator,
+                    &sub_account,
+                    reap_deposit,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+                ReapDeposits::<T>::insert(campaign_id, reap_deposit);
+            }
+
+            // Lock the creator bond alongside the deposit.  Only milestone
+            // campaigns have a delivery phase the bond can be slashed for.
+            let bond = match &config.funding_model {
+                FundingModel::MilestoneBased { goal, .. } => {
+                    Self::bps_of(*goal, CreatorBondBps::<T>::get())
+                }
+                _ => Zero::zero(),
+            };
+            if !bond.is_zero() {
+                T::NativeCurrency::transfer(
+                    &creator,
+                    &sub_account,
+                    bond,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+                CreatorBonds::<T>::insert(
+                    campaign_id,
+                    CreatorBond {
+                        amount: bond,
+                        released: Zero::zero(),
+                        slashed: Zero::zero(),
+                        refunded: Zero::zero(),
+                    },
+                );
+            }
 
             let rules = custom_rules.unwrap_or_else(|| DefaultEligibilityRules::<T>::get());
 
@@ -489,16 +1595,23 @@ pub mod pallet {
             };
 
             Campaigns::<T>::insert(campaign_id, &campaign);
+            if let (Some(asset_id), Some(part_id)) = (rwa_asset_id, participation_id) {
+                LicensedCampaigns::<T>::insert((asset_id, part_id, campaign_id), ());
+            }
             CreatorCampaigns::<T>::try_mutate(&creator, |ids| -> DispatchResult {
                 ids.try_push(campaign_id).map_err(|_| Error::<T>::MaxCampaignsPerCreatorReached)?;
                 Ok(())
             })?;
+            Self::schedule_finalization(campaign_id, campaign.config.deadline)?;
 
             Self::deposit_event(Event::CampaignCreated {
                 campaign_id,
                 creator,
                 deadline: campaign.config.deadline,
             });
+            if !bond.is_zero() {
+                Self::deposit_event(Event::CreatorBondLocked { campaign_id, amount: bond });
+            }
             Ok(())
         }
 
@@ -506,20 +1619,24 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::cancel_campaign())]
         pub fn cancel_campaign(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
             T::ForceOrigin::ensure_origin(origin)?;
+            Self::settle_vested_funds(campaign_id)?;
+            let mut undelivered = false;
             Campaigns::<T>::try_mutate(campaign_id, |maybe| -> DispatchResult {
                 let c = maybe.as_mut().ok_or(Error::<T>::CampaignNotFound)?;
                 // CRIT-02: also block Succeeded — once a campaign has finalized to
                 // Succeeded the creator has a committed economic relationship with
                 // investors and must be guaranteed a window to claim funds.
                 // MilestonePhase remains cancellable as an emergency valve if the
-                // creator disappears.
+                // creator disappears.  A Succeeded campaign still vesting is
+                // cancellable too: the vested part was just paid out, and the
+                // unvested remainder is refunded.
                 ensure!(
-                    !matches!(
-                        c.status,
-                        CampaignStatus::Cancelled
-                            | CampaignStatus::Completed
-                            | CampaignStatus::Succeeded
-                    ),
+                    !matches!(c.status, CampaignStatus::Cancelled | CampaignStatus::Completed),
+                    Error::<T>::InvalidCampaignStatus
+                );
+                ensure!(
+                    !matches!(c.status, CampaignStatus::Succeeded)
+                        || VestingSchedules::<T>::contains_key(campaign_id),
                     Error::<T>::InvalidCampaignStatus
                 );
                 // P2-08: clean up milestone statuses (bounded by MaxMilestones ≤ 5)
@@ -528,13 +1645,23 @@ pub mod pallet {
                         MilestoneStatuses::<T>::remove(campaign_id, i);
                     }
                 }
+                if matches!(c.status, CampaignStatus::Funding) {
+                    Self::unschedule_finalization(campaign_id, c.config.deadline);
+                }
+                undelivered = matches!(c.status, CampaignStatus::MilestonePhase);
                 c.status = CampaignStatus::Cancelled;
+                Self::unlink_license(campaign_id, c);
                 Ok(())
             })?;
+            if undelivered {
+                Self::slash_creator_bond(campaign_id);
+            }
             // H-2: clean up whitelist entries — they have no use once a campaign is
             // cancelled, and leaving them wastes storage indefinitely.
-            let _ = CampaignWhitelist::<T>::clear_prefix(campaign_id, u32::MAX, None);
-            CampaignWhitelistCount::<T>::remove(campaign_id);
+            Self::clear_whitelist(campaign_id);
+            Self::clear_registrations(campaign_id);
+            Self::queue_refunds(campaign_id);
+            Self::release_asset_offering(campaign_id);
             Self::deposit_event(Event::CampaignCancelled { campaign_id });
             Ok(())
         }
@@ -559,101 +1686,7 @@ pub mod pallet {
             amount: BalanceOf<T>,
         ) -> DispatchResult {
             let investor = ensure_signed(origin)?;
-            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
-            ensure!(
-                matches!(campaign.status, CampaignStatus::Funding),
-                Error::<T>::InvalidCampaignStatus
-            );
-            let now = frame_system::Pallet::<T>::block_number();
-            ensure!(now <= campaign.config.deadline, Error::<T>::DeadlinePassed);
-
-            // P2-01: reject zero-amount investments before any other checks
-            ensure!(!amount.is_zero(), Error::<T>::InvestmentBelowMinimum);
-
-            // eligibility
-            Self::check_eligibility_inner(&investor, &campaign, campaign_id)?;
-
-            // min investment
-            if let Some(min) = campaign.config.min_investment {
-                ensure!(amount >= min, Error::<T>::InvestmentBelowMinimum);
-            }
-
-            // hard cap
-            if let Some(cap) = campaign.config.hard_cap {
-                ensure!(
-                    campaign.total_raised.saturating_add(amount) <= cap,
-                    Error::<T>::HardCapExceeded
-                );
-            }
-
-            // per-investor max
-            let mut inv = Investments::<T>::get(campaign_id, &investor).unwrap_or_default();
-            let current = inv.total_invested.saturating_sub(inv.total_withdrawn);
-            if let Some(max_per) = campaign.config.max_investment_per_investor {
-                ensure!(
-                    current.saturating_add(amount) <= max_per,
-                    Error::<T>::InvestmentExceedsMaxPerInvestor
-                );
-            }
-
-            // H-1: use InvestorCampaigns membership as the authoritative "new investor"
-            // signal. The Investment record persists after a full withdrawal
-            // (total_invested and total_withdrawn are both non-zero), so
-            // checking those fields produces a false negative on re-invest.
-            // InvestorCampaigns is removed on full withdrawal (and on refund),
-            // so its absence correctly identifies a new participant.
-            let is_new = !InvestorCampaigns::<T>::get(&investor).contains(&campaign_id);
-
-            if is_new {
-                let current = InvestorCampaigns::<T>::get(&investor);
-                ensure!(
-                    (current.len() as u32) < T::MaxInvestmentsPerInvestor::get(),
-                    Error::<T>::MaxInvestmentsPerInvestorReached
-                );
-            }
-
-            let sub_account = Self::campaign_account(campaign_id);
-            Self::do_transfer(
-                &campaign.config.funding_currency,
-                &investor,
-                &sub_account,
-                amount,
-                ExistenceRequirement::KeepAlive,
-            )?;
-
-            inv.total_invested = inv.total_invested.saturating_add(amount);
-            Investments::<T>::insert(campaign_id, &investor, inv);
-
-            let mut hard_cap_reached = false;
-            Campaigns::<T>::mutate(campaign_id, |maybe| {
-                if let Some(c) = maybe {
-                    c.total_raised = c.total_raised.saturating_add(amount);
-                    if is_new {
-                        c.investor_count = c.investor_count.saturating_add(1);
-                    }
-                    // P3-01: check hard cap using the updated total_raised
-                    if let Some(cap) = c.config.hard_cap {
-                        if c.total_raised == cap {
-                            hard_cap_reached = true;
-                        }
-                    }
-                }
-            });
-
-            if is_new {
-                InvestorCampaigns::<T>::try_mutate(&investor, |ids| -> DispatchResult {
-                    ids.try_push(campaign_id)
-                        .map_err(|_| Error::<T>::MaxInvestmentsPerInvestorReached)?;
-                    Ok(())
-                })?;
-            }
-
-            if hard_cap_reached {
-                Self::deposit_event(Event::HardCapReached { campaign_id });
-            }
-
-            Self::deposit_event(Event::Invested { campaign_id, investor, amount });
-            Ok(())
+            Self::do_invest(investor, campaign_id, amount, None, None)
         }
 
         #[pallet::call_index(4)]
@@ -678,37 +1711,81 @@ pub mod pallet {
             let current = inv.total_invested.saturating_sub(inv.total_withdrawn);
             ensure!(amount <= current, Error::<T>::InsufficientInvestment);
 
-            let penalty_bps = campaign
-                .config
-                .early_withdrawal_penalty_bps
-                .unwrap_or_else(|| T::EarlyWithdrawalPenaltyBps::get());
-            // P2-10: compute_penalty merged into bps_of (with clamping)
-            let penalty = Self::bps_of(amount, penalty_bps);
-            let net = amount.saturating_sub(penalty);
+            let now = frame_system::Pallet::<T>::block_number();
+            let penalty_bps = Self::withdrawal_penalty_bps(campaign_id, &campaign, &investor, now);
+            let destination = PenaltyDestinations::<T>::get(campaign_id);
 
             let sub_account = Self::campaign_account(campaign_id);
-            // transfer net to investor
-            Self::do_transfer(
-                &campaign.config.funding_currency,
-                &sub_account,
-                &investor,
-                net,
-                ExistenceRequirement::AllowDeath,
-            )?;
-            // burn penalty
-            if !penalty.is_zero() {
-                Self::do_burn(
-                    &campaign.config.funding_currency,
+            let holdings = InvestorHoldings::<T>::get(campaign_id, &investor).unwrap_or_default();
+            let parts = Self::currency_parts(&campaign, &holdings, amount, current);
+            // The penalty is charged per currency part; the reported total is
+            // what those charges are worth.
+            let penalty = Self::parts_penalty(&parts, penalty_bps);
+            let net = amount.saturating_sub(penalty);
+            for part in &parts {
+                // transfer net to investor, route the penalty
+                let part_penalty = Self::bps_of(part.amount, penalty_bps);
+                Self::do_transfer(
+                    &part.currency,
                     &sub_account,
-                    penalty,
+                    &investor,
+                    part.amount.saturating_sub(part_penalty),
                     ExistenceRequirement::AllowDeath,
                 )?;
+                match destination {
+                    PenaltyDestination::Burn => Self::do_burn(
+                        &part.currency,
+                        &sub_account,
+                        part_penalty,
+                        ExistenceRequirement::AllowDeath,
+                    )?,
+                    PenaltyDestination::ProtocolFeeRecipient => Self::do_transfer(
+                        &part.currency,
+                        &sub_account,
+                        &Self::effective_protocol_fee_recipient(),
+                        part_penalty,
+                        ExistenceRequirement::AllowDeath,
+                    )?,
+                    // Retained penalties stay in the sub-account.  Only the
+                    // funding currency can be retained, see
+                    // `set_withdrawal_penalty`.
+                    PenaltyDestination::CampaignPot | PenaltyDestination::RemainingInvestors => {}
+                }
+            }
+            Self::reduce_holdings(campaign_id, Some(&investor), &parts);
+            if destination.is_retained() && !penalty.is_zero() {
+                PenaltyPots::<T>::mutate(campaign_id, |pot| {
+                    let pot = pot.get_or_insert_with(Default::default);
+                    pot.amount = pot.amount.saturating_add(penalty);
+                });
             }
 
             inv.total_withdrawn = inv.total_withdrawn.saturating_add(amount);
             let fully_withdrawn = inv.total_invested == inv.total_withdrawn;
+            let remaining_position = inv.total_invested.saturating_sub(inv.total_withdrawn);
+            // Claw back the early-bird bonus pro-rata: the withdrawn units lose
+            // their share of the weighted allocation.
+            let weighted = if fully_withdrawn {
+                Zero::zero()
+            } else {
+                Self::pro_rata(inv.weighted_allocation, remaining_position, current)
+            };
+            TotalWeightedAllocations::<T>::mutate(campaign_id, |total| {
+                *total = total.saturating_sub(inv.weighted_allocation.saturating_sub(weighted))
+            });
+            inv.weighted_allocation = weighted;
             Investments::<T>::insert(campaign_id, &investor, inv);
 
+            // Restore the reward tier supply once the remaining position no
+            // longer meets the tier minimum.
+            if let Some(alloc) = InvestorTiers::<T>::get(campaign_id, &investor) {
+                let tier_min = RewardTiers::<T>::get(campaign_id)
+                    .and_then(|tiers| tiers.get(alloc.tier_index as usize).map(|t| t.min_amount));
+                if fully_withdrawn || tier_min.map_or(true, |min| remaining_position < min) {
+                    Self::release_tier(campaign_id, &investor);
+                }
+            }
+
             if fully_withdrawn {
                 InvestorCampaigns::<T>::mutate(&investor, |ids| {
                     if let Some(pos) = ids.iter().position(|&id| id == campaign_id) {
@@ -717,9 +1794,15 @@ pub mod pallet {
                 });
             }
 
+            // A penalty kept in the campaign pot remains part of the raised
+            // funds.
+            let released = match destination {
+                PenaltyDestination::CampaignPot => net,
+                _ => amount,
+            };
             Campaigns::<T>::mutate(campaign_id, |maybe| {
                 if let Some(c) = maybe {
-                    c.total_raised = c.total_raised.saturating_sub(amount);
+                    c.total_raised = c.total_raised.saturating_sub(released);
                     // P2-12: decrement investor_count when fully withdrawn
                     if fully_withdrawn {
                         c.investor_count = c.investor_count.saturating_sub(1);
@@ -727,6 +1810,14 @@ pub mod pallet {
                 }
             });
 
+            if !penalty.is_zero() {
+                Self::deposit_event(Event::WithdrawalPenaltyApplied {
+                    campaign_id,
+                    investor: investor.clone(),
+                    amount: penalty,
+                    destination,
+                });
+            }
             Self::deposit_event(Event::InvestmentWithdrawn {
                 campaign_id,
                 investor,
@@ -748,80 +1839,25 @@ pub mod pallet {
 
             let inv = Investments::<T>::get(campaign_id, &investor)
                 .ok_or(Error::<T>::NoInvestmentFound)?;
-            let raw_refund = inv.total_invested.saturating_sub(inv.total_withdrawn);
-            ensure!(!raw_refund.is_zero(), Error::<T>::NothingToRefund);
+            Self::do_refund(campaign_id, &campaign, &investor, &inv)?;
+            Ok(())
+        }
 
-            // When milestones have been partially disbursed before cancellation,
-            // reduce the refund proportionally so it doesn't exceed the sub-account
-            // balance.
-            let refund = if !campaign.total_disbursed.is_zero() && !campaign.total_raised.is_zero()
-            {
-                let remaining_ratio = Permill::from_rational(
-                    campaign.total_raised.saturating_sub(campaign.total_disbursed),
-                    campaign.total_raised,
-                );
-                remaining_ratio * raw_refund
-            } else {
-                raw_refund
-            };
-            ensure!(!refund.is_zero(), Error::<T>::NothingToRefund);
-
-            let sub_account = Self::campaign_account(campaign_id);
-            Self::do_transfer(
-                &campaign.config.funding_currency,
-                &sub_account,
-                &investor,
-                refund,
-                ExistenceRequirement::AllowDeath,
-            )?;
-
-            Investments::<T>::remove(campaign_id, &investor);
-
-            InvestorCampaigns::<T>::mutate(&investor, |ids| {
-                if let Some(pos) = ids.iter().position(|&id| id == campaign_id) {
-                    ids.remove(pos);
-                }
-            });
-
-            // P2-12: decrement investor_count on refund
-            Campaigns::<T>::mutate(campaign_id, |maybe| {
-                if let Some(c) = maybe {
-                    c.investor_count = c.investor_count.saturating_sub(1);
-                }
-            });
-
-            Self::deposit_event(Event::RefundClaimed { campaign_id, investor, amount: refund });
-            Ok(())
-        }
-
-        /// Transition a Funding campaign to its terminal status after the
-        /// deadline passes.
-        ///
-        /// This extrinsic is **permissionless by design**: any signed account
-        /// can trigger finalization once the campaign deadline has
-        /// elapsed.  This avoids a liveness dependency on a single
-        /// privileged actor (e.g., the creator going offline) while
-        /// imposing no economic risk — the outcome is fully determined
-        /// by on-chain state (total_raised vs. goal).
-        #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::finalize_campaign())]
-        pub fn finalize_campaign(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
-            ensure_signed(origin)?;
-            Campaigns::<T>::try_mutate(campaign_id, |maybe| -> DispatchResult {
-                let c = maybe.as_mut().ok_or(Error::<T>::CampaignNotFound)?;
-                ensure!(
-                    matches!(c.status, CampaignStatus::Funding),
-                    Error::<T>::InvalidCampaignStatus
-                );
-                let now = frame_system::Pallet::<T>::block_number();
-                ensure!(now > c.config.deadline, Error::<T>::CampaignStillFunding);
-
-                let new_status = Self::do_finalize(campaign_id, c);
-                c.status = new_status;
-                Self::deposit_event(Event::CampaignFinalized { campaign_id, status: new_status });
-                Ok(())
-            })
-        }
+        /// Transition a Funding campaign to its terminal status after the
+        /// deadline passes.
+        ///
+        /// This extrinsic is **permissionless by design**: any signed account
+        /// can trigger finalization once the campaign deadline has
+        /// elapsed.  This avoids a liveness dependency on a single
+        /// privileged actor (e.g., the creator going offline) while
+        /// imposing no economic risk — the outcome is fully determined
+        /// by on-chain state (total_raised vs. goal).
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::finalize_campaign())]
+        pub fn finalize_campaign(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::do_finalize_campaign(campaign_id)
+        }
 
         #[pallet::call_index(7)]
         #[pallet::weight(T::WeightInfo::claim_funds())]
@@ -855,44 +1891,10 @@ pub mod pallet {
             //    creator") conflated RWA payer with campaign creator — these
             //    are independent concepts.
 
-            let claimable = campaign.total_raised.saturating_sub(campaign.total_disbursed);
+            let now = frame_system::Pallet::<T>::block_number();
+            let claimable = Self::claimable_funds(campaign_id, &campaign, now);
             ensure!(!claimable.is_zero(), Error::<T>::NothingToClaim);
-
-            // CAT-3.9-C-I: use the fee locked at creation time, not the current global rate
-            let fee_bps = campaign.protocol_fee_bps;
-            let fee = Self::bps_of(claimable, fee_bps);
-            let creator_amount = claimable.saturating_sub(fee);
-
-            let sub_account = Self::campaign_account(campaign_id);
-            if !fee.is_zero() {
-                let fee_recipient = Self::effective_protocol_fee_recipient();
-                Self::do_transfer(
-                    &campaign.config.funding_currency,
-                    &sub_account,
-                    &fee_recipient,
-                    fee,
-                    ExistenceRequirement::AllowDeath,
-                )?;
-                Self::deposit_event(Event::ProtocolFeeCollected { campaign_id, amount: fee });
-            }
-            Self::do_transfer(
-                &campaign.config.funding_currency,
-                &sub_account,
-                &who,
-                creator_amount,
-                ExistenceRequirement::AllowDeath,
-            )?;
-
-            campaign.total_disbursed = campaign.total_raised;
-            campaign.status = CampaignStatus::Completed;
-            Campaigns::<T>::insert(campaign_id, &campaign);
-
-            // P1-02: emit net amount (after fee), not gross
-            Self::deposit_event(Event::FundsClaimed {
-                campaign_id,
-                creator: who.clone(),
-                amount: creator_amount,
-            });
+            Self::do_claim_funds(campaign_id, &mut campaign, claimable)?;
             // P2-09: CampaignCompleted renamed to CreationDepositClaimed; the
             // "completed" signal here is implicit from the status transition.
             // We keep a separate CreationDepositClaimed event for the deposit
@@ -925,6 +1927,14 @@ pub mod pallet {
             let deposit = campaign.creation_deposit;
             let sub_account = Self::campaign_account(campaign_id);
 
+            // Return whatever part of the creator bond was neither released
+            // nor slashed (a failed campaign never reached delivery).
+            if let Some(bond) = CreatorBonds::<T>::get(campaign_id) {
+                let remaining =
+                    bond.amount.saturating_sub(bond.released).saturating_sub(bond.slashed);
+                Self::release_creator_bond(campaign_id, &who, bond, remaining)?;
+            }
+
             // CRIT-06: For campaigns funded with a fungible asset (not native),
             // the sub-account also holds asset token balances for investor
             // refunds.  If the native balance drops to zero the sub-account is
@@ -953,8 +1963,8 @@ pub mod pallet {
 
             // H-2: clean up any remaining whitelist entries now that the campaign
             // has fully concluded and the deposit is being reclaimed.
-            let _ = CampaignWhitelist::<T>::clear_prefix(campaign_id, u32::MAX, None);
-            CampaignWhitelistCount::<T>::remove(campaign_id);
+            Self::clear_whitelist(campaign_id);
+            Self::clear_registrations(campaign_id);
 
             // P2-09: renamed from CampaignCompleted to CreationDepositClaimed
             Self::deposit_event(Event::CreationDepositClaimed {
@@ -1021,6 +2031,14 @@ pub mod pallet {
                 Ok(())
             })?;
 
+            if let (Some(bond), FundingModel::MilestoneBased { milestones, .. }) =
+                (CreatorBonds::<T>::get(campaign_id), &campaign.config.funding_model)
+            {
+                let release_bps = milestones.get(index as usize).map_or(0, |m| m.release_bps);
+                let amount = Self::bps_of_floor(bond.amount, release_bps);
+                Self::release_creator_bond(campaign_id, &campaign.creator, bond, amount)?;
+            }
+
             Self::deposit_event(Event::MilestoneApproved { campaign_id, index });
             Ok(())
         }
@@ -1106,25 +2124,12 @@ pub mod pallet {
             let fee = Self::bps_of(release_amount, fee_bps);
             let creator_amount = release_amount.saturating_sub(fee);
 
-            let sub_account = Self::campaign_account(campaign_id);
+            let holdings = CampaignHoldings::<T>::get(campaign_id).unwrap_or_default();
+            let parts = Self::currency_parts(&campaign, &holdings, release_amount, remaining);
+            Self::pay_creator(campaign_id, &who, &parts, fee_bps)?;
             if !fee.is_zero() {
-                let fee_recipient = Self::effective_protocol_fee_recipient();
-                Self::do_transfer(
-                    &campaign.config.funding_currency,
-                    &sub_account,
-                    &fee_recipient,
-                    fee,
-                    ExistenceRequirement::AllowDeath,
-                )?;
                 Self::deposit_event(Event::ProtocolFeeCollected { campaign_id, amount: fee });
             }
-            Self::do_transfer(
-                &campaign.config.funding_currency,
-                &sub_account,
-                &who,
-                creator_amount,
-                ExistenceRequirement::AllowDeath,
-            )?;
 
             campaign.total_disbursed = campaign.total_disbursed.saturating_add(release_amount);
             MilestoneStatuses::<T>::insert(campaign_id, index, MilestoneStatus::Claimed);
@@ -1136,6 +2141,7 @@ pub mod pallet {
             });
             if all_claimed {
                 campaign.status = CampaignStatus::Completed;
+                Self::unlink_license(campaign_id, &campaign);
             }
 
             Campaigns::<T>::insert(campaign_id, &campaign);
@@ -1159,7 +2165,7 @@ pub mod pallet {
         #[pallet::call_index(13)]
         #[pallet::weight(T::WeightInfo::pause_campaign())]
         pub fn pause_campaign(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
+            T::PauseOrigin::ensure_origin(origin)?;
             Campaigns::<T>::try_mutate(campaign_id, |maybe| -> DispatchResult {
                 let c = maybe.as_mut().ok_or(Error::<T>::CampaignNotFound)?;
                 ensure!(
@@ -1170,6 +2176,9 @@ pub mod pallet {
                 // M-2: record the block at which we paused so resume can extend deadline
                 c.paused_at = Some(now);
                 c.status = CampaignStatus::Paused;
+                // Paused campaigns are not finalized; resume re-indexes them
+                // at the extended deadline.
+                Self::unschedule_finalization(campaign_id, c.config.deadline);
                 Ok(())
             })?;
             Self::deposit_event(Event::CampaignPaused { campaign_id });
@@ -1179,7 +2188,7 @@ pub mod pallet {
         #[pallet::call_index(14)]
         #[pallet::weight(T::WeightInfo::resume_campaign())]
         pub fn resume_campaign(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
+            T::PauseOrigin::ensure_origin(origin)?;
             Campaigns::<T>::try_mutate(campaign_id, |maybe| -> DispatchResult {
                 let c = maybe.as_mut().ok_or(Error::<T>::CampaignNotFound)?;
                 ensure!(matches!(c.status, CampaignStatus::Paused), Error::<T>::CampaignNotPaused);
@@ -1189,7 +2198,18 @@ pub mod pallet {
                 if let Some(paused_at) = c.paused_at {
                     let pause_duration = now.saturating_sub(paused_at);
                     c.config.deadline = c.config.deadline.saturating_add(pause_duration);
+                    // Rounds still open at the pause are extended likewise.
+                    FundingRounds::<T>::mutate(campaign_id, |maybe| {
+                        for round in maybe.iter_mut().flat_map(|rounds| rounds.iter_mut()) {
+                            if round.deadline >= paused_at {
+                                round.deadline = round.deadline.saturating_add(pause_duration);
+                            }
+                        }
+                    });
                 }
+                // A full deadline slot pushes the deadline to the next block
+                // with room in the index.
+                c.config.deadline = Self::free_deadline(c.config.deadline)?;
                 // CRIT-04: if the campaign has a linked license, re-validate that the
                 // extended deadline still falls before the license expiry.  Without
                 // this check, an admin can pause/resume to push the deadline past the
@@ -1203,6 +2223,7 @@ pub mod pallet {
                         );
                     }
                 }
+                Self::schedule_finalization(campaign_id, c.config.deadline)?;
                 c.paused_at = None;
                 c.status = CampaignStatus::Funding;
                 Ok(())
@@ -1281,6 +2302,20 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Set the creator bond, in basis points of the goal, that new
+        /// milestone-based campaigns lock at creation (zero disables it).
+        ///
+        /// Campaigns keep the bond they locked when they were created.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::set_creator_bond_bps())]
+        pub fn set_creator_bond_bps(origin: OriginFor<T>, bond_bps: u16) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(bond_bps <= 10_000, Error::<T>::InvalidBondBps);
+            CreatorBondBps::<T>::put(bond_bps);
+            Self::deposit_event(Event::CreatorBondBpsSet { bond_bps });
+            Ok(())
+        }
+
         // ─── License Revocation ──────────────────────────────────────
 
         /// Permissionless: anyone can cancel a campaign whose linked RWA
@@ -1290,6 +2325,8 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::report_license_revoked())]
         pub fn report_license_revoked(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
             ensure_signed(origin)?;
+            Self::settle_vested_funds(campaign_id)?;
+            let mut undelivered = false;
             Campaigns::<T>::try_mutate(campaign_id, |maybe| -> DispatchResult {
                 let c = maybe.as_mut().ok_or(Error::<T>::CampaignNotFound)?;
                 // Only applies to campaigns with a linked license.
@@ -1301,14 +2338,12 @@ pub mod pallet {
                 // active-disbursement state.  Succeeded is intentionally excluded:
                 // once a campaign has finalized to Succeeded the creator has a
                 // guaranteed window to claim funds and must not be rug-pulled by a
-                // permissionless license-revocation report.
+                // permissionless license-revocation report.  Funds still
+                // vesting are the exception: the creator keeps what has vested
+                // and investors are refunded the rest.
                 ensure!(
-                    matches!(
-                        c.status,
-                        CampaignStatus::Funding
-                            | CampaignStatus::Paused
-                            | CampaignStatus::MilestonePhase
-                    ),
+                    matches!(c.status, CampaignStatus::Funding | CampaignStatus::Paused)
+                        || Self::is_cancellable_success(campaign_id, c),
                     Error::<T>::InvalidCampaignStatus
                 );
                 // Verify the license is indeed no longer active.
@@ -1322,11 +2357,21 @@ pub mod pallet {
                         MilestoneStatuses::<T>::remove(campaign_id, i);
                     }
                 }
+                if matches!(c.status, CampaignStatus::Funding) {
+                    Self::unschedule_finalization(campaign_id, c.config.deadline);
+                }
+                undelivered = matches!(c.status, CampaignStatus::MilestonePhase);
                 c.status = CampaignStatus::Cancelled;
+                Self::unlink_license(campaign_id, c);
                 Ok(())
             })?;
-            let _ = CampaignWhitelist::<T>::clear_prefix(campaign_id, u32::MAX, None);
-            CampaignWhitelistCount::<T>::remove(campaign_id);
+            if undelivered {
+                Self::slash_creator_bond(campaign_id);
+            }
+            Self::clear_whitelist(campaign_id);
+            Self::clear_registrations(campaign_id);
+            Self::queue_refunds(campaign_id);
+            Self::release_asset_offering(campaign_id);
             Self::deposit_event(Event::CampaignLicenseReported { campaign_id });
             Self::deposit_event(Event::CampaignCancelled { campaign_id });
             Ok(())
@@ -1352,8 +2397,14 @@ pub mod pallet {
                 );
                 // NOTE: deadline check intentionally skipped.
 
+                Self::unschedule_finalization(campaign_id, c.config.deadline);
                 let new_status = Self::do_finalize(campaign_id, c);
                 c.status = new_status;
+                if new_status == CampaignStatus::Failed {
+                    Self::queue_refunds(campaign_id);
+                    Self::release_asset_offering(campaign_id);
+                    Self::unlink_license(campaign_id, c);
+                }
                 Self::deposit_event(Event::CampaignForceFinalized {
                     campaign_id,
                     status: new_status,
@@ -1361,101 +2412,1862 @@ pub mod pallet {
                 Ok(())
             })
         }
-    }
 
-    // ── Helpers ──────────────────────────────────────────────────────
+        // ─── Reward Tiers ───────────────────────────────────────────────
 
-    impl<T: Config> Pallet<T> {
-        /// Determine the finalization outcome for a Funding campaign and
-        /// initialize milestone statuses if applicable.  Returns the new
-        /// `CampaignStatus`.  Caller is responsible for writing it back
-        /// and emitting the appropriate event.
-        fn do_finalize(campaign_id: u32, c: &CampaignOf<T>) -> CampaignStatus {
-            match &c.config.funding_model {
-                FundingModel::AllOrNothing { goal } => {
-                    if c.total_raised >= *goal {
-                        CampaignStatus::Succeeded
-                    } else {
-                        CampaignStatus::Failed
-                    }
-                }
-                FundingModel::KeepWhatYouRaise { soft_cap } => match soft_cap {
-                    Some(cap) if c.total_raised < *cap => CampaignStatus::Failed,
-                    _ => CampaignStatus::Succeeded,
-                },
-                FundingModel::MilestoneBased { goal, milestones } => {
-                    if c.total_raised >= *goal {
-                        for i in 0..milestones.len() {
-                            MilestoneStatuses::<T>::insert(
-                                campaign_id,
-                                i as u8,
-                                MilestoneStatus::Pending,
-                            );
-                        }
-                        CampaignStatus::MilestonePhase
-                    } else {
-                        CampaignStatus::Failed
-                    }
+        /// Declare the campaign's reward tiers, replacing any previous set.
+        ///
+        /// Only the creator can set tiers, and only while the campaign is
+        /// `Funding` and has no investors, so tier terms never change under
+        /// existing backers.  A tier's NFT collection must be owned by the
+        /// creator.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_reward_tiers(tiers.len() as u32))]
+        pub fn set_reward_tiers(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            mut tiers: BoundedVec<RewardTierOf<T>, T::MaxRewardTiers>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::RewardTiersLocked
+            );
+
+            for tier in tiers.iter_mut() {
+                ensure!(tier.quantity > 0, Error::<T>::InvalidRewardTier);
+                if let Some(collection) = &tier.nft_collection {
+                    let owner =
+                        <T::NftInspect as nonfungibles_v2::Inspect<T::AccountId>>::collection_owner(
+                            collection,
+                        );
+                    ensure!(owner.as_ref() == Some(&who), Error::<T>::InvalidRewardTier);
                 }
+                tier.remaining = tier.quantity;
             }
+
+            let tier_count = tiers.len() as u32;
+            if tiers.is_empty() {
+                RewardTiers::<T>::remove(campaign_id);
+            } else {
+                RewardTiers::<T>::insert(campaign_id, tiers);
+            }
+            Self::deposit_event(Event::RewardTiersSet { campaign_id, tier_count });
+            Ok(())
         }
 
-        pub fn campaign_account(campaign_id: u32) -> T::AccountId {
-            T::PalletId::get().into_sub_account_truncating(campaign_id)
+        /// Invest and select a reward tier.
+        ///
+        /// The investor's net position after this investment must meet the
+        /// tier's `min_amount`.  Selecting a tier other than the one already
+        /// held releases the previous allocation.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::invest_with_tier())]
+        pub fn invest_with_tier(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            amount: BalanceOf<T>,
+            tier_index: u8,
+        ) -> DispatchResult {
+            let investor = ensure_signed(origin)?;
+            Self::do_invest(investor, campaign_id, amount, Some(tier_index), None)
         }
 
-        /// Return the effective protocol fee in basis points.
+        /// Mint the NFT reward of the caller's reward tier.
         ///
-        /// Uses the runtime override if set, otherwise falls back to the
-        /// compile-time `Config::ProtocolFeeBps` constant.
-        pub fn effective_protocol_fee_bps() -> u16 {
-            ProtocolFeeBpsOverride::<T>::get().unwrap_or_else(T::ProtocolFeeBps::get)
+        /// Available once the campaign has succeeded and can no longer be
+        /// cancelled: Completed, or Succeeded without a vesting schedule.
+        /// Each allocation is minted at most once.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::claim_reward_nft())]
+        pub fn claim_reward_nft(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(
+                matches!(
+                    campaign.status,
+                    CampaignStatus::Succeeded
+                        | CampaignStatus::MilestonePhase
+                        | CampaignStatus::Completed
+                ),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                !Self::is_cancellable_success(campaign_id, &campaign),
+                Error::<T>::CampaignStillCancellable
+            );
+
+            let mut alloc =
+                InvestorTiers::<T>::get(campaign_id, &who).ok_or(Error::<T>::NoRewardToMint)?;
+            ensure!(!alloc.reward_minted, Error::<T>::AlreadyClaimed);
+            let tiers = RewardTiers::<T>::get(campaign_id).ok_or(Error::<T>::RewardTierNotFound)?;
+            let tier =
+                tiers.get(alloc.tier_index as usize).ok_or(Error::<T>::RewardTierNotFound)?;
+            let collection = tier.nft_collection.ok_or(Error::<T>::NoRewardToMint)?;
+
+            Self::do_mint_reward(campaign_id, &who, alloc.tier_index, collection)?;
+            alloc.reward_minted = true;
+            InvestorTiers::<T>::insert(campaign_id, &who, alloc);
+            Ok(())
         }
 
-        /// Return the effective protocol fee recipient.
+        // ─── Bulk Refunds ───────────────────────────────────────────────
+
+        /// Permissionless: push refunds to up to `max_count` investors of a
+        /// Failed or Cancelled campaign.
         ///
-        /// Uses the runtime override if set, otherwise falls back to the
-        /// compile-time `Config::ProtocolFeeRecipient` constant.
-        pub fn effective_protocol_fee_recipient() -> T::AccountId {
-            ProtocolFeeRecipientOverride::<T>::get().unwrap_or_else(T::ProtocolFeeRecipient::get)
+        /// Uses the same proportional math as `claim_refund` and resumes
+        /// from a stored cursor, so repeated calls eventually visit every
+        /// investor.  Once all records have been visited, the cursor and the
+        /// campaign's whitelist are cleaned up.  `max_count` is capped at
+        /// `MaxRefundsPerCall`.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::process_refunds(
+            (*max_count).min(T::MaxRefundsPerCall::get())
+        ))]
+        pub fn process_refunds(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            max_count: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(max_count > 0, Error::<T>::InvalidRefundBatch);
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Failed | CampaignStatus::Cancelled),
+                Error::<T>::InvalidCampaignStatus
+            );
+            let resuming = RefundCursor::<T>::contains_key(campaign_id);
+            let visited = Self::do_process_refunds(
+                campaign_id,
+                &campaign,
+                max_count.min(T::MaxRefundsPerCall::get()),
+            );
+            ensure!(visited > 0 || resuming, Error::<T>::NothingToRefund);
+            Ok(())
         }
 
-        fn do_transfer(
-            currency: &PaymentCurrency<T::AssetId>,
-            from: &T::AccountId,
-            to: &T::AccountId,
-            amount: BalanceOf<T>,
-            existence_req: ExistenceRequirement,
+        // ─── Archival ───────────────────────────────────────────────────
+
+        /// Permissionless: remove the storage of a fully settled campaign.
+        ///
+        /// A campaign is settled once it is Completed, Failed or Cancelled,
+        /// its creation deposit has been claimed and, unless Completed, every
+        /// investor has been refunded.  Each call removes up to `max_count`
+        /// investment records (capped at `MaxReapsPerCall`), minting any
+        /// unclaimed NFT rewards on the way.  The call that removes the last
+        /// record replaces the campaign with an `ArchivedCampaigns` summary
+        /// and pays the reap deposit to the caller.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::reap_campaign(
+            (*max_count).min(T::MaxReapsPerCall::get())
+        ))]
+        pub fn reap_campaign(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            max_count: u32,
         ) -> DispatchResult {
-            if amount.is_zero() {
-                return Ok(());
-            }
-            match currency {
-                PaymentCurrency::Native => {
-                    T::NativeCurrency::transfer(from, to, amount, existence_req)?;
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            let settled = match campaign.status {
+                CampaignStatus::Completed => true,
+                CampaignStatus::Failed | CampaignStatus::Cancelled => campaign.investor_count == 0,
+                _ => false,
+            };
+            ensure!(settled && campaign.creation_deposit.is_zero(), Error::<T>::CampaignNotSettled);
+
+            let limit = max_count.min(T::MaxReapsPerCall::get());
+            let tiers = RewardTiers::<T>::get(campaign_id).unwrap_or_default();
+            let is_offering = AssetOfferings::<T>::contains_key(campaign_id);
+            let mut removed = 0u32;
+            for (investor, inv) in Investments::<T>::iter_prefix(campaign_id).take(limit as usize) {
+                // Mint NFT rewards the investor never claimed before the
+                // allocation is dropped.  Refunded investors hold no tier.
+                if let Some(alloc) = InvestorTiers::<T>::take(campaign_id, &investor) {
+                    let collection =
+                        tiers.get(alloc.tier_index as usize).and_then(|t| t.nft_collection);
+                    if let (false, Some(collection)) = (alloc.reward_minted, collection) {
+                        // A failed mint must not keep the campaign from
+                        // being reaped.
+                        let minted = storage::with_storage_layer(|| {
+                            Self::do_mint_reward(
+                                campaign_id,
+                                &investor,
+                                alloc.tier_index,
+                                collection,
+                            )
+                        });
+                        if minted.is_err() {
+                            Self::deposit_event(Event::RewardMintFailed {
+                                campaign_id,
+                                investor: investor.clone(),
+                                tier_index: alloc.tier_index,
+                            });
+                        }
+                    }
                 }
-                PaymentCurrency::Asset(asset_id) => {
-                    let keep_alive = matches!(existence_req, ExistenceRequirement::KeepAlive);
-                    <T::Fungibles as fungibles::Transfer<T::AccountId>>::transfer(
-                        *asset_id, from, to, amount, keep_alive,
-                    )?;
+                // Likewise issue unclaimed asset shares.  Only Completed
+                // campaigns still have an offering at this point.
+                if is_offering && OfferingShareClaims::<T>::take(campaign_id, &investor).is_none() {
+                    Self::issue_asset_share(campaign_id, &investor, &inv);
+                }
+                // And pay out unclaimed shares of retained penalties.
+                // A failed transfer must not keep the campaign from being
+                // reaped either; the unpaid share stays in the pot.
+                if PenaltyShareClaims::<T>::take(campaign_id, &investor).is_none() {
+                    let net = inv.total_invested.saturating_sub(inv.total_withdrawn);
+                    let paid = storage::with_storage_layer(|| {
+                        Self::pay_penalty_share(campaign_id, &campaign, &investor, net)
+                    });
+                    if paid.is_err() {
+                        Self::deposit_event(Event::PenaltySharePaymentFailed {
+                            campaign_id,
+                            investor: investor.clone(),
+                        });
+                    }
                 }
+                Investments::<T>::remove(campaign_id, &investor);
+                InvestedAt::<T>::remove(campaign_id, &investor);
+                InvestorHoldings::<T>::remove(campaign_id, &investor);
+                InvestorCampaigns::<T>::mutate(&investor, |ids| {
+                    ids.retain(|&id| id != campaign_id);
+                });
+                removed += 1;
+            }
+
+            let completed = Investments::<T>::iter_key_prefix(campaign_id).next().is_none();
+            if completed {
+                Self::archive_campaign(campaign_id, &campaign, &who)?;
             }
+            Self::deposit_event(Event::CampaignReaped { campaign_id, removed, completed });
             Ok(())
         }
 
-        fn do_burn(
-            currency: &PaymentCurrency<T::AssetId>,
-            who: &T::AccountId,
-            amount: BalanceOf<T>,
-            existence_req: ExistenceRequirement,
+        // ─── Eligibility Expressions ────────────────────────────────────
+
+        /// Set or clear (empty `expression`) the campaign's eligibility
+        /// expression.
+        ///
+        /// Investors must satisfy the expression in addition to the flat
+        /// `eligibility_rules`.  Only the creator can set it, and only while
+        /// the campaign is `Funding` and has no investors.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::set_eligibility_expression(expression.len() as u32))]
+        pub fn set_eligibility_expression(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            expression: EligibilityExpressionOf<T>,
         ) -> DispatchResult {
-            if amount.is_zero() {
-                return Ok(());
-            }
-            match currency {
-                PaymentCurrency::Native => {
-                    // L-1: use FEE reason, not TRANSFER — this is a penalty burn, not a
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::EligibilityLocked
+            );
+            ensure!(
+                Self::is_valid_expression(&expression),
+                Error::<T>::InvalidEligibilityExpression
+            );
+
+            let node_count = expression.len() as u32;
+            if expression.is_empty() {
+                EligibilityExpressions::<T>::remove(campaign_id);
+            } else {
+                EligibilityExpressions::<T>::insert(campaign_id, expression);
+            }
+            Self::deposit_event(Event::EligibilityExpressionSet { campaign_id, node_count });
+            Ok(())
+        }
+
+        // ─── Registration Phase ─────────────────────────────────────────
+
+        /// Open a registration phase that lasts until `funding_opens_at`.
+        ///
+        /// While it lasts, accounts call `register_interest` to snapshot
+        /// their balances and investing is rejected.  Afterwards only
+        /// registered accounts can invest, and balance rules use the lower
+        /// of the snapshot and the current balance, so a balance borrowed
+        /// for a single block cannot qualify.  Only the creator can set the
+        /// phase, and only before the first investment.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::set_registration_phase())]
+        pub fn set_registration_phase(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            funding_opens_at: T::BlockNumber,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::EligibilityLocked
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                funding_opens_at > now && funding_opens_at < campaign.config.deadline,
+                Error::<T>::InvalidRegistrationPhase
+            );
+            ensure!(
+                Self::snapshot_assets(&campaign, campaign_id).len() as u32
+                    <= T::MaxSnapshotAssets::get(),
+                Error::<T>::TooManySnapshotAssets
+            );
+
+            RegistrationPhases::<T>::insert(campaign_id, funding_opens_at);
+            Self::deposit_event(Event::RegistrationPhaseSet { campaign_id, funding_opens_at });
+            Ok(())
+        }
+
+        /// Register interest in a campaign during its registration phase,
+        /// recording the caller's native balance and the balances of the
+        /// assets its rules reference.  The caller must pass the campaign's
+        /// eligibility checks against that snapshot.  Registering again
+        /// refreshes the snapshot.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::register_interest())]
+        pub fn register_interest(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            let opens_at =
+                RegistrationPhases::<T>::get(campaign_id).ok_or(Error::<T>::RegistrationClosed)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < opens_at,
+                Error::<T>::RegistrationClosed
+            );
+
+            let mut assets = BoundedVec::default();
+            for asset_id in Self::snapshot_assets(&campaign, campaign_id) {
+                let balance =
+                    <T::Fungibles as fungibles::Inspect<T::AccountId>>::balance(asset_id, &who);
+                assets
+                    .try_push((asset_id, balance))
+                    .map_err(|_| Error::<T>::TooManySnapshotAssets)?;
+            }
+
+            let registered = Registrations::<T>::contains_key(campaign_id, &who);
+            ensure!(
+                registered || RegistrationCount::<T>::get(campaign_id) < T::MaxRegistrations::get(),
+                Error::<T>::MaxRegistrationsReached
+            );
+            let native = T::NativeCurrency::free_balance(&who);
+            // Only accounts that are eligible with this snapshot take one of
+            // the `MaxRegistrations` slots, so throwaway accounts cannot
+            // crowd out real investors.  A failed check restores the
+            // previous snapshot.
+            storage::with_storage_layer(|| {
+                Registrations::<T>::insert(
+                    campaign_id,
+                    &who,
+                    RegistrationSnapshot { native, assets },
+                );
+                Self::check_eligibility_inner(&who, &campaign, campaign_id)
+            })?;
+            if !registered {
+                RegistrationCount::<T>::mutate(campaign_id, |count| *count += 1);
+            }
+
+            Self::deposit_event(Event::InterestRegistered { campaign_id, who });
+            Ok(())
+        }
+
+        /// Set or clear a campaign's whitelist Merkle root.
+        ///
+        /// Leaves commit to an account and an optional per-account
+        /// investment cap.  Proofs cached against a previous root stop
+        /// counting once the root changes.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::set_whitelist_root())]
+        pub fn set_whitelist_root(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            root: Option<[u8; 32]>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding | CampaignStatus::Paused),
+                Error::<T>::InvalidCampaignStatus
+            );
+            match root {
+                Some(root) => WhitelistRoots::<T>::insert(campaign_id, root),
+                None => WhitelistRoots::<T>::remove(campaign_id),
+            }
+            Self::deposit_event(Event::WhitelistRootSet { campaign_id, root });
+            Ok(())
+        }
+
+        /// Invest with a Merkle proof of whitelist membership.
+        ///
+        /// The first successful proof is cached, after which `invest` and
+        /// `invest_with_tier` work without one.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::invest_with_proof(proof.siblings.len() as u32))]
+        pub fn invest_with_proof(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            amount: BalanceOf<T>,
+            proof: WhitelistProof<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let investor = ensure_signed(origin)?;
+            if Self::whitelist_allowance(campaign_id, &investor).is_none() {
+                let root = WhitelistRoots::<T>::get(campaign_id)
+                    .filter(|root| Self::proof_matches(root, &investor, &proof))
+                    .ok_or(Error::<T>::InvalidWhitelistProof)?;
+                WhitelistAllowances::<T>::insert(
+                    campaign_id,
+                    &investor,
+                    WhitelistAllowance { root, cap: proof.cap },
+                );
+                Self::deposit_event(Event::WhitelistProofVerified {
+                    campaign_id,
+                    who: investor.clone(),
+                });
+            }
+            Self::do_invest(investor, campaign_id, amount, None, None)
+        }
+
+        /// Set or clear the alternative currencies a campaign accepts.
+        ///
+        /// Only the creator, while Funding and before the first investment.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::set_accepted_currencies())]
+        pub fn set_accepted_currencies(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            currencies: BoundedVec<PaymentCurrency<T::AssetId>, T::MaxAcceptedCurrencies>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::CurrenciesLocked
+            );
+            for (i, currency) in currencies.iter().enumerate() {
+                ensure!(
+                    *currency != campaign.config.funding_currency
+                        && !currencies.iter().take(i).any(|c| c == currency),
+                    Error::<T>::InvalidAcceptedCurrencies
+                );
+            }
+            ensure!(
+                currencies.is_empty() || !PenaltyDestinations::<T>::get(campaign_id).is_retained(),
+                Error::<T>::PenaltyDestinationUnsupported
+            );
+
+            let list = currencies.to_vec();
+            if currencies.is_empty() {
+                AcceptedCurrencies::<T>::remove(campaign_id);
+            } else {
+                AcceptedCurrencies::<T>::insert(campaign_id, currencies);
+            }
+            Self::deposit_event(Event::AcceptedCurrenciesSet { campaign_id, currencies: list });
+            Ok(())
+        }
+
+        /// Invest in one of a campaign's alternative currencies.
+        ///
+        /// `amount` is converted into the funding currency through
+        /// `PriceFeed` for goal, cap and allocation purposes, but held and
+        /// later paid back in the currency it was contributed in.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::invest_in_currency())]
+        pub fn invest_in_currency(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            currency: PaymentCurrency<T::AssetId>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let investor = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(
+                AcceptedCurrencies::<T>::get(campaign_id)
+                    .map_or(false, |accepted| accepted.contains(&currency)),
+                Error::<T>::CurrencyNotAccepted
+            );
+            let normalized =
+                T::PriceFeed::convert(&currency, &campaign.config.funding_currency, amount)
+                    .filter(|n| !n.is_zero())
+                    .ok_or(Error::<T>::PriceUnavailable)?;
+
+            Self::do_invest(
+                investor.clone(),
+                campaign_id,
+                normalized,
+                None,
+                Some((currency.clone(), amount)),
+            )?;
+            Self::deposit_event(Event::InvestedInCurrency {
+                campaign_id,
+                investor,
+                currency,
+                amount,
+                normalized,
+            });
+            Ok(())
+        }
+
+        // ─── Asset-Backed Offerings ─────────────────────────────────────
+
+        /// Turn a campaign into an offering of `fraction` of its linked RWA
+        /// asset.
+        ///
+        /// The goal and hard cap are set to `fraction` of the asset's
+        /// current valuation, converted into the funding currency through
+        /// `PriceFeed` if needed.  Once the campaign succeeds, investors
+        /// receive a part of `fraction` in proportion to their weighted
+        /// allocation as an asset share.
+        /// Only the creator can configure an offering, and only while the
+        /// campaign is `Funding` and has no investors.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::configure_asset_offering())]
+        pub fn configure_asset_offering(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            fraction: Perbill,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut campaign =
+                Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::OfferingLocked
+            );
+            let rwa_asset_id = campaign.rwa_asset_id.ok_or(Error::<T>::InvalidAssetOffering)?;
+
+            let (currency, amount) = T::ValuationProvider::asset_valuation(rwa_asset_id)
+                .ok_or(Error::<T>::ValuationUnavailable)?;
+            let valuation = if currency == campaign.config.funding_currency {
+                Some(amount)
+            } else {
+                T::PriceFeed::convert(&currency, &campaign.config.funding_currency, amount)
+            }
+            .filter(|v| !v.is_zero())
+            .ok_or(Error::<T>::ValuationUnavailable)?;
+            let goal = fraction * valuation;
+            ensure!(!goal.is_zero(), Error::<T>::InvalidAssetOffering);
+
+            // A previous configuration of this campaign is replaced, so its
+            // fraction does not count against the asset.
+            let previous =
+                AssetOfferings::<T>::get(campaign_id).map_or(Zero::zero(), |o| o.fraction);
+            let committed = CommittedAssetFraction::<T>::get(rwa_asset_id).saturating_sub(previous);
+            let available = Perbill::one()
+                .saturating_sub(committed)
+                .saturating_sub(IssuedAssetFraction::<T>::get(rwa_asset_id));
+            ensure!(fraction <= available, Error::<T>::AssetFractionExceeded);
+
+            match &mut campaign.config.funding_model {
+                FundingModel::AllOrNothing { goal: g }
+                | FundingModel::MilestoneBased { goal: g, .. } => *g = goal,
+                FundingModel::KeepWhatYouRaise { .. } => {
+                    return Err(Error::<T>::InvalidAssetOffering.into())
+                }
+            }
+            campaign.config.hard_cap = Some(goal);
+            Campaigns::<T>::insert(campaign_id, &campaign);
+            CommittedAssetFraction::<T>::insert(rwa_asset_id, committed.saturating_add(fraction));
+            AssetOfferings::<T>::insert(
+                campaign_id,
+                AssetOffering { rwa_asset_id, fraction, valuation, issued: Zero::zero() },
+            );
+
+            Self::deposit_event(Event::AssetOfferingConfigured {
+                campaign_id,
+                rwa_asset_id,
+                fraction,
+                goal,
+            });
+            Ok(())
+        }
+
+        /// Claim the caller's share of the RWA asset sold by a successful
+        /// asset-backed offering.
+        ///
+        /// Shares are pro-rata to the weighted allocation, so early-bird
+        /// bonuses count.  Unclaimed shares are issued when the campaign is
+        /// reaped.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::claim_asset_share())]
+        pub fn claim_asset_share(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            // MilestonePhase is excluded: the campaign can still be cancelled
+            // and refunded.
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Succeeded | CampaignStatus::Completed),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                !Self::is_cancellable_success(campaign_id, &campaign),
+                Error::<T>::CampaignStillCancellable
+            );
+            ensure!(AssetOfferings::<T>::contains_key(campaign_id), Error::<T>::NotAnAssetOffering);
+            ensure!(
+                !OfferingShareClaims::<T>::contains_key(campaign_id, &who),
+                Error::<T>::AlreadyClaimed
+            );
+            let inv =
+                Investments::<T>::get(campaign_id, &who).ok_or(Error::<T>::NoInvestmentFound)?;
+
+            Self::issue_asset_share(campaign_id, &who, &inv).ok_or(Error::<T>::NoAssetShare)?;
+            OfferingShareClaims::<T>::insert(campaign_id, &who, ());
+            Ok(())
+        }
+
+        /// Give up the caller's whole share of an RWA asset.
+        ///
+        /// Used once the off-chain rights a share stands for have been
+        /// settled; the asset cannot be retired while shares are held.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::surrender_asset_share())]
+        pub fn surrender_asset_share(origin: OriginFor<T>, rwa_asset_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let share =
+                AssetShares::<T>::take(rwa_asset_id, &who).ok_or(Error::<T>::NoAssetShare)?;
+            IssuedAssetFraction::<T>::mutate(rwa_asset_id, |f| *f = f.saturating_sub(share));
+            Self::deposit_event(Event::AssetShareSurrendered { rwa_asset_id, who, share });
+            Ok(())
+        }
+
+        // ─── Stretch Goals and Rounds ───────────────────────────────────
+
+        /// Replace the campaign's stretch goals (empty `goals` clears the
+        /// unreached ones).
+        ///
+        /// Stretch goals can be announced at any time while the campaign is
+        /// `Funding`, but goals already reached must be kept unchanged at
+        /// the front of the list.  Only the creator can set them.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::set_stretch_goals())]
+        pub fn set_stretch_goals(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            goals: BoundedVec<StretchGoal<BalanceOf<T>>, T::MaxStretchGoals>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+
+            let reached = StretchGoalsReached::<T>::get(campaign_id) as usize;
+            let previous = StretchGoals::<T>::get(campaign_id).unwrap_or_default();
+            ensure!(
+                goals.get(..reached) == previous.get(..reached),
+                Error::<T>::InvalidStretchGoals
+            );
+            let floor = match &campaign.config.funding_model {
+                FundingModel::AllOrNothing { goal } | FundingModel::MilestoneBased { goal, .. } => {
+                    *goal
+                }
+                FundingModel::KeepWhatYouRaise { soft_cap } => soft_cap.unwrap_or_default(),
+            };
+            for (i, goal) in goals.iter().enumerate().skip(reached) {
+                let above = if i == 0 { floor } else { goals[i - 1].threshold };
+                ensure!(
+                    goal.threshold > above && goal.threshold > campaign.total_raised,
+                    Error::<T>::InvalidStretchGoals
+                );
+                if let Some(cap) = campaign.config.hard_cap {
+                    ensure!(goal.threshold <= cap, Error::<T>::InvalidStretchGoals);
+                }
+            }
+
+            let count = goals.len() as u32;
+            if goals.is_empty() {
+                StretchGoals::<T>::remove(campaign_id);
+            } else {
+                StretchGoals::<T>::insert(campaign_id, goals);
+            }
+            Self::deposit_event(Event::StretchGoalsSet { campaign_id, count });
+            Ok(())
+        }
+
+        /// Split the campaign's funding window into sequential rounds, each
+        /// with its own deadline, cap and minimum investment (empty `rounds`
+        /// clears them).
+        ///
+        /// The last round must end at the campaign deadline.  Only the
+        /// creator can set rounds, and only while the campaign is `Funding`
+        /// and has no investors.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::set_funding_rounds())]
+        pub fn set_funding_rounds(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            rounds: BoundedVec<FundingRoundOf<T>, T::MaxFundingRounds>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::FundingRoundsLocked
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            for (i, round) in rounds.iter().enumerate() {
+                ensure!(!round.cap.is_zero(), Error::<T>::InvalidFundingRounds);
+                match i.checked_sub(1).and_then(|p| rounds.get(p)) {
+                    Some(prev) => ensure!(
+                        round.deadline > prev.deadline && round.cap > prev.cap,
+                        Error::<T>::InvalidFundingRounds
+                    ),
+                    None => ensure!(round.deadline > now, Error::<T>::InvalidFundingRounds),
+                }
+                if let Some(cap) = campaign.config.hard_cap {
+                    ensure!(round.cap <= cap, Error::<T>::InvalidFundingRounds);
+                }
+            }
+            if let Some(last) = rounds.last() {
+                ensure!(
+                    last.deadline == campaign.config.deadline,
+                    Error::<T>::InvalidFundingRounds
+                );
+            }
+
+            let count = rounds.len() as u32;
+            if rounds.is_empty() {
+                FundingRounds::<T>::remove(campaign_id);
+            } else {
+                FundingRounds::<T>::insert(campaign_id, rounds);
+            }
+            Self::deposit_event(Event::FundingRoundsSet { campaign_id, count });
+            Ok(())
+        }
+
+        // ─── Vesting ─────────────────────────────────────────────────────
+
+        /// Set or clear (`None`) the vesting schedule of the campaign's funds.
+        ///
+        /// With a schedule, `claim_funds` pays out only what has vested, and
+        /// the campaign stays cancellable until everything has been claimed.
+        /// Only the creator can set it, and only while the campaign is
+        /// `Funding` and has no investors.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::set_vesting_schedule())]
+        pub fn set_vesting_schedule(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            schedule: Option<VestingScheduleOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::VestingLocked
+            );
+
+            match &schedule {
+                Some(s) => {
+                    // Milestones already release funds progressively.
+                    ensure!(
+                        !matches!(
+                            campaign.config.funding_model,
+                            FundingModel::MilestoneBased { .. }
+                        ),
+                        Error::<T>::InvalidVestingSchedule
+                    );
+                    ensure!(
+                        !s.duration.is_zero() && s.cliff <= s.duration,
+                        Error::<T>::InvalidVestingSchedule
+                    );
+                    VestingSchedules::<T>::insert(campaign_id, s);
+                }
+                None => VestingSchedules::<T>::remove(campaign_id),
+            }
+            Self::deposit_event(Event::VestingScheduleSet { campaign_id, schedule });
+            Ok(())
+        }
+
+        // ─── Withdrawal Penalties ────────────────────────────────────────
+
+        /// Set the campaign's early-withdrawal penalty schedule (`None` =
+        /// flat penalty) and where penalties go.
+        ///
+        /// Only the creator can set them, and only while the campaign is
+        /// `Funding` and has no investors.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::set_withdrawal_penalty())]
+        pub fn set_withdrawal_penalty(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            schedule: Option<PenaltyScheduleOf<T>>,
+            destination: PenaltyDestination,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::PenaltyLocked
+            );
+            // Retained penalties are accounted in the funding currency.
+            ensure!(
+                !destination.is_retained() || !AcceptedCurrencies::<T>::contains_key(campaign_id),
+                Error::<T>::PenaltyDestinationUnsupported
+            );
+
+            match schedule {
+                Some(schedule) => {
+                    for (i, step) in schedule.steps.iter().enumerate() {
+                        ensure!(step.penalty_bps <= 10_000, Error::<T>::InvalidPenaltySchedule);
+                        if let Some(prev) = i.checked_sub(1).and_then(|p| schedule.steps.get(p)) {
+                            ensure!(step.within < prev.within, Error::<T>::InvalidPenaltySchedule);
+                        }
+                    }
+                    PenaltySchedules::<T>::insert(campaign_id, schedule);
+                }
+                None => PenaltySchedules::<T>::remove(campaign_id),
+            }
+            if destination == PenaltyDestination::Burn {
+                PenaltyDestinations::<T>::remove(campaign_id);
+            } else {
+                PenaltyDestinations::<T>::insert(campaign_id, destination);
+            }
+            Self::deposit_event(Event::WithdrawalPenaltySet { campaign_id, destination });
+            Ok(())
+        }
+
+        /// Claim the caller's share of penalties retained for the remaining
+        /// investors of a successful campaign.
+        ///
+        /// Investors of failed or cancelled campaigns receive their share
+        /// with their refund instead.
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::claim_penalty_share())]
+        pub fn claim_penalty_share(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
+            let investor = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(
+                matches!(
+                    campaign.status,
+                    CampaignStatus::Succeeded
+                        | CampaignStatus::MilestonePhase
+                        | CampaignStatus::Completed
+                ),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                !PenaltyShareClaims::<T>::contains_key(campaign_id, &investor),
+                Error::<T>::AlreadyClaimed
+            );
+            let inv = Investments::<T>::get(campaign_id, &investor)
+                .ok_or(Error::<T>::NoInvestmentFound)?;
+            let net = inv.total_invested.saturating_sub(inv.total_withdrawn);
+            let paid = Self::pay_penalty_share(campaign_id, &campaign, &investor, net)?;
+            ensure!(!paid.is_zero(), Error::<T>::NoPenaltyShare);
+            PenaltyShareClaims::<T>::insert(campaign_id, &investor, ());
+            Ok(())
+        }
+
+        // ─── Investment Transfers ───────────────────────────────────────
+
+        /// Transfer `amount` of the caller's net position in a campaign to
+        /// `to`.
+        ///
+        /// The recipient must pass the campaign's eligibility checks and stay
+        /// within the per-investor limits, as if investing.  Allowed while
+        /// the campaign is `Funding`, `Succeeded`, `MilestonePhase` or
+        /// `Completed`, once its transfer lockup has passed.  An unminted
+        /// reward tier moves with a whole position if the recipient holds
+        /// none, and is otherwise released once the sender's remaining
+        /// position no longer meets the tier minimum.
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::transfer_investment())]
+        pub fn transfer_investment(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(
+                matches!(
+                    campaign.status,
+                    CampaignStatus::Funding
+                        | CampaignStatus::Succeeded
+                        | CampaignStatus::MilestonePhase
+                        | CampaignStatus::Completed
+                ),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(from != to, Error::<T>::InvalidTransferRecipient);
+            ensure!(!amount.is_zero(), Error::<T>::InsufficientInvestment);
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(until) = TransferLockups::<T>::get(campaign_id) {
+                ensure!(now >= until, Error::<T>::TransferLocked);
+            }
+            // Claims are made once per account, so a claimed position would
+            // be claimed twice.
+            for who in [&from, &to] {
+                ensure!(
+                    !OfferingShareClaims::<T>::contains_key(campaign_id, who)
+                        && !PenaltyShareClaims::<T>::contains_key(campaign_id, who),
+                    Error::<T>::PositionAlreadyClaimed
+                );
+            }
+
+            let mut sender =
+                Investments::<T>::get(campaign_id, &from).ok_or(Error::<T>::NoInvestmentFound)?;
+            let current = sender.total_invested.saturating_sub(sender.total_withdrawn);
+            ensure!(amount <= current, Error::<T>::InsufficientInvestment);
+            let whole = amount == current;
+
+            // The recipient is checked as if investing `amount`.
+            Self::check_eligibility_inner(&to, &campaign, campaign_id)?;
+            let mut recipient = Investments::<T>::get(campaign_id, &to).unwrap_or_default();
+            let held = recipient.total_invested.saturating_sub(recipient.total_withdrawn);
+            if let Some(max_per) = campaign.config.max_investment_per_investor {
+                ensure!(
+                    held.saturating_add(amount) <= max_per,
+                    Error::<T>::InvestmentExceedsMaxPerInvestor
+                );
+            }
+            if let Some(cap) = Self::whitelist_allowance(campaign_id, &to).and_then(|a| a.cap) {
+                ensure!(held.saturating_add(amount) <= cap, Error::<T>::WhitelistCapExceeded);
+            }
+            // See `do_invest` (H-1) for why membership marks a new investor.
+            let is_new = !InvestorCampaigns::<T>::get(&to).contains(&campaign_id);
+            if is_new {
+                InvestorCampaigns::<T>::try_mutate(&to, |ids| -> DispatchResult {
+                    ids.try_push(campaign_id)
+                        .map_err(|_| Error::<T>::MaxInvestmentsPerInvestorReached)?;
+                    Ok(())
+                })?;
+            }
+
+            // Alternative currency holdings and the weighted allocation move
+            // pro-rata with the position.
+            let holdings = InvestorHoldings::<T>::get(campaign_id, &from).unwrap_or_default();
+            let parts = Self::currency_parts(&campaign, &holdings, amount, current);
+            Self::move_holdings(campaign_id, &from, &to, &parts[1..])?;
+            let weighted = if whole {
+                sender.weighted_allocation
+            } else {
+                Self::pro_rata(sender.weighted_allocation, amount, current)
+            };
+            sender.total_invested = sender.total_invested.saturating_sub(amount);
+            sender.weighted_allocation = sender.weighted_allocation.saturating_sub(weighted);
+            recipient.total_invested = recipient.total_invested.saturating_add(amount);
+            recipient.weighted_allocation = recipient.weighted_allocation.saturating_add(weighted);
+            Investments::<T>::insert(campaign_id, &from, sender);
+            Investments::<T>::insert(campaign_id, &to, recipient);
+            // The recipient's cooling-off does not restart: a transfer to a
+            // fresh account must not waive the penalty.
+            let start = Self::invested_at(campaign_id, &campaign, &from);
+            InvestedAt::<T>::mutate(campaign_id, &to, |at| {
+                *at = Some(at.map_or(start, |at| at.min(start)));
+            });
+            if whole {
+                InvestedAt::<T>::remove(campaign_id, &from);
+            }
+
+            if let Some(alloc) = InvestorTiers::<T>::get(campaign_id, &from) {
+                let tier_min = RewardTiers::<T>::get(campaign_id)
+                    .and_then(|tiers| tiers.get(alloc.tier_index as usize).map(|t| t.min_amount));
+                let remaining = current.saturating_sub(amount);
+                if !alloc.reward_minted && (whole || tier_min.map_or(true, |min| remaining < min)) {
+                    if whole && !InvestorTiers::<T>::contains_key(campaign_id, &to) {
+                        InvestorTiers::<T>::remove(campaign_id, &from);
+                        InvestorTiers::<T>::insert(campaign_id, &to, alloc);
+                    } else {
+                        Self::release_tier(campaign_id, &from);
+                    }
+                }
+            }
+
+            if whole {
+                InvestorCampaigns::<T>::mutate(&from, |ids| {
+                    ids.retain(|&id| id != campaign_id);
+                });
+            }
+            Campaigns::<T>::mutate(campaign_id, |maybe| {
+                if let Some(c) = maybe {
+                    if is_new {
+                        c.investor_count = c.investor_count.saturating_add(1);
+                    }
+                    if whole {
+                        c.investor_count = c.investor_count.saturating_sub(1);
+                    }
+                }
+            });
+
+            Self::deposit_event(Event::InvestmentTransferred { campaign_id, from, to, amount });
+            Ok(())
+        }
+
+        /// Set or clear (`None`) the block until which investments in the
+        /// campaign cannot be transferred.
+        ///
+        /// Only the creator can set it, and only while the campaign is
+        /// `Funding` and has no investors.
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::set_transfer_lockup())]
+        pub fn set_transfer_lockup(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            until: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            ensure!(
+                campaign.investor_count == 0 && campaign.total_raised.is_zero(),
+                Error::<T>::TransferLockupLocked
+            );
+
+            match until {
+                Some(until) => TransferLockups::<T>::insert(campaign_id, until),
+                None => TransferLockups::<T>::remove(campaign_id),
+            }
+            Self::deposit_event(Event::TransferLockupSet { campaign_id, until });
+            Ok(())
+        }
+
+        // ─── Fee sponsorship ──────────────────────────────────────────
+
+        /// Add `amount` to the budget paying the transaction fees of the
+        /// campaign's investor calls (see `Call::sponsoring_campaign`), and
+        /// set how many of them each account may have paid per
+        /// `SponsorshipPeriod`.
+        ///
+        /// Only the creator can fund it; a running sponsorship can only be
+        /// topped up by its sponsor.
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::fund_fee_sponsorship())]
+        pub fn fund_fee_sponsorship(
+            origin: OriginFor<T>,
+            campaign_id: u32,
+            amount: BalanceOf<T>,
+            max_calls_per_period: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            ensure!(max_calls_per_period > 0, Error::<T>::InvalidSponsorshipLimit);
+            if let Some(existing) = FeeSponsorships::<T>::get(campaign_id) {
+                ensure!(existing.sponsor == who, Error::<T>::SponsoredByAnother);
+            }
+
+            if !amount.is_zero() {
+                T::NativeCurrency::transfer(
+                    &who,
+                    &Self::sponsorship_account(campaign_id),
+                    amount,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+            }
+            FeeSponsorships::<T>::insert(
+                campaign_id,
+                FeeSponsorship { sponsor: who.clone(), max_calls_per_period },
+            );
+            Self::deposit_event(Event::FeeSponsorshipFunded {
+                campaign_id,
+                sponsor: who,
+                amount,
+                max_calls_per_period,
+            });
+            Ok(())
+        }
+
+        /// End the campaign's fee sponsorship and return what is left of its
+        /// budget to the sponsor.
+        ///
+        /// Callable by the sponsor, or by the creator while the campaign
+        /// exists.
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::end_fee_sponsorship())]
+        pub fn end_fee_sponsorship(origin: OriginFor<T>, campaign_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let sponsorship =
+                FeeSponsorships::<T>::get(campaign_id).ok_or(Error::<T>::NoFeeSponsorship)?;
+            if sponsorship.sponsor != who {
+                let campaign =
+                    Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+                ensure!(campaign.creator == who, Error::<T>::NotCampaignCreator);
+            }
+
+            FeeSponsorships::<T>::remove(campaign_id);
+            Self::end_sponsorship(campaign_id, sponsorship)
+        }
+
+        // ─── Offchain finalization ───────────────────────────────────────
+
+        /// Finalize a batch of past-deadline campaigns found by the offchain
+        /// worker.  Campaigns that can no longer be finalized are skipped.
+        #[pallet::call_index(45)]
+        #[pallet::weight(
+            T::WeightInfo::finalize_campaign().saturating_mul(payload.campaign_ids.len() as u64)
+        )]
+        pub fn finalize_campaigns_unsigned(
+            origin: OriginFor<T>,
+            payload: FinalizationPayload<T::Public, T::BlockNumber>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            for campaign_id in payload.campaign_ids {
+                // Each campaign gets its own layer so a failure part-way
+                // through leaves no partial writes behind.
+                if let Err(e) =
+                    storage::with_storage_layer(|| Self::do_finalize_campaign(campaign_id))
+                {
+                    frame_support::log::warn!(
+                        target: "runtime::crowdfunding",
+                        "finalizing campaign {} failed: {:?}",
+                        campaign_id,
+                        e,
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        /// Allow (`true`) or disallow (`false`) a key to sign the offchain
+        /// worker's finalization payloads.
+        #[pallet::call_index(46)]
+        #[pallet::weight(T::WeightInfo::set_finalization_key())]
+        pub fn set_finalization_key(
+            origin: OriginFor<T>,
+            key: T::AccountId,
+            allowed: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if allowed {
+                FinalizationKeys::<T>::insert(&key, ());
+            } else {
+                FinalizationKeys::<T>::remove(&key);
+            }
+            Self::deposit_event(Event::FinalizationKeySet { key, allowed });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Call<T> {
+        /// The campaign whose fee sponsorship may pay for this call: the calls
+        /// an investor makes on a campaign.
+        pub fn sponsoring_campaign(&self) -> Option<u32> {
+            match self {
+                Call::invest { campaign_id, .. }
+                | Call::invest_with_tier { campaign_id, .. }
+                | Call::invest_with_proof { campaign_id, .. }
+                | Call::invest_in_currency { campaign_id, .. }
+                | Call::withdraw_investment { campaign_id, .. }
+                | Call::register_interest { campaign_id }
+                | Call::claim_refund { campaign_id }
+                | Call::claim_reward_nft { campaign_id }
+                | Call::claim_asset_share { campaign_id }
+                | Call::transfer_investment { campaign_id, .. } => Some(*campaign_id),
+                _ => None,
+            }
+        }
+
+        /// The amount of a plain `invest`, which a fee sponsorship may pay
+        /// for even when it is the sender's first investment.
+        pub fn sponsored_investment(&self) -> Option<BalanceOf<T>> {
+            match self {
+                Call::invest { amount, .. } => Some(*amount),
+                _ => None,
+            }
+        }
+    }
+
+    // ── Helpers ──────────────────────────────────────────────────────
+
+    impl<T: Config> Pallet<T> {
+        /// Determine the finalization outcome for a Funding campaign and
+        /// initialize milestone statuses if applicable.  Returns the new
+        /// `CampaignStatus`.  Caller is responsible for writing it back
+        /// and emitting the appropriate event.
+        fn do_finalize(campaign_id: u32, c: &CampaignOf<T>) -> CampaignStatus {
+            match &c.config.funding_model {
+                FundingModel::AllOrNothing { goal } => {
+                    if c.total_raised >= *goal {
+                        CampaignStatus::Succeeded
+                    } else {
+                        CampaignStatus::Failed
+                    }
+                }
+                FundingModel::KeepWhatYouRaise { soft_cap } => match soft_cap {
+                    Some(cap) if c.total_raised < *cap => CampaignStatus::Failed,
+                    _ => CampaignStatus::Succeeded,
+                },
+                FundingModel::MilestoneBased { goal, milestones } => {
+                    if c.total_raised >= *goal {
+                        for i in 0..milestones.len() {
+                            MilestoneStatuses::<T>::insert(
+                                campaign_id,
+                                i as u8,
+                                MilestoneStatus::Pending,
+                            );
+                        }
+                        CampaignStatus::MilestonePhase
+                    } else {
+                        CampaignStatus::Failed
+                    }
+                }
+            }
+        }
+
+        /// Block at which a campaign with the given deadline becomes due for
+        /// finalization (`finalize_campaign` requires `now > deadline`).
+        fn finalization_block(deadline: T::BlockNumber) -> T::BlockNumber {
+            deadline.saturating_add(One::one())
+        }
+
+        fn ensure_deadline_slot(deadline: T::BlockNumber) -> DispatchResult {
+            let count = CampaignDeadlines::<T>::decode_len(Self::finalization_block(deadline))
+                .unwrap_or(0) as u32;
+            ensure!(count < T::MaxDeadlinesPerBlock::get(), Error::<T>::DeadlineSlotsFull);
+            Ok(())
+        }
+
+        /// The first deadline at or after `deadline` whose finalization
+        /// slot still has room, trying at most `MAX_DEADLINE_ROLLOVERS`
+        /// later blocks.
+        fn free_deadline(deadline: T::BlockNumber) -> Result<T::BlockNumber, DispatchError> {
+            let mut deadline = deadline;
+            for _ in 0..MAX_DEADLINE_ROLLOVERS {
+                if Self::ensure_deadline_slot(deadline).is_ok() {
+                    return Ok(deadline);
+                }
+                deadline = deadline.saturating_add(One::one());
+            }
+            Self::ensure_deadline_slot(deadline)?;
+            Ok(deadline)
+        }
+
+        /// Add a campaign to the deadline index.
+        fn schedule_finalization(campaign_id: u32, deadline: T::BlockNumber) -> DispatchResult {
+            CampaignDeadlines::<T>::try_mutate(
+                Self::finalization_block(deadline),
+                |ids| -> DispatchResult {
+                    ids.try_push(campaign_id).map_err(|_| Error::<T>::DeadlineSlotsFull.into())
+                },
+            )
+        }
+
+        /// Remove a campaign from the deadline index and the `on_idle` queue.
+        fn unschedule_finalization(campaign_id: u32, deadline: T::BlockNumber) {
+            CampaignDeadlines::<T>::mutate_exists(Self::finalization_block(deadline), |maybe| {
+                if let Some(ids) = maybe {
+                    ids.retain(|&id| id != campaign_id);
+                    if ids.is_empty() {
+                        *maybe = None;
+                    }
+                }
+            });
+            PendingFinalizations::<T>::remove(campaign_id);
+        }
+
+        /// Remove a campaign that reached a terminal status from
+        /// `LicensedCampaigns`.
+        fn unlink_license(campaign_id: u32, c: &CampaignOf<T>) {
+            if let (Some(asset_id), Some(part_id)) = (c.rwa_asset_id, c.participation_id) {
+                LicensedCampaigns::<T>::remove((asset_id, part_id, campaign_id));
+            }
+        }
+
+        /// Whether a non-terminal campaign was created under a license of
+        /// `rwa_asset_id`.
+        ///
+        /// Answers `true` while a stepped migration runs, since it may still
+        /// be rebuilding `LicensedCampaigns`.
+        pub fn has_licensed_campaigns(rwa_asset_id: u32) -> bool {
+            Self::migration_ongoing()
+                || LicensedCampaigns::<T>::iter_key_prefix((rwa_asset_id,)).next().is_some()
+        }
+
+        /// Whether a non-terminal campaign was created under the license
+        /// `(rwa_asset_id, participation_id)`.
+        ///
+        /// Answers `true` while a stepped migration runs, like
+        /// [`Self::has_licensed_campaigns`].
+        pub fn has_campaigns_under_license(rwa_asset_id: u32, participation_id: u32) -> bool {
+            Self::migration_ongoing()
+                || LicensedCampaigns::<T>::iter_key_prefix((rwa_asset_id, participation_id))
+                    .next()
+                    .is_some()
+        }
+
+        /// Worst-case weight of [`Self::has_licensed_campaigns`] and
+        /// [`Self::has_campaigns_under_license`]: `ActiveMigration` and the
+        /// first key under the `LicensedCampaigns` prefix.
+        pub fn licensed_campaigns_lookup_weight() -> Weight { T::DbWeight::get().reads(2) }
+
+        /// Finalize a `Funding` campaign whose deadline has passed, removing
+        /// it from the finalization queues.
+        fn do_finalize_campaign(campaign_id: u32) -> DispatchResult {
+            Campaigns::<T>::try_mutate(campaign_id, |maybe| -> DispatchResult {
+                let c = maybe.as_mut().ok_or(Error::<T>::CampaignNotFound)?;
+                ensure!(
+                    matches!(c.status, CampaignStatus::Funding),
+                    Error::<T>::InvalidCampaignStatus
+                );
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(now > c.config.deadline, Error::<T>::CampaignStillFunding);
+
+                Self::unschedule_finalization(campaign_id, c.config.deadline);
+                let new_status = Self::do_finalize(campaign_id, c);
+                c.status = new_status;
+                if new_status == CampaignStatus::Failed {
+                    Self::queue_refunds(campaign_id);
+                    Self::release_asset_offering(campaign_id);
+                    Self::unlink_license(campaign_id, c);
+                }
+                Self::deposit_event(Event::CampaignFinalized { campaign_id, status: new_status });
+                Ok(())
+            })
+        }
+
+        /// Whether the campaign is `Funding` past its deadline, so
+        /// `finalize_campaign` would finalize it.
+        pub fn is_finalizable(campaign_id: u32) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            Campaigns::<T>::get(campaign_id).map_or(false, |c| {
+                matches!(c.status, CampaignStatus::Funding) && now > c.config.deadline
+            })
+        }
+
+        /// Up to `limit` finalizable campaigns, for the offchain worker.
+        /// Scans all campaigns, which is fine offchain.
+        fn finalizable_campaigns(limit: u32) -> Vec<u32> {
+            let now = frame_system::Pallet::<T>::block_number();
+            Campaigns::<T>::iter()
+                .filter(|(_, c)| {
+                    matches!(c.status, CampaignStatus::Funding) && now > c.config.deadline
+                })
+                .map(|(campaign_id, _)| campaign_id)
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// Record in offchain storage that the worker submits at `n`, unless
+        /// it already did within the last `UnsignedInterval` blocks, so the
+        /// same batch is not resubmitted while it waits in the pool.
+        fn acquire_offchain_slot(n: T::BlockNumber) -> bool {
+            let last_submission = StorageValueRef::persistent(b"crowdfunding::last_finalization");
+            let result = last_submission.mutate(
+                |last: Result<Option<T::BlockNumber>, StorageRetrievalError>| match last {
+                    Ok(Some(block)) if n < block.saturating_add(T::UnsignedInterval::get()) => {
+                        Err(())
+                    }
+                    _ => Ok(n),
+                },
+            );
+            match result {
+                Ok(_) => true,
+                Err(MutateStorageError::ValueFunctionFailed(())) => false,
+                Err(MutateStorageError::ConcurrentModification(_)) => false,
+            }
+        }
+
+        /// Whether `public` may sign finalization payloads.
+        fn is_finalization_key(public: &T::Public) -> bool {
+            FinalizationKeys::<T>::contains_key(public.clone().into_account())
+        }
+
+        /// The finalization keys in the local keystore that may sign.
+        fn local_finalization_keys() -> Vec<T::Public> {
+            <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+                .into_iter()
+                .map(|key| {
+                    <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(key)
+                        .into()
+                })
+                .filter(Self::is_finalization_key)
+                .collect()
+        }
+
+        /// Finalize a campaign from the block hooks.  Campaigns that are no
+        /// longer `Funding` or whose deadline has not passed are skipped.
+        fn auto_finalize(campaign_id: u32, now: T::BlockNumber) {
+            Campaigns::<T>::mutate(campaign_id, |maybe| {
+                if let Some(c) = maybe {
+                    if matches!(c.status, CampaignStatus::Funding) && now > c.config.deadline {
+                        let new_status = Self::do_finalize(campaign_id, c);
+                        c.status = new_status;
+                        if new_status == CampaignStatus::Failed {
+                            Self::queue_refunds(campaign_id);
+                            Self::release_asset_offering(campaign_id);
+                            Self::unlink_license(campaign_id, c);
+                        }
+                        Self::deposit_event(Event::CampaignFinalized {
+                            campaign_id,
+                            status: new_status,
+                        });
+                    }
+                }
+            });
+        }
+
+        /// Upper bound on the weight of a single `auto_finalize`: the campaign
+        /// read and write, one milestone status write per milestone, and the
+        /// release of an asset-backed offering on failure.
+        fn auto_finalize_weight() -> Weight {
+            T::DbWeight::get().reads_writes(3, 3 + T::MaxMilestones::get() as u64)
+        }
+
+        pub fn campaign_account(campaign_id: u32) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(campaign_id)
+        }
+
+        /// Whether a stepped migration is in progress.
+        pub fn migration_ongoing() -> bool { ActiveMigration::<T>::exists() }
+
+        /// Account holding the fee sponsorship budget of `campaign_id`.
+        pub fn sponsorship_account(campaign_id: u32) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating((b"sponsor", campaign_id))
+        }
+
+        /// `who`'s sponsored-call count, reset once `SponsorshipPeriod` has
+        /// passed since its period started.
+        fn sponsored_usage(campaign_id: u32, who: &T::AccountId) -> SponsoredUsage<T::BlockNumber> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let usage = SponsoredCalls::<T>::get(campaign_id, who);
+            if now >= usage.period_start.saturating_add(T::SponsorshipPeriod::get()) {
+                SponsoredUsage { period_start: now, calls: 0 }
+            } else {
+                usage
+            }
+        }
+
+        /// Whether the fee sponsorship of `campaign_id` can pay `fee` for
+        /// `who`: it exists, `who` already takes part in the campaign or is
+        /// making an `investment` it could open one with, `who` is within
+        /// its call limit, and the budget covers `fee` without reaping the
+        /// sponsorship account.
+        ///
+        /// Only investors, registered and whitelisted accounts, and first
+        /// investments that would pass dispatch are sponsored, so fresh
+        /// accounts cannot drain the budget with calls that fail.
+        pub fn can_sponsor_fee(
+            campaign_id: u32,
+            who: &T::AccountId,
+            fee: BalanceOf<T>,
+            investment: Option<BalanceOf<T>>,
+        ) -> bool {
+            let sponsorship = match FeeSponsorships::<T>::get(campaign_id) {
+                Some(sponsorship) => sponsorship,
+                None => return false,
+            };
+            let participates = Investments::<T>::contains_key(campaign_id, who)
+                || Registrations::<T>::contains_key(campaign_id, who)
+                || CampaignWhitelist::<T>::get(campaign_id, who)
+                || investment
+                    .map_or(false, |amount| Self::can_open_investment(campaign_id, who, amount));
+            if !participates {
+                return false;
+            }
+            if Self::sponsored_usage(campaign_id, who).calls >= sponsorship.max_calls_per_period {
+                return false;
+            }
+            let budget = T::NativeCurrency::free_balance(&Self::sponsorship_account(campaign_id));
+            budget.saturating_sub(fee) >= T::NativeCurrency::minimum_balance()
+        }
+
+        /// Whether `who` could open a position in `campaign_id` with an
+        /// `invest` of `amount`: the campaign takes investments, `who` is
+        /// eligible, and `who` can pay `amount` in the funding currency.
+        fn can_open_investment(campaign_id: u32, who: &T::AccountId, amount: BalanceOf<T>) -> bool {
+            let campaign = match Campaigns::<T>::get(campaign_id) {
+                Some(campaign) => campaign,
+                None => return false,
+            };
+            let now = frame_system::Pallet::<T>::block_number();
+            if !matches!(campaign.status, CampaignStatus::Funding)
+                || now > campaign.config.deadline
+                || amount.is_zero()
+                || campaign.config.min_investment.map_or(false, |min| amount < min)
+                || RegistrationPhases::<T>::get(campaign_id)
+                    .map_or(false, |opens_at| now < opens_at)
+                || Self::eligibility_failure(who, &campaign, campaign_id, None).is_some()
+            {
+                return false;
+            }
+            let payable = match &campaign.config.funding_currency {
+                PaymentCurrency::Native => T::NativeCurrency::free_balance(who)
+                    .saturating_sub(T::NativeCurrency::minimum_balance()),
+                PaymentCurrency::Asset(asset_id) => {
+                    <T::Fungibles as fungibles::Inspect<T::AccountId>>::reducible_balance(
+                        *asset_id, who, true,
+                    )
+                }
+            };
+            amount <= payable
+        }
+
+        /// Return what is left of the budget of `campaign_id`'s ended fee
+        /// sponsorship to its sponsor.
+        fn end_sponsorship(
+            campaign_id: u32,
+            sponsorship: FeeSponsorship<T::AccountId>,
+        ) -> DispatchResult {
+            let account = Self::sponsorship_account(campaign_id);
+            let refunded = T::NativeCurrency::free_balance(&account);
+            T::NativeCurrency::transfer(
+                &account,
+                &sponsorship.sponsor,
+                refunded,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            Self::deposit_event(Event::FeeSponsorshipEnded {
+                campaign_id,
+                sponsor: sponsorship.sponsor,
+                refunded,
+            });
+            Ok(())
+        }
+
+        /// Withdraw `fee` from the sponsorship budget of `campaign_id` and
+        /// count the transaction against `who`'s limit.  `None` if the
+        /// sponsorship cannot pay.
+        pub fn withdraw_sponsored_fee(
+            campaign_id: u32,
+            who: &T::AccountId,
+            fee: BalanceOf<T>,
+            investment: Option<BalanceOf<T>>,
+        ) -> Option<NegativeImbalanceOf<T>> {
+            if !Self::can_sponsor_fee(campaign_id, who, fee, investment) {
+                return None;
+            }
+            let paid = T::NativeCurrency::withdraw(
+                &Self::sponsorship_account(campaign_id),
+                fee,
+                WithdrawReasons::TRANSACTION_PAYMENT,
+                ExistenceRequirement::KeepAlive,
+            )
+            .ok()?;
+            let mut usage = Self::sponsored_usage(campaign_id, who);
+            usage.calls = usage.calls.saturating_add(1);
+            SponsoredCalls::<T>::insert(campaign_id, who, usage);
+            Some(paid)
+        }
+
+        /// Return the part of `paid` above the actual `fee` to the budget of
+        /// `campaign_id`, and hand back the fee itself.
+        pub fn settle_sponsored_fee(
+            campaign_id: u32,
+            who: &T::AccountId,
+            paid: NegativeImbalanceOf<T>,
+            fee: BalanceOf<T>,
+        ) -> NegativeImbalanceOf<T> {
+            let (fee, refund) = paid.split(fee);
+            T::NativeCurrency::resolve_creating(&Self::sponsorship_account(campaign_id), refund);
+            Self::deposit_event(Event::FeeSponsored {
+                campaign_id,
+                who: who.clone(),
+                fee: fee.peek(),
+            });
+            fee
+        }
+
+        /// Advance the active stepped migration by up to
+        /// `MigrationItemsPerBlock` items, stamping its target storage version
+        /// once it finishes.
+        pub(crate) fn step_migration(mut cursor: MigrationCursorOf<T>) -> Weight {
+            let outcome =
+                T::SteppedMigration::step(cursor.last_key.take(), T::MigrationItemsPerBlock::get());
+            cursor.migrated = cursor.migrated.saturating_add(outcome.migrated);
+            let weight = outcome.weight.saturating_add(T::DbWeight::get().writes(1));
+            match outcome.next {
+                Some(key) => {
+                    cursor.last_key = Some(key);
+                    ActiveMigration::<T>::put(cursor);
+                    weight
+                }
+                None => {
+                    ActiveMigration::<T>::kill();
+                    StorageVersion::new(cursor.target_version).put::<Pallet<T>>();
+                    Self::deposit_event(Event::MigrationCompleted {
+                        target_version: cursor.target_version,
+                        migrated: cursor.migrated,
+                    });
+                    weight.saturating_add(T::DbWeight::get().writes(1))
+                }
+            }
+        }
+
+        /// Return the effective protocol fee in basis points.
+        ///
+        /// Uses the runtime override if set, otherwise falls back to the
+        /// compile-time `Config::ProtocolFeeBps` constant.
+        pub fn effective_protocol_fee_bps() -> u16 {
+            ProtocolFeeBpsOverride::<T>::get().unwrap_or_else(T::ProtocolFeeBps::get)
+        }
+
+        /// Return the effective protocol fee recipient.
+        ///
+        /// Uses the runtime override if set, otherwise falls back to the
+        /// compile-time `Config::ProtocolFeeRecipient` constant.
+        pub fn effective_protocol_fee_recipient() -> T::AccountId {
+            ProtocolFeeRecipientOverride::<T>::get().unwrap_or_else(T::ProtocolFeeRecipient::get)
+        }
+
+        /// `value * numerator / denominator`, rounded down, without the
+        /// precision loss of a per-thing ratio on large balances.
+        fn pro_rata(
+            value: BalanceOf<T>,
+            numerator: BalanceOf<T>,
+            denominator: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            multiply_by_rational_with_rounding(
+                value.unique_saturated_into(),
+                numerator.unique_saturated_into(),
+                denominator.unique_saturated_into(),
+                Rounding::Down,
+            )
+            .map(UniqueSaturatedInto::unique_saturated_into)
+            .unwrap_or_else(Zero::zero)
+        }
+
+        /// Split a payout of `amount` from a position worth `total` (both in
+        /// the funding currency) by currency: each alternative currency in
+        /// `holdings` pays its pro-rata share, and the funding currency pays
+        /// the rest.  The funding currency part comes first.
+        fn currency_parts(
+            campaign: &CampaignOf<T>,
+            holdings: &[CurrencyHoldingOf<T>],
+            amount: BalanceOf<T>,
+            total: BalanceOf<T>,
+        ) -> Vec<CurrencyHoldingOf<T>> {
+            let ratio = Perbill::from_rational(amount.min(total), total);
+            let shares = holdings.iter().map(|h| {
+                let (share, normalized) = if amount >= total {
+                    (h.amount, h.normalized)
+                } else {
+                    (ratio * h.amount, ratio * h.normalized)
+                };
+                CurrencyHolding { currency: h.currency.clone(), amount: share, normalized }
+            });
+            let mut parts = vec![CurrencyHolding {
+                currency: campaign.config.funding_currency.clone(),
+                amount: Zero::zero(),
+                normalized: Zero::zero(),
+            }];
+            parts.extend(shares);
+            let in_alternatives = parts
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |acc, p| acc.saturating_add(p.normalized));
+            let rest = amount.saturating_sub(in_alternatives);
+            parts[0].amount = rest;
+            parts[0].normalized = rest;
+            parts
+        }
+
+        /// Record an alternative currency contribution for the campaign and
+        /// the investor.
+        fn add_holding(
+            campaign_id: u32,
+            investor: &T::AccountId,
+            currency: PaymentCurrency<T::AssetId>,
+            amount: BalanceOf<T>,
+            normalized: BalanceOf<T>,
+        ) -> DispatchResult {
+            let add = |maybe: &mut Option<CurrencyHoldingsOf<T>>| -> DispatchResult {
+                let holdings = maybe.get_or_insert_with(Default::default);
+                match holdings.iter_mut().find(|h| h.currency == currency) {
+                    Some(h) => {
+                        h.amount = h.amount.saturating_add(amount);
+                        h.normalized = h.normalized.saturating_add(normalized);
+                    }
+                    None => holdings
+                        .try_push(CurrencyHolding {
+                            currency: currency.clone(),
+                            amount,
+                            normalized,
+                        })
+                        .map_err(|_| Error::<T>::CurrencyNotAccepted)?,
+                }
+                Ok(())
+            };
+            CampaignHoldings::<T>::try_mutate(campaign_id, add)?;
+            InvestorHoldings::<T>::try_mutate(campaign_id, investor, add)
+        }
+
+        /// Deduct paid-out alternative currency `parts` from the campaign's
+        /// holdings and, if given, the investor's.
+        fn reduce_holdings(
+            campaign_id: u32,
+            investor: Option<&T::AccountId>,
+            parts: &[CurrencyHoldingOf<T>],
+        ) {
+            let reduce = |maybe: &mut Option<CurrencyHoldingsOf<T>>| {
+                if let Some(holdings) = maybe {
+                    for part in parts {
+                        if let Some(h) = holdings.iter_mut().find(|h| h.currency == part.currency) {
+                            h.amount = h.amount.saturating_sub(part.amount);
+                            h.normalized = h.normalized.saturating_sub(part.normalized);
+                        }
+                    }
+                    holdings.retain(|h| !h.amount.is_zero());
+                    if holdings.is_empty() {
+                        *maybe = None;
+                    }
+                }
+            };
+            CampaignHoldings::<T>::mutate_exists(campaign_id, reduce);
+            if let Some(investor) = investor {
+                InvestorHoldings::<T>::mutate_exists(campaign_id, investor, reduce);
+            }
+        }
+
+        /// Move alternative currency `parts` of a position from one
+        /// investor's holdings to another's.
+        fn move_holdings(
+            campaign_id: u32,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            parts: &[CurrencyHoldingOf<T>],
+        ) -> DispatchResult {
+            for part in parts.iter().filter(|p| !p.amount.is_zero()) {
+                InvestorHoldings::<T>::mutate_exists(campaign_id, from, |maybe| {
+                    if let Some(holdings) = maybe {
+                        if let Some(h) = holdings.iter_mut().find(|h| h.currency == part.currency) {
+                            h.amount = h.amount.saturating_sub(part.amount);
+                            h.normalized = h.normalized.saturating_sub(part.normalized);
+                        }
+                        holdings.retain(|h| !h.amount.is_zero());
+                        if holdings.is_empty() {
+                            *maybe = None;
+                        }
+                    }
+                });
+                InvestorHoldings::<T>::try_mutate(campaign_id, to, |maybe| -> DispatchResult {
+                    let holdings = maybe.get_or_insert_with(Default::default);
+                    match holdings.iter_mut().find(|h| h.currency == part.currency) {
+                        Some(h) => {
+                            h.amount = h.amount.saturating_add(part.amount);
+                            h.normalized = h.normalized.saturating_add(part.normalized);
+                        }
+                        None => holdings
+                            .try_push(part.clone())
+                            .map_err(|_| Error::<T>::CurrencyNotAccepted)?,
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        }
+
+        /// Pay currency `parts` of a creator disbursement, less the protocol
+        /// fee taken from each currency.
+        fn pay_creator(
+            campaign_id: u32,
+            creator: &T::AccountId,
+            parts: &[CurrencyHoldingOf<T>],
+            fee_bps: u16,
+        ) -> DispatchResult {
+            let sub_account = Self::campaign_account(campaign_id);
+            let fee_recipient = Self::effective_protocol_fee_recipient();
+            for part in parts {
+                let fee = Self::bps_of(part.amount, fee_bps);
+                Self::do_transfer(
+                    &part.currency,
+                    &sub_account,
+                    &fee_recipient,
+                    fee,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+                Self::do_transfer(
+                    &part.currency,
+                    &sub_account,
+                    creator,
+                    part.amount.saturating_sub(fee),
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+            Self::reduce_holdings(campaign_id, None, parts);
+            Ok(())
+        }
+
+        fn do_transfer(
+            currency: &PaymentCurrency<T::AssetId>,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+            existence_req: ExistenceRequirement,
+        ) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+            match currency {
+                PaymentCurrency::Native => {
+                    T::NativeCurrency::transfer(from, to, amount, existence_req)?;
+                }
+                PaymentCurrency::Asset(asset_id) => {
+                    let keep_alive = matches!(existence_req, ExistenceRequirement::KeepAlive);
+                    <T::Fungibles as fungibles::Transfer<T::AccountId>>::transfer(
+                        *asset_id, from, to, amount, keep_alive,
+                    )?;
+                }
+            }
+            Ok(())
+        }
+
+        fn do_burn(
+            currency: &PaymentCurrency<T::AssetId>,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+            existence_req: ExistenceRequirement,
+        ) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+            match currency {
+                PaymentCurrency::Native => {
+                    // L-1: use FEE reason, not TRANSFER — this is a penalty burn, not a
                     // transfer to another account.  Some Currency implementations gate
                     // certain withdrawal paths on the reason flag.
                     let imbalance = T::NativeCurrency::withdraw(
@@ -1466,12 +4278,949 @@ pub mod pallet {
                     )?;
                     drop(imbalance);
                 }
-                PaymentCurrency::Asset(asset_id) => {
-                    <T::Fungibles as fungibles::Mutate<T::AccountId>>::burn_from(
-                        *asset_id, who, amount,
-                    )?;
+                PaymentCurrency::Asset(asset_id) => {
+                    <T::Fungibles as fungibles::Mutate<T::AccountId>>::burn_from(
+                        *asset_id, who, amount,
+                    )?;
+                }
+            }
+            Ok(())
+        }
+
+        fn do_invest(
+            investor: T::AccountId,
+            campaign_id: u32,
+            amount: BalanceOf<T>,
+            tier_index: Option<u8>,
+            payment: Option<(PaymentCurrency<T::AssetId>, BalanceOf<T>)>,
+        ) -> DispatchResult {
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(
+                matches!(campaign.status, CampaignStatus::Funding),
+                Error::<T>::InvalidCampaignStatus
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now <= campaign.config.deadline, Error::<T>::DeadlinePassed);
+
+            // P2-01: reject zero-amount investments before any other checks
+            ensure!(!amount.is_zero(), Error::<T>::InvestmentBelowMinimum);
+
+            if let Some(opens_at) = RegistrationPhases::<T>::get(campaign_id) {
+                ensure!(now >= opens_at, Error::<T>::FundingNotOpen);
+                ensure!(
+                    Registrations::<T>::contains_key(campaign_id, &investor),
+                    Error::<T>::NotRegistered
+                );
+            }
+
+            // eligibility
+            Self::check_eligibility_inner(&investor, &campaign, campaign_id)?;
+
+            // min investment
+            if let Some(min) = campaign.config.min_investment {
+                ensure!(amount >= min, Error::<T>::InvestmentBelowMinimum);
+            }
+
+            // hard cap
+            if let Some(cap) = campaign.config.hard_cap {
+                ensure!(
+                    campaign.total_raised.saturating_add(amount) <= cap,
+                    Error::<T>::HardCapExceeded
+                );
+            }
+
+            // active funding round
+            if let Some((_, round)) = Self::active_funding_round(campaign_id, now) {
+                if let Some(min) = round.min_investment {
+                    ensure!(amount >= min, Error::<T>::InvestmentBelowMinimum);
+                }
+                ensure!(
+                    campaign.total_raised.saturating_add(amount) <= round.cap,
+                    Error::<T>::RoundCapExceeded
+                );
+            }
+
+            // per-investor max
+            let mut inv = Investments::<T>::get(campaign_id, &investor).unwrap_or_default();
+            let current = inv.total_invested.saturating_sub(inv.total_withdrawn);
+            if let Some(max_per) = campaign.config.max_investment_per_investor {
+                ensure!(
+                    current.saturating_add(amount) <= max_per,
+                    Error::<T>::InvestmentExceedsMaxPerInvestor
+                );
+            }
+            if let Some(cap) = Self::whitelist_allowance(campaign_id, &investor).and_then(|a| a.cap)
+            {
+                ensure!(current.saturating_add(amount) <= cap, Error::<T>::WhitelistCapExceeded);
+            }
+
+            // H-1: use InvestorCampaigns membership as the authoritative "new investor"
+            // signal. The Investment record persists after a full withdrawal
+            // (total_invested and total_withdrawn are both non-zero), so
+            // checking those fields produces a false negative on re-invest.
+            // InvestorCampaigns is removed on full withdrawal (and on refund),
+            // so its absence correctly identifies a new participant.
+            let is_new = !InvestorCampaigns::<T>::get(&investor).contains(&campaign_id);
+
+            if is_new {
+                let current = InvestorCampaigns::<T>::get(&investor);
+                ensure!(
+                    (current.len() as u32) < T::MaxInvestmentsPerInvestor::get(),
+                    Error::<T>::MaxInvestmentsPerInvestorReached
+                );
+            }
+
+            // Reward tier: the net position after this investment must meet the
+            // tier minimum, and the tier needs remaining supply unless the
+            // investor already holds it.
+            let previous_tier = InvestorTiers::<T>::get(campaign_id, &investor);
+            let tier_change = match tier_index {
+                Some(index) => {
+                    let tiers = RewardTiers::<T>::get(campaign_id).unwrap_or_default();
+                    let tier = tiers.get(index as usize).ok_or(Error::<T>::RewardTierNotFound)?;
+                    ensure!(
+                        current.saturating_add(amount) >= tier.min_amount,
+                        Error::<T>::BelowTierMinimum
+                    );
+                    match &previous_tier {
+                        Some(alloc) if alloc.tier_index == index => None,
+                        _ => {
+                            ensure!(tier.remaining > 0, Error::<T>::RewardTierSoldOut);
+                            Some(index)
+                        }
+                    }
+                }
+                None => None,
+            };
+
+            // `amount` is in the funding currency; an alternative currency
+            // payment is held as paid and recorded alongside its value.
+            let sub_account = Self::campaign_account(campaign_id);
+            let (currency, paid) = match &payment {
+                Some((currency, paid)) => (currency, *paid),
+                None => (&campaign.config.funding_currency, amount),
+            };
+            Self::do_transfer(
+                currency,
+                &investor,
+                &sub_account,
+                paid,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            if let Some((currency, paid)) = payment {
+                Self::add_holding(campaign_id, &investor, currency, paid, amount)?;
+            }
+
+            let bonus_bps = Self::early_bird_bonus_bps(&campaign, now);
+            let weighted = amount.saturating_add(Self::bps_of_floor(amount, bonus_bps));
+            inv.total_invested = inv.total_invested.saturating_add(amount);
+            inv.weighted_allocation = inv.weighted_allocation.saturating_add(weighted);
+            Investments::<T>::insert(campaign_id, &investor, inv);
+            TotalWeightedAllocations::<T>::mutate(campaign_id, |total| {
+                *total = total.saturating_add(weighted)
+            });
+            InvestedAt::<T>::mutate(campaign_id, &investor, |at| {
+                at.get_or_insert(now);
+            });
+
+            let mut hard_cap_reached = false;
+            let mut total_raised = campaign.total_raised;
+            Campaigns::<T>::mutate(campaign_id, |maybe| {
+                if let Some(c) = maybe {
+                    c.total_raised = c.total_raised.saturating_add(amount);
+                    total_raised = c.total_raised;
+                    if is_new {
+                        c.investor_count = c.investor_count.saturating_add(1);
+                    }
+                    // P3-01: check hard cap using the updated total_raised
+                    if let Some(cap) = c.config.hard_cap {
+                        if c.total_raised == cap {
+                            hard_cap_reached = true;
+                        }
+                    }
+                }
+            });
+
+            if is_new {
+                InvestorCampaigns::<T>::try_mutate(&investor, |ids| -> DispatchResult {
+                    ids.try_push(campaign_id)
+                        .map_err(|_| Error::<T>::MaxInvestmentsPerInvestorReached)?;
+                    Ok(())
+                })?;
+            }
+
+            if let Some(index) = tier_change {
+                if previous_tier.is_some() {
+                    Self::release_tier(campaign_id, &investor);
+                }
+                RewardTiers::<T>::mutate(campaign_id, |maybe| {
+                    if let Some(t) = maybe.as_mut().and_then(|tiers| tiers.get_mut(index as usize))
+                    {
+                        t.remaining = t.remaining.saturating_sub(1);
+                    }
+                });
+                InvestorTiers::<T>::insert(
+                    campaign_id,
+                    &investor,
+                    TierAllocation { tier_index: index, reward_minted: false },
+                );
+                Self::deposit_event(Event::RewardTierSelected {
+                    campaign_id,
+                    investor: investor.clone(),
+                    tier_index: index,
+                });
+            }
+
+            Self::note_stretch_goals(campaign_id, total_raised);
+            if hard_cap_reached {
+                Self::deposit_event(Event::HardCapReached { campaign_id });
+            }
+
+            Self::deposit_event(Event::Invested { campaign_id, investor, amount });
+            Ok(())
+        }
+
+        /// The funding round open at block `now`, with its index, or `None`
+        /// if the campaign has no rounds or they are over.
+        pub fn active_funding_round(
+            campaign_id: u32,
+            now: T::BlockNumber,
+        ) -> Option<(u32, FundingRoundOf<T>)> {
+            FundingRounds::<T>::get(campaign_id)?
+                .into_iter()
+                .enumerate()
+                .find(|(_, round)| now <= round.deadline)
+                .map(|(i, round)| (i as u32, round))
+        }
+
+        /// Mark the stretch goals `total_raised` has newly reached.
+        fn note_stretch_goals(campaign_id: u32, total_raised: BalanceOf<T>) {
+            let goals = match StretchGoals::<T>::get(campaign_id) {
+                Some(goals) => goals,
+                None => return,
+            };
+            let reached = StretchGoalsReached::<T>::get(campaign_id);
+            let mut count = reached;
+            for goal in goals.iter().skip(reached as usize) {
+                if total_raised < goal.threshold {
+                    break;
+                }
+                Self::deposit_event(Event::StretchGoalReached {
+                    campaign_id,
+                    index: count,
+                    threshold: goal.threshold,
+                });
+                count += 1;
+            }
+            if count != reached {
+                StretchGoalsReached::<T>::insert(campaign_id, count);
+            }
+        }
+
+        /// Early-bird bonus (in basis points) applicable to an investment made
+        /// at block `now`.
+        ///
+        /// The full bonus applies for `full_bonus_blocks` after campaign
+        /// creation, then decays linearly to zero over `decay_blocks`.
+        pub fn early_bird_bonus_bps(campaign: &CampaignOf<T>, now: T::BlockNumber) -> u16 {
+            let schedule = match &campaign.config.early_bird_bonus {
+                Some(s) => s,
+                None => return 0,
+            };
+            let elapsed = now.saturating_sub(campaign.created_at);
+            if elapsed < schedule.full_bonus_blocks {
+                return schedule.bonus_bps;
+            }
+            let into_decay = elapsed.saturating_sub(schedule.full_bonus_blocks);
+            if into_decay >= schedule.decay_blocks {
+                return 0;
+            }
+            let bps: T::BlockNumber = schedule.bonus_bps.into();
+            let decayed = bps.saturating_mul(schedule.decay_blocks.saturating_sub(into_decay))
+                / schedule.decay_blocks;
+            let decayed: u32 = decayed.unique_saturated_into();
+            decayed.min(schedule.bonus_bps as u32) as u16
+        }
+
+        /// Compute `bps` basis-points of `amount` using floor division.
+        ///
+        /// Used for bonuses, where rounding must not favour the investor (see
+        /// `bps_of` for the ceiling variant used for penalties and fees).
+        fn bps_of_floor(amount: BalanceOf<T>, bps: u16) -> BalanceOf<T> {
+            let bps_balance: BalanceOf<T> = (bps.min(10_000) as u32).into();
+            let divisor: BalanceOf<T> = 10_000u32.into();
+            amount.saturating_mul(bps_balance) / divisor
+        }
+
+        /// Refund an investor's net position in a Failed/Cancelled campaign
+        /// and remove their investment record.  Shared by `claim_refund` and
+        /// bulk refund processing.
+        fn do_refund(
+            campaign_id: u32,
+            campaign: &CampaignOf<T>,
+            investor: &T::AccountId,
+            inv: &Investment<BalanceOf<T>>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let raw_refund = inv.total_invested.saturating_sub(inv.total_withdrawn);
+            ensure!(!raw_refund.is_zero(), Error::<T>::NothingToRefund);
+
+            // When milestones have been partially disbursed before cancellation,
+            // reduce the refund proportionally so it doesn't exceed the sub-account
+            // balance.
+            let refund = if !campaign.total_disbursed.is_zero() && !campaign.total_raised.is_zero()
+            {
+                let remaining_ratio = Permill::from_rational(
+                    campaign.total_raised.saturating_sub(campaign.total_disbursed),
+                    campaign.total_raised,
+                );
+                remaining_ratio * raw_refund
+            } else {
+                raw_refund
+            };
+            ensure!(!refund.is_zero(), Error::<T>::NothingToRefund);
+
+            let sub_account = Self::campaign_account(campaign_id);
+            let holdings = InvestorHoldings::<T>::get(campaign_id, investor).unwrap_or_default();
+            let parts = Self::currency_parts(campaign, &holdings, refund, raw_refund);
+            for part in &parts {
+                Self::do_transfer(
+                    &part.currency,
+                    &sub_account,
+                    investor,
+                    part.amount,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+            Self::reduce_holdings(campaign_id, None, &parts);
+            InvestorHoldings::<T>::remove(campaign_id, investor);
+            Self::refund_bond_share(campaign_id, campaign, investor, raw_refund)?;
+            if PenaltyShareClaims::<T>::take(campaign_id, investor).is_none() {
+                Self::pay_penalty_share(campaign_id, campaign, investor, raw_refund)?;
+            }
+
+            Investments::<T>::remove(campaign_id, investor);
+            InvestedAt::<T>::remove(campaign_id, investor);
+            Self::release_tier(campaign_id, investor);
+
+            InvestorCampaigns::<T>::mutate(investor, |ids| {
+                if let Some(pos) = ids.iter().position(|&id| id == campaign_id) {
+                    ids.remove(pos);
+                }
+            });
+
+            // P2-12: decrement investor_count on refund
+            Campaigns::<T>::mutate(campaign_id, |maybe| {
+                if let Some(c) = maybe {
+                    c.investor_count = c.investor_count.saturating_sub(1);
+                }
+            });
+
+            Self::deposit_event(Event::RefundClaimed {
+                campaign_id,
+                investor: investor.clone(),
+                amount: refund,
+            });
+            Ok(refund)
+        }
+
+        /// Visit up to `max_count` investment records of a Failed/Cancelled
+        /// campaign, starting after `RefundCursor`, refunding each net
+        /// position.  Records of fully withdrawn investors are removed.
+        /// Investors whose refund fails are skipped and can still call
+        /// `claim_refund`.  Returns the number of records visited.
+        fn do_process_refunds(campaign_id: u32, campaign: &CampaignOf<T>, max_count: u32) -> u32 {
+            let mut iter = match RefundCursor::<T>::get(campaign_id) {
+                Some(last) => Investments::<T>::iter_prefix_from(
+                    campaign_id,
+                    Investments::<T>::hashed_key_for(campaign_id, &last),
+                ),
+                None => Investments::<T>::iter_prefix(campaign_id),
+            };
+
+            let mut visited = 0u32;
+            let mut refunded = 0u32;
+            let mut last = None;
+            while visited < max_count {
+                let (investor, inv) = match iter.next() {
+                    Some(entry) => entry,
+                    None => break,
+                };
+                visited += 1;
+                if inv.total_invested == inv.total_withdrawn {
+                    Investments::<T>::remove(campaign_id, &investor);
+                    InvestedAt::<T>::remove(campaign_id, &investor);
+                    Self::release_tier(campaign_id, &investor);
+                } else if storage::with_storage_layer(|| {
+                    Self::do_refund(campaign_id, campaign, &investor, &inv)
+                })
+                .is_ok()
+                {
+                    // Counted only once the refund and its bond and penalty
+                    // shares have all been paid; a failed transfer rolls the
+                    // whole refund back.
+                    refunded += 1;
+                }
+                last = Some(investor);
+            }
+
+            let completed = iter.next().is_none();
+            if completed {
+                RefundCursor::<T>::remove(campaign_id);
+                PendingRefunds::<T>::remove(campaign_id);
+                Self::clear_whitelist(campaign_id);
+                Self::clear_registrations(campaign_id);
+            } else if let Some(last) = last {
+                RefundCursor::<T>::insert(campaign_id, last);
+            }
+
+            if visited > 0 || completed {
+                Self::deposit_event(Event::RefundsProcessed { campaign_id, refunded, completed });
+            }
+            visited
+        }
+
+        /// Queue a campaign that just became Failed or Cancelled for
+        /// automatic refunds in `on_idle`.
+        fn queue_refunds(campaign_id: u32) {
+            if T::MaxIdleRefunds::get() > 0 {
+                PendingRefunds::<T>::insert(campaign_id, ());
+            }
+        }
+
+        /// Total early-withdrawal penalty charged on `parts`, in the funding
+        /// currency.  Each part is rounded up on its own, as
+        /// `withdraw_investment` charges it.
+        fn parts_penalty(parts: &[CurrencyHoldingOf<T>], penalty_bps: u16) -> BalanceOf<T> {
+            parts.iter().fold(Zero::zero(), |acc: BalanceOf<T>, part| {
+                acc.saturating_add(Self::bps_of(part.normalized, penalty_bps))
+            })
+        }
+
+        /// Early-withdrawal penalty `investor` would pay for withdrawing
+        /// `amount` at block `now`, with its rate in basis points.  Used by
+        /// the `preview_withdrawal` runtime API.
+        pub fn withdrawal_penalty(
+            campaign_id: u32,
+            campaign: &CampaignOf<T>,
+            investor: &T::AccountId,
+            amount: BalanceOf<T>,
+            now: T::BlockNumber,
+        ) -> (BalanceOf<T>, u16) {
+            let penalty_bps = Self::withdrawal_penalty_bps(campaign_id, campaign, investor, now);
+            let current = Investments::<T>::get(campaign_id, investor)
+                .map_or_else(Zero::zero, |inv| {
+                    inv.total_invested.saturating_sub(inv.total_withdrawn)
+                });
+            let holdings = InvestorHoldings::<T>::get(campaign_id, investor).unwrap_or_default();
+            let parts = Self::currency_parts(campaign, &holdings, amount, current);
+            (Self::parts_penalty(&parts, penalty_bps), penalty_bps)
+        }
+
+        /// Block from which `who`'s cooling-off period runs.
+        fn invested_at(
+            campaign_id: u32,
+            campaign: &CampaignOf<T>,
+            who: &T::AccountId,
+        ) -> T::BlockNumber {
+            InvestedAt::<T>::get(campaign_id, who).unwrap_or(campaign.created_at)
+        }
+
+        /// Early-withdrawal penalty (in basis points) of a withdrawal by
+        /// `who` made at block `now`.
+        pub fn withdrawal_penalty_bps(
+            campaign_id: u32,
+            campaign: &CampaignOf<T>,
+            who: &T::AccountId,
+            now: T::BlockNumber,
+        ) -> u16 {
+            let flat = campaign
+                .config
+                .early_withdrawal_penalty_bps
+                .unwrap_or_else(|| T::EarlyWithdrawalPenaltyBps::get());
+            let schedule = match PenaltySchedules::<T>::get(campaign_id) {
+                Some(schedule) => schedule,
+                None => return flat,
+            };
+            let invested_at = Self::invested_at(campaign_id, campaign, who);
+            if now < invested_at.saturating_add(schedule.cooling_off) {
+                return 0;
+            }
+            let remaining = campaign.config.deadline.saturating_sub(now);
+            schedule
+                .steps
+                .iter()
+                .rev()
+                .find(|step| remaining <= step.within)
+                .map_or(flat, |step| step.penalty_bps)
+        }
+
+        /// Pay an investor whose net position is `net` their share of the
+        /// penalties retained by the campaign, if they are entitled to one.
+        /// Returns the amount paid.
+        fn pay_penalty_share(
+            campaign_id: u32,
+            campaign: &CampaignOf<T>,
+            investor: &T::AccountId,
+            net: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let mut pot = match PenaltyPots::<T>::get(campaign_id) {
+                Some(pot) => pot,
+                None => return Ok(Zero::zero()),
+            };
+            let refunding =
+                matches!(campaign.status, CampaignStatus::Failed | CampaignStatus::Cancelled);
+            let share = match PenaltyDestinations::<T>::get(campaign_id) {
+                PenaltyDestination::RemainingInvestors => {
+                    Perbill::from_rational(net, campaign.total_raised) * pot.amount
+                }
+                // The pot counts towards `total_raised`, so it is shared only
+                // if the campaign does not pay out, and in proportion to what
+                // was not disbursed.
+                PenaltyDestination::CampaignPot if refunding => {
+                    let positions = Self::positions_total(campaign_id, campaign);
+                    let undisbursed = Perbill::from_rational(
+                        campaign.total_raised.saturating_sub(campaign.total_disbursed),
+                        campaign.total_raised,
+                    );
+                    Perbill::from_rational(net, positions) * (undisbursed * pot.amount)
+                }
+                _ => Zero::zero(),
+            }
+            .min(pot.amount.saturating_sub(pot.paid));
+            if share.is_zero() {
+                return Ok(share);
+            }
+
+            Self::do_transfer(
+                &campaign.config.funding_currency,
+                &Self::campaign_account(campaign_id),
+                investor,
+                share,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            pot.paid = pot.paid.saturating_add(share);
+            PenaltyPots::<T>::insert(campaign_id, pot);
+            Self::deposit_event(Event::PenaltySharePaid {
+                campaign_id,
+                investor: investor.clone(),
+                amount: share,
+            });
+            Ok(share)
+        }
+
+        /// Amount of a campaign's funds that has vested by block `now`.
+        fn vested_funds(
+            campaign: &CampaignOf<T>,
+            schedule: &VestingScheduleOf<T>,
+            now: T::BlockNumber,
+        ) -> BalanceOf<T> {
+            let elapsed = now.saturating_sub(campaign.config.deadline);
+            if elapsed < schedule.cliff {
+                return Zero::zero();
+            }
+            if elapsed >= schedule.duration {
+                return campaign.total_raised;
+            }
+            let elapsed: u32 = elapsed.unique_saturated_into();
+            let duration: u32 = schedule.duration.unique_saturated_into();
+            Perbill::from_rational(elapsed, duration) * campaign.total_raised
+        }
+
+        /// Whether a campaign that reached its goal can still be cancelled
+        /// and refunded: in the milestone phase, or Succeeded with funds
+        /// still vesting.
+        fn is_cancellable_success(campaign_id: u32, campaign: &CampaignOf<T>) -> bool {
+            match campaign.status {
+                CampaignStatus::MilestonePhase => true,
+                CampaignStatus::Succeeded => VestingSchedules::<T>::contains_key(campaign_id),
+                _ => false,
+            }
+        }
+
+        /// Amount the creator of a Succeeded campaign can claim at block
+        /// `now`: everything left, or only what has vested.
+        fn claimable_funds(
+            campaign_id: u32,
+            campaign: &CampaignOf<T>,
+            now: T::BlockNumber,
+        ) -> BalanceOf<T> {
+            match VestingSchedules::<T>::get(campaign_id) {
+                Some(schedule) => Self::vested_funds(campaign, &schedule, now),
+                None => campaign.total_raised,
+            }
+            .saturating_sub(campaign.total_disbursed)
+        }
+
+        /// Pay `amount` of a Succeeded campaign's funds, less the protocol
+        /// fee, to the creator.  The campaign is Completed once all of its
+        /// funds are disbursed.
+        fn do_claim_funds(
+            campaign_id: u32,
+            campaign: &mut CampaignOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            // CAT-3.9-C-I: use the fee locked at creation time, not the current global rate
+            let fee_bps = campaign.protocol_fee_bps;
+            let fee = Self::bps_of(amount, fee_bps);
+            let creator_amount = amount.saturating_sub(fee);
+
+            let remaining = campaign.total_raised.saturating_sub(campaign.total_disbursed);
+            let holdings = CampaignHoldings::<T>::get(campaign_id).unwrap_or_default();
+            let parts = Self::currency_parts(campaign, &holdings, amount, remaining);
+            Self::pay_creator(campaign_id, &campaign.creator, &parts, fee_bps)?;
+            if !fee.is_zero() {
+                Self::deposit_event(Event::ProtocolFeeCollected { campaign_id, amount: fee });
+            }
+
+            campaign.total_disbursed = campaign.total_disbursed.saturating_add(amount);
+            if campaign.total_disbursed >= campaign.total_raised {
+                campaign.status = CampaignStatus::Completed;
+                Self::unlink_license(campaign_id, campaign);
+            }
+            Campaigns::<T>::insert(campaign_id, &*campaign);
+
+            // P1-02: emit net amount (after fee), not gross
+            Self::deposit_event(Event::FundsClaimed {
+                campaign_id,
+                creator: campaign.creator.clone(),
+                amount: creator_amount,
+            });
+            Ok(())
+        }
+
+        /// Before a vesting campaign is cancelled, pay its creator what has
+        /// vested so that only the unvested remainder is refunded.
+        fn settle_vested_funds(campaign_id: u32) -> DispatchResult {
+            let mut campaign = match Campaigns::<T>::get(campaign_id) {
+                Some(c) if matches!(c.status, CampaignStatus::Succeeded) => c,
+                _ => return Ok(()),
+            };
+            let now = frame_system::Pallet::<T>::block_number();
+            let claimable = Self::claimable_funds(campaign_id, &campaign, now);
+            if claimable.is_zero() {
+                return Ok(());
+            }
+            Self::do_claim_funds(campaign_id, &mut campaign, claimable)
+        }
+
+        /// Vesting progress of a campaign's funds, for the runtime API.
+        pub fn vesting_status(
+            campaign_id: u32,
+        ) -> Option<VestingStatus<BalanceOf<T>, T::BlockNumber>> {
+            let campaign = Campaigns::<T>::get(campaign_id)?;
+            let schedule = VestingSchedules::<T>::get(campaign_id)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let vested = match campaign.status {
+                CampaignStatus::Succeeded | CampaignStatus::Completed => {
+                    Self::vested_funds(&campaign, &schedule, now)
+                }
+                _ => Zero::zero(),
+            };
+            Some(VestingStatus {
+                start: campaign.config.deadline,
+                total: campaign.total_raised,
+                claimed: campaign.total_disbursed,
+                claimable: vested.saturating_sub(campaign.total_disbursed),
+                vested,
+                schedule,
+            })
+        }
+
+        /// Return `amount` of a campaign's creator bond to `creator`.
+        fn release_creator_bond(
+            campaign_id: u32,
+            creator: &T::AccountId,
+            mut bond: CreatorBond<BalanceOf<T>>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+            T::NativeCurrency::transfer(
+                &Self::campaign_account(campaign_id),
+                creator,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            bond.released = bond.released.saturating_add(amount);
+            CreatorBonds::<T>::insert(campaign_id, bond);
+            Self::deposit_event(Event::CreatorBondReleased { campaign_id, amount });
+            Ok(())
+        }
+
+        /// Slash the still-locked creator bond of a campaign cancelled
+        /// before its milestones were delivered.  Investors receive it
+        /// pro-rata with their refunds.
+        fn slash_creator_bond(campaign_id: u32) {
+            CreatorBonds::<T>::mutate(campaign_id, |maybe| {
+                if let Some(bond) = maybe {
+                    let amount =
+                        bond.amount.saturating_sub(bond.released).saturating_sub(bond.slashed);
+                    if !amount.is_zero() {
+                        bond.slashed = bond.slashed.saturating_add(amount);
+                        Self::deposit_event(Event::CreatorBondSlashed { campaign_id, amount });
+                    }
+                }
+            });
+        }
+
+        /// Pay an investor whose net position is `net` their pro-rata share
+        /// of the campaign's slashed creator bond.
+        fn refund_bond_share(
+            campaign_id: u32,
+            campaign: &CampaignOf<T>,
+            investor: &T::AccountId,
+            net: BalanceOf<T>,
+        ) -> DispatchResult {
+            let mut bond = match CreatorBonds::<T>::get(campaign_id) {
+                Some(bond) if !bond.slashed.is_zero() => bond,
+                _ => return Ok(()),
+            };
+            // Rounding down keeps the shares paid within `slashed`.
+            let positions = Self::positions_total(campaign_id, campaign);
+            let share = (Perbill::from_rational(net, positions) * bond.slashed)
+                .min(bond.slashed.saturating_sub(bond.refunded));
+            if share.is_zero() {
+                return Ok(());
+            }
+            T::NativeCurrency::transfer(
+                &Self::campaign_account(campaign_id),
+                investor,
+                share,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            bond.refunded = bond.refunded.saturating_add(share);
+            CreatorBonds::<T>::insert(campaign_id, bond);
+            Self::deposit_event(Event::BondShareRefunded {
+                campaign_id,
+                investor: investor.clone(),
+                amount: share,
+            });
+            Ok(())
+        }
+
+        /// Sum of the investors' net positions: `total_raised` less the
+        /// penalties kept in the campaign pot, which count towards it.
+        fn positions_total(campaign_id: u32, campaign: &CampaignOf<T>) -> BalanceOf<T> {
+            let retained = match PenaltyDestinations::<T>::get(campaign_id) {
+                PenaltyDestination::CampaignPot => {
+                    PenaltyPots::<T>::get(campaign_id).map_or_else(Zero::zero, |pot| pot.amount)
+                }
+                _ => Zero::zero(),
+            };
+            campaign.total_raised.saturating_sub(retained)
+        }
+
+        /// Issue an investor's share of a successful asset-backed offering.
+        /// Returns `None` if the campaign is not an offering or the share
+        /// rounds to zero.
+        fn issue_asset_share(
+            campaign_id: u32,
+            investor: &T::AccountId,
+            inv: &Investment<BalanceOf<T>>,
+        ) -> Option<Perbill> {
+            AssetOfferings::<T>::mutate(campaign_id, |maybe| {
+                let offering = maybe.as_mut()?;
+                // Shares follow the weighted allocation, so early backers get
+                // their bonus.  Rounding down keeps the issued shares within
+                // `fraction`.
+                let share = (offering.fraction
+                    * Perbill::from_rational(
+                        inv.weighted_allocation,
+                        TotalWeightedAllocations::<T>::get(campaign_id),
+                    ))
+                .min(offering.fraction.saturating_sub(offering.issued));
+                if share.is_zero() {
+                    return None;
                 }
+                offering.issued = offering.issued.saturating_add(share);
+                let rwa_asset_id = offering.rwa_asset_id;
+                CommittedAssetFraction::<T>::mutate(rwa_asset_id, |f| *f = f.saturating_sub(share));
+                IssuedAssetFraction::<T>::mutate(rwa_asset_id, |f| *f = f.saturating_add(share));
+                AssetShares::<T>::mutate(rwa_asset_id, investor, |held| {
+                    *held = Some(held.unwrap_or_default().saturating_add(share))
+                });
+                Self::deposit_event(Event::AssetShareIssued {
+                    campaign_id,
+                    rwa_asset_id,
+                    who: investor.clone(),
+                    share,
+                });
+                Some(share)
+            })
+        }
+
+        /// Drop a campaign's asset-backed offering and release the part of
+        /// its fraction that was never issued, so other offerings can sell
+        /// it.
+        fn release_asset_offering(campaign_id: u32) {
+            if let Some(offering) = AssetOfferings::<T>::take(campaign_id) {
+                let unissued = offering.fraction.saturating_sub(offering.issued);
+                CommittedAssetFraction::<T>::mutate_exists(offering.rwa_asset_id, |maybe| {
+                    let left = maybe.unwrap_or_default().saturating_sub(unissued);
+                    *maybe = if left.is_zero() { None } else { Some(left) };
+                });
+            }
+        }
+
+        /// Whether shares of `rwa_asset_id` are held or promised by an
+        /// offering, in which case the asset must not be retired.
+        pub fn has_asset_obligations(rwa_asset_id: u32) -> bool {
+            !CommittedAssetFraction::<T>::get(rwa_asset_id).is_zero()
+                || !IssuedAssetFraction::<T>::get(rwa_asset_id).is_zero()
+        }
+
+        /// Worst-case weight of [`Self::has_asset_obligations`]: both
+        /// fractions.
+        pub fn asset_obligations_weight() -> Weight { T::DbWeight::get().reads(2) }
+
+        /// Return an investor's reward tier allocation to the tier supply.
+        fn release_tier(campaign_id: u32, investor: &T::AccountId) {
+            if let Some(alloc) = InvestorTiers::<T>::take(campaign_id, investor) {
+                RewardTiers::<T>::mutate(campaign_id, |maybe| {
+                    if let Some(t) =
+                        maybe.as_mut().and_then(|tiers| tiers.get_mut(alloc.tier_index as usize))
+                    {
+                        t.remaining = t.remaining.saturating_add(1).min(t.quantity);
+                    }
+                });
+                Self::deposit_event(Event::RewardTierReleased {
+                    campaign_id,
+                    investor: investor.clone(),
+                    tier_index: alloc.tier_index,
+                });
+            }
+        }
+
+        /// Mint the NFT reward of a tier to an investor from the next free
+        /// serial of its collection.  Serials the collection owner minted
+        /// directly are skipped, up to `MAX_REWARD_SERIAL_SKIPS` per mint.
+        fn do_mint_reward(
+            campaign_id: u32,
+            investor: &T::AccountId,
+            tier_index: u8,
+            collection: T::CollectionId,
+        ) -> DispatchResult {
+            let mut serial = NextRewardSerial::<T>::get(collection);
+            let mut skipped = 0;
+            while T::RewardMinter::is_minted(&collection, serial) {
+                ensure!(skipped < MAX_REWARD_SERIAL_SKIPS, Error::<T>::RewardSerialsExhausted);
+                serial = serial.saturating_add(1);
+                skipped += 1;
+            }
+            T::RewardMinter::mint_reward(&collection, serial, investor)?;
+            NextRewardSerial::<T>::insert(collection, serial.saturating_add(1));
+            Self::deposit_event(Event::RewardMinted {
+                campaign_id,
+                investor: investor.clone(),
+                tier_index,
+                collection,
+                serial,
+            });
+            Ok(())
+        }
+
+        /// Replace a settled campaign whose investment records are gone with
+        /// its archived summary, and pay the reap deposit to `reaper`.
+        fn archive_campaign(
+            campaign_id: u32,
+            campaign: &CampaignOf<T>,
+            reaper: &T::AccountId,
+        ) -> DispatchResult {
+            // Penalties nobody is entitled to any more (e.g. every investor
+            // withdrew) go to the protocol fee recipient.  A campaign pot
+            // of a Completed campaign was paid to the creator; that of a
+            // failed or cancelled one went back to the investors with their
+            // refunds, less the part disbursed before cancellation, so it
+            // is swept only if no investor was left to share it.
+            if let Some(pot) = PenaltyPots::<T>::take(campaign_id) {
+                let unclaimed = match PenaltyDestinations::<T>::get(campaign_id) {
+                    PenaltyDestination::RemainingInvestors => pot.amount.saturating_sub(pot.paid),
+                    PenaltyDestination::CampaignPot
+                        if !matches!(campaign.status, CampaignStatus::Completed)
+                            && campaign.total_raised <= pot.amount =>
+                    {
+                        let undisbursed = Perbill::from_rational(
+                            campaign.total_raised.saturating_sub(campaign.total_disbursed),
+                            campaign.total_raised,
+                        );
+                        (undisbursed * pot.amount).saturating_sub(pot.paid)
+                    }
+                    _ => Zero::zero(),
+                };
+                Self::do_transfer(
+                    &campaign.config.funding_currency,
+                    &Self::campaign_account(campaign_id),
+                    &Self::effective_protocol_fee_recipient(),
+                    unclaimed,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+            // Likewise the part of a slashed creator bond no refund paid out.
+            if let Some(bond) = CreatorBonds::<T>::take(campaign_id) {
+                T::NativeCurrency::transfer(
+                    &Self::campaign_account(campaign_id),
+                    &Self::effective_protocol_fee_recipient(),
+                    bond.slashed.saturating_sub(bond.refunded),
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+            // The sponsorship ends with the campaign.
+            if let Some(sponsorship) = FeeSponsorships::<T>::take(campaign_id) {
+                Self::end_sponsorship(campaign_id, sponsorship)?;
+            }
+            if let Some(amount) = ReapDeposits::<T>::take(campaign_id) {
+                T::NativeCurrency::transfer(
+                    &Self::campaign_account(campaign_id),
+                    reaper,
+                    amount,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+                Self::deposit_event(Event::ReapRewardPaid {
+                    campaign_id,
+                    who: reaper.clone(),
+                    amount,
+                });
             }
+
+            let _ =
+                MilestoneStatuses::<T>::clear_prefix(campaign_id, T::MaxMilestones::get(), None);
+            Self::clear_whitelist(campaign_id);
+            Self::clear_registrations(campaign_id);
+            RewardTiers::<T>::remove(campaign_id);
+            EligibilityExpressions::<T>::remove(campaign_id);
+            AcceptedCurrencies::<T>::remove(campaign_id);
+            CampaignHoldings::<T>::remove(campaign_id);
+            Self::release_asset_offering(campaign_id);
+            StretchGoals::<T>::remove(campaign_id);
+            StretchGoalsReached::<T>::remove(campaign_id);
+            FundingRounds::<T>::remove(campaign_id);
+            TotalWeightedAllocations::<T>::remove(campaign_id);
+            VestingSchedules::<T>::remove(campaign_id);
+            PenaltySchedules::<T>::remove(campaign_id);
+            PenaltyDestinations::<T>::remove(campaign_id);
+            TransferLockups::<T>::remove(campaign_id);
+            RefundCursor::<T>::remove(campaign_id);
+            PendingRefunds::<T>::remove(campaign_id);
+            // Left to `on_idle` with the whitelist and registrations queued
+            // above: sponsored callers need not be investors.
+            PendingCleanups::<T>::insert(campaign_id, ());
+            Campaigns::<T>::remove(campaign_id);
+
+            ArchivedCampaigns::<T>::insert(
+                campaign_id,
+                ArchivedCampaign {
+                    creator: campaign.creator.clone(),
+                    status: campaign.status,
+                    funding_currency: campaign.config.funding_currency.clone(),
+                    total_raised: campaign.total_raised,
+                    total_disbursed: campaign.total_disbursed,
+                    created_at: campaign.created_at,
+                    reaped_at: frame_system::Pallet::<T>::block_number(),
+                    rwa_asset_id: campaign.rwa_asset_id,
+                    participation_id: campaign.participation_id,
+                },
+            );
             Ok(())
         }
 
@@ -1480,32 +5229,327 @@ pub mod pallet {
             campaign: &CampaignOf<T>,
             campaign_id: u32,
         ) -> DispatchResult {
-            for rule in campaign.eligibility_rules.iter() {
-                let passes = match rule {
-                    EligibilityRule::NativeBalance { min_balance } => {
-                        T::NativeCurrency::free_balance(who) >= *min_balance
+            Self::eligibility_failure(who, campaign, campaign_id, None)
+                .map_or(Ok(()), |_| Err(Error::<T>::EligibilityCheckFailed.into()))
+        }
+
+        /// Check `who` against a campaign's flat rules and eligibility
+        /// expression.
+        pub fn check_eligibility(
+            campaign_id: u32,
+            who: &T::AccountId,
+        ) -> Result<(), EligibilityFailure> {
+            Self::check_eligibility_with_proof(campaign_id, who, None)
+        }
+
+        /// As `check_eligibility`, treating `who` as whitelisted if `proof`
+        /// matches the campaign's whitelist root.  Used by the
+        /// `check_eligibility` runtime API.
+        pub fn check_eligibility_with_proof(
+            campaign_id: u32,
+            who: &T::AccountId,
+            proof: Option<&WhitelistProof<BalanceOf<T>>>,
+        ) -> Result<(), EligibilityFailure> {
+            let campaign =
+                Campaigns::<T>::get(campaign_id).ok_or(EligibilityFailure::CampaignNotFound)?;
+            Self::eligibility_failure(who, &campaign, campaign_id, proof).map_or(Ok(()), Err)
+        }
+
+        /// First failing branch for `who`, or `None` if eligible.  The flat
+        /// rules are an implicit AND checked before the expression.
+        fn eligibility_failure(
+            who: &T::AccountId,
+            campaign: &CampaignOf<T>,
+            campaign_id: u32,
+            proof: Option<&WhitelistProof<BalanceOf<T>>>,
+        ) -> Option<EligibilityFailure> {
+            let snapshot = if RegistrationPhases::<T>::contains_key(campaign_id) {
+                match Registrations::<T>::get(campaign_id, who) {
+                    Some(snapshot) => Some(snapshot),
+                    None => return Some(EligibilityFailure::NotRegistered),
+                }
+            } else {
+                None
+            };
+            let snapshot = snapshot.as_ref();
+            let whitelisted = CampaignWhitelist::<T>::get(campaign_id, who)
+                || Self::whitelist_allowance(campaign_id, who).is_some()
+                || proof.map_or(false, |proof| {
+                    WhitelistRoots::<T>::get(campaign_id)
+                        .map_or(false, |root| Self::proof_matches(&root, who, proof))
+                });
+            for (index, rule) in campaign.eligibility_rules.iter().enumerate() {
+                if let Some(error) = Self::rule_error(rule, who, whitelisted, snapshot) {
+                    return Some(EligibilityFailure::Rule { index: index as u8, error });
+                }
+            }
+            let nodes = EligibilityExpressions::<T>::get(campaign_id)?;
+            let root = nodes.len().checked_sub(1)?;
+            Self::node_error(&nodes, root, who, whitelisted, snapshot).map(|(mut path, error)| {
+                path.reverse();
+                EligibilityFailure::Expression { path, error }
+            })
+        }
+
+        /// Evaluate the subtree rooted at `index`.  On failure, returns the
+        /// path from the failing node up to `index` and the reason.
+        fn node_error(
+            nodes: &[EligibilityNode<EligibilityRuleOf<T>>],
+            index: usize,
+            who: &T::AccountId,
+            whitelisted: bool,
+            snapshot: Option<&RegistrationSnapshotOf<T>>,
+        ) -> Option<(Vec<u8>, EligibilityError)> {
+            let failure = match nodes.get(index)? {
+                EligibilityNode::Rule(rule) => {
+                    Self::rule_error(rule, who, whitelisted, snapshot).map(|e| (Vec::new(), e))
+                }
+                EligibilityNode::And(a, b) => {
+                    Self::node_error(nodes, *a as usize, who, whitelisted, snapshot).or_else(|| {
+                        Self::node_error(nodes, *b as usize, who, whitelisted, snapshot)
+                    })
+                }
+                EligibilityNode::Or(a, b) => {
+                    let failed = Self::node_error(nodes, *a as usize, who, whitelisted, snapshot)
+                        .is_some()
+                        && Self::node_error(nodes, *b as usize, who, whitelisted, snapshot)
+                            .is_some();
+                    failed.then(|| (Vec::new(), EligibilityError::NoBranchPassed))
+                }
+                EligibilityNode::Not(a) => {
+                    Self::node_error(nodes, *a as usize, who, whitelisted, snapshot)
+                        .is_none()
+                        .then(|| (Vec::new(), EligibilityError::NegatedConditionMet))
+                }
+            };
+            failure.map(|(mut path, error)| {
+                path.push(index as u8);
+                (path, error)
+            })
+        }
+
+        /// Reason a single rule fails for `who`, or `None` if it passes.
+        /// `whitelisted` covers both the explicit whitelist and the Merkle
+        /// root.  With a registration `snapshot`, balance rules see the lower
+        /// of the snapshot and the current balance.
+        fn rule_error(
+            rule: &EligibilityRuleOf<T>,
+            who: &T::AccountId,
+            whitelisted: bool,
+            snapshot: Option<&RegistrationSnapshotOf<T>>,
+        ) -> Option<EligibilityError> {
+            let (passes, error) = match rule {
+                EligibilityRule::NativeBalance { min_balance } => {
+                    let mut balance = T::NativeCurrency::free_balance(who);
+                    if let Some(s) = snapshot {
+                        balance = balance.min(s.native);
                     }
-                    EligibilityRule::AssetBalance { asset_id, min_balance } => {
-                        <T::Fungibles as fungibles::Inspect<T::AccountId>>::balance(*asset_id, who)
-                            >= *min_balance
+                    (balance >= *min_balance, EligibilityError::InsufficientNativeBalance)
+                }
+                EligibilityRule::AssetBalance { asset_id, min_balance } => {
+                    let mut balance =
+                        <T::Fungibles as fungibles::Inspect<T::AccountId>>::balance(*asset_id, who);
+                    if let Some(s) = snapshot {
+                        let recorded = s.assets.iter().find(|(id, _)| id == asset_id);
+                        balance = balance.min(recorded.map_or(Zero::zero(), |(_, b)| *b));
                     }
-                    EligibilityRule::NftOwnership { required_sets } => {
-                        required_sets.iter().any(|set| {
-                            set.iter().all(|(cid, iid)| {
-                                <T::NftInspect as nonfungibles_v2::Inspect<T::AccountId>>::owner(
-                                    cid, iid,
-                                )
-                                .map_or(false, |o| o == *who)
-                            })
+                    (balance >= *min_balance, EligibilityError::InsufficientAssetBalance)
+                }
+                EligibilityRule::NftOwnership { required_sets } => (
+                    required_sets.iter().any(|set| {
+                        set.iter().all(|(cid, iid)| {
+                            <T::NftInspect as nonfungibles_v2::Inspect<T::AccountId>>::owner(
+                                cid, iid,
+                            )
+                            .map_or(false, |o| o == *who)
                         })
+                    }),
+                    EligibilityError::NftOwnershipNotMet,
+                ),
+                EligibilityRule::AccountWhitelist => {
+                    (whitelisted, EligibilityError::NotWhitelisted)
+                }
+                EligibilityRule::RwaParticipation { rwa_asset_id } => (
+                    T::LicenseVerifier::has_active_participation(*rwa_asset_id, who),
+                    EligibilityError::NoRwaParticipation,
+                ),
+                EligibilityRule::Attestation { claim } => (
+                    T::EligibilityProvider::has_attestation(who, claim),
+                    EligibilityError::AttestationMissing,
+                ),
+                EligibilityRule::MinAccountAge { blocks } => (
+                    T::EligibilityProvider::account_created_at(who).map_or(false, |at| {
+                        frame_system::Pallet::<T>::block_number().saturating_sub(at)
+                            >= (*blocks).into()
+                    }),
+                    EligibilityError::AccountTooNew,
+                ),
+            };
+            if passes {
+                return None;
+            }
+            Some(error)
+        }
+
+        /// Distinct assets referenced by the `AssetBalance` rules of a
+        /// campaign's flat rules and eligibility expression.
+        fn snapshot_assets(campaign: &CampaignOf<T>, campaign_id: u32) -> Vec<T::AssetId> {
+            let expression = EligibilityExpressions::<T>::get(campaign_id).unwrap_or_default();
+            let leaves = expression.iter().filter_map(|node| match node {
+                EligibilityNode::Rule(rule) => Some(rule),
+                _ => None,
+            });
+            let mut assets = Vec::new();
+            for rule in campaign.eligibility_rules.iter().chain(leaves) {
+                if let EligibilityRule::AssetBalance { asset_id, .. } = rule {
+                    if !assets.contains(asset_id) {
+                        assets.push(*asset_id);
                     }
-                    EligibilityRule::AccountWhitelist => {
-                        CampaignWhitelist::<T>::get(campaign_id, who)
+                }
+            }
+            assets
+        }
+
+        /// Remove a campaign's registration phase and count, and queue its
+        /// snapshots for removal in `on_idle`.
+        fn clear_registrations(campaign_id: u32) {
+            RegistrationPhases::<T>::remove(campaign_id);
+            RegistrationCount::<T>::remove(campaign_id);
+            PendingCleanups::<T>::insert(campaign_id, ());
+        }
+
+        /// Remove a campaign's whitelist root and count, and queue its
+        /// explicit whitelist and cached proofs for removal in `on_idle`.
+        fn clear_whitelist(campaign_id: u32) {
+            CampaignWhitelistCount::<T>::remove(campaign_id);
+            WhitelistRoots::<T>::remove(campaign_id);
+            PendingCleanups::<T>::insert(campaign_id, ());
+        }
+
+        /// Remove up to `limit` per-account records of a campaign queued in
+        /// `PendingCleanups`, dequeuing it once none are left.  Returns the
+        /// number of keys visited.
+        ///
+        /// `on_idle` calls this once per block, and each block's removals are
+        /// committed before the next, so a limited `clear_prefix` resumes
+        /// where the last one stopped without keeping a cursor.
+        pub(crate) fn do_cleanup(campaign_id: u32, limit: u32) -> u32 {
+            let mut visited = 0u32;
+            let mut completed = true;
+            let mut clear =
+                |clear_prefix: &dyn Fn(u32) -> frame_support::sp_io::MultiRemovalResults| {
+                    let left = limit.saturating_sub(visited);
+                    if left == 0 {
+                        completed = false;
+                        return;
                     }
+                    let result = clear_prefix(left);
+                    visited = visited.saturating_add(result.loops);
+                    completed &= result.maybe_cursor.is_none();
                 };
-                ensure!(passes, Error::<T>::EligibilityCheckFailed);
+            clear(&|n| CampaignWhitelist::<T>::clear_prefix(campaign_id, n, None));
+            clear(&|n| WhitelistAllowances::<T>::clear_prefix(campaign_id, n, None));
+            clear(&|n| Registrations::<T>::clear_prefix(campaign_id, n, None));
+            clear(&|n| SponsoredCalls::<T>::clear_prefix(campaign_id, n, None));
+            if completed {
+                PendingCleanups::<T>::remove(campaign_id);
             }
-            Ok(())
+            visited
+        }
+
+        /// Spend leftover weight on `PendingCleanups`, returning `used` plus
+        /// the weight spent.
+        fn idle_cleanup(remaining_weight: Weight, mut used: Weight) -> Weight {
+            let max_cleanups = T::MaxIdleCleanups::get();
+            // Reading the queue head and dequeuing it.
+            let queue = T::DbWeight::get().reads_writes(1, 1);
+            if max_cleanups == 0 || remaining_weight.any_lt(used.saturating_add(queue)) {
+                return used;
+            }
+            used = used.saturating_add(queue);
+            let campaign_id = match PendingCleanups::<T>::iter_keys().next() {
+                Some(id) => id,
+                None => return used,
+            };
+            let per_record = T::WeightInfo::idle_cleanup(1);
+            let mut limit = 0u32;
+            while limit < max_cleanups
+                && !remaining_weight
+                    .any_lt(used.saturating_add(per_record.saturating_mul(limit as u64 + 1)))
+            {
+                limit += 1;
+            }
+            if limit == 0 {
+                return used;
+            }
+            let visited = Self::do_cleanup(campaign_id, limit);
+            used.saturating_add(per_record.saturating_mul(visited as u64))
+        }
+
+        /// Cached whitelist proof of `who`, if made against the campaign's
+        /// current root.
+        fn whitelist_allowance(
+            campaign_id: u32,
+            who: &T::AccountId,
+        ) -> Option<WhitelistAllowance<BalanceOf<T>>> {
+            let allowance = WhitelistAllowances::<T>::get(campaign_id, who)?;
+            (WhitelistRoots::<T>::get(campaign_id)? == allowance.root).then_some(allowance)
+        }
+
+        /// Leaf committing `who` and its optional investment cap to a
+        /// campaign's whitelist tree.
+        pub fn whitelist_leaf(who: &T::AccountId, cap: Option<BalanceOf<T>>) -> [u8; 32] {
+            sp_core::hashing::blake2_256(&(who, cap).encode())
+        }
+
+        /// Root reached by hashing the leaf of `who` up through `proof`.
+        /// Sibling pairs are hashed in sorted order.
+        pub fn whitelist_proof_root(
+            who: &T::AccountId,
+            proof: &WhitelistProof<BalanceOf<T>>,
+        ) -> [u8; 32] {
+            let leaf = Self::whitelist_leaf(who, proof.cap);
+            proof.siblings.iter().fold(leaf, |node, sibling| {
+                let (left, right) =
+                    if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+                sp_core::hashing::blake2_256(&[left, right].concat())
+            })
+        }
+
+        /// Whether `proof` links the leaf of `who` to `root` within
+        /// `MaxWhitelistProofDepth`.
+        fn proof_matches(
+            root: &[u8; 32],
+            who: &T::AccountId,
+            proof: &WhitelistProof<BalanceOf<T>>,
+        ) -> bool {
+            proof.siblings.len() <= T::MaxWhitelistProofDepth::get() as usize
+                && Self::whitelist_proof_root(who, proof) == *root
+        }
+
+        /// An expression is valid when every child index precedes its parent
+        /// and every node except the last (the root) is used exactly once.
+        /// This keeps evaluation linear in the number of nodes.
+        fn is_valid_expression(nodes: &[EligibilityNode<EligibilityRuleOf<T>>]) -> bool {
+            if nodes.len() > u8::MAX as usize + 1 {
+                return false;
+            }
+            let mut used = vec![false; nodes.len()];
+            for (index, node) in nodes.iter().enumerate() {
+                let children: &[u8] = match node {
+                    EligibilityNode::Rule(_) => &[],
+                    EligibilityNode::And(a, b) | EligibilityNode::Or(a, b) => &[*a, *b],
+                    EligibilityNode::Not(a) => &[*a],
+                };
+                for &child in children {
+                    let child = child as usize;
+                    if child >= index || used[child] {
+                        return false;
+                    }
+                    used[child] = true;
+                }
+            }
+            used.iter().rev().skip(1).all(|&u| u)
         }
 
         /// Compute `bps` basis-points of `amount` using **ceiling** division.
@@ -1534,6 +5578,14 @@ pub mod pallet {
 
         #[cfg(feature = "try-runtime")]
         fn do_try_state() -> Result<(), &'static str> {
+            // Storage is only partly migrated while a stepped migration runs.
+            if let Some(cursor) = ActiveMigration::<T>::get() {
+                if Self::on_chain_storage_version() >= cursor.target_version {
+                    return Err("ActiveMigration target not above on-chain version");
+                }
+                return Ok(());
+            }
+
             // 1. NextCampaignId >= campaign count
             let next_id = NextCampaignId::<T>::get();
             let campaign_count = Campaigns::<T>::iter().count() as u32;
@@ -1660,6 +5712,104 @@ pub mod pallet {
                 }
             }
 
+            // LicensedCampaigns indexes exactly the non-terminal campaigns
+            // created under a license.
+            for ((asset_id, part_id, id), ()) in LicensedCampaigns::<T>::iter() {
+                let indexed = Campaigns::<T>::get(id).map_or(false, |c| {
+                    c.rwa_asset_id == Some(asset_id)
+                        && c.participation_id == Some(part_id)
+                        && !matches!(
+                            c.status,
+                            CampaignStatus::Failed
+                                | CampaignStatus::Cancelled
+                                | CampaignStatus::Completed
+                        )
+                });
+                if !indexed {
+                    frame_support::log::error!(
+                        target: "pallet-crowdfunding",
+                        "LicensedCampaigns entry ({}, {}, {}) is stale",
+                        asset_id, part_id, id,
+                    );
+                    return Err("LicensedCampaigns references a terminal or unlicensed campaign");
+                }
+            }
+            for (id, c) in Campaigns::<T>::iter() {
+                if let (Some(asset_id), Some(part_id)) = (c.rwa_asset_id, c.participation_id) {
+                    let terminal = matches!(
+                        c.status,
+                        CampaignStatus::Failed
+                            | CampaignStatus::Cancelled
+                            | CampaignStatus::Completed
+                    );
+                    if !terminal && !LicensedCampaigns::<T>::contains_key((asset_id, part_id, id)) {
+                        return Err("Non-terminal licensed campaign missing from LicensedCampaigns");
+                    }
+                }
+            }
+
+            // Reward tiers: allocations held by investors plus remaining
+            // supply must equal each tier's quantity.
+            for (id, tiers) in RewardTiers::<T>::iter() {
+                for (index, tier) in tiers.iter().enumerate() {
+                    let allocated = InvestorTiers::<T>::iter_prefix(id)
+                        .filter(|(_, alloc)| alloc.tier_index as usize == index)
+                        .count() as u32;
+                    if tier.remaining.saturating_add(allocated) != tier.quantity {
+                        frame_support::log::error!(
+                            target: "pallet-crowdfunding",
+                            "Campaign {} tier {}: remaining ({}) + allocated ({}) != quantity ({})",
+                            id, index, tier.remaining, allocated, tier.quantity,
+                        );
+                        return Err("Reward tier supply inconsistent with InvestorTiers");
+                    }
+                }
+            }
+
+            // Deadline index: indexed campaigns are Funding and keyed by the
+            // block after their deadline.
+            for (block, ids) in CampaignDeadlines::<T>::iter() {
+                for &id in ids.iter() {
+                    match Campaigns::<T>::get(id) {
+                        Some(c)
+                            if matches!(c.status, CampaignStatus::Funding)
+                                && Self::finalization_block(c.config.deadline) == block => {}
+                        _ => return Err("CampaignDeadlines references non-indexable campaign"),
+                    }
+                }
+            }
+            for id in PendingFinalizations::<T>::iter_keys() {
+                match Campaigns::<T>::get(id) {
+                    Some(c) if matches!(c.status, CampaignStatus::Funding) => {}
+                    _ => return Err("PendingFinalizations references non-Funding campaign"),
+                }
+            }
+
+            // Bulk refunds only apply to Failed/Cancelled campaigns.
+            for id in PendingRefunds::<T>::iter_keys().chain(RefundCursor::<T>::iter_keys()) {
+                match Campaigns::<T>::get(id) {
+                    Some(c)
+                        if matches!(
+                            c.status,
+                            CampaignStatus::Failed | CampaignStatus::Cancelled
+                        ) => {}
+                    _ => return Err("Refund queue references non-refundable campaign"),
+                }
+            }
+
+            // Reaping: deposits belong to live campaigns, archives to reaped
+            // ones.
+            for id in ReapDeposits::<T>::iter_keys() {
+                if !Campaigns::<T>::contains_key(id) {
+                    return Err("ReapDeposits references non-existent campaign");
+                }
+            }
+            for id in ArchivedCampaigns::<T>::iter_keys() {
+                if Campaigns::<T>::contains_key(id) {
+                    return Err("ArchivedCampaigns entry for a live campaign");
+                }
+            }
+
             Ok(())
         }
     }
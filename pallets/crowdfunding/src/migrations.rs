@@ -3,6 +3,7 @@ pub mod v3 {
     use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight, BoundedVec};
     use sp_std::vec::Vec;
 
+    use super::v4::{OldCampaign as V3Campaign, OldCampaignConfig};
     use crate::{
         pallet::{self, Config},
         types::*,
@@ -35,7 +36,7 @@ pub mod v3 {
     > {
         pub creator: AccountId,
         pub status: CampaignStatus,
-        pub config: CampaignConfig<Balance, BlockNumber, AssetId, MaxMilestones>,
+        pub config: OldCampaignConfig<Balance, BlockNumber, AssetId, MaxMilestones>,
         pub eligibility_rules: BoundedVec<
             EligibilityRule<AssetId, Balance, CollectionId, ItemId, MaxNftSets, MaxNftsPerSet>,
             MaxEligibilityRules,
@@ -50,6 +51,11 @@ pub mod v3 {
         pub participation_id: Option<u32>,
     }
 
+    /// `Campaigns` as laid out at storage version 3.
+    #[frame_support::storage_alias]
+    type Campaigns<T: Config> =
+        StorageMap<pallet::Pallet<T>, Blake2_128Concat, u32, super::v4::OldCampaignOf<T>>;
+
     type OldCampaignOf<T> = OldCampaign<
         <T as frame_system::Config>::AccountId,
         pallet::BalanceOf<T>,
@@ -80,9 +86,9 @@ pub mod v3 {
             let default_fee_bps = T::ProtocolFeeBps::get();
             let mut count: u64 = 0;
 
-            pallet::Campaigns::<T>::translate::<OldCampaignOf<T>, _>(|_id, old| {
+            Campaigns::<T>::translate::<OldCampaignOf<T>, _>(|_id, old| {
                 count += 1;
-                Some(Campaign {
+                Some(V3Campaign {
                     creator: old.creator,
                     status: old.status,
                     config: old.config,
@@ -112,17 +118,17 @@ pub mod v3 {
 
         #[cfg(feature = "try-runtime")]
         fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
-            let count = pallet::Campaigns::<T>::iter().count() as u32;
+            let count = Campaigns::<T>::iter_keys().count() as u32;
             Ok(count.encode())
         }
 
         #[cfg(feature = "try-runtime")]
         fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
             let old_count = u32::decode(&mut &state[..]).map_err(|_| "decode failed")?;
-            let new_count = pallet::Campaigns::<T>::iter().count() as u32;
+            let new_count = Campaigns::<T>::iter().count() as u32;
             frame_support::ensure!(old_count == new_count, "campaign count mismatch");
             // verify all campaigns now have protocol_fee_bps set
-            for (_id, campaign) in pallet::Campaigns::<T>::iter() {
+            for (_id, campaign) in Campaigns::<T>::iter() {
                 // non-zero check only if the config default is non-zero
                 if T::ProtocolFeeBps::get() > 0 {
                     frame_support::ensure!(
@@ -135,3 +141,516 @@ pub mod v3 {
         }
     }
 }
+
+/// Adds the early-bird bonus schedule to `CampaignConfig` and the weighted
+/// allocation to `Investment`.
+///
+/// Existing campaigns get no bonus schedule and existing positions are
+/// weighted 1:1 with their net investment, summed into each campaign's
+/// `TotalWeightedAllocations`.
+pub mod v4 {
+    use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight, BoundedVec};
+    use sp_runtime::traits::Saturating;
+    use sp_std::vec::Vec;
+
+    use crate::{
+        pallet::{self, Config},
+        types::*,
+    };
+
+    /// Old CampaignConfig struct WITHOUT the `early_bird_bonus` field
+    /// (storage version 3).
+    #[derive(
+        frame_support::CloneNoBound,
+        frame_support::PartialEqNoBound,
+        frame_support::EqNoBound,
+        codec::Encode,
+        codec::Decode,
+        frame_support::RuntimeDebugNoBound,
+        scale_info::TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(MaxMilestones))]
+    pub struct OldCampaignConfig<
+        Balance: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        BlockNumber: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        AssetId: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        MaxMilestones: Get<u32>,
+    > {
+        pub funding_model: FundingModel<Balance, MaxMilestones>,
+        pub funding_currency: PaymentCurrency<AssetId>,
+        pub deadline: BlockNumber,
+        pub hard_cap: Option<Balance>,
+        pub min_investment: Option<Balance>,
+        pub max_investment_per_investor: Option<Balance>,
+        pub metadata_hash: [u8; 32],
+        pub early_withdrawal_penalty_bps: Option<u16>,
+    }
+
+    /// Old Campaign struct holding an [`OldCampaignConfig`] (storage version
+    /// 3).
+    #[derive(
+        frame_support::CloneNoBound,
+        frame_support::PartialEqNoBound,
+        frame_support::EqNoBound,
+        codec::Encode,
+        codec::Decode,
+        frame_support::RuntimeDebugNoBound,
+        scale_info::TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(MaxMilestones, MaxEligibilityRules, MaxNftSets, MaxNftsPerSet))]
+    pub struct OldCampaign<
+        AccountId: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        Balance: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        BlockNumber: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        AssetId: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        CollectionId: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        ItemId: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+        MaxMilestones: Get<u32>,
+        MaxEligibilityRules: Get<u32>,
+        MaxNftSets: Get<u32>,
+        MaxNftsPerSet: Get<u32>,
+    > {
+        pub creator: AccountId,
+        pub status: CampaignStatus,
+        pub config: OldCampaignConfig<Balance, BlockNumber, AssetId, MaxMilestones>,
+        pub eligibility_rules: BoundedVec<
+            EligibilityRule<AssetId, Balance, CollectionId, ItemId, MaxNftSets, MaxNftsPerSet>,
+            MaxEligibilityRules,
+        >,
+        pub total_raised: Balance,
+        pub total_disbursed: Balance,
+        pub investor_count: u32,
+        pub creation_deposit: Balance,
+        pub created_at: BlockNumber,
+        pub paused_at: Option<BlockNumber>,
+        pub rwa_asset_id: Option<u32>,
+        pub participation_id: Option<u32>,
+        pub protocol_fee_bps: u16,
+    }
+
+    pub(crate) type OldCampaignOf<T> = OldCampaign<
+        <T as frame_system::Config>::AccountId,
+        pallet::BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+        <T as Config>::AssetId,
+        <T as Config>::CollectionId,
+        <T as Config>::ItemId,
+        <T as Config>::MaxMilestones,
+        <T as Config>::MaxEligibilityRules,
+        <T as Config>::MaxNftSets,
+        <T as Config>::MaxNftsPerSet,
+    >;
+
+    /// Old Investment struct WITHOUT the `weighted_allocation` field (storage
+    /// version 3).
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct OldInvestment<Balance> {
+        pub total_invested: Balance,
+        pub total_withdrawn: Balance,
+    }
+
+    pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current = pallet::Pallet::<T>::on_chain_storage_version();
+            if current != 3 {
+                frame_support::log::info!(
+                    target: "pallet-crowdfunding",
+                    "MigrateToV4: on-chain version is {:?}, skipping",
+                    current
+                );
+                return Weight::zero();
+            }
+
+            let mut campaigns: u64 = 0;
+            pallet::Campaigns::<T>::translate::<OldCampaignOf<T>, _>(|_id, old| {
+                campaigns += 1;
+                let c = old.config;
+                Some(Campaign {
+                    creator: old.creator,
+                    status: old.status,
+                    config: CampaignConfig {
+                        funding_model: c.funding_model,
+                        funding_currency: c.funding_currency,
+                        deadline: c.deadline,
+                        hard_cap: c.hard_cap,
+                        min_investment: c.min_investment,
+                        max_investment_per_investor: c.max_investment_per_investor,
+                        metadata_hash: c.metadata_hash,
+                        early_withdrawal_penalty_bps: c.early_withdrawal_penalty_bps,
+                        early_bird_bonus: None,
+                    },
+                    eligibility_rules: old.eligibility_rules,
+                    total_raised: old.total_raised,
+                    total_disbursed: old.total_disbursed,
+                    investor_count: old.investor_count,
+                    creation_deposit: old.creation_deposit,
+                    created_at: old.created_at,
+                    paused_at: old.paused_at,
+                    rwa_asset_id: old.rwa_asset_id,
+                    participation_id: old.participation_id,
+                    protocol_fee_bps: old.protocol_fee_bps,
+                })
+            });
+
+            let mut investments: u64 = 0;
+            pallet::Investments::<T>::translate::<OldInvestment<pallet::BalanceOf<T>>, _>(
+                |id, _who, old| {
+                    investments += 1;
+                    let weighted = old.total_invested.saturating_sub(old.total_withdrawn);
+                    pallet::TotalWeightedAllocations::<T>::mutate(id, |total| {
+                        *total = total.saturating_add(weighted)
+                    });
+                    Some(Investment {
+                        weighted_allocation: weighted,
+                        total_invested: old.total_invested,
+                        total_withdrawn: old.total_withdrawn,
+                    })
+                },
+            );
+
+            StorageVersion::new(4).put::<pallet::Pallet<T>>();
+
+            frame_support::log::info!(
+                target: "pallet-crowdfunding",
+                "MigrateToV4: migrated {} campaigns and {} investments",
+                campaigns, investments,
+            );
+
+            // Each investment also updates its campaign's weighted total.
+            let count = campaigns.saturating_add(investments.saturating_mul(2));
+            T::DbWeight::get().reads_writes(count + 1, count + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let campaigns = pallet::Campaigns::<T>::iter_keys().count() as u32;
+            let investments = pallet::Investments::<T>::iter_keys().count() as u32;
+            Ok((campaigns, investments).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let (old_campaigns, old_investments) =
+                <(u32, u32)>::decode(&mut &state[..]).map_err(|_| "decode failed")?;
+            let campaigns = pallet::Campaigns::<T>::iter().count() as u32;
+            frame_support::ensure!(old_campaigns == campaigns, "campaign count mismatch");
+            let mut investments = 0u32;
+            for (_id, _who, inv) in pallet::Investments::<T>::iter() {
+                investments += 1;
+                frame_support::ensure!(
+                    inv.weighted_allocation
+                        == inv.total_invested.saturating_sub(inv.total_withdrawn),
+                    "weighted_allocation should equal the net position after migration"
+                );
+            }
+            frame_support::ensure!(old_investments == investments, "investment count mismatch");
+            Ok(())
+        }
+    }
+}
+
+/// Populates the deadline index used for automatic finalization.
+///
+/// Funding campaigns whose deadline has already passed are queued in
+/// `PendingFinalizations`; the rest are indexed in `CampaignDeadlines`.
+/// Campaigns that do not fit their index slot are left for manual
+/// `finalize_campaign`.
+pub mod v5 {
+    use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+    use sp_runtime::traits::{One, Saturating};
+    use sp_std::vec::Vec;
+
+    use crate::{
+        pallet::{self, Config},
+        types::*,
+    };
+
+    pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current = pallet::Pallet::<T>::on_chain_storage_version();
+            if current != 4 {
+                frame_support::log::info!(
+                    target: "pallet-crowdfunding",
+                    "MigrateToV5: on-chain version is {:?}, skipping",
+                    current
+                );
+                return Weight::zero();
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+            let mut skipped: u32 = 0;
+
+            for (id, campaign) in pallet::Campaigns::<T>::iter() {
+                reads += 1;
+                if !matches!(campaign.status, CampaignStatus::Funding) {
+                    continue;
+                }
+                writes += 1;
+                if now > campaign.config.deadline {
+                    pallet::PendingFinalizations::<T>::insert(id, ());
+                    continue;
+                }
+                let block = campaign.config.deadline.saturating_add(One::one());
+                reads += 1;
+                let pushed =
+                    pallet::CampaignDeadlines::<T>::mutate(block, |ids| ids.try_push(id).is_ok());
+                if !pushed {
+                    skipped += 1;
+                    frame_support::log::warn!(
+                        target: "pallet-crowdfunding",
+                        "MigrateToV5: deadline slot {:?} is full, campaign {} is left for \
+                         manual finalization",
+                        block, id,
+                    );
+                }
+            }
+
+            StorageVersion::new(5).put::<pallet::Pallet<T>>();
+
+            frame_support::log::info!(
+                target: "pallet-crowdfunding",
+                "MigrateToV5: indexed {} funding campaigns, {} left for manual finalization",
+                writes.saturating_sub(skipped as u64), skipped,
+            );
+
+            T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let funding = pallet::Campaigns::<T>::iter()
+                .filter(|(_, c)| matches!(c.status, CampaignStatus::Funding))
+                .count() as u32;
+            Ok(funding.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let funding = u32::decode(&mut &state[..]).map_err(|_| "decode failed")?;
+            let indexed = pallet::CampaignDeadlines::<T>::iter_values()
+                .map(|ids| ids.len() as u32)
+                .sum::<u32>()
+                .saturating_add(pallet::PendingFinalizations::<T>::iter_keys().count() as u32);
+            frame_support::ensure!(indexed <= funding, "more indexed campaigns than funding");
+            Ok(())
+        }
+    }
+}
+
+/// Populates `LicensedCampaigns` with the non-terminal campaigns created
+/// under an RWA license.
+///
+/// Runs as a [`stepped`] migration over `Campaigns`: configure
+/// `MigrateToV6` as `T::SteppedMigration` and list `StartSteppedMigration`
+/// among the runtime's migrations.
+pub mod v6 {
+    use frame_support::{pallet_prelude::*, weights::Weight};
+    use sp_std::vec::Vec;
+
+    use super::stepped::{StepOutcome, SteppedMigration};
+    use crate::{
+        pallet::{self, Config},
+        types::*,
+    };
+
+    pub struct MigrateToV6<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for MigrateToV6<T> {
+        const TARGET_VERSION: u16 = 6;
+
+        fn step(cursor: Option<MigrationKey>, limit: u32) -> StepOutcome {
+            let mut iter = match cursor {
+                Some(key) => pallet::Campaigns::<T>::iter_from(key.into_inner()),
+                None => pallet::Campaigns::<T>::iter(),
+            };
+            let mut migrated: u32 = 0;
+            let mut indexed: u64 = 0;
+            let mut done = false;
+            while migrated < limit {
+                let (id, campaign) = match iter.next() {
+                    Some(entry) => entry,
+                    None => {
+                        done = true;
+                        break;
+                    }
+                };
+                migrated += 1;
+                if matches!(
+                    campaign.status,
+                    CampaignStatus::Failed | CampaignStatus::Cancelled | CampaignStatus::Completed
+                ) {
+                    continue;
+                }
+                if let (Some(asset_id), Some(part_id)) =
+                    (campaign.rwa_asset_id, campaign.participation_id)
+                {
+                    pallet::LicensedCampaigns::<T>::insert((asset_id, part_id, id), ());
+                    indexed += 1;
+                }
+            }
+
+            // Campaign keys are 52 bytes, well within the cursor bound.
+            let next =
+                if done { None } else { BoundedVec::try_from(iter.last_raw_key().to_vec()).ok() };
+            // The extra read is the one that finds the map exhausted.
+            let weight = T::DbWeight::get().reads_writes(migrated as u64 + 1, indexed);
+            StepOutcome { next, migrated, weight }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let licensed = pallet::Campaigns::<T>::iter()
+                .filter(|(_, c)| {
+                    c.rwa_asset_id.is_some()
+                        && c.participation_id.is_some()
+                        && !matches!(
+                            c.status,
+                            CampaignStatus::Failed
+                                | CampaignStatus::Cancelled
+                                | CampaignStatus::Completed
+                        )
+                })
+                .count() as u32;
+            Ok(licensed.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let licensed = u32::decode(&mut &state[..]).map_err(|_| "decode failed")?;
+            let indexed = pallet::LicensedCampaigns::<T>::iter_keys().count() as u32;
+            frame_support::ensure!(indexed == licensed, "licensed campaigns not all indexed");
+            Ok(())
+        }
+    }
+}
+
+/// Cursor-based migrations that run over several blocks.
+///
+/// [`StartSteppedMigration`] records an [`ActiveMigration`] cursor on upgrade
+/// when `T::SteppedMigration` targets a version above the on-chain one.  Each
+/// `on_initialize` then calls [`SteppedMigration::step`] until it reports no
+/// further key, at which point the target version is stamped.  While the
+/// cursor exists the runtime call filter rejects the pallet's extrinsics and
+/// campaigns reaching their deadline wait in `PendingFinalizations`.
+///
+/// A step over a map typically resumes with `iter_from(last_key)`, migrates up
+/// to `limit` entries and returns `iter.last_raw_key()` as the next cursor.
+///
+/// [`ActiveMigration`]: crate::pallet::ActiveMigration
+pub mod stepped {
+    use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+    use sp_std::vec::Vec;
+
+    use crate::{
+        pallet::{self, ActiveMigration, Config},
+        types::*,
+    };
+
+    /// Result of a single [`SteppedMigration::step`].
+    pub struct StepOutcome {
+        /// Key to resume after, or `None` once every item is migrated.
+        pub next: Option<MigrationKey>,
+        /// Items migrated in this step.
+        pub migrated: u32,
+        pub weight: Weight,
+    }
+
+    /// A migration processed in bounded steps across blocks.
+    pub trait SteppedMigration {
+        /// Storage version stamped once the migration finishes.
+        const TARGET_VERSION: u16;
+
+        /// Migrate at most `limit` items following `cursor`, or from the
+        /// first item when `cursor` is `None`.
+        fn step(cursor: Option<MigrationKey>, limit: u32) -> StepOutcome;
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> { Ok(Vec::new()) }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> { Ok(()) }
+    }
+
+    /// No stepped migration pending.
+    impl SteppedMigration for () {
+        const TARGET_VERSION: u16 = 0;
+
+        fn step(_cursor: Option<MigrationKey>, _limit: u32) -> StepOutcome {
+            StepOutcome { next: None, migrated: 0, weight: Weight::zero() }
+        }
+    }
+
+    /// Starts `T::SteppedMigration` if the chain is below its target version.
+    pub struct StartSteppedMigration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> StartSteppedMigration<T> {
+        fn should_start() -> bool {
+            pallet::Pallet::<T>::on_chain_storage_version() < T::SteppedMigration::TARGET_VERSION
+                && !ActiveMigration::<T>::exists()
+        }
+    }
+
+    impl<T: Config> OnRuntimeUpgrade for StartSteppedMigration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !Self::should_start() {
+                return T::DbWeight::get().reads(2);
+            }
+            let target_version = T::SteppedMigration::TARGET_VERSION;
+            ActiveMigration::<T>::put(MigrationCursor {
+                target_version,
+                last_key: None,
+                migrated: 0,
+                started_at: frame_system::Pallet::<T>::block_number(),
+            });
+            pallet::Pallet::<T>::deposit_event(pallet::Event::MigrationStarted { target_version });
+
+            frame_support::log::info!(
+                target: "pallet-crowdfunding",
+                "StartSteppedMigration: migrating to V{} over {} items per block",
+                target_version,
+                T::MigrationItemsPerBlock::get(),
+            );
+
+            T::DbWeight::get().reads_writes(3, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let start = Self::should_start();
+            let state = if start { T::SteppedMigration::pre_upgrade()? } else { Vec::new() };
+            Ok((start, state).encode())
+        }
+
+        /// Drives the started migration to completion so its own
+        /// `post_upgrade` checks the fully migrated state.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let (started, inner) =
+                <(bool, Vec<u8>)>::decode(&mut &state[..]).map_err(|_| "decode failed")?;
+            if !started {
+                return Ok(());
+            }
+            frame_support::ensure!(
+                ActiveMigration::<T>::exists(),
+                "pallet-crowdfunding: stepped migration should be active after upgrade"
+            );
+            while let Some(cursor) = ActiveMigration::<T>::get() {
+                pallet::Pallet::<T>::step_migration(cursor);
+            }
+            frame_support::ensure!(
+                pallet::Pallet::<T>::on_chain_storage_version()
+                    == T::SteppedMigration::TARGET_VERSION,
+                "pallet-crowdfunding: on-chain version should match the stepped migration target"
+            );
+            T::SteppedMigration::post_upgrade(inner)
+        }
+    }
+}
@@ -3,12 +3,13 @@ use std::{cell::RefCell, collections::BTreeMap};
 use frame_support::{
     parameter_types,
     traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64},
+    weights::Weight,
     BoundedVec, PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::H256;
 use sp_runtime::{
-    testing::Header,
+    testing::{Header, TestSignature, TestXt, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
 };
 
@@ -98,6 +99,7 @@ pub struct MockNftInspect;
 
 thread_local! {
     pub static NFT_OWNERS: RefCell<BTreeMap<(u32, u32), u64>> = RefCell::new(BTreeMap::new());
+    pub static COLLECTION_OWNERS: RefCell<BTreeMap<u32, u64>> = RefCell::new(BTreeMap::new());
 }
 
 impl MockNftInspect {
@@ -105,7 +107,14 @@ impl MockNftInspect {
         NFT_OWNERS.with(|m| m.borrow_mut().insert((collection, item), owner));
     }
 
-    pub fn clear() { NFT_OWNERS.with(|m| m.borrow_mut().clear()); }
+    pub fn set_collection_owner(collection: u32, owner: u64) {
+        COLLECTION_OWNERS.with(|m| m.borrow_mut().insert(collection, owner));
+    }
+
+    pub fn clear() {
+        NFT_OWNERS.with(|m| m.borrow_mut().clear());
+        COLLECTION_OWNERS.with(|m| m.borrow_mut().clear());
+    }
 }
 
 impl frame_support::traits::tokens::nonfungibles_v2::Inspect<u64> for MockNftInspect {
@@ -115,6 +124,160 @@ impl frame_support::traits::tokens::nonfungibles_v2::Inspect<u64> for MockNftIns
     fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<u64> {
         NFT_OWNERS.with(|m| m.borrow().get(&(*collection, *item)).copied())
     }
+
+    fn collection_owner(collection: &Self::CollectionId) -> Option<u64> {
+        COLLECTION_OWNERS.with(|m| m.borrow().get(collection).copied())
+    }
+}
+
+// ── MockEligibilityProvider ─────────────────────────────────────────────
+
+pub struct MockEligibilityProvider;
+
+thread_local! {
+    pub static ATTESTATIONS: RefCell<BTreeMap<(u64, [u8; 32]), ()>> = RefCell::new(BTreeMap::new());
+    pub static ACCOUNT_CREATED_AT: RefCell<BTreeMap<u64, u64>> = RefCell::new(BTreeMap::new());
+}
+
+impl MockEligibilityProvider {
+    pub fn attest(who: u64, claim: [u8; 32]) {
+        ATTESTATIONS.with(|m| m.borrow_mut().insert((who, claim), ()));
+    }
+
+    pub fn set_created_at(who: u64, block: u64) {
+        ACCOUNT_CREATED_AT.with(|m| m.borrow_mut().insert(who, block));
+    }
+}
+
+impl crate::EligibilityProvider<u64, u64> for MockEligibilityProvider {
+    fn has_attestation(who: &u64, claim: &[u8; 32]) -> bool {
+        ATTESTATIONS.with(|m| m.borrow().contains_key(&(*who, *claim)))
+    }
+
+    fn account_created_at(who: &u64) -> Option<u64> {
+        ACCOUNT_CREATED_AT.with(|m| m.borrow().get(who).copied())
+    }
+}
+
+// ── MockPriceFeed ───────────────────────────────────────────────────────
+
+/// Fixed conversion rates, keyed by (from, to) with `None` for the native
+/// currency.
+pub struct MockPriceFeed;
+
+thread_local! {
+    pub static PRICES: RefCell<BTreeMap<(Option<u32>, Option<u32>), (u128, u128)>> =
+        RefCell::new(BTreeMap::new());
+}
+
+impl MockPriceFeed {
+    /// One unit of `from` is worth `numerator / denominator` units of `to`.
+    pub fn set_price(
+        from: crate::PaymentCurrency<u32>,
+        to: crate::PaymentCurrency<u32>,
+        numerator: u128,
+        denominator: u128,
+    ) {
+        PRICES.with(|m| {
+            m.borrow_mut().insert((Self::key(&from), Self::key(&to)), (numerator, denominator))
+        });
+    }
+
+    fn key(currency: &crate::PaymentCurrency<u32>) -> Option<u32> {
+        match currency {
+            crate::PaymentCurrency::Native => None,
+            crate::PaymentCurrency::Asset(id) => Some(*id),
+        }
+    }
+}
+
+impl crate::PriceFeed<u32, u128> for MockPriceFeed {
+    fn convert(
+        from: &crate::PaymentCurrency<u32>,
+        to: &crate::PaymentCurrency<u32>,
+        amount: u128,
+    ) -> Option<u128> {
+        let (numerator, denominator) =
+            PRICES.with(|m| m.borrow().get(&(Self::key(from), Self::key(to))).copied())?;
+        amount.checked_mul(numerator)?.checked_div(denominator)
+    }
+}
+
+// ── MockValuationProvider ───────────────────────────────────────────────
+
+/// Fixed RWA asset valuations, keyed by RWA asset ID.
+pub struct MockValuationProvider;
+
+thread_local! {
+    pub static VALUATIONS: RefCell<BTreeMap<u32, (crate::PaymentCurrency<u32>, u128)>> =
+        RefCell::new(BTreeMap::new());
+}
+
+impl MockValuationProvider {
+    pub fn set_valuation(rwa_asset_id: u32, currency: crate::PaymentCurrency<u32>, amount: u128) {
+        VALUATIONS.with(|m| m.borrow_mut().insert(rwa_asset_id, (currency, amount)));
+    }
+}
+
+impl crate::ValuationProvider<u32, u128> for MockValuationProvider {
+    fn asset_valuation(rwa_asset_id: u32) -> Option<(crate::PaymentCurrency<u32>, u128)> {
+        VALUATIONS.with(|m| m.borrow().get(&rwa_asset_id).cloned())
+    }
+}
+
+// ── MockRewardMinter ────────────────────────────────────────────────────
+
+/// Mints reward NFTs into `MockNftInspect`, using the serial as item ID.
+pub struct MockRewardMinter;
+
+impl crate::RewardMinter<u64, u32> for MockRewardMinter {
+    fn mint_reward(
+        collection: &u32,
+        serial: u32,
+        who: &u64,
+    ) -> frame_support::dispatch::DispatchResult {
+        frame_support::ensure!(
+            NFT_OWNERS.with(|m| !m.borrow().contains_key(&(*collection, serial))),
+            sp_runtime::DispatchError::Other("ItemAlreadyExists")
+        );
+        MockNftInspect::set_owner(*collection, serial, *who);
+        Ok(())
+    }
+
+    fn is_minted(collection: &u32, serial: u32) -> bool {
+        NFT_OWNERS.with(|m| m.borrow().contains_key(&(*collection, serial)))
+    }
+}
+
+// ── MockBenchmarkHelper ─────────────────────────────────────────────────
+
+/// Sets up the mock NFT collection, price feed and valuations for
+/// benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64, u32, u32, u128> for MockBenchmarkHelper {
+    fn reward_collection(owner: &u64) -> u32 {
+        MockNftInspect::set_collection_owner(7, *owner);
+        7
+    }
+
+    fn payment_asset() -> u32 {
+        Assets::force_create(RuntimeOrigin::root(), codec::Compact(1), ALICE, true, 1)
+            .expect("asset 1 is free");
+        MockPriceFeed::set_price(
+            crate::PaymentCurrency::Asset(1),
+            crate::PaymentCurrency::Native,
+            1,
+            1,
+        );
+        1
+    }
+
+    fn set_valuation(rwa_asset_id: u32, currency: crate::PaymentCurrency<u32>, amount: u128) {
+        MockValuationProvider::set_valuation(rwa_asset_id, currency, amount);
+    }
 }
 
 // ── MockLicenseVerifier ──────────────────────────────────────────────────
@@ -216,6 +379,67 @@ impl crate::LicenseVerifier<u64, u64> for MockLicenseVerifier {
     fn license_expiry(rwa_asset_id: u32, participation_id: u32) -> Option<u64> {
         LICENSE_EXPIRY.with(|m| m.borrow().get(&(rwa_asset_id, participation_id)).copied())
     }
+
+    fn has_active_participation(rwa_asset_id: u32, who: &u64) -> bool {
+        Self::is_asset_active(rwa_asset_id)
+            && LICENSE_STATE.with(|m| {
+                m.borrow().iter().any(|((asset, _), (authorized, active))| {
+                    *asset == rwa_asset_id && authorized == who && *active
+                })
+            })
+    }
+}
+
+// ── TestMigration ───────────────────────────────────────────────────────
+//
+// Stepped migration over `Campaigns` that rewrites each entry unchanged and
+// records the visited ids, so tests can check every campaign is migrated once.
+
+pub struct TestMigration;
+
+thread_local! {
+    static MIGRATED: RefCell<Vec<u32>> = RefCell::new(Vec::new());
+}
+
+impl TestMigration {
+    /// Campaign ids migrated so far, in visiting order.
+    pub fn migrated() -> Vec<u32> { MIGRATED.with(|m| m.borrow().clone()) }
+}
+
+impl crate::SteppedMigration for TestMigration {
+    const TARGET_VERSION: u16 = 7;
+
+    fn step(
+        cursor: Option<crate::MigrationKey>,
+        limit: u32,
+    ) -> crate::migrations::stepped::StepOutcome {
+        let mut iter = match cursor {
+            Some(key) => crate::pallet::Campaigns::<Test>::iter_from(key.into_inner()),
+            None => crate::pallet::Campaigns::<Test>::iter(),
+        };
+        let mut migrated = 0;
+        while migrated < limit {
+            let (id, campaign) = match iter.next() {
+                Some(entry) => entry,
+                None => {
+                    return crate::migrations::stepped::StepOutcome {
+                        next: None,
+                        migrated,
+                        weight: Weight::zero(),
+                    }
+                }
+            };
+            crate::pallet::Campaigns::<Test>::insert(id, campaign);
+            MIGRATED.with(|m| m.borrow_mut().push(id));
+            migrated += 1;
+        }
+        let next = BoundedVec::try_from(iter.last_raw_key().to_vec()).expect("key fits cursor");
+        crate::migrations::stepped::StepOutcome {
+            next: Some(next),
+            migrated,
+            weight: Weight::zero(),
+        }
+    }
 }
 
 // ── Crowdfunding config ─────────────────────────────────────────────────
@@ -223,38 +447,93 @@ impl crate::LicenseVerifier<u64, u64> for MockLicenseVerifier {
 parameter_types! {
     pub const CrowdfundingPalletId: PalletId = PalletId(*b"py/crwdf");
     pub const ProtocolFeeAccount: u64 = 99;
+    pub static MaxDeadlinesPerBlock: u32 = 20;
+    pub static ReapDeposit: u128 = 0;
 }
 
 impl pallet_crowdfunding::Config for Test {
     type AdminOrigin = EnsureRoot<u64>;
     type AssetId = u32;
+    type AuthorityId = TestAuthId;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockBenchmarkHelper;
     type CampaignCreationDeposit = ConstU128<100>;
     type CollectionId = u32;
     type EarlyWithdrawalPenaltyBps = ConstU16<100>;
     // 1%
+    type EligibilityProvider = MockEligibilityProvider;
     type ForceOrigin = EnsureRoot<u64>;
     type Fungibles = Assets;
     type ItemId = u32;
     type LicenseVerifier = MockLicenseVerifier;
+    type MaxAcceptedCurrencies = ConstU32<2>;
+    type MaxAutoFinalizationsPerBlock = ConstU32<2>;
     type MaxCampaignDuration = ConstU64<1000>;
     type MaxCampaignsPerCreator = ConstU32<5>;
+    type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+    type MaxEligibilityNodes = ConstU32<7>;
     type MaxEligibilityRules = ConstU32<3>;
+    type MaxFinalizationsPerTx = ConstU32<3>;
+    type MaxFundingRounds = ConstU32<3>;
+    type MaxIdleCleanups = ConstU32<2>;
+    type MaxIdleRefunds = ConstU32<2>;
     type MaxInvestmentsPerInvestor = ConstU32<5>;
     type MaxMilestones = ConstU32<5>;
     type MaxNftSets = ConstU32<3>;
     type MaxNftsPerSet = ConstU32<3>;
+    type MaxPenaltySteps = ConstU32<3>;
+    type MaxReapsPerCall = ConstU32<10>;
+    type MaxRefundsPerCall = ConstU32<10>;
+    type MaxRegistrations = ConstU32<3>;
+    type MaxRewardTiers = ConstU32<3>;
+    type MaxSnapshotAssets = ConstU32<2>;
+    type MaxStretchGoals = ConstU32<3>;
+    type MaxWhitelistProofDepth = ConstU32<8>;
     type MaxWhitelistSize = ConstU32<100>;
+    type MigrationItemsPerBlock = ConstU32<2>;
     type MilestoneApprover = EnsureRoot<u64>;
     type MinCampaignDuration = ConstU64<10>;
     type NativeCurrency = Balances;
     type NftInspect = MockNftInspect;
     type PalletId = CrowdfundingPalletId;
+    type PauseOrigin = EnsureRoot<u64>;
+    type PriceFeed = MockPriceFeed;
     type ProtocolFeeBps = ConstU16<0>;
     type ProtocolFeeRecipient = ProtocolFeeAccount;
+    type ReapDeposit = ReapDeposit;
+    type RewardMinter = MockRewardMinter;
     type RuntimeEvent = RuntimeEvent;
+    type SponsorshipPeriod = ConstU64<10>;
+    type SteppedMigration = TestMigration;
+    type UnsignedInterval = ConstU64<5>;
+    type UnsignedPriority = ConstU64<100>;
+    type ValuationProvider = MockValuationProvider;
     type WeightInfo = ();
 }
 
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = TestXt<RuntimeCall, ()>;
+    type OverarchingCall = RuntimeCall;
+}
+
+/// Signs finalization payloads with the test keys set by
+/// `UintAuthorityId::set_all_keys`.
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type RuntimeAppPublic = UintAuthorityId;
+}
+
 pub const ALICE: u64 = 1;
 pub const BOB: u64 = 2;
 pub const CHARLIE: u64 = 3;
@@ -279,6 +558,7 @@ impl ExtBuilder {
     pub fn build(self) -> sp_io::TestExternalities {
         MockNftInspect::clear();
         MockLicenseVerifier::clear();
+        MIGRATED.with(|m| m.borrow_mut().clear());
         let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
         pallet_balances::GenesisConfig::<Test> { balances: self.balances }
             .assimilate_storage(&mut t)
@@ -307,6 +587,7 @@ pub fn default_aon_config(deadline: u64, goal: u128) -> CampaignConfigOf {
         max_investment_per_investor: None,
         metadata_hash: [0u8; 32],
         early_withdrawal_penalty_bps: None,
+        early_bird_bonus: None,
     }
 }
 
@@ -320,6 +601,7 @@ pub fn default_kwyr_config(deadline: u64) -> CampaignConfigOf {
         max_investment_per_investor: None,
         metadata_hash: [0u8; 32],
         early_withdrawal_penalty_bps: None,
+        early_bird_bonus: None,
     }
 }
 
@@ -333,6 +615,7 @@ pub fn kwyr_config_with_soft_cap(deadline: u64, soft_cap: u128) -> CampaignConfi
         max_investment_per_investor: None,
         metadata_hash: [0u8; 32],
         early_withdrawal_penalty_bps: None,
+        early_bird_bonus: None,
     }
 }
 
@@ -353,6 +636,7 @@ pub fn milestone_config(
         max_investment_per_investor: None,
         metadata_hash: [0u8; 32],
         early_withdrawal_penalty_bps: None,
+        early_bird_bonus: None,
     }
 }
 
@@ -4487,6 +4487,7 @@ mod asset_currency {
             max_investment_per_investor: None,
             metadata_hash: [0u8; 32],
             early_withdrawal_penalty_bps: None,
+            early_bird_bonus: None,
         }
     }
 
@@ -4500,6 +4501,7 @@ mod asset_currency {
             max_investment_per_investor: None,
             metadata_hash: [0u8; 32],
             early_withdrawal_penalty_bps: None,
+            early_bird_bonus: None,
         }
     }
 
@@ -4521,6 +4523,7 @@ mod asset_currency {
             max_investment_per_investor: None,
             metadata_hash: [0u8; 32],
             early_withdrawal_penalty_bps: None,
+            early_bird_bonus: None,
         }
     }
 
@@ -5088,8 +5091,8 @@ mod storage_version_check {
             let current = Crowdfunding::current_storage_version();
             // on_chain is 0 in fresh mock (no genesis migration)
             assert_eq!(on_chain, frame_support::traits::StorageVersion::new(0));
-            // current (compile-time declared) is 3
-            assert_eq!(current, frame_support::traits::StorageVersion::new(3));
+            // current (compile-time declared) is 6
+            assert_eq!(current, frame_support::traits::StorageVersion::new(6));
         });
     }
 }
@@ -8432,6 +8435,7 @@ mod atk_campaign_creation {
                 max_investment_per_investor: None,
                 metadata_hash: [0u8; 32],
                 early_withdrawal_penalty_bps: None,
+                early_bird_bonus: None,
             };
             // Creation should succeed (currency not validated at creation)
             let id = create_funded_campaign(ALICE, config);
@@ -8453,6 +8457,7 @@ mod atk_campaign_creation {
                 max_investment_per_investor: None,
                 metadata_hash: [0u8; 32],
                 early_withdrawal_penalty_bps: None,
+                early_bird_bonus: None,
             };
             let id = create_funded_campaign(ALICE, config);
             // Investing should fail at asset transfer level
@@ -10289,6 +10294,7 @@ mod cross_pallet_adversarial {
             max_investment_per_investor: None,
             metadata_hash: [0u8; 32],
             early_withdrawal_penalty_bps: None,
+            early_bird_bonus: None,
         }
     }
 
@@ -10302,6 +10308,7 @@ mod cross_pallet_adversarial {
             max_investment_per_investor: None,
             metadata_hash: [0u8; 32],
             early_withdrawal_penalty_bps: None,
+            early_bird_bonus: None,
         }
     }
 
@@ -12481,6 +12488,7 @@ mod adversarial_persona_crowdfunding {
                     max_investment_per_investor: None,
                     metadata_hash: [0u8; 32],
                     early_withdrawal_penalty_bps: None,
+                    early_bird_bonus: None,
                 };
                 let id = create_funded_campaign(ALICE, config);
 
@@ -13851,6 +13859,7 @@ mod adversarial_persona_crowdfunding {
                         max_investment_per_investor: None,
                         metadata_hash: [0u8; 32],
                         early_withdrawal_penalty_bps: None,
+                        early_bird_bonus: None,
                     };
                     let id = create_funded_campaign(ALICE, config);
 
@@ -13894,6 +13903,7 @@ mod adversarial_persona_crowdfunding {
                         max_investment_per_investor: None,
                         metadata_hash: [0u8; 32],
                         early_withdrawal_penalty_bps: None,
+                        early_bird_bonus: None,
                     };
                     let id = create_funded_campaign(ALICE, config);
                     assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1000));
@@ -15770,3 +15780,4131 @@ mod force_finalize_campaign {
         });
     }
 }
+
+// ── reward_tiers ────────────────────────────────────────────────────────
+
+mod reward_tiers {
+    use super::*;
+
+    fn tier(min_amount: u128, quantity: u32, nft_collection: Option<u32>) -> RewardTierOf<Test> {
+        RewardTier {
+            min_amount,
+            quantity,
+            remaining: 0,
+            description_hash: [7u8; 32],
+            nft_collection,
+        }
+    }
+
+    fn set_tiers(id: u32, tiers: Vec<RewardTierOf<Test>>) {
+        assert_ok!(Crowdfunding::set_reward_tiers(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            BoundedVec::try_from(tiers).unwrap(),
+        ));
+    }
+
+    #[test]
+    fn set_reward_tiers_resets_remaining() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_tiers(id, vec![tier(100, 2, None), tier(500, 1, None)]);
+            let tiers = pallet::RewardTiers::<Test>::get(id).unwrap();
+            assert_eq!(tiers[0].remaining, 2);
+            assert_eq!(tiers[1].remaining, 1);
+        });
+    }
+
+    #[test]
+    fn set_reward_tiers_rejects_non_creator() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::set_reward_tiers(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    BoundedVec::try_from(vec![tier(100, 2, None)]).unwrap(),
+                ),
+                Error::<Test>::NotCampaignCreator
+            );
+        });
+    }
+
+    #[test]
+    fn set_reward_tiers_locked_after_investment() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_noop!(
+                Crowdfunding::set_reward_tiers(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    BoundedVec::try_from(vec![tier(100, 2, None)]).unwrap(),
+                ),
+                Error::<Test>::RewardTiersLocked
+            );
+        });
+    }
+
+    #[test]
+    fn set_reward_tiers_rejects_zero_quantity() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::set_reward_tiers(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    BoundedVec::try_from(vec![tier(100, 0, None)]).unwrap(),
+                ),
+                Error::<Test>::InvalidRewardTier
+            );
+        });
+    }
+
+    #[test]
+    fn set_reward_tiers_rejects_foreign_collection() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockNftInspect::set_collection_owner(7, BOB);
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::set_reward_tiers(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    BoundedVec::try_from(vec![tier(100, 2, Some(7))]).unwrap(),
+                ),
+                Error::<Test>::InvalidRewardTier
+            );
+        });
+    }
+
+    #[test]
+    fn invest_with_tier_allocates() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_tiers(id, vec![tier(100, 2, None)]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 150, 0));
+            assert_eq!(pallet::RewardTiers::<Test>::get(id).unwrap()[0].remaining, 1);
+            let alloc = pallet::InvestorTiers::<Test>::get(id, BOB).unwrap();
+            assert_eq!(alloc.tier_index, 0);
+            assert!(!alloc.reward_minted);
+        });
+    }
+
+    #[test]
+    fn invest_with_tier_rejects_below_minimum() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_tiers(id, vec![tier(100, 2, None)]);
+            assert_noop!(
+                Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 50, 0),
+                Error::<Test>::BelowTierMinimum
+            );
+        });
+    }
+
+    #[test]
+    fn invest_with_tier_counts_existing_position() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_tiers(id, vec![tier(100, 2, None)]);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 60));
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 40, 0));
+            assert!(pallet::InvestorTiers::<Test>::get(id, BOB).is_some());
+        });
+    }
+
+    #[test]
+    fn invest_with_tier_rejects_sold_out() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_tiers(id, vec![tier(100, 1, None)]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 100, 0));
+            assert_noop!(
+                Crowdfunding::invest_with_tier(RuntimeOrigin::signed(CHARLIE), id, 100, 0),
+                Error::<Test>::RewardTierSoldOut
+            );
+            // The holder can still top up within the same tier
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 100, 0));
+        });
+    }
+
+    #[test]
+    fn invest_with_tier_rejects_unknown_tier() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 100, 0),
+                Error::<Test>::RewardTierNotFound
+            );
+            set_tiers(id, vec![tier(100, 1, None)]);
+            assert_noop!(
+                Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 100, 1),
+                Error::<Test>::RewardTierNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn switching_tier_releases_previous() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_tiers(id, vec![tier(100, 2, None), tier(300, 2, None)]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 100, 0));
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 200, 1));
+            let tiers = pallet::RewardTiers::<Test>::get(id).unwrap();
+            assert_eq!(tiers[0].remaining, 2);
+            assert_eq!(tiers[1].remaining, 1);
+            assert_eq!(pallet::InvestorTiers::<Test>::get(id, BOB).unwrap().tier_index, 1);
+        });
+    }
+
+    #[test]
+    fn partial_withdrawal_above_minimum_keeps_tier() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_tiers(id, vec![tier(100, 2, None)]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 300, 0));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 100));
+            assert!(pallet::InvestorTiers::<Test>::get(id, BOB).is_some());
+            assert_eq!(pallet::RewardTiers::<Test>::get(id).unwrap()[0].remaining, 1);
+        });
+    }
+
+    #[test]
+    fn withdrawal_below_minimum_releases_tier() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_tiers(id, vec![tier(100, 2, None)]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 150, 0));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 100));
+            assert!(pallet::InvestorTiers::<Test>::get(id, BOB).is_none());
+            assert_eq!(pallet::RewardTiers::<Test>::get(id).unwrap()[0].remaining, 2);
+        });
+    }
+
+    #[test]
+    fn refund_restores_supply() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 5000));
+            set_tiers(id, vec![tier(100, 2, None)]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 100, 0));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert!(pallet::InvestorTiers::<Test>::get(id, BOB).is_none());
+            assert_eq!(pallet::RewardTiers::<Test>::get(id).unwrap()[0].remaining, 2);
+        });
+    }
+
+    #[test]
+    fn claim_reward_nft_mints_to_investor() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockNftInspect::set_collection_owner(7, ALICE);
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 200));
+            set_tiers(id, vec![tier(100, 2, Some(7))]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 200, 0));
+            assert_noop!(
+                Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::InvalidCampaignStatus
+            );
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+
+            assert_ok!(Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(NFT_OWNERS.with(|m| m.borrow().get(&(7, 0)).copied()), Some(BOB));
+            assert_eq!(pallet::NextRewardSerial::<Test>::get(7), 1);
+            assert!(pallet::InvestorTiers::<Test>::get(id, BOB).unwrap().reward_minted);
+            assert_noop!(
+                Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::AlreadyClaimed
+            );
+        });
+    }
+
+    #[test]
+    fn claim_reward_nft_skips_serial_premined_by_creator() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockNftInspect::set_collection_owner(7, ALICE);
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 200));
+            set_tiers(id, vec![tier(100, 2, Some(7))]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 200, 0));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+
+            // The creator owns the collection and mints the next serials first
+            MockNftInspect::set_owner(7, 0, ALICE);
+            MockNftInspect::set_owner(7, 1, ALICE);
+            assert_ok!(Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(NFT_OWNERS.with(|m| m.borrow().get(&(7, 2)).copied()), Some(BOB));
+            assert_eq!(NFT_OWNERS.with(|m| m.borrow().get(&(7, 0)).copied()), Some(ALICE));
+            assert_eq!(pallet::NextRewardSerial::<Test>::get(7), 3);
+        });
+    }
+
+    #[test]
+    fn claim_reward_nft_gives_up_after_too_many_taken_serials() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockNftInspect::set_collection_owner(7, ALICE);
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 200));
+            set_tiers(id, vec![tier(100, 2, Some(7))]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 200, 0));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+
+            for serial in 0..=16 {
+                MockNftInspect::set_owner(7, serial, ALICE);
+            }
+            assert_noop!(
+                Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::RewardSerialsExhausted
+            );
+        });
+    }
+
+    #[test]
+    fn claim_reward_nft_without_collection() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 200));
+            set_tiers(id, vec![tier(100, 2, None)]);
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 200, 0));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_noop!(
+                Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::NoRewardToMint
+            );
+            assert_noop!(
+                Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(CHARLIE), id),
+                Error::<Test>::NoRewardToMint
+            );
+        });
+    }
+}
+
+// ── early_bird_bonus ────────────────────────────────────────────────────
+
+mod early_bird_bonus {
+    use super::*;
+
+    fn bonus_config(deadline: u64, goal: u128) -> CampaignConfigOf {
+        let mut config = default_aon_config(deadline, goal);
+        config.early_bird_bonus =
+            Some(BonusSchedule { bonus_bps: 1000, full_bonus_blocks: 10, decay_blocks: 10 });
+        config
+    }
+
+    #[test]
+    fn full_bonus_within_window() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, bonus_config(100, 5000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1000));
+            let inv = pallet::Investments::<Test>::get(id, BOB).unwrap();
+            assert_eq!(inv.total_invested, 1000);
+            assert_eq!(inv.weighted_allocation, 1100);
+        });
+    }
+
+    #[test]
+    fn bonus_decays_linearly() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, bonus_config(100, 5000));
+            // created at block 1: full bonus until block 11, zero from block 21
+            run_to_block(16);
+            let c = pallet::Campaigns::<Test>::get(id).unwrap();
+            assert_eq!(Crowdfunding::early_bird_bonus_bps(&c, 16), 500);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1000));
+            assert_eq!(
+                pallet::Investments::<Test>::get(id, BOB).unwrap().weighted_allocation,
+                1050
+            );
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 1000));
+            assert_eq!(
+                pallet::Investments::<Test>::get(id, CHARLIE).unwrap().weighted_allocation,
+                1000
+            );
+        });
+    }
+
+    #[test]
+    fn no_schedule_weights_one_to_one() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 5000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1000));
+            assert_eq!(
+                pallet::Investments::<Test>::get(id, BOB).unwrap().weighted_allocation,
+                1000
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_schedule() {
+        ExtBuilder::default().build().execute_with(|| {
+            for schedule in [
+                BonusSchedule { bonus_bps: 0, full_bonus_blocks: 10, decay_blocks: 10 },
+                BonusSchedule { bonus_bps: 10_001, full_bonus_blocks: 10, decay_blocks: 10 },
+                BonusSchedule { bonus_bps: 1000, full_bonus_blocks: 0, decay_blocks: 0 },
+            ] {
+                let mut config = default_aon_config(100, 5000);
+                config.early_bird_bonus = Some(schedule);
+                assert_noop!(
+                    Crowdfunding::create_campaign(RuntimeOrigin::signed(ALICE), config, None, None),
+                    Error::<Test>::InvalidBonusSchedule
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn partial_withdrawal_claws_back_bonus_pro_rata() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, bonus_config(100, 5000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1000));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 500));
+            let inv = pallet::Investments::<Test>::get(id, BOB).unwrap();
+            assert_eq!(inv.weighted_allocation, 550);
+            assert_eq!(pallet::TotalWeightedAllocations::<Test>::get(id), 550);
+
+            // Re-investing after the bonus window only adds unit weight
+            run_to_block(30);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 500));
+            assert_eq!(
+                pallet::Investments::<Test>::get(id, BOB).unwrap().weighted_allocation,
+                1050
+            );
+            assert_eq!(pallet::TotalWeightedAllocations::<Test>::get(id), 1050);
+        });
+    }
+
+    #[test]
+    fn clawback_is_exact_on_large_positions() {
+        ExtBuilder::default()
+            .balances(vec![(ALICE, 10_000), (BOB, 10_000_000_000), (CHARLIE, 10_000)])
+            .build()
+            .execute_with(|| {
+                let id = create_funded_campaign(ALICE, bonus_config(100, 5000));
+                assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 3_000_000_000));
+                // A one-unit withdrawal rounds to a whole Permill and used to
+                // keep the full bonus.
+                assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 1));
+                assert_eq!(
+                    pallet::Investments::<Test>::get(id, BOB).unwrap().weighted_allocation,
+                    3_299_999_998
+                );
+
+                assert_ok!(Crowdfunding::transfer_investment(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    CHARLIE,
+                    1_000_000_000
+                ));
+                let bob = pallet::Investments::<Test>::get(id, BOB).unwrap().weighted_allocation;
+                let charlie =
+                    pallet::Investments::<Test>::get(id, CHARLIE).unwrap().weighted_allocation;
+                assert_eq!(charlie, 1_099_999_999);
+                assert_eq!(bob + charlie, 3_299_999_998);
+            });
+    }
+
+    #[test]
+    fn full_withdrawal_clears_weight() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, bonus_config(100, 5000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1000));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 1000));
+            assert_eq!(pallet::Investments::<Test>::get(id, BOB).unwrap().weighted_allocation, 0);
+        });
+    }
+
+    #[test]
+    fn migration_to_v4_weights_existing_positions() {
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        ExtBuilder::default().build().execute_with(|| {
+            StorageVersion::new(3).put::<Crowdfunding>();
+            let old = crate::migrations::v4::OldInvestment::<u128> {
+                total_invested: 700,
+                total_withdrawn: 200,
+            };
+            frame_support::storage::unhashed::put(
+                &pallet::Investments::<Test>::hashed_key_for(0, BOB),
+                &old,
+            );
+
+            crate::migrations::v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+            let inv = pallet::Investments::<Test>::get(0, BOB).unwrap();
+            assert_eq!(inv.total_invested, 700);
+            assert_eq!(inv.total_withdrawn, 200);
+            assert_eq!(inv.weighted_allocation, 500);
+            assert_eq!(Crowdfunding::on_chain_storage_version(), 4);
+        });
+    }
+}
+
+// ── auto_finalization ───────────────────────────────────────────────────
+
+mod auto_finalization {
+    use frame_support::{traits::Hooks, weights::Weight};
+
+    use super::*;
+
+    #[test]
+    fn create_indexes_deadline() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_eq!(pallet::CampaignDeadlines::<Test>::get(21).into_inner(), vec![id]);
+        });
+    }
+
+    #[test]
+    fn on_initialize_finalizes_due_campaigns() {
+        ExtBuilder::default().build().execute_with(|| {
+            let ok = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            let failed = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), ok, 500));
+
+            run_to_block(20);
+            Crowdfunding::on_initialize(20);
+            assert_eq!(pallet::Campaigns::<Test>::get(ok).unwrap().status, CampaignStatus::Funding);
+
+            run_to_block(21);
+            Crowdfunding::on_initialize(21);
+            assert_eq!(
+                pallet::Campaigns::<Test>::get(ok).unwrap().status,
+                CampaignStatus::Succeeded
+            );
+            assert_eq!(
+                pallet::Campaigns::<Test>::get(failed).unwrap().status,
+                CampaignStatus::Failed
+            );
+            assert!(!pallet::CampaignDeadlines::<Test>::contains_key(21));
+            System::assert_has_event(
+                Event::CampaignFinalized { campaign_id: ok, status: CampaignStatus::Succeeded }
+                    .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn overflow_spills_into_on_idle() {
+        ExtBuilder::default().build().execute_with(|| {
+            let ids: Vec<u32> = (0..3)
+                .map(|_| create_funded_campaign(ALICE, default_aon_config(20, 500)))
+                .collect();
+
+            run_to_block(21);
+            Crowdfunding::on_initialize(21);
+            // MaxAutoFinalizationsPerBlock = 2 in the mock
+            assert_eq!(
+                pallet::Campaigns::<Test>::get(ids[2]).unwrap().status,
+                CampaignStatus::Funding
+            );
+            assert!(pallet::PendingFinalizations::<Test>::contains_key(ids[2]));
+
+            // No leftover weight: nothing happens
+            assert_eq!(Crowdfunding::on_idle(21, Weight::zero()), Weight::zero());
+            assert!(pallet::PendingFinalizations::<Test>::contains_key(ids[2]));
+
+            Crowdfunding::on_idle(21, Weight::MAX);
+            assert_eq!(
+                pallet::Campaigns::<Test>::get(ids[2]).unwrap().status,
+                CampaignStatus::Failed
+            );
+            assert_eq!(pallet::PendingFinalizations::<Test>::iter_keys().count(), 0);
+        });
+    }
+
+    #[test]
+    fn manual_finalize_and_cancel_unindex() {
+        ExtBuilder::default().build().execute_with(|| {
+            let a = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            let b = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), a));
+            assert_eq!(pallet::CampaignDeadlines::<Test>::get(21).into_inner(), vec![b]);
+            assert_ok!(Crowdfunding::force_finalize_campaign(RuntimeOrigin::root(), b));
+            assert!(!pallet::CampaignDeadlines::<Test>::contains_key(21));
+        });
+    }
+
+    #[test]
+    fn pause_and_resume_reindex_extended_deadline() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            run_to_block(5);
+            assert_ok!(Crowdfunding::pause_campaign(RuntimeOrigin::root(), id));
+            assert!(!pallet::CampaignDeadlines::<Test>::contains_key(21));
+
+            // Paused campaigns are not finalized when the original deadline passes
+            run_to_block(21);
+            Crowdfunding::on_initialize(21);
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().status, CampaignStatus::Paused);
+
+            assert_ok!(Crowdfunding::resume_campaign(RuntimeOrigin::root(), id));
+            // Paused for 16 blocks: deadline 20 → 36
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().config.deadline, 36);
+            assert_eq!(pallet::CampaignDeadlines::<Test>::get(37).into_inner(), vec![id]);
+
+            run_to_block(37);
+            Crowdfunding::on_initialize(37);
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().status, CampaignStatus::Failed);
+        });
+    }
+
+    #[test]
+    fn pause_removes_pending_entry() {
+        ExtBuilder::default().build().execute_with(|| {
+            for _ in 0..3 {
+                create_funded_campaign(ALICE, default_aon_config(20, 500));
+            }
+            run_to_block(21);
+            Crowdfunding::on_initialize(21);
+            assert!(pallet::PendingFinalizations::<Test>::contains_key(2));
+            assert_ok!(Crowdfunding::pause_campaign(RuntimeOrigin::root(), 2));
+            assert!(!pallet::PendingFinalizations::<Test>::contains_key(2));
+        });
+    }
+
+    #[test]
+    fn rejects_full_deadline_slot() {
+        ExtBuilder::default().build().execute_with(|| {
+            MaxDeadlinesPerBlock::set(&2);
+            create_funded_campaign(ALICE, default_aon_config(20, 500));
+            create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_noop!(
+                Crowdfunding::create_campaign(
+                    RuntimeOrigin::signed(ALICE),
+                    default_aon_config(20, 500),
+                    None,
+                    None
+                ),
+                Error::<Test>::DeadlineSlotsFull
+            );
+            // A different deadline block is unaffected
+            create_funded_campaign(ALICE, default_aon_config(21, 500));
+        });
+    }
+
+    #[test]
+    fn resume_into_full_slot_rolls_deadline_over() {
+        ExtBuilder::default().build().execute_with(|| {
+            MaxDeadlinesPerBlock::set(&1);
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            run_to_block(5);
+            assert_ok!(Crowdfunding::pause_campaign(RuntimeOrigin::root(), id));
+            // Occupy the slots the resumed deadline would land in (20 + 5 = 25)
+            // and the one after it
+            create_funded_campaign(BOB, default_aon_config(25, 500));
+            create_funded_campaign(BOB, default_aon_config(26, 500));
+            run_to_block(10);
+            assert_ok!(Crowdfunding::resume_campaign(RuntimeOrigin::root(), id));
+            let campaign = pallet::Campaigns::<Test>::get(id).unwrap();
+            assert_eq!(campaign.config.deadline, 27);
+            assert_eq!(pallet::CampaignDeadlines::<Test>::get(28).into_inner(), vec![id]);
+        });
+    }
+
+    #[test]
+    fn resume_fails_when_rollover_finds_no_slot() {
+        ExtBuilder::default().build().execute_with(|| {
+            MaxDeadlinesPerBlock::set(&1);
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            run_to_block(5);
+            assert_ok!(Crowdfunding::pause_campaign(RuntimeOrigin::root(), id));
+            // MaxCampaignsPerCreator = 5, so spread the squatting campaigns
+            for (i, deadline) in (25..=41).enumerate() {
+                let creator = [BOB, CHARLIE, DAVE, ALICE][i % 4];
+                create_funded_campaign(creator, default_aon_config(deadline, 500));
+            }
+            run_to_block(10);
+            assert_noop!(
+                Crowdfunding::resume_campaign(RuntimeOrigin::root(), id),
+                Error::<Test>::DeadlineSlotsFull
+            );
+        });
+    }
+}
+
+// ── process_refunds ─────────────────────────────────────────────────────
+
+mod process_refunds {
+    use frame_support::{traits::Hooks, weights::Weight};
+
+    use super::*;
+
+    fn failed_campaign_with_investors() -> u32 {
+        let id = create_funded_campaign(ALICE, default_aon_config(20, 5000));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 500));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 300));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(DAVE), id, 200));
+        run_to_block(21);
+        assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+        id
+    }
+
+    #[test]
+    fn refunds_every_investor() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = failed_campaign_with_investors();
+            assert!(pallet::PendingRefunds::<Test>::contains_key(id));
+            assert_ok!(Crowdfunding::process_refunds(RuntimeOrigin::signed(ALICE), id, 10));
+
+            assert_eq!(Balances::free_balance(BOB), 10_000);
+            assert_eq!(Balances::free_balance(CHARLIE), 10_000);
+            assert_eq!(Balances::free_balance(DAVE), 10_000);
+            assert_eq!(pallet::Investments::<Test>::iter_prefix(id).count(), 0);
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().investor_count, 0);
+            assert!(pallet::InvestorCampaigns::<Test>::get(BOB).is_empty());
+            assert!(!pallet::PendingRefunds::<Test>::contains_key(id));
+            assert!(pallet::RefundCursor::<Test>::get(id).is_none());
+            System::assert_last_event(
+                Event::RefundsProcessed { campaign_id: id, refunded: 3, completed: true }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn resumes_from_cursor() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = failed_campaign_with_investors();
+            assert_ok!(Crowdfunding::process_refunds(RuntimeOrigin::signed(ALICE), id, 2));
+            System::assert_last_event(
+                Event::RefundsProcessed { campaign_id: id, refunded: 2, completed: false }.into(),
+            );
+            assert!(pallet::RefundCursor::<Test>::get(id).is_some());
+            assert_eq!(pallet::Investments::<Test>::iter_prefix(id).count(), 1);
+
+            assert_ok!(Crowdfunding::process_refunds(RuntimeOrigin::signed(ALICE), id, 2));
+            System::assert_last_event(
+                Event::RefundsProcessed { campaign_id: id, refunded: 1, completed: true }.into(),
+            );
+            assert_eq!(pallet::Investments::<Test>::iter_prefix(id).count(), 0);
+            assert!(pallet::RefundCursor::<Test>::get(id).is_none());
+        });
+    }
+
+    #[test]
+    fn manual_claim_still_works_mid_batch() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = failed_campaign_with_investors();
+            assert_ok!(Crowdfunding::process_refunds(RuntimeOrigin::signed(ALICE), id, 1));
+            for who in [BOB, CHARLIE, DAVE] {
+                if pallet::Investments::<Test>::contains_key(id, who) {
+                    assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(who), id));
+                }
+                assert_eq!(Balances::free_balance(who), 10_000);
+            }
+            // Completes the pending batch and clears the cursor
+            assert_ok!(Crowdfunding::process_refunds(RuntimeOrigin::signed(ALICE), id, 10));
+            assert!(pallet::RefundCursor::<Test>::get(id).is_none());
+            assert_noop!(
+                Crowdfunding::process_refunds(RuntimeOrigin::signed(ALICE), id, 10),
+                Error::<Test>::NothingToRefund
+            );
+        });
+    }
+
+    #[test]
+    fn removes_fully_withdrawn_records() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 5000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 500));
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            assert!(pallet::Investments::<Test>::contains_key(id, BOB));
+
+            assert_ok!(Crowdfunding::process_refunds(RuntimeOrigin::signed(CHARLIE), id, 10));
+            assert!(!pallet::Investments::<Test>::contains_key(id, BOB));
+            System::assert_last_event(
+                Event::RefundsProcessed { campaign_id: id, refunded: 0, completed: true }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_calls() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 5000));
+            assert_noop!(
+                Crowdfunding::process_refunds(RuntimeOrigin::signed(BOB), id, 10),
+                Error::<Test>::InvalidCampaignStatus
+            );
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            assert_noop!(
+                Crowdfunding::process_refunds(RuntimeOrigin::signed(BOB), id, 0),
+                Error::<Test>::InvalidRefundBatch
+            );
+            assert_noop!(
+                Crowdfunding::process_refunds(RuntimeOrigin::signed(BOB), 99, 10),
+                Error::<Test>::CampaignNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn on_idle_pushes_queued_refunds() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = failed_campaign_with_investors();
+            // No leftover weight: nothing is refunded
+            Crowdfunding::on_idle(21, Weight::zero());
+            assert_eq!(pallet::Investments::<Test>::iter_prefix(id).count(), 3);
+
+            // MaxIdleRefunds = 2 in the mock
+            Crowdfunding::on_idle(21, Weight::MAX);
+            assert_eq!(pallet::Investments::<Test>::iter_prefix(id).count(), 1);
+            assert!(pallet::PendingRefunds::<Test>::contains_key(id));
+
+            Crowdfunding::on_idle(22, Weight::MAX);
+            assert_eq!(pallet::Investments::<Test>::iter_prefix(id).count(), 0);
+            assert!(!pallet::PendingRefunds::<Test>::contains_key(id));
+            assert_eq!(Balances::free_balance(BOB), 10_000);
+        });
+    }
+}
+
+// ── reap_campaign ───────────────────────────────────────────────────────
+
+mod reap_campaign {
+    use super::*;
+
+    fn completed_campaign() -> u32 {
+        let id = create_funded_campaign(ALICE, default_kwyr_config(20));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 500));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 300));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(DAVE), id, 200));
+        run_to_block(21);
+        assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+        assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+        id
+    }
+
+    #[test]
+    fn archives_completed_campaign_and_pays_reward() {
+        ExtBuilder::default().build().execute_with(|| {
+            ReapDeposit::set(10);
+            let id = completed_campaign();
+            assert_eq!(pallet::ReapDeposits::<Test>::get(id), Some(10));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+
+            let dave_before = Balances::free_balance(DAVE);
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(DAVE), id, 10));
+
+            assert_eq!(Balances::free_balance(DAVE), dave_before + 10);
+            assert!(pallet::Campaigns::<Test>::get(id).is_none());
+            assert!(pallet::ReapDeposits::<Test>::get(id).is_none());
+            assert_eq!(pallet::Investments::<Test>::iter_prefix(id).count(), 0);
+            assert!(pallet::InvestorCampaigns::<Test>::get(BOB).is_empty());
+            let archived = pallet::ArchivedCampaigns::<Test>::get(id).unwrap();
+            assert_eq!(archived.creator, ALICE);
+            assert_eq!(archived.status, CampaignStatus::Completed);
+            assert_eq!(archived.total_raised, 1000);
+            assert_eq!(archived.total_disbursed, 1000);
+            assert_eq!(archived.reaped_at, 21);
+            System::assert_has_event(
+                Event::ReapRewardPaid { campaign_id: id, who: DAVE, amount: 10 }.into(),
+            );
+            System::assert_last_event(
+                Event::CampaignReaped { campaign_id: id, removed: 3, completed: true }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn reaps_in_batches() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = completed_campaign();
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(BOB), id, 2));
+            assert_eq!(pallet::Investments::<Test>::iter_prefix(id).count(), 1);
+            assert!(pallet::Campaigns::<Test>::get(id).is_some());
+            System::assert_last_event(
+                Event::CampaignReaped { campaign_id: id, removed: 2, completed: false }.into(),
+            );
+
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(BOB), id, 2));
+            assert!(pallet::Campaigns::<Test>::get(id).is_none());
+            assert!(pallet::ArchivedCampaigns::<Test>::contains_key(id));
+            assert_noop!(
+                Crowdfunding::reap_campaign(RuntimeOrigin::signed(BOB), id, 2),
+                Error::<Test>::CampaignNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_unsettled_campaigns() {
+        ExtBuilder::default().build().execute_with(|| {
+            // Still funding
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 5000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 500));
+            assert_noop!(
+                Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10),
+                Error::<Test>::CampaignNotSettled
+            );
+
+            // Failed with a refund outstanding
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            assert_noop!(
+                Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10),
+                Error::<Test>::CampaignNotSettled
+            );
+
+            // Settled once the refund is claimed
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10));
+            assert_eq!(
+                pallet::ArchivedCampaigns::<Test>::get(id).unwrap().status,
+                CampaignStatus::Failed
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_unclaimed_creation_deposit() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = completed_campaign();
+            assert_noop!(
+                Crowdfunding::reap_campaign(RuntimeOrigin::signed(BOB), id, 10),
+                Error::<Test>::CampaignNotSettled
+            );
+        });
+    }
+
+    #[test]
+    fn mints_unclaimed_nft_rewards() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockNftInspect::set_collection_owner(7, ALICE);
+            let id = create_funded_campaign(ALICE, default_kwyr_config(20));
+            let tier = RewardTier {
+                min_amount: 100,
+                quantity: 2,
+                remaining: 0,
+                description_hash: [7u8; 32],
+                nft_collection: Some(7),
+            };
+            assert_ok!(Crowdfunding::set_reward_tiers(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                BoundedVec::try_from(vec![tier]).unwrap(),
+            ));
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 200, 0));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10));
+            assert_eq!(NFT_OWNERS.with(|m| m.borrow().get(&(7, 0)).copied()), Some(BOB));
+            assert!(pallet::InvestorTiers::<Test>::get(id, BOB).is_none());
+            assert!(pallet::RewardTiers::<Test>::get(id).is_none());
+        });
+    }
+
+    #[test]
+    fn reaps_even_when_reward_mint_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockNftInspect::set_collection_owner(7, ALICE);
+            let id = create_funded_campaign(ALICE, default_kwyr_config(20));
+            let tier = RewardTier {
+                min_amount: 100,
+                quantity: 2,
+                remaining: 0,
+                description_hash: [7u8; 32],
+                nft_collection: Some(7),
+            };
+            assert_ok!(Crowdfunding::set_reward_tiers(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                BoundedVec::try_from(vec![tier]).unwrap(),
+            ));
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 200, 0));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+
+            // The creator squats every serial the reaper would try
+            for serial in 0..=16 {
+                MockNftInspect::set_owner(7, serial, ALICE);
+            }
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10));
+            System::assert_has_event(
+                Event::RewardMintFailed { campaign_id: id, investor: BOB, tier_index: 0 }.into(),
+            );
+            assert_eq!(pallet::NextRewardSerial::<Test>::get(7), 0);
+            assert!(pallet::ArchivedCampaigns::<Test>::contains_key(id));
+        });
+    }
+
+    #[test]
+    fn frees_investor_campaign_slots() {
+        ExtBuilder::default().build().execute_with(|| {
+            // MaxInvestmentsPerInvestor = 5 in the mock
+            let ids: Vec<u32> = (0..5)
+                .map(|_| {
+                    let id = create_funded_campaign(ALICE, default_kwyr_config(20));
+                    assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+                    id
+                })
+                .collect();
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), ids[0]));
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), ids[0]));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), ids[0]));
+
+            let next = create_funded_campaign(ALICE, default_kwyr_config(40));
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(BOB), next, 100),
+                Error::<Test>::MaxInvestmentsPerInvestorReached
+            );
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(BOB), ids[0], 10));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), next, 100));
+        });
+    }
+}
+
+// ── eligibility_expressions ─────────────────────────────────────────────
+
+mod eligibility_expressions {
+    use super::*;
+
+    const KYC: [u8; 32] = [9u8; 32];
+
+    fn set_expression(id: u32, nodes: Vec<EligibilityNode<EligibilityRuleOf<Test>>>) {
+        assert_ok!(Crowdfunding::set_eligibility_expression(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            BoundedVec::try_from(nodes).unwrap(),
+        ));
+    }
+
+    #[test]
+    fn or_accepts_either_branch() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_expression(
+                id,
+                vec![
+                    EligibilityNode::Rule(EligibilityRule::AccountWhitelist),
+                    EligibilityNode::Rule(EligibilityRule::NativeBalance { min_balance: 20_000 }),
+                    EligibilityNode::Or(0, 1),
+                ],
+            );
+
+            assert_eq!(
+                Crowdfunding::check_eligibility(id, &BOB),
+                Err(EligibilityFailure::Expression {
+                    path: vec![2],
+                    error: EligibilityError::NoBranchPassed,
+                })
+            );
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100),
+                Error::<Test>::EligibilityCheckFailed
+            );
+
+            assert_ok!(Crowdfunding::add_to_whitelist(RuntimeOrigin::signed(ALICE), id, BOB));
+            assert_eq!(Crowdfunding::check_eligibility(id, &BOB), Ok(()));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+        });
+    }
+
+    #[test]
+    fn and_reports_failing_leaf_path() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_expression(
+                id,
+                vec![
+                    EligibilityNode::Rule(EligibilityRule::NativeBalance { min_balance: 1 }),
+                    EligibilityNode::Rule(EligibilityRule::Attestation { claim: KYC }),
+                    EligibilityNode::And(0, 1),
+                ],
+            );
+
+            assert_eq!(
+                Crowdfunding::check_eligibility(id, &BOB),
+                Err(EligibilityFailure::Expression {
+                    path: vec![2, 1],
+                    error: EligibilityError::AttestationMissing,
+                })
+            );
+            MockEligibilityProvider::attest(BOB, KYC);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+        });
+    }
+
+    #[test]
+    fn not_excludes_rwa_participants() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            set_expression(
+                id,
+                vec![
+                    EligibilityNode::Rule(EligibilityRule::RwaParticipation { rwa_asset_id: 5 }),
+                    EligibilityNode::Not(0),
+                ],
+            );
+            MockLicenseVerifier::set_license(5, 0, BOB, true);
+
+            assert_eq!(
+                Crowdfunding::check_eligibility(id, &BOB),
+                Err(EligibilityFailure::Expression {
+                    path: vec![1],
+                    error: EligibilityError::NegatedConditionMet,
+                })
+            );
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 100));
+
+            // An inactive participation no longer counts
+            MockLicenseVerifier::set_active(5, 0, false);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+        });
+    }
+
+    #[test]
+    fn flat_rules_report_rule_index() {
+        ExtBuilder::default().build().execute_with(|| {
+            let rules: BoundedVec<_, _> = vec![
+                EligibilityRule::NativeBalance { min_balance: 1 },
+                EligibilityRule::MinAccountAge { blocks: 10 },
+            ]
+            .try_into()
+            .unwrap();
+            assert_ok!(Crowdfunding::create_campaign(
+                RuntimeOrigin::signed(ALICE),
+                default_aon_config(100, 1000),
+                Some(rules),
+                None,
+            ));
+
+            assert_eq!(
+                Crowdfunding::check_eligibility(0, &BOB),
+                Err(EligibilityFailure::Rule { index: 1, error: EligibilityError::AccountTooNew })
+            );
+            MockEligibilityProvider::set_created_at(BOB, 1);
+            run_to_block(10);
+            assert!(Crowdfunding::check_eligibility(0, &BOB).is_err());
+            run_to_block(11);
+            assert_eq!(Crowdfunding::check_eligibility(0, &BOB), Ok(()));
+            assert_eq!(
+                Crowdfunding::check_eligibility(99, &BOB),
+                Err(EligibilityFailure::CampaignNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            let leaf = || EligibilityNode::Rule(EligibilityRule::AccountWhitelist);
+            let malformed = vec![
+                // Child does not precede its parent
+                vec![EligibilityNode::Not(0)],
+                // Shared child
+                vec![leaf(), EligibilityNode::And(0, 0)],
+                // Unused node
+                vec![leaf(), leaf(), EligibilityNode::Not(1)],
+            ];
+            for nodes in malformed {
+                assert_noop!(
+                    Crowdfunding::set_eligibility_expression(
+                        RuntimeOrigin::signed(ALICE),
+                        id,
+                        BoundedVec::try_from(nodes).unwrap(),
+                    ),
+                    Error::<Test>::InvalidEligibilityExpression
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn only_creator_before_first_investment() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            let nodes: EligibilityExpressionOf<Test> =
+                BoundedVec::try_from(vec![EligibilityNode::Rule(
+                    EligibilityRule::AccountWhitelist,
+                )])
+                .unwrap();
+            assert_noop!(
+                Crowdfunding::set_eligibility_expression(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    nodes.clone()
+                ),
+                Error::<Test>::NotCampaignCreator
+            );
+
+            set_expression(id, nodes.to_vec());
+            assert!(pallet::EligibilityExpressions::<Test>::contains_key(id));
+            set_expression(id, vec![]);
+            assert!(!pallet::EligibilityExpressions::<Test>::contains_key(id));
+            System::assert_last_event(
+                Event::EligibilityExpressionSet { campaign_id: id, node_count: 0 }.into(),
+            );
+
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_noop!(
+                Crowdfunding::set_eligibility_expression(RuntimeOrigin::signed(ALICE), id, nodes),
+                Error::<Test>::EligibilityLocked
+            );
+        });
+    }
+}
+
+// ── registration_phase ──────────────────────────────────────────────────
+
+mod registration_phase {
+    use frame_support::{traits::Hooks, weights::Weight};
+
+    use super::*;
+
+    fn create_with_native_rule(min_balance: u128) -> u32 {
+        let rules: BoundedVec<_, _> =
+            vec![EligibilityRule::NativeBalance { min_balance }].try_into().unwrap();
+        assert_ok!(Crowdfunding::create_campaign(
+            RuntimeOrigin::signed(ALICE),
+            default_aon_config(100, 1000),
+            Some(rules),
+            None,
+        ));
+        pallet::NextCampaignId::<Test>::get() - 1
+    }
+
+    #[test]
+    fn invest_requires_open_funding_and_registration() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10));
+            System::assert_last_event(
+                Event::RegistrationPhaseSet { campaign_id: id, funding_opens_at: 10 }.into(),
+            );
+
+            assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id));
+            System::assert_last_event(
+                Event::InterestRegistered { campaign_id: id, who: BOB }.into(),
+            );
+            assert_eq!(pallet::RegistrationCount::<Test>::get(id), 1);
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100),
+                Error::<Test>::FundingNotOpen
+            );
+
+            run_to_block(10);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_eq!(
+                Crowdfunding::check_eligibility(id, &CHARLIE),
+                Err(EligibilityFailure::NotRegistered)
+            );
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 100),
+                Error::<Test>::NotRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn ineligible_accounts_cannot_register() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_with_native_rule(15_000);
+            assert_ok!(Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10));
+            assert_noop!(
+                Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::EligibilityCheckFailed
+            );
+            assert_eq!(pallet::RegistrationCount::<Test>::get(id), 0);
+
+            // Registering once the balance qualifies records the new snapshot
+            assert_ok!(Balances::transfer(RuntimeOrigin::signed(CHARLIE), BOB, 9_000));
+            assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(pallet::Registrations::<Test>::get(id, BOB).unwrap().native, 19_000);
+            assert_eq!(pallet::RegistrationCount::<Test>::get(id), 1);
+        });
+    }
+
+    #[test]
+    fn refresh_that_fails_eligibility_keeps_previous_snapshot() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_with_native_rule(5_000);
+            assert_ok!(Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10));
+            assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id));
+            assert_ok!(Balances::transfer(RuntimeOrigin::signed(BOB), DAVE, 6_000));
+            assert_noop!(
+                Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::EligibilityCheckFailed
+            );
+            assert_eq!(pallet::Registrations::<Test>::get(id, BOB).unwrap().native, 10_000);
+        });
+    }
+
+    #[test]
+    fn balance_moved_away_after_snapshot_does_not_count() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_with_native_rule(5_000);
+            assert_ok!(Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10));
+            assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id));
+            assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(CHARLIE), id));
+            assert_ok!(Balances::transfer(RuntimeOrigin::signed(BOB), DAVE, 6_000));
+
+            run_to_block(10);
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100),
+                Error::<Test>::EligibilityCheckFailed
+            );
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 100));
+        });
+    }
+
+    #[test]
+    fn registration_closes_when_funding_opens() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::RegistrationClosed
+            );
+
+            assert_ok!(Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10));
+            run_to_block(10);
+            assert_noop!(
+                Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::RegistrationClosed
+            );
+        });
+    }
+
+    #[test]
+    fn reregistering_refreshes_without_counting_twice() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10));
+            for who in [BOB, CHARLIE, DAVE] {
+                assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(who), id));
+            }
+            assert_ok!(Balances::transfer(RuntimeOrigin::signed(BOB), DAVE, 1_000));
+            assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(pallet::RegistrationCount::<Test>::get(id), 3);
+            assert_eq!(pallet::Registrations::<Test>::get(id, BOB).unwrap().native, 9_000);
+
+            assert_noop!(
+                Crowdfunding::register_interest(RuntimeOrigin::signed(ALICE), id),
+                Error::<Test>::MaxRegistrationsReached
+            );
+        });
+    }
+
+    #[test]
+    fn phase_must_end_between_now_and_deadline() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::set_registration_phase(RuntimeOrigin::signed(BOB), id, 10),
+                Error::<Test>::NotCampaignCreator
+            );
+            assert_noop!(
+                Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 1),
+                Error::<Test>::InvalidRegistrationPhase
+            );
+            assert_noop!(
+                Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 100),
+                Error::<Test>::InvalidRegistrationPhase
+            );
+        });
+    }
+
+    #[test]
+    fn locked_once_investment_exists() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_noop!(
+                Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10),
+                Error::<Test>::EligibilityLocked
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_clears_registrations() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10));
+            assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id));
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            assert!(!pallet::RegistrationPhases::<Test>::contains_key(id));
+            assert_eq!(pallet::RegistrationCount::<Test>::get(id), 0);
+
+            Crowdfunding::on_idle(1, Weight::MAX);
+            assert!(!pallet::Registrations::<Test>::contains_key(id, BOB));
+        });
+    }
+}
+
+// ── merkle_whitelist ────────────────────────────────────────────────────
+
+mod merkle_whitelist {
+    use frame_support::{traits::Hooks, weights::Weight};
+
+    use super::*;
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        sp_core::hashing::blake2_256(&[left, right].concat())
+    }
+
+    /// Root and per-leaf sibling paths of a tree over a power-of-two number
+    /// of leaves.
+    fn build_tree(leaves: Vec<[u8; 32]>) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let mut proofs = vec![Vec::new(); leaves.len()];
+        let mut level = leaves;
+        let mut positions: Vec<usize> = (0..proofs.len()).collect();
+        while level.len() > 1 {
+            for (leaf, pos) in positions.iter_mut().enumerate() {
+                proofs[leaf].push(level[*pos ^ 1]);
+                *pos /= 2;
+            }
+            level = level.chunks(2).map(|pair| hash_pair(pair[0], pair[1])).collect();
+        }
+        (level[0], proofs)
+    }
+
+    /// Tree over BOB (no cap), CHARLIE (cap 300), DAVE (no cap) and a filler
+    /// leaf.  Returns the root and the proofs of BOB, CHARLIE and DAVE.
+    fn setup_tree() -> ([u8; 32], Vec<WhitelistProof<u128>>) {
+        let members = [(BOB, None), (CHARLIE, Some(300)), (DAVE, None)];
+        let mut leaves: Vec<_> =
+            members.iter().map(|(who, cap)| Crowdfunding::whitelist_leaf(who, *cap)).collect();
+        leaves.push([0u8; 32]);
+        let (root, siblings) = build_tree(leaves);
+        let proofs = members
+            .iter()
+            .zip(siblings)
+            .map(|((_, cap), siblings)| WhitelistProof { cap: *cap, siblings })
+            .collect();
+        (root, proofs)
+    }
+
+    fn create_whitelisted_campaign(root: [u8; 32]) -> u32 {
+        let rules: BoundedVec<_, _> = vec![EligibilityRule::AccountWhitelist].try_into().unwrap();
+        assert_ok!(Crowdfunding::create_campaign(
+            RuntimeOrigin::signed(ALICE),
+            default_aon_config(100, 1000),
+            Some(rules),
+            None,
+        ));
+        let id = pallet::NextCampaignId::<Test>::get() - 1;
+        assert_ok!(Crowdfunding::set_whitelist_root(RuntimeOrigin::signed(ALICE), id, Some(root)));
+        System::assert_last_event(
+            Event::WhitelistRootSet { campaign_id: id, root: Some(root) }.into(),
+        );
+        id
+    }
+
+    #[test]
+    fn first_proof_is_cached() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (root, proofs) = setup_tree();
+            let id = create_whitelisted_campaign(root);
+
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100),
+                Error::<Test>::EligibilityCheckFailed
+            );
+            assert_eq!(
+                Crowdfunding::check_eligibility(id, &BOB),
+                Err(EligibilityFailure::Rule { index: 0, error: EligibilityError::NotWhitelisted })
+            );
+            assert_eq!(
+                Crowdfunding::check_eligibility_with_proof(id, &BOB, Some(&proofs[0])),
+                Ok(())
+            );
+
+            assert_ok!(Crowdfunding::invest_with_proof(
+                RuntimeOrigin::signed(BOB),
+                id,
+                100,
+                proofs[0].clone(),
+            ));
+            System::assert_has_event(
+                Event::WhitelistProofVerified { campaign_id: id, who: BOB }.into(),
+            );
+            assert_eq!(
+                pallet::WhitelistAllowances::<Test>::get(id, BOB),
+                Some(WhitelistAllowance { root, cap: None })
+            );
+            assert_eq!(Crowdfunding::check_eligibility(id, &BOB), Ok(()));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_proofs() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (root, proofs) = setup_tree();
+            let id = create_whitelisted_campaign(root);
+
+            // Someone else's proof.
+            assert_noop!(
+                Crowdfunding::invest_with_proof(
+                    RuntimeOrigin::signed(DAVE),
+                    id,
+                    100,
+                    proofs[0].clone()
+                ),
+                Error::<Test>::InvalidWhitelistProof
+            );
+            // A cap other than the one committed to in the leaf.
+            let uncapped = WhitelistProof { cap: None, ..proofs[1].clone() };
+            assert_noop!(
+                Crowdfunding::invest_with_proof(RuntimeOrigin::signed(CHARLIE), id, 100, uncapped),
+                Error::<Test>::InvalidWhitelistProof
+            );
+            // Deeper than MaxWhitelistProofDepth, even if it hashes to the root.
+            let deep = WhitelistProof { cap: None, siblings: vec![[1u8; 32]; 9] };
+            let deep_root = Crowdfunding::whitelist_proof_root(&DAVE, &deep);
+            assert_ok!(Crowdfunding::set_whitelist_root(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Some(deep_root)
+            ));
+            assert_noop!(
+                Crowdfunding::invest_with_proof(RuntimeOrigin::signed(DAVE), id, 100, deep),
+                Error::<Test>::InvalidWhitelistProof
+            );
+        });
+    }
+
+    #[test]
+    fn no_root_rejects_proofs() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (_, proofs) = setup_tree();
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::invest_with_proof(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    100,
+                    proofs[0].clone()
+                ),
+                Error::<Test>::InvalidWhitelistProof
+            );
+        });
+    }
+
+    #[test]
+    fn per_account_cap_is_enforced() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (root, proofs) = setup_tree();
+            let id = create_whitelisted_campaign(root);
+
+            assert_noop!(
+                Crowdfunding::invest_with_proof(
+                    RuntimeOrigin::signed(CHARLIE),
+                    id,
+                    301,
+                    proofs[1].clone()
+                ),
+                Error::<Test>::WhitelistCapExceeded
+            );
+            assert_ok!(Crowdfunding::invest_with_proof(
+                RuntimeOrigin::signed(CHARLIE),
+                id,
+                200,
+                proofs[1].clone(),
+            ));
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 101),
+                Error::<Test>::WhitelistCapExceeded
+            );
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 100));
+        });
+    }
+
+    #[test]
+    fn changing_root_invalidates_cached_proofs() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (root, proofs) = setup_tree();
+            let id = create_whitelisted_campaign(root);
+            assert_ok!(Crowdfunding::invest_with_proof(
+                RuntimeOrigin::signed(BOB),
+                id,
+                100,
+                proofs[0].clone(),
+            ));
+
+            assert_ok!(Crowdfunding::set_whitelist_root(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Some([9u8; 32])
+            ));
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100),
+                Error::<Test>::EligibilityCheckFailed
+            );
+
+            // The explicit whitelist still works alongside the root.
+            assert_ok!(Crowdfunding::add_to_whitelist(RuntimeOrigin::signed(ALICE), id, BOB));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+        });
+    }
+
+    #[test]
+    fn only_creator_sets_root() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::set_whitelist_root(RuntimeOrigin::signed(BOB), id, Some([1u8; 32])),
+                Error::<Test>::NotCampaignCreator
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_clears_root_and_cached_proofs() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (root, proofs) = setup_tree();
+            let id = create_whitelisted_campaign(root);
+            assert_ok!(Crowdfunding::invest_with_proof(
+                RuntimeOrigin::signed(BOB),
+                id,
+                100,
+                proofs[0].clone(),
+            ));
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            assert!(!pallet::WhitelistRoots::<Test>::contains_key(id));
+
+            Crowdfunding::on_idle(1, Weight::MAX);
+            assert!(!pallet::WhitelistAllowances::<Test>::contains_key(id, BOB));
+        });
+    }
+}
+
+// ── idle_cleanup ────────────────────────────────────────────────────────
+
+mod idle_cleanup {
+    use frame_support::{traits::Hooks, weights::Weight};
+
+    use super::*;
+
+    #[test]
+    fn removes_whitelist_and_registrations_together() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::add_to_whitelist(RuntimeOrigin::signed(ALICE), id, BOB));
+            assert_ok!(Crowdfunding::set_registration_phase(RuntimeOrigin::signed(ALICE), id, 10));
+            assert_ok!(Crowdfunding::register_interest(RuntimeOrigin::signed(BOB), id));
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+
+            Crowdfunding::on_idle(1, Weight::MAX);
+            assert!(!pallet::CampaignWhitelist::<Test>::get(id, BOB));
+            assert!(!pallet::Registrations::<Test>::contains_key(id, BOB));
+            assert!(!pallet::PendingCleanups::<Test>::contains_key(id));
+        });
+    }
+
+    #[test]
+    fn removes_ended_campaign_records_a_bounded_number_per_block() {
+        let mut ext = ExtBuilder::default().build();
+        let id = ext.execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            for who in [BOB, CHARLIE, DAVE] {
+                assert_ok!(Crowdfunding::add_to_whitelist(RuntimeOrigin::signed(ALICE), id, who));
+            }
+            id
+        });
+        // Records written in earlier blocks are in the backend, where a
+        // limited removal is felt.
+        ext.commit_all().unwrap();
+        ext.execute_with(|| {
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            assert_eq!(pallet::CampaignWhitelistCount::<Test>::get(id), 0);
+            assert!(pallet::PendingCleanups::<Test>::contains_key(id));
+
+            // No leftover weight: nothing is removed
+            Crowdfunding::on_idle(1, Weight::zero());
+            assert_eq!(pallet::CampaignWhitelist::<Test>::iter_prefix(id).count(), 3);
+
+            // MaxIdleCleanups = 2 in the mock
+            Crowdfunding::on_idle(1, Weight::MAX);
+            assert_eq!(pallet::CampaignWhitelist::<Test>::iter_prefix(id).count(), 1);
+        });
+        ext.commit_all().unwrap();
+        ext.execute_with(|| {
+            Crowdfunding::on_idle(2, Weight::MAX);
+            assert_eq!(pallet::CampaignWhitelist::<Test>::iter_prefix(id).count(), 0);
+            assert!(!pallet::PendingCleanups::<Test>::contains_key(id));
+        });
+    }
+}
+
+// ── multi_currency ──────────────────────────────────────────────────────
+
+mod multi_currency {
+    use frame_support::traits::tokens::fungibles;
+
+    use super::*;
+
+    const USD: u32 = 7;
+
+    fn asset_balance(who: u64) -> u128 { <Assets as fungibles::Inspect<u64>>::balance(USD, &who) }
+
+    /// Creates `USD` worth 2 native units each, funds BOB and CHARLIE, and
+    /// opens a native campaign accepting it.
+    fn setup(config: CampaignConfigOf) -> u32 {
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            codec::Compact(USD),
+            ALICE,
+            true,
+            1
+        ));
+        for who in [BOB, CHARLIE] {
+            assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), codec::Compact(USD), who, 5_000));
+        }
+        MockPriceFeed::set_price(PaymentCurrency::Asset(USD), PaymentCurrency::Native, 2, 1);
+
+        let id = create_funded_campaign(ALICE, config);
+        let currencies: BoundedVec<_, _> = vec![PaymentCurrency::Asset(USD)].try_into().unwrap();
+        assert_ok!(Crowdfunding::set_accepted_currencies(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            currencies
+        ));
+        id
+    }
+
+    #[test]
+    fn contribution_is_normalized_into_funding_currency() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::invest_in_currency(
+                RuntimeOrigin::signed(BOB),
+                id,
+                PaymentCurrency::Asset(USD),
+                300,
+            ));
+            System::assert_last_event(
+                Event::InvestedInCurrency {
+                    campaign_id: id,
+                    investor: BOB,
+                    currency: PaymentCurrency::Asset(USD),
+                    amount: 300,
+                    normalized: 600,
+                }
+                .into(),
+            );
+
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().total_raised, 600);
+            assert_eq!(pallet::Investments::<Test>::get(id, BOB).unwrap().total_invested, 600);
+            assert_eq!(asset_balance(Crowdfunding::campaign_account(id)), 300);
+            let holding = CurrencyHolding {
+                currency: PaymentCurrency::Asset(USD),
+                amount: 300,
+                normalized: 600,
+            };
+            assert_eq!(
+                pallet::CampaignHoldings::<Test>::get(id).unwrap().to_vec(),
+                vec![holding.clone()]
+            );
+            assert_eq!(
+                pallet::InvestorHoldings::<Test>::get(id, BOB).unwrap().to_vec(),
+                vec![holding]
+            );
+        });
+    }
+
+    #[test]
+    fn hard_cap_uses_normalized_amount() {
+        ExtBuilder::default().build().execute_with(|| {
+            let mut config = default_aon_config(100, 1000);
+            config.hard_cap = Some(1000);
+            let id = setup(config);
+            assert_noop!(
+                Crowdfunding::invest_in_currency(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    PaymentCurrency::Asset(USD),
+                    501,
+                ),
+                Error::<Test>::HardCapExceeded
+            );
+            assert_ok!(Crowdfunding::invest_in_currency(
+                RuntimeOrigin::signed(BOB),
+                id,
+                PaymentCurrency::Asset(USD),
+                500,
+            ));
+        });
+    }
+
+    #[test]
+    fn rejects_unaccepted_or_unpriced_currencies() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(100, 1000));
+            assert_noop!(
+                Crowdfunding::invest_in_currency(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    PaymentCurrency::Asset(8),
+                    100,
+                ),
+                Error::<Test>::CurrencyNotAccepted
+            );
+
+            let unpriced = create_funded_campaign(ALICE, default_aon_config(100, 1000));
+            let currencies: BoundedVec<_, _> = vec![PaymentCurrency::Asset(8)].try_into().unwrap();
+            assert_ok!(Crowdfunding::set_accepted_currencies(
+                RuntimeOrigin::signed(ALICE),
+                unpriced,
+                currencies
+            ));
+            assert_noop!(
+                Crowdfunding::invest_in_currency(
+                    RuntimeOrigin::signed(BOB),
+                    unpriced,
+                    PaymentCurrency::Asset(8),
+                    100,
+                ),
+                Error::<Test>::PriceUnavailable
+            );
+        });
+    }
+
+    #[test]
+    fn accepted_currencies_are_validated_and_locked() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(100, 1000));
+            for currencies in [
+                vec![PaymentCurrency::Native],
+                vec![PaymentCurrency::Asset(USD), PaymentCurrency::Asset(USD)],
+            ] {
+                assert_noop!(
+                    Crowdfunding::set_accepted_currencies(
+                        RuntimeOrigin::signed(ALICE),
+                        id,
+                        currencies.try_into().unwrap()
+                    ),
+                    Error::<Test>::InvalidAcceptedCurrencies
+                );
+            }
+            assert_noop!(
+                Crowdfunding::set_accepted_currencies(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    BoundedVec::default()
+                ),
+                Error::<Test>::NotCampaignCreator
+            );
+
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_noop!(
+                Crowdfunding::set_accepted_currencies(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    BoundedVec::default()
+                ),
+                Error::<Test>::CurrenciesLocked
+            );
+        });
+    }
+
+    #[test]
+    fn refund_is_paid_in_contributed_currencies() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::invest_in_currency(
+                RuntimeOrigin::signed(BOB),
+                id,
+                PaymentCurrency::Asset(USD),
+                300,
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 200));
+
+            run_to_block(101);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+
+            assert_eq!(asset_balance(BOB), 5_000);
+            assert_eq!(Balances::free_balance(BOB), 10_000);
+            assert!(!pallet::InvestorHoldings::<Test>::contains_key(id, BOB));
+            assert!(!pallet::CampaignHoldings::<Test>::contains_key(id));
+        });
+    }
+
+    #[test]
+    fn claim_pays_creator_in_each_currency() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(100, 1000));
+            assert_ok!(Crowdfunding::invest_in_currency(
+                RuntimeOrigin::signed(BOB),
+                id,
+                PaymentCurrency::Asset(USD),
+                300,
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 400));
+
+            run_to_block(101);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            let native_before = Balances::free_balance(ALICE);
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+
+            assert_eq!(asset_balance(ALICE), 300);
+            assert_eq!(Balances::free_balance(ALICE), native_before + 400);
+            assert!(!pallet::CampaignHoldings::<Test>::contains_key(id));
+        });
+    }
+
+    #[test]
+    fn withdrawal_is_split_pro_rata() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(100, 2000));
+            assert_ok!(Crowdfunding::invest_in_currency(
+                RuntimeOrigin::signed(BOB),
+                id,
+                PaymentCurrency::Asset(USD),
+                300,
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 400));
+
+            // Half of a 1000 position: 150 USD (worth 300) and 200 native,
+            // each less the 1% penalty rounded up.
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 500));
+            assert_eq!(asset_balance(BOB), 5_000 - 300 + 148);
+            assert_eq!(Balances::free_balance(BOB), 10_000 - 400 + 198);
+            assert_eq!(
+                pallet::InvestorHoldings::<Test>::get(id, BOB).unwrap().to_vec(),
+                vec![CurrencyHolding {
+                    currency: PaymentCurrency::Asset(USD),
+                    amount: 150,
+                    normalized: 300
+                }]
+            );
+        });
+    }
+}
+
+// ── asset_backed_offering ───────────────────────────────────────────────
+
+mod asset_backed_offering {
+    use sp_runtime::Perbill;
+
+    use super::*;
+
+    const RWA: u32 = 0;
+
+    /// Creates a campaign licensed against `RWA`, which is valued at
+    /// 10_000 native units.
+    fn setup(config: CampaignConfigOf) -> u32 {
+        MockLicenseVerifier::set_license(RWA, 0, ALICE, true);
+        MockValuationProvider::set_valuation(RWA, PaymentCurrency::Native, 10_000);
+        let id = pallet::NextCampaignId::<Test>::get();
+        assert_ok!(Crowdfunding::create_campaign(
+            RuntimeOrigin::signed(ALICE),
+            config,
+            None,
+            Some((RWA, 0))
+        ));
+        id
+    }
+
+    /// Offering of 10% of `RWA` (goal 1_000), fully funded by BOB (600)
+    /// and CHARLIE (400), then finalized.
+    fn succeeded_offering() -> u32 {
+        let id = setup(default_aon_config(20, 1));
+        assert_ok!(Crowdfunding::configure_asset_offering(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            Perbill::from_percent(10)
+        ));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 400));
+        run_to_block(21);
+        assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+        id
+    }
+
+    #[test]
+    fn goal_and_hard_cap_follow_valuation() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(20, 1));
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Perbill::from_percent(25)
+            ));
+            System::assert_last_event(
+                Event::AssetOfferingConfigured {
+                    campaign_id: id,
+                    rwa_asset_id: RWA,
+                    fraction: Perbill::from_percent(25),
+                    goal: 2_500,
+                }
+                .into(),
+            );
+
+            let c = pallet::Campaigns::<Test>::get(id).unwrap();
+            assert_eq!(c.config.funding_model, FundingModel::AllOrNothing { goal: 2_500 });
+            assert_eq!(c.config.hard_cap, Some(2_500));
+            assert_eq!(pallet::CommittedAssetFraction::<Test>::get(RWA), Perbill::from_percent(25));
+            assert!(Crowdfunding::has_asset_obligations(RWA));
+        });
+    }
+
+    #[test]
+    fn valuation_is_converted_into_funding_currency() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(20, 1));
+            MockValuationProvider::set_valuation(RWA, PaymentCurrency::Asset(7), 10_000);
+            assert_noop!(
+                Crowdfunding::configure_asset_offering(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    Perbill::from_percent(10)
+                ),
+                Error::<Test>::ValuationUnavailable
+            );
+
+            MockPriceFeed::set_price(PaymentCurrency::Asset(7), PaymentCurrency::Native, 3, 1);
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Perbill::from_percent(10)
+            ));
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().config.hard_cap, Some(3_000));
+        });
+    }
+
+    #[test]
+    fn requires_linked_asset_and_goal_based_model() {
+        ExtBuilder::default().build().execute_with(|| {
+            let unlinked = create_funded_campaign(ALICE, default_aon_config(20, 1));
+            assert_noop!(
+                Crowdfunding::configure_asset_offering(
+                    RuntimeOrigin::signed(ALICE),
+                    unlinked,
+                    Perbill::from_percent(10)
+                ),
+                Error::<Test>::InvalidAssetOffering
+            );
+
+            let kwyr = setup(default_kwyr_config(20));
+            assert_noop!(
+                Crowdfunding::configure_asset_offering(
+                    RuntimeOrigin::signed(ALICE),
+                    kwyr,
+                    Perbill::from_percent(10)
+                ),
+                Error::<Test>::InvalidAssetOffering
+            );
+        });
+    }
+
+    #[test]
+    fn only_creator_before_first_investment() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(20, 1));
+            assert_noop!(
+                Crowdfunding::configure_asset_offering(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    Perbill::from_percent(10)
+                ),
+                Error::<Test>::NotCampaignCreator
+            );
+
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_noop!(
+                Crowdfunding::configure_asset_offering(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    Perbill::from_percent(10)
+                ),
+                Error::<Test>::OfferingLocked
+            );
+        });
+    }
+
+    #[test]
+    fn offerings_cannot_exceed_whole_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            let first = setup(default_aon_config(20, 1));
+            let second = setup(default_aon_config(20, 1));
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                first,
+                Perbill::from_percent(70)
+            ));
+            assert_noop!(
+                Crowdfunding::configure_asset_offering(
+                    RuntimeOrigin::signed(ALICE),
+                    second,
+                    Perbill::from_percent(40)
+                ),
+                Error::<Test>::AssetFractionExceeded
+            );
+
+            // Reconfiguring replaces the campaign's own commitment.
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                first,
+                Perbill::from_percent(60)
+            ));
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                second,
+                Perbill::from_percent(40)
+            ));
+            assert_eq!(pallet::CommittedAssetFraction::<Test>::get(RWA), Perbill::one());
+        });
+    }
+
+    #[test]
+    fn investors_claim_pro_rata_shares() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = succeeded_offering();
+            assert_ok!(Crowdfunding::claim_asset_share(RuntimeOrigin::signed(BOB), id));
+            System::assert_last_event(
+                Event::AssetShareIssued {
+                    campaign_id: id,
+                    rwa_asset_id: RWA,
+                    who: BOB,
+                    share: Perbill::from_percent(6),
+                }
+                .into(),
+            );
+            assert_noop!(
+                Crowdfunding::claim_asset_share(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::AlreadyClaimed
+            );
+            assert_noop!(
+                Crowdfunding::claim_asset_share(RuntimeOrigin::signed(DAVE), id),
+                Error::<Test>::NoInvestmentFound
+            );
+
+            assert_eq!(pallet::AssetShares::<Test>::get(RWA, BOB), Some(Perbill::from_percent(6)));
+            assert_eq!(pallet::IssuedAssetFraction::<Test>::get(RWA), Perbill::from_percent(6));
+            assert_eq!(pallet::CommittedAssetFraction::<Test>::get(RWA), Perbill::from_percent(4));
+        });
+    }
+
+    #[test]
+    fn early_bird_bonus_increases_share() {
+        ExtBuilder::default().build().execute_with(|| {
+            let mut config = default_aon_config(40, 1);
+            config.early_bird_bonus =
+                Some(BonusSchedule { bonus_bps: 5000, full_bonus_blocks: 10, decay_blocks: 10 });
+            let id = setup(config);
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Perbill::from_percent(10)
+            ));
+            // Same amounts, but only BOB's carries the 50 % bonus.
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 500));
+            run_to_block(25);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_eq!(pallet::TotalWeightedAllocations::<Test>::get(id), 1_250);
+            run_to_block(41);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+
+            assert_ok!(Crowdfunding::claim_asset_share(RuntimeOrigin::signed(BOB), id));
+            assert_ok!(Crowdfunding::claim_asset_share(RuntimeOrigin::signed(CHARLIE), id));
+            assert_eq!(pallet::AssetShares::<Test>::get(RWA, BOB), Some(Perbill::from_percent(6)));
+            assert_eq!(
+                pallet::AssetShares::<Test>::get(RWA, CHARLIE),
+                Some(Perbill::from_percent(4))
+            );
+        });
+    }
+
+    #[test]
+    fn shares_not_claimable_before_success() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(20, 1));
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Perbill::from_percent(10)
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+            assert_noop!(
+                Crowdfunding::claim_asset_share(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::InvalidCampaignStatus
+            );
+        });
+    }
+
+    #[test]
+    fn failed_offering_releases_its_fraction() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup(default_aon_config(20, 1));
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Perbill::from_percent(10)
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+
+            assert!(pallet::AssetOfferings::<Test>::get(id).is_none());
+            assert!(!Crowdfunding::has_asset_obligations(RWA));
+        });
+    }
+
+    #[test]
+    fn reaping_issues_unclaimed_shares() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = succeeded_offering();
+            assert_ok!(Crowdfunding::claim_asset_share(RuntimeOrigin::signed(BOB), id));
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(DAVE), id, 10));
+
+            assert_eq!(pallet::AssetShares::<Test>::get(RWA, BOB), Some(Perbill::from_percent(6)));
+            assert_eq!(
+                pallet::AssetShares::<Test>::get(RWA, CHARLIE),
+                Some(Perbill::from_percent(4))
+            );
+            assert!(pallet::AssetOfferings::<Test>::get(id).is_none());
+            assert_eq!(pallet::OfferingShareClaims::<Test>::iter_prefix(id).count(), 0);
+            assert!(pallet::CommittedAssetFraction::<Test>::get(RWA).is_zero());
+            assert_eq!(pallet::IssuedAssetFraction::<Test>::get(RWA), Perbill::from_percent(10));
+        });
+    }
+
+    #[test]
+    fn surrendering_shares_clears_obligations() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = succeeded_offering();
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(DAVE), id, 10));
+
+            assert_ok!(Crowdfunding::surrender_asset_share(RuntimeOrigin::signed(BOB), RWA));
+            System::assert_last_event(
+                Event::AssetShareSurrendered {
+                    rwa_asset_id: RWA,
+                    who: BOB,
+                    share: Perbill::from_percent(6),
+                }
+                .into(),
+            );
+            assert!(Crowdfunding::has_asset_obligations(RWA));
+            assert_ok!(Crowdfunding::surrender_asset_share(RuntimeOrigin::signed(CHARLIE), RWA));
+            assert!(!Crowdfunding::has_asset_obligations(RWA));
+            assert_noop!(
+                Crowdfunding::surrender_asset_share(RuntimeOrigin::signed(BOB), RWA),
+                Error::<Test>::NoAssetShare
+            );
+        });
+    }
+}
+
+// ── stretch_goals_and_rounds ────────────────────────────────────────────
+
+mod stretch_goals_and_rounds {
+    use super::*;
+
+    fn goal(threshold: u128) -> StretchGoal<u128> {
+        StretchGoal { threshold, description_hash: [7u8; 32] }
+    }
+
+    fn goals(
+        thresholds: &[u128],
+    ) -> BoundedVec<StretchGoal<u128>, <Test as Config>::MaxStretchGoals> {
+        thresholds.iter().map(|t| goal(*t)).collect::<Vec<_>>().try_into().unwrap()
+    }
+
+    fn rounds(
+        rounds: &[(u64, u128, Option<u128>)],
+    ) -> BoundedVec<pallet::FundingRoundOf<Test>, <Test as Config>::MaxFundingRounds> {
+        rounds
+            .iter()
+            .map(|(deadline, cap, min_investment)| FundingRound {
+                deadline: *deadline,
+                cap: *cap,
+                min_investment: *min_investment,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn stretch_goals_are_reached_in_order() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            assert_ok!(Crowdfunding::set_stretch_goals(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                goals(&[1_500, 2_000, 3_000])
+            ));
+            System::assert_last_event(Event::StretchGoalsSet { campaign_id: id, count: 3 }.into());
+
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_200));
+            assert_eq!(pallet::StretchGoalsReached::<Test>::get(id), 0);
+
+            // A single investment can cross several goals.
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 1_000));
+            System::assert_has_event(
+                Event::StretchGoalReached { campaign_id: id, index: 0, threshold: 1_500 }.into(),
+            );
+            System::assert_has_event(
+                Event::StretchGoalReached { campaign_id: id, index: 1, threshold: 2_000 }.into(),
+            );
+            assert_eq!(pallet::StretchGoalsReached::<Test>::get(id), 2);
+
+            // Withdrawing does not un-reach a goal.
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_eq!(pallet::StretchGoalsReached::<Test>::get(id), 2);
+        });
+    }
+
+    #[test]
+    fn stretch_goals_must_ascend_above_goal() {
+        ExtBuilder::default().build().execute_with(|| {
+            let mut config = default_aon_config(20, 1_000);
+            config.hard_cap = Some(2_000);
+            let id = create_funded_campaign(ALICE, config);
+            for bad in [&[1_000][..], &[1_500, 1_500], &[1_800, 1_600], &[2_500]] {
+                assert_noop!(
+                    Crowdfunding::set_stretch_goals(RuntimeOrigin::signed(ALICE), id, goals(bad)),
+                    Error::<Test>::InvalidStretchGoals
+                );
+            }
+            assert_noop!(
+                Crowdfunding::set_stretch_goals(RuntimeOrigin::signed(BOB), id, goals(&[1_500])),
+                Error::<Test>::NotCampaignCreator
+            );
+        });
+    }
+
+    #[test]
+    fn reached_goals_cannot_change() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            assert_ok!(Crowdfunding::set_stretch_goals(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                goals(&[1_500])
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_600));
+
+            assert_noop!(
+                Crowdfunding::set_stretch_goals(RuntimeOrigin::signed(ALICE), id, goals(&[])),
+                Error::<Test>::InvalidStretchGoals
+            );
+            assert_noop!(
+                Crowdfunding::set_stretch_goals(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    goals(&[1_400, 2_000])
+                ),
+                Error::<Test>::InvalidStretchGoals
+            );
+            // New goals must still be ahead of the amount raised.
+            assert_noop!(
+                Crowdfunding::set_stretch_goals(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    goals(&[1_500, 1_550])
+                ),
+                Error::<Test>::InvalidStretchGoals
+            );
+            assert_ok!(Crowdfunding::set_stretch_goals(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                goals(&[1_500, 2_500])
+            ));
+            assert_eq!(pallet::StretchGoals::<Test>::get(id).unwrap().len(), 2);
+        });
+    }
+
+    #[test]
+    fn rounds_enforce_cap_and_minimum() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(20));
+            assert_ok!(Crowdfunding::set_funding_rounds(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                rounds(&[(10, 500, Some(100)), (20, 2_000, None)])
+            ));
+            System::assert_last_event(Event::FundingRoundsSet { campaign_id: id, count: 2 }.into());
+            assert_eq!(Crowdfunding::active_funding_round(id, 1).map(|(i, _)| i), Some(0));
+
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 50),
+                Error::<Test>::InvestmentBelowMinimum
+            );
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 400));
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 200),
+                Error::<Test>::RoundCapExceeded
+            );
+
+            // The second round lifts the cap and drops the minimum.
+            run_to_block(11);
+            assert_eq!(Crowdfunding::active_funding_round(id, 11).map(|(i, _)| i), Some(1));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 50));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 1_550));
+            assert_noop!(
+                Crowdfunding::invest(RuntimeOrigin::signed(DAVE), id, 1),
+                Error::<Test>::RoundCapExceeded
+            );
+        });
+    }
+
+    #[test]
+    fn rounds_are_validated() {
+        ExtBuilder::default().build().execute_with(|| {
+            let mut config = default_kwyr_config(20);
+            config.hard_cap = Some(1_000);
+            let id = create_funded_campaign(ALICE, config);
+            for bad in [
+                &[(10, 500, None), (15, 1_000, None)][..],
+                &[(10, 500, None), (10, 1_000, None), (20, 1_000, None)],
+                &[(10, 500, None), (20, 500, None)],
+                &[(10, 0, None), (20, 500, None)],
+                &[(20, 2_000, None)],
+                &[(1, 100, None), (20, 500, None)],
+            ] {
+                assert_noop!(
+                    Crowdfunding::set_funding_rounds(RuntimeOrigin::signed(ALICE), id, rounds(bad)),
+                    Error::<Test>::InvalidFundingRounds
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn rounds_lock_after_first_investment() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(20));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_noop!(
+                Crowdfunding::set_funding_rounds(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    rounds(&[(20, 500, None)])
+                ),
+                Error::<Test>::FundingRoundsLocked
+            );
+        });
+    }
+
+    #[test]
+    fn resume_extends_open_rounds() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(20));
+            assert_ok!(Crowdfunding::set_funding_rounds(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                rounds(&[(5, 500, None), (10, 1_000, None), (20, 2_000, None)])
+            ));
+            run_to_block(6);
+            assert_ok!(Crowdfunding::pause_campaign(RuntimeOrigin::root(), id));
+            run_to_block(9);
+            assert_ok!(Crowdfunding::resume_campaign(RuntimeOrigin::root(), id));
+
+            let deadlines: Vec<u64> = pallet::FundingRounds::<Test>::get(id)
+                .unwrap()
+                .iter()
+                .map(|r| r.deadline)
+                .collect();
+            assert_eq!(deadlines, vec![5, 13, 23]);
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().config.deadline, 23);
+        });
+    }
+}
+
+// ── creator_bond ────────────────────────────────────────────────────────
+
+mod creator_bond {
+    use super::*;
+
+    /// Milestone campaign with goal 1_000 (60% / 40% milestones) under a
+    /// 10% creator bond, funded by BOB (600) and CHARLIE (400).
+    fn setup() -> u32 {
+        assert_ok!(Crowdfunding::set_creator_bond_bps(RuntimeOrigin::root(), 1_000));
+        let config = milestone_config(
+            20,
+            1_000,
+            vec![
+                Milestone { release_bps: 6000, description_hash: [1u8; 32] },
+                Milestone { release_bps: 4000, description_hash: [2u8; 32] },
+            ],
+        );
+        create_funded_campaign(ALICE, config)
+    }
+
+    fn in_milestone_phase() -> u32 {
+        let id = setup();
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 400));
+        run_to_block(21);
+        assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+        id
+    }
+
+    fn approve(id: u32, index: u8) {
+        assert_ok!(Crowdfunding::submit_milestone(RuntimeOrigin::signed(ALICE), id, index));
+        assert_ok!(Crowdfunding::approve_milestone(RuntimeOrigin::root(), id, index));
+    }
+
+    #[test]
+    fn bond_is_locked_at_creation() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup();
+            System::assert_last_event(
+                Event::CreatorBondLocked { campaign_id: id, amount: 100 }.into(),
+            );
+            assert_eq!(Balances::free_balance(ALICE), 10_000 - 100 - 100);
+            assert_eq!(Balances::free_balance(Crowdfunding::campaign_account(id)), 200);
+            assert_eq!(pallet::CreatorBonds::<Test>::get(id).unwrap().amount, 100);
+
+            // Only milestone campaigns lock a bond.
+            let aon = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            assert!(pallet::CreatorBonds::<Test>::get(aon).is_none());
+        });
+    }
+
+    #[test]
+    fn approvals_release_bond_progressively() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = in_milestone_phase();
+            let before = Balances::free_balance(ALICE);
+
+            approve(id, 0);
+            System::assert_has_event(
+                Event::CreatorBondReleased { campaign_id: id, amount: 60 }.into(),
+            );
+            assert_eq!(Balances::free_balance(ALICE), before + 60);
+
+            approve(id, 1);
+            assert_eq!(Balances::free_balance(ALICE), before + 100);
+            assert_eq!(pallet::CreatorBonds::<Test>::get(id).unwrap().released, 100);
+        });
+    }
+
+    #[test]
+    fn cancel_in_milestone_phase_slashes_to_investors() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = in_milestone_phase();
+            approve(id, 0);
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            System::assert_has_event(
+                Event::CreatorBondSlashed { campaign_id: id, amount: 40 }.into(),
+            );
+
+            let bob_before = Balances::free_balance(BOB);
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            System::assert_last_event(
+                Event::RefundClaimed { campaign_id: id, investor: BOB, amount: 600 }.into(),
+            );
+            System::assert_has_event(
+                Event::BondShareRefunded { campaign_id: id, investor: BOB, amount: 24 }.into(),
+            );
+            assert_eq!(Balances::free_balance(BOB), bob_before + 600 + 24);
+
+            let charlie_before = Balances::free_balance(CHARLIE);
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(CHARLIE), id));
+            assert_eq!(Balances::free_balance(CHARLIE), charlie_before + 400 + 16);
+
+            // Nothing of the bond is left for the creator.
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(Balances::free_balance(ALICE), alice_before + 100);
+        });
+    }
+
+    #[test]
+    fn bulk_refund_rolls_back_when_bond_share_transfer_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup();
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_000));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+
+            // Drain the bond pot: the investment refund still goes through,
+            // the bond share that follows it does not.
+            let account = Crowdfunding::campaign_account(id);
+            let _ = Balances::make_free_balance_be(&account, 1_000);
+            let bob_before = Balances::free_balance(BOB);
+            assert_ok!(Crowdfunding::process_refunds(RuntimeOrigin::signed(CHARLIE), id, 10));
+            System::assert_last_event(
+                Event::RefundsProcessed { campaign_id: id, refunded: 0, completed: true }.into(),
+            );
+            assert_eq!(Balances::free_balance(BOB), bob_before);
+            assert_eq!(Balances::free_balance(&account), 1_000);
+            assert!(pallet::Investments::<Test>::contains_key(id, BOB));
+            assert_eq!(pallet::CreatorBonds::<Test>::get(id).unwrap().refunded, 0);
+
+            // Once the pot is topped up the investor claims the full refund.
+            let _ = Balances::make_free_balance_be(&account, 1_200);
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(Balances::free_balance(BOB), bob_before + 1_000 + 100);
+        });
+    }
+
+    #[test]
+    fn bond_is_shared_over_positions_not_campaign_pot() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup();
+            let schedule = PenaltySchedule {
+                cooling_off: 0,
+                steps: vec![PenaltyStep { within: 1_000, penalty_bps: 1_000 }].try_into().unwrap(),
+            };
+            assert_ok!(Crowdfunding::set_withdrawal_penalty(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Some(schedule),
+                PenaltyDestination::CampaignPot
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 960));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(DAVE), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(DAVE), id, 500));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            approve(id, 0);
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+
+            // BOB holds every position, so the whole slashed bond is his.
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            System::assert_has_event(
+                Event::BondShareRefunded { campaign_id: id, investor: BOB, amount: 40 }.into(),
+            );
+            let bond = pallet::CreatorBonds::<Test>::get(id).unwrap();
+            assert_eq!(bond.refunded, bond.slashed);
+        });
+    }
+
+    #[test]
+    fn failed_campaign_returns_bond() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = setup();
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 300));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(Balances::free_balance(BOB), 10_000);
+
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(Balances::free_balance(ALICE), 10_000);
+        });
+    }
+
+    #[test]
+    fn set_creator_bond_bps_is_validated() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_noop!(
+                Crowdfunding::set_creator_bond_bps(RuntimeOrigin::signed(ALICE), 1_000),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Crowdfunding::set_creator_bond_bps(RuntimeOrigin::root(), 10_001),
+                Error::<Test>::InvalidBondBps
+            );
+        });
+    }
+}
+
+// ── vesting ─────────────────────────────────────────────────────────────
+
+mod vesting {
+    use super::*;
+
+    fn schedule(cliff: u64, duration: u64) -> Option<VestingScheduleOf<Test>> {
+        Some(VestingSchedule { cliff, duration })
+    }
+
+    /// AllOrNothing campaign (goal 1_000, deadline 20) vesting over 100
+    /// blocks with a 10-block cliff, fully funded by BOB and finalized.
+    fn vesting_campaign(license: Option<(u32, u32)>) -> u32 {
+        let id = pallet::NextCampaignId::<Test>::get();
+        assert_ok!(Crowdfunding::create_campaign(
+            RuntimeOrigin::signed(ALICE),
+            default_aon_config(20, 1_000),
+            None,
+            license
+        ));
+        assert_ok!(Crowdfunding::set_vesting_schedule(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            schedule(10, 100)
+        ));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_000));
+        run_to_block(21);
+        assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+        id
+    }
+
+    #[test]
+    fn claims_follow_schedule() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = vesting_campaign(None);
+            let before = Balances::free_balance(ALICE);
+
+            run_to_block(25);
+            assert_noop!(
+                Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id),
+                Error::<Test>::NothingToClaim
+            );
+
+            run_to_block(40);
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(Balances::free_balance(ALICE), before + 200);
+            let c = pallet::Campaigns::<Test>::get(id).unwrap();
+            assert_eq!(c.status, CampaignStatus::Succeeded);
+            assert_eq!(c.total_disbursed, 200);
+
+            run_to_block(120);
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(Balances::free_balance(ALICE), before + 1_000);
+            assert_eq!(
+                pallet::Campaigns::<Test>::get(id).unwrap().status,
+                CampaignStatus::Completed
+            );
+        });
+    }
+
+    #[test]
+    fn vesting_status_reports_progress() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = vesting_campaign(None);
+            run_to_block(45);
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            run_to_block(70);
+            assert_eq!(
+                Crowdfunding::vesting_status(id),
+                Some(VestingStatus {
+                    schedule: VestingSchedule { cliff: 10, duration: 100 },
+                    start: 20,
+                    total: 1_000,
+                    vested: 500,
+                    claimed: 250,
+                    claimable: 250,
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_during_vesting_refunds_unvested_remainder() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = vesting_campaign(None);
+            let alice_before = Balances::free_balance(ALICE);
+            run_to_block(70);
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            // The vested half goes to the creator.
+            assert_eq!(Balances::free_balance(ALICE), alice_before + 500);
+
+            let bob_before = Balances::free_balance(BOB);
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(Balances::free_balance(BOB), bob_before + 500);
+        });
+    }
+
+    #[test]
+    fn license_revocation_during_vesting_refunds_unvested_remainder() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockLicenseVerifier::set_license(0, 0, ALICE, true);
+            let id = vesting_campaign(Some((0, 0)));
+            run_to_block(45);
+            MockLicenseVerifier::set_license(0, 0, ALICE, false);
+            assert_ok!(Crowdfunding::report_license_revoked(RuntimeOrigin::signed(CHARLIE), id));
+            assert_eq!(
+                pallet::Campaigns::<Test>::get(id).unwrap().status,
+                CampaignStatus::Cancelled
+            );
+
+            let bob_before = Balances::free_balance(BOB);
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(Balances::free_balance(BOB), bob_before + 750);
+        });
+    }
+
+    #[test]
+    fn reward_nft_claim_waits_until_vesting_ends() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockNftInspect::set_collection_owner(7, ALICE);
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            let tier = RewardTier {
+                min_amount: 100,
+                quantity: 2,
+                remaining: 0,
+                description_hash: [7u8; 32],
+                nft_collection: Some(7),
+            };
+            assert_ok!(Crowdfunding::set_reward_tiers(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                BoundedVec::try_from(vec![tier]).unwrap(),
+            ));
+            assert_ok!(Crowdfunding::set_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                schedule(10, 100)
+            ));
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 1_000, 0));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+
+            assert_noop!(
+                Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::CampaignStillCancellable
+            );
+
+            run_to_block(120);
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(NFT_OWNERS.with(|m| m.borrow().get(&(7, 0)).copied()), Some(BOB));
+        });
+    }
+
+    #[test]
+    fn cancel_during_vesting_leaves_no_reward_or_share_behind() {
+        ExtBuilder::default().build().execute_with(|| {
+            MockNftInspect::set_collection_owner(7, ALICE);
+            MockLicenseVerifier::set_license(0, 0, ALICE, true);
+            MockValuationProvider::set_valuation(0, PaymentCurrency::Native, 10_000);
+            let id = pallet::NextCampaignId::<Test>::get();
+            assert_ok!(Crowdfunding::create_campaign(
+                RuntimeOrigin::signed(ALICE),
+                default_aon_config(20, 1),
+                None,
+                Some((0, 0))
+            ));
+            assert_ok!(Crowdfunding::configure_asset_offering(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                sp_runtime::Perbill::from_percent(10)
+            ));
+            let tier = RewardTier {
+                min_amount: 100,
+                quantity: 2,
+                remaining: 0,
+                description_hash: [7u8; 32],
+                nft_collection: Some(7),
+            };
+            assert_ok!(Crowdfunding::set_reward_tiers(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                BoundedVec::try_from(vec![tier]).unwrap(),
+            ));
+            assert_ok!(Crowdfunding::set_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                schedule(10, 100)
+            ));
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 1_000, 0));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+
+            // Claim → cancel → refund must not leave BOB with both the refund
+            // and the share or reward.
+            assert_noop!(
+                Crowdfunding::claim_asset_share(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::CampaignStillCancellable
+            );
+            assert_noop!(
+                Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::CampaignStillCancellable
+            );
+            run_to_block(70);
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            let bob_before = Balances::free_balance(BOB);
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(Balances::free_balance(BOB), bob_before + 500);
+
+            assert_noop!(
+                Crowdfunding::claim_asset_share(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::InvalidCampaignStatus
+            );
+            assert_noop!(
+                Crowdfunding::claim_reward_nft(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::InvalidCampaignStatus
+            );
+            assert!(pallet::AssetShares::<Test>::get(0, BOB).is_none());
+            assert!(NFT_OWNERS.with(|m| m.borrow().is_empty()));
+        });
+    }
+
+    #[test]
+    fn succeeded_without_vesting_cannot_be_cancelled() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_000));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_noop!(
+                Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id),
+                Error::<Test>::InvalidCampaignStatus
+            );
+        });
+    }
+
+    #[test]
+    fn schedule_is_validated() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(20));
+            for bad in [schedule(0, 0), schedule(20, 10)] {
+                assert_noop!(
+                    Crowdfunding::set_vesting_schedule(RuntimeOrigin::signed(ALICE), id, bad),
+                    Error::<Test>::InvalidVestingSchedule
+                );
+            }
+
+            let milestones = create_funded_campaign(
+                ALICE,
+                milestone_config(
+                    20,
+                    1_000,
+                    vec![Milestone { release_bps: 10_000, description_hash: [1u8; 32] }],
+                ),
+            );
+            assert_noop!(
+                Crowdfunding::set_vesting_schedule(
+                    RuntimeOrigin::signed(ALICE),
+                    milestones,
+                    schedule(0, 10)
+                ),
+                Error::<Test>::InvalidVestingSchedule
+            );
+
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_noop!(
+                Crowdfunding::set_vesting_schedule(RuntimeOrigin::signed(ALICE), id, None),
+                Error::<Test>::VestingLocked
+            );
+        });
+    }
+}
+
+// ── withdrawal_penalty_schedule ─────────────────────────────────────────
+
+mod withdrawal_penalty_schedule {
+    use frame_support::{traits::Hooks, weights::Weight};
+
+    use super::*;
+
+    /// Free for 10 blocks after creation, then 5 % within 50 blocks of the
+    /// deadline and 20 % within 10 blocks.
+    fn schedule() -> Option<crate::pallet::PenaltyScheduleOf<Test>> {
+        Some(PenaltySchedule {
+            cooling_off: 10,
+            steps: vec![
+                PenaltyStep { within: 50, penalty_bps: 500 },
+                PenaltyStep { within: 10, penalty_bps: 2_000 },
+            ]
+            .try_into()
+            .unwrap(),
+        })
+    }
+
+    /// Flat 10 % penalty, routed to `destination`.
+    fn flat_ten_percent(id: u32, destination: PenaltyDestination) {
+        let schedule = PenaltySchedule {
+            cooling_off: 0,
+            steps: vec![PenaltyStep { within: 1_000, penalty_bps: 1_000 }].try_into().unwrap(),
+        };
+        assert_ok!(Crowdfunding::set_withdrawal_penalty(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            Some(schedule),
+            destination
+        ));
+    }
+
+    #[test]
+    fn penalty_follows_time_remaining() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(100));
+            assert_ok!(Crowdfunding::set_withdrawal_penalty(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                schedule(),
+                PenaltyDestination::Burn
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_000));
+            let campaign = pallet::Campaigns::<Test>::get(id).unwrap();
+
+            // (block, bps, net received for withdrawing 100)
+            for (block, bps, net) in [(5, 0, 100), (20, 100, 99), (60, 500, 95), (95, 2_000, 80)] {
+                run_to_block(block);
+                assert_eq!(Crowdfunding::withdrawal_penalty_bps(id, &campaign, &BOB, block), bps);
+                let before = Balances::free_balance(BOB);
+                assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 100));
+                assert_eq!(Balances::free_balance(BOB), before + net);
+            }
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().total_raised, 600);
+        });
+    }
+
+    #[test]
+    fn cooling_off_runs_from_each_investment() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(100));
+            assert_ok!(Crowdfunding::set_withdrawal_penalty(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                schedule(),
+                PenaltyDestination::Burn
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_000));
+            run_to_block(30);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 1_000));
+
+            run_to_block(35);
+            let campaign = pallet::Campaigns::<Test>::get(id).unwrap();
+            assert_eq!(Crowdfunding::withdrawal_penalty_bps(id, &campaign, &BOB, 35), 100);
+            assert_eq!(Crowdfunding::withdrawal_penalty_bps(id, &campaign, &CHARLIE, 35), 0);
+
+            // Topping up does not restart BOB's cooling-off.
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_eq!(Crowdfunding::withdrawal_penalty_bps(id, &campaign, &BOB, 35), 100);
+        });
+    }
+
+    #[test]
+    fn transfer_keeps_senders_cooling_off() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(100));
+            assert_ok!(Crowdfunding::set_withdrawal_penalty(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                schedule(),
+                PenaltyDestination::Burn
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_000));
+            run_to_block(35);
+            assert_ok!(Crowdfunding::transfer_investment(
+                RuntimeOrigin::signed(BOB),
+                id,
+                DAVE,
+                1_000
+            ));
+
+            let campaign = pallet::Campaigns::<Test>::get(id).unwrap();
+            assert_eq!(Crowdfunding::withdrawal_penalty_bps(id, &campaign, &DAVE, 35), 100);
+            assert!(pallet::InvestedAt::<Test>::get(id, BOB).is_none());
+            let before = Balances::free_balance(DAVE);
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(DAVE), id, 100));
+            assert_eq!(Balances::free_balance(DAVE), before + 99);
+        });
+    }
+
+    #[test]
+    fn penalty_can_go_to_protocol_fee_recipient() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(100));
+            flat_ten_percent(id, PenaltyDestination::ProtocolFeeRecipient);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_000));
+
+            let recipient = Crowdfunding::effective_protocol_fee_recipient();
+            let before = Balances::free_balance(recipient);
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(BOB), id, 500));
+            assert_eq!(Balances::free_balance(recipient), before + 50);
+            System::assert_has_event(RuntimeEvent::Crowdfunding(Event::WithdrawalPenaltyApplied {
+                campaign_id: id,
+                investor: BOB,
+                amount: 50,
+                destination: PenaltyDestination::ProtocolFeeRecipient,
+            }));
+        });
+    }
+
+    #[test]
+    fn remaining_investors_share_penalties_of_successful_campaign() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            flat_ten_percent(id, PenaltyDestination::RemainingInvestors);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(DAVE), id, 400));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().total_raised, 1_000);
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(Balances::free_balance(ALICE), alice_before + 1_000);
+
+            let bob_before = Balances::free_balance(BOB);
+            assert_ok!(Crowdfunding::claim_penalty_share(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(Balances::free_balance(BOB), bob_before + 30);
+            assert_noop!(
+                Crowdfunding::claim_penalty_share(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::AlreadyClaimed
+            );
+            assert_noop!(
+                Crowdfunding::claim_penalty_share(RuntimeOrigin::signed(CHARLIE), id),
+                Error::<Test>::NoPenaltyShare
+            );
+
+            // DAVE's unclaimed share is paid when the campaign is reaped.
+            let dave_before = Balances::free_balance(DAVE);
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10));
+            assert_eq!(Balances::free_balance(DAVE), dave_before + 20);
+            assert!(pallet::PenaltyPots::<Test>::get(id).is_none());
+        });
+    }
+
+    #[test]
+    fn reaps_even_when_penalty_share_payment_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            flat_ten_percent(id, PenaltyDestination::RemainingInvestors);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(DAVE), id, 400));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(CHARLIE), id, 500));
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+
+            // Crediting DAVE's 20 would overflow their balance.
+            let _ = Balances::make_free_balance_be(&DAVE, u128::MAX - 5);
+            let bob_before = Balances::free_balance(BOB);
+            let recipient = Crowdfunding::effective_protocol_fee_recipient();
+            let recipient_before = Balances::free_balance(recipient);
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10));
+            System::assert_has_event(RuntimeEvent::Crowdfunding(
+                Event::PenaltySharePaymentFailed { campaign_id: id, investor: DAVE },
+            ));
+            assert_eq!(Balances::free_balance(BOB), bob_before + 30);
+            assert_eq!(Balances::free_balance(DAVE), u128::MAX - 5);
+            // The unpaid share is swept with the rest of the pot.
+            assert_eq!(Balances::free_balance(recipient), recipient_before + 20);
+            assert!(pallet::ArchivedCampaigns::<Test>::contains_key(id));
+        });
+    }
+
+    #[test]
+    fn remaining_investors_share_is_refunded_when_campaign_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            flat_ten_percent(id, PenaltyDestination::RemainingInvestors);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(CHARLIE), id, 500));
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            let bob_before = Balances::free_balance(BOB);
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert_eq!(Balances::free_balance(BOB), bob_before + 650);
+        });
+    }
+
+    #[test]
+    fn campaign_pot_counts_towards_goal() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            flat_ten_percent(id, PenaltyDestination::CampaignPot);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 960));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().total_raised, 1_010);
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(Balances::free_balance(ALICE), alice_before + 1_010);
+        });
+    }
+
+    #[test]
+    fn campaign_pot_is_refunded_when_campaign_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            flat_ten_percent(id, PenaltyDestination::CampaignPot);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 300));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(DAVE), id, 200));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(CHARLIE), id, 500));
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            let bob_before = Balances::free_balance(BOB);
+            let dave_before = Balances::free_balance(DAVE);
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(BOB), id));
+            assert_ok!(Crowdfunding::claim_refund(RuntimeOrigin::signed(DAVE), id));
+            assert_eq!(Balances::free_balance(BOB), bob_before + 330);
+            assert_eq!(Balances::free_balance(DAVE), dave_before + 220);
+        });
+    }
+
+    #[test]
+    fn campaign_pot_is_refunded_when_campaign_is_cancelled() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            flat_ten_percent(id, PenaltyDestination::CampaignPot);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 300));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(DAVE), id, 200));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(CHARLIE), id, 500));
+
+            let bob_before = Balances::free_balance(BOB);
+            let dave_before = Balances::free_balance(DAVE);
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), id));
+            // Three records, two refunded per block.
+            Crowdfunding::on_idle(1, Weight::MAX);
+            Crowdfunding::on_idle(2, Weight::MAX);
+            assert_eq!(Balances::free_balance(BOB), bob_before + 330);
+            assert_eq!(Balances::free_balance(DAVE), dave_before + 220);
+
+            // Nothing of the pot is left for the protocol fee recipient.
+            let recipient = Crowdfunding::effective_protocol_fee_recipient();
+            let recipient_before = Balances::free_balance(recipient);
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10));
+            assert_eq!(Balances::free_balance(recipient), recipient_before);
+            assert!(pallet::PenaltyPots::<Test>::get(id).is_none());
+        });
+    }
+
+    #[test]
+    fn unshared_campaign_pot_is_swept_when_campaign_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1_000));
+            flat_ten_percent(id, PenaltyDestination::CampaignPot);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 500));
+            assert_ok!(Crowdfunding::withdraw_investment(RuntimeOrigin::signed(CHARLIE), id, 500));
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            Crowdfunding::on_idle(21, Weight::MAX);
+            let recipient = Crowdfunding::effective_protocol_fee_recipient();
+            let recipient_before = Balances::free_balance(recipient);
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10));
+            assert_eq!(Balances::free_balance(recipient), recipient_before + 50);
+        });
+    }
+
+    #[test]
+    fn terms_are_validated() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(100));
+            let bad_steps = [
+                vec![
+                    PenaltyStep { within: 10, penalty_bps: 100 },
+                    PenaltyStep { within: 10, penalty_bps: 200 },
+                ],
+                vec![PenaltyStep { within: 10, penalty_bps: 10_001 }],
+            ];
+            for steps in bad_steps {
+                let schedule = PenaltySchedule { cooling_off: 0, steps: steps.try_into().unwrap() };
+                assert_noop!(
+                    Crowdfunding::set_withdrawal_penalty(
+                        RuntimeOrigin::signed(ALICE),
+                        id,
+                        Some(schedule),
+                        PenaltyDestination::Burn
+                    ),
+                    Error::<Test>::InvalidPenaltySchedule
+                );
+            }
+            assert_noop!(
+                Crowdfunding::set_withdrawal_penalty(
+                    RuntimeOrigin::signed(BOB),
+                    id,
+                    schedule(),
+                    PenaltyDestination::Burn
+                ),
+                Error::<Test>::NotCampaignCreator
+            );
+
+            // Retained penalties and alternative currencies exclude each other.
+            assert_ok!(Crowdfunding::set_withdrawal_penalty(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                schedule(),
+                PenaltyDestination::RemainingInvestors
+            ));
+            let currencies: BoundedVec<_, _> = vec![PaymentCurrency::Asset(7)].try_into().unwrap();
+            assert_noop!(
+                Crowdfunding::set_accepted_currencies(RuntimeOrigin::signed(ALICE), id, currencies),
+                Error::<Test>::PenaltyDestinationUnsupported
+            );
+
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_noop!(
+                Crowdfunding::set_withdrawal_penalty(
+                    RuntimeOrigin::signed(ALICE),
+                    id,
+                    None,
+                    PenaltyDestination::Burn
+                ),
+                Error::<Test>::PenaltyLocked
+            );
+        });
+    }
+}
+
+// ── investment_transfers ────────────────────────────────────────────────
+
+mod investment_transfers {
+    use super::*;
+
+    fn position(id: u32, who: u64) -> u128 {
+        pallet::Investments::<Test>::get(id, who)
+            .map_or(0, |inv| inv.total_invested - inv.total_withdrawn)
+    }
+
+    #[test]
+    fn partial_transfer_adds_recipient() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(100));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+            assert_ok!(Crowdfunding::transfer_investment(
+                RuntimeOrigin::signed(BOB),
+                id,
+                CHARLIE,
+                200
+            ));
+
+            assert_eq!(position(id, BOB), 400);
+            assert_eq!(position(id, CHARLIE), 200);
+            let campaign = pallet::Campaigns::<Test>::get(id).unwrap();
+            assert_eq!(campaign.total_raised, 600);
+            assert_eq!(campaign.investor_count, 2);
+            assert!(pallet::InvestorCampaigns::<Test>::get(CHARLIE).contains(&id));
+            System::assert_last_event(RuntimeEvent::Crowdfunding(Event::InvestmentTransferred {
+                campaign_id: id,
+                from: BOB,
+                to: CHARLIE,
+                amount: 200,
+            }));
+        });
+    }
+
+    #[test]
+    fn whole_transfer_to_existing_investor_merges_positions() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(100));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 600));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 100));
+            assert_ok!(Crowdfunding::transfer_investment(
+                RuntimeOrigin::signed(BOB),
+                id,
+                CHARLIE,
+                600
+            ));
+
+            assert_eq!(position(id, BOB), 0);
+            assert_eq!(position(id, CHARLIE), 700);
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().investor_count, 1);
+            assert!(!pallet::InvestorCampaigns::<Test>::get(BOB).contains(&id));
+
+            // BOB can re-invest as a new investor.
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 50));
+            assert_eq!(pallet::Campaigns::<Test>::get(id).unwrap().investor_count, 2);
+        });
+    }
+
+    #[test]
+    fn recipient_must_qualify_as_investor() {
+        ExtBuilder::default().build().execute_with(|| {
+            let rules: BoundedVec<_, _> =
+                vec![EligibilityRule::NativeBalance { min_balance: 9000 }].try_into().unwrap();
+            let mut config = default_kwyr_config(100);
+            config.max_investment_per_investor = Some(500);
+            let id = pallet::NextCampaignId::<Test>::get();
+            assert_ok!(Crowdfunding::create_campaign(
+                RuntimeOrigin::signed(ALICE),
+                config,
+                Some(rules),
+                None,
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 400));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 400));
+
+            let _ = Balances::deposit_creating(&99u64, 500);
+            assert_noop!(
+                Crowdfunding::transfer_investment(RuntimeOrigin::signed(BOB), id, 99, 100),
+                Error::<Test>::EligibilityCheckFailed
+            );
+            assert_noop!(
+                Crowdfunding::transfer_investment(RuntimeOrigin::signed(BOB), id, CHARLIE, 200),
+                Error::<Test>::InvestmentExceedsMaxPerInvestor
+            );
+            assert_noop!(
+                Crowdfunding::transfer_investment(RuntimeOrigin::signed(BOB), id, BOB, 100),
+                Error::<Test>::InvalidTransferRecipient
+            );
+            assert_noop!(
+                Crowdfunding::transfer_investment(RuntimeOrigin::signed(BOB), id, DAVE, 401),
+                Error::<Test>::InsufficientInvestment
+            );
+
+            let full: BoundedVec<_, _> = (100..105).collect::<Vec<u32>>().try_into().unwrap();
+            pallet::InvestorCampaigns::<Test>::insert(DAVE, full);
+            assert_noop!(
+                Crowdfunding::transfer_investment(RuntimeOrigin::signed(BOB), id, DAVE, 100),
+                Error::<Test>::MaxInvestmentsPerInvestorReached
+            );
+        });
+    }
+
+    #[test]
+    fn lockup_delays_transfers_in_milestone_phase() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(
+                ALICE,
+                milestone_config(
+                    20,
+                    1_000,
+                    vec![Milestone { release_bps: 10_000, description_hash: [1u8; 32] }],
+                ),
+            );
+            assert_ok!(Crowdfunding::set_transfer_lockup(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Some(30)
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 1_000));
+            assert_noop!(
+                Crowdfunding::set_transfer_lockup(RuntimeOrigin::signed(ALICE), id, None),
+                Error::<Test>::TransferLockupLocked
+            );
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(
+                pallet::Campaigns::<Test>::get(id).unwrap().status,
+                CampaignStatus::MilestonePhase
+            );
+            assert_noop!(
+                Crowdfunding::transfer_investment(RuntimeOrigin::signed(BOB), id, CHARLIE, 1_000),
+                Error::<Test>::TransferLocked
+            );
+
+            run_to_block(30);
+            assert_ok!(Crowdfunding::transfer_investment(
+                RuntimeOrigin::signed(BOB),
+                id,
+                CHARLIE,
+                1_000
+            ));
+            assert_eq!(position(id, CHARLIE), 1_000);
+        });
+    }
+
+    #[test]
+    fn reward_tier_follows_whole_position() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_kwyr_config(100));
+            let tier = RewardTier {
+                min_amount: 100,
+                quantity: 3,
+                remaining: 0,
+                description_hash: [7u8; 32],
+                nft_collection: None,
+            };
+            assert_ok!(Crowdfunding::set_reward_tiers(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                BoundedVec::try_from(vec![tier]).unwrap(),
+            ));
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(BOB), id, 300, 0));
+            assert_ok!(Crowdfunding::invest_with_tier(RuntimeOrigin::signed(DAVE), id, 150, 0));
+            let remaining = || pallet::RewardTiers::<Test>::get(id).unwrap()[0].remaining;
+            assert_eq!(remaining(), 1);
+
+            // The allocation moves with BOB's whole position.
+            assert_ok!(Crowdfunding::transfer_investment(
+                RuntimeOrigin::signed(BOB),
+                id,
+                CHARLIE,
+                300
+            ));
+            assert!(pallet::InvestorTiers::<Test>::get(id, BOB).is_none());
+            assert_eq!(pallet::InvestorTiers::<Test>::get(id, CHARLIE).unwrap().tier_index, 0);
+            assert_eq!(remaining(), 1);
+
+            // DAVE drops below the tier minimum and releases the allocation.
+            assert_ok!(Crowdfunding::transfer_investment(
+                RuntimeOrigin::signed(DAVE),
+                id,
+                BOB,
+                100
+            ));
+            assert!(pallet::InvestorTiers::<Test>::get(id, DAVE).is_none());
+            assert!(pallet::InvestorTiers::<Test>::get(id, BOB).is_none());
+            assert_eq!(remaining(), 2);
+        });
+    }
+}
+
+// ── stepped_migration ───────────────────────────────────────────────────
+
+mod stepped_migration {
+    use frame_support::{
+        traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+        weights::Weight,
+    };
+
+    use super::*;
+    use crate::migrations::stepped::StartSteppedMigration;
+
+    #[test]
+    fn upgrade_skips_when_already_at_target() {
+        ExtBuilder::default().build().execute_with(|| {
+            StorageVersion::new(7).put::<Crowdfunding>();
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+            assert!(!Crowdfunding::migration_ongoing());
+        });
+    }
+
+    #[test]
+    fn migrates_items_per_block_until_complete() {
+        ExtBuilder::default().build().execute_with(|| {
+            for _ in 0..3 {
+                create_funded_campaign(ALICE, default_aon_config(20, 500));
+            }
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+            System::assert_last_event(Event::<Test>::MigrationStarted { target_version: 7 }.into());
+
+            Crowdfunding::on_initialize(2);
+            assert_eq!(pallet::ActiveMigration::<Test>::get().unwrap().migrated, 2);
+
+            Crowdfunding::on_initialize(3);
+            assert!(!Crowdfunding::migration_ongoing());
+            assert_eq!(Crowdfunding::on_chain_storage_version(), 7);
+            let mut migrated = TestMigration::migrated();
+            migrated.sort();
+            assert_eq!(migrated, vec![0, 1, 2]);
+            System::assert_last_event(
+                Event::<Test>::MigrationCompleted { target_version: 7, migrated: 3 }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn finalization_waits_for_migration() {
+        ExtBuilder::default().build().execute_with(|| {
+            let ids: Vec<u32> = (0..3)
+                .map(|_| create_funded_campaign(ALICE, default_aon_config(20, 500)))
+                .collect();
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+
+            run_to_block(21);
+            Crowdfunding::on_initialize(21);
+            assert!(Crowdfunding::migration_ongoing());
+            for id in &ids {
+                assert_eq!(
+                    pallet::Campaigns::<Test>::get(id).unwrap().status,
+                    CampaignStatus::Funding
+                );
+                assert!(pallet::PendingFinalizations::<Test>::contains_key(id));
+            }
+            assert!(!pallet::CampaignDeadlines::<Test>::contains_key(21));
+
+            Crowdfunding::on_idle(21, Weight::MAX);
+            assert_eq!(pallet::PendingFinalizations::<Test>::iter_keys().count(), 3);
+
+            Crowdfunding::on_initialize(22);
+            assert!(!Crowdfunding::migration_ongoing());
+            Crowdfunding::on_idle(22, Weight::MAX);
+            for id in &ids {
+                assert_eq!(
+                    pallet::Campaigns::<Test>::get(id).unwrap().status,
+                    CampaignStatus::Failed
+                );
+            }
+            assert_eq!(pallet::PendingFinalizations::<Test>::iter_keys().count(), 0);
+        });
+    }
+}
+
+// ── campaign_events ─────────────────────────────────────────────────────
+
+mod campaign_events {
+    use super::*;
+
+    #[test]
+    fn campaign_events_name_their_campaign() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            let touched: Vec<_> = System::events()
+                .into_iter()
+                .filter_map(|r| match r.event {
+                    RuntimeEvent::Crowdfunding(e) => e.campaign_id(),
+                    _ => None,
+                })
+                .collect();
+            assert!(!touched.is_empty());
+            assert!(touched.iter().all(|c| *c == id));
+            assert_eq!(
+                Event::<Test>::ProtocolConfigUpdated { fee_bps: 0, recipient: ALICE }.campaign_id(),
+                None
+            );
+        });
+    }
+}
+
+// ── fee_sponsorship ─────────────────────────────────────────────────────
+
+mod fee_sponsorship {
+    use frame_support::{
+        traits::{Hooks, Imbalance},
+        weights::Weight,
+    };
+
+    use super::*;
+
+    #[test]
+    fn creator_funds_and_ends_sponsorship() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            let account = Crowdfunding::sponsorship_account(id);
+            let budget_before = Balances::free_balance(account);
+            assert_noop!(
+                Crowdfunding::fund_fee_sponsorship(RuntimeOrigin::signed(BOB), id, 500, 2),
+                Error::<Test>::NotCampaignCreator
+            );
+            assert_noop!(
+                Crowdfunding::fund_fee_sponsorship(RuntimeOrigin::signed(ALICE), id, 500, 0),
+                Error::<Test>::InvalidSponsorshipLimit
+            );
+            assert_ok!(Crowdfunding::fund_fee_sponsorship(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                500,
+                2
+            ));
+            assert_eq!(Balances::free_balance(account), budget_before + 500);
+            System::assert_last_event(
+                Event::<Test>::FeeSponsorshipFunded {
+                    campaign_id: id,
+                    sponsor: ALICE,
+                    amount: 500,
+                    max_calls_per_period: 2,
+                }
+                .into(),
+            );
+
+            assert_noop!(
+                Crowdfunding::end_fee_sponsorship(RuntimeOrigin::signed(BOB), id),
+                Error::<Test>::NotCampaignCreator
+            );
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(Crowdfunding::end_fee_sponsorship(RuntimeOrigin::signed(ALICE), id));
+            assert!(Balances::free_balance(ALICE) > alice_before);
+            assert!(pallet::FeeSponsorships::<Test>::get(id).is_none());
+            assert_noop!(
+                Crowdfunding::end_fee_sponsorship(RuntimeOrigin::signed(ALICE), id),
+                Error::<Test>::NoFeeSponsorship
+            );
+        });
+    }
+
+    #[test]
+    fn sponsored_fees_are_rate_limited_per_account() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 100));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 100));
+            assert!(!Crowdfunding::can_sponsor_fee(id, &BOB, 10, None));
+            assert_ok!(Crowdfunding::fund_fee_sponsorship(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                500,
+                1
+            ));
+            let account = Crowdfunding::sponsorship_account(id);
+            let budget = Balances::free_balance(account);
+            assert!(!Crowdfunding::can_sponsor_fee(id, &BOB, budget, None));
+
+            let paid = Crowdfunding::withdraw_sponsored_fee(id, &BOB, 10, None).unwrap();
+            let fee = Crowdfunding::settle_sponsored_fee(id, &BOB, paid, 4);
+            assert_eq!(fee.peek(), 4);
+            drop(fee);
+            assert_eq!(Balances::free_balance(account), budget - 4);
+            System::assert_last_event(
+                Event::<Test>::FeeSponsored { campaign_id: id, who: BOB, fee: 4 }.into(),
+            );
+
+            assert!(Crowdfunding::withdraw_sponsored_fee(id, &BOB, 10, None).is_none());
+            assert!(Crowdfunding::can_sponsor_fee(id, &CHARLIE, 10, None));
+            run_to_block(11);
+            assert!(Crowdfunding::can_sponsor_fee(id, &BOB, 10, None));
+        });
+    }
+
+    #[test]
+    fn only_participants_are_sponsored() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_ok!(Crowdfunding::fund_fee_sponsorship(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                500,
+                5
+            ));
+            assert!(!Crowdfunding::can_sponsor_fee(id, &BOB, 10, None));
+            assert!(Crowdfunding::withdraw_sponsored_fee(id, &BOB, 10, None).is_none());
+
+            assert_ok!(Crowdfunding::add_to_whitelist(RuntimeOrigin::signed(ALICE), id, BOB));
+            assert!(Crowdfunding::can_sponsor_fee(id, &BOB, 10, None));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(CHARLIE), id, 100));
+            assert!(Crowdfunding::can_sponsor_fee(id, &CHARLIE, 10, None));
+        });
+    }
+
+    #[test]
+    fn first_investment_is_sponsored_when_it_would_succeed() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_ok!(Crowdfunding::fund_fee_sponsorship(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                500,
+                5
+            ));
+            assert!(!Crowdfunding::can_sponsor_fee(id, &DAVE, 10, None));
+            assert!(Crowdfunding::can_sponsor_fee(id, &DAVE, 10, Some(100)));
+            // DAVE cannot pay it, or it is empty.
+            assert!(!Crowdfunding::can_sponsor_fee(id, &DAVE, 10, Some(10_000)));
+            assert!(!Crowdfunding::can_sponsor_fee(id, &DAVE, 10, Some(0)));
+            let paid = Crowdfunding::withdraw_sponsored_fee(id, &DAVE, 10, Some(100)).unwrap();
+            drop(Crowdfunding::settle_sponsored_fee(id, &DAVE, paid, 10));
+
+            // Not eligible for a whitelist-only campaign.
+            let rules: BoundedVec<_, _> =
+                vec![EligibilityRule::AccountWhitelist].try_into().unwrap();
+            let gated = pallet::NextCampaignId::<Test>::get();
+            assert_ok!(Crowdfunding::create_campaign(
+                RuntimeOrigin::signed(ALICE),
+                default_aon_config(20, 500),
+                Some(rules),
+                None,
+            ));
+            assert_ok!(Crowdfunding::fund_fee_sponsorship(
+                RuntimeOrigin::signed(ALICE),
+                gated,
+                500,
+                5
+            ));
+            assert!(!Crowdfunding::can_sponsor_fee(gated, &DAVE, 10, Some(100)));
+
+            // Past the deadline.
+            run_to_block(21);
+            assert!(!Crowdfunding::can_sponsor_fee(id, &CHARLIE, 10, Some(100)));
+        });
+    }
+
+    #[test]
+    fn reaping_ends_sponsorship() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 500));
+            assert_ok!(Crowdfunding::fund_fee_sponsorship(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                500,
+                5
+            ));
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 500));
+            let paid = Crowdfunding::withdraw_sponsored_fee(id, &BOB, 10, None).unwrap();
+            drop(Crowdfunding::settle_sponsored_fee(id, &BOB, paid, 10));
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_ok!(Crowdfunding::claim_creation_deposit(RuntimeOrigin::signed(ALICE), id));
+            let account = Crowdfunding::sponsorship_account(id);
+            let budget = Balances::free_balance(account);
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(Crowdfunding::reap_campaign(RuntimeOrigin::signed(CHARLIE), id, 10));
+
+            assert_eq!(Balances::free_balance(ALICE), alice_before + budget);
+            assert_eq!(Balances::free_balance(account), 0);
+            assert!(pallet::FeeSponsorships::<Test>::get(id).is_none());
+            assert!(pallet::PendingCleanups::<Test>::contains_key(id));
+            Crowdfunding::on_idle(21, Weight::MAX);
+            assert!(!pallet::SponsoredCalls::<Test>::contains_key(id, BOB));
+            System::assert_has_event(
+                Event::<Test>::FeeSponsorshipEnded {
+                    campaign_id: id,
+                    sponsor: ALICE,
+                    refunded: budget,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn investor_calls_are_sponsorable() {
+        let invest = pallet::Call::<Test>::invest { campaign_id: 3, amount: 100 };
+        assert_eq!(invest.sponsoring_campaign(), Some(3));
+        let cancel = pallet::Call::<Test>::cancel_campaign { campaign_id: 3 };
+        assert_eq!(cancel.sponsoring_campaign(), None);
+        assert_eq!(invest.sponsored_investment(), Some(100));
+        let withdraw = pallet::Call::<Test>::withdraw_investment { campaign_id: 3, amount: 100 };
+        assert_eq!(withdraw.sponsored_investment(), None);
+    }
+}
+
+// ── offchain finalization ───────────────────────────────────────────────
+
+mod offchain_finalization {
+    use codec::{Decode, Encode};
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{
+        testing::{TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+    use sp_runtime::{
+        testing::{TestSignature, UintAuthorityId},
+        traits::ValidateUnsigned,
+        transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
+    };
+
+    use super::*;
+
+    type Extrinsic = <Test as frame_system::offchain::SendTransactionTypes<Call<Test>>>::Extrinsic;
+
+    fn signed_payload(
+        block_number: u64,
+        campaign_ids: Vec<u32>,
+        signer: u64,
+    ) -> (FinalizationPayload<UintAuthorityId, u64>, TestSignature) {
+        let payload =
+            FinalizationPayload { block_number, campaign_ids, public: UintAuthorityId(1) };
+        let signature = TestSignature(signer, payload.encode());
+        (payload, signature)
+    }
+
+    fn finalization_call(block_number: u64, campaign_ids: Vec<u32>, signer: u64) -> Call<Test> {
+        let (payload, signature) = signed_payload(block_number, campaign_ids, signer);
+        Call::finalize_campaigns_unsigned { payload, signature }
+    }
+
+    fn validate(call: &Call<Test>) -> TransactionValidity {
+        Crowdfunding::validate_unsigned(TransactionSource::External, call)
+    }
+
+    fn allow_key(key: u64) {
+        assert_ok!(Crowdfunding::set_finalization_key(RuntimeOrigin::root(), key, true));
+    }
+
+    /// Allows key 1, creates two campaigns with a deadline at block 20, one
+    /// funded to its goal, and runs past the deadline.
+    fn setup_past_deadline() -> (u32, u32) {
+        allow_key(1);
+        let funded = create_funded_campaign(ALICE, default_aon_config(20, 1000));
+        let unfunded = create_funded_campaign(ALICE, default_aon_config(20, 1000));
+        assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), funded, 1000));
+        run_to_block(21);
+        (funded, unfunded)
+    }
+
+    #[test]
+    fn accepts_finalizable_campaigns_with_a_tag_each() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (funded, unfunded) = setup_past_deadline();
+            let valid = validate(&finalization_call(21, vec![funded, unfunded], 1)).unwrap();
+            assert_eq!(valid.priority, 100);
+            assert_eq!(valid.longevity, 5);
+            assert_eq!(valid.provides.len(), 2);
+            // A batch overlapping the first provides the same tag.
+            let overlapping = validate(&finalization_call(21, vec![unfunded], 1)).unwrap();
+            assert!(valid.provides.contains(&overlapping.provides[0]));
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_payloads() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (funded, unfunded) = setup_past_deadline();
+            assert_eq!(
+                validate(&finalization_call(21, vec![funded], 2)),
+                InvalidTransaction::BadProof.into()
+            );
+            assert_eq!(
+                validate(&finalization_call(21, vec![], 1)),
+                InvalidTransaction::Call.into()
+            );
+            assert_eq!(
+                validate(&finalization_call(21, vec![funded; 4], 1)),
+                InvalidTransaction::Call.into()
+            );
+            assert_eq!(
+                validate(&finalization_call(22, vec![funded], 1)),
+                InvalidTransaction::Future.into()
+            );
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(CHARLIE), unfunded));
+            assert_eq!(
+                validate(&finalization_call(21, vec![funded, unfunded], 1)),
+                InvalidTransaction::Stale.into()
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_campaigns_before_their_deadline() {
+        ExtBuilder::default().build().execute_with(|| {
+            allow_key(1);
+            let id = create_funded_campaign(ALICE, default_aon_config(20, 1000));
+            run_to_block(20);
+            assert_eq!(
+                validate(&finalization_call(20, vec![id], 1)),
+                InvalidTransaction::Stale.into()
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_keys_not_allowed() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (funded, _) = setup_past_deadline();
+            // Correctly signed, but by a key governance never allowed.
+            let payload = FinalizationPayload {
+                block_number: 21,
+                campaign_ids: vec![funded],
+                public: UintAuthorityId(2),
+            };
+            let signature = TestSignature(2, payload.encode());
+            let call = Call::finalize_campaigns_unsigned { payload, signature };
+            assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+
+            assert_noop!(
+                Crowdfunding::set_finalization_key(RuntimeOrigin::signed(ALICE), 2, true),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            allow_key(2);
+            System::assert_last_event(
+                Event::<Test>::FinalizationKeySet { key: 2, allowed: true }.into(),
+            );
+            assert_ok!(validate(&call));
+
+            assert_ok!(Crowdfunding::set_finalization_key(RuntimeOrigin::root(), 1, false));
+            assert_eq!(
+                validate(&finalization_call(21, vec![funded], 1)),
+                InvalidTransaction::BadProof.into()
+            );
+        });
+    }
+
+    #[test]
+    fn unsigned_call_finalizes_and_unschedules() {
+        ExtBuilder::default().build().execute_with(|| {
+            let (funded, unfunded) = setup_past_deadline();
+            let (payload, signature) = signed_payload(21, vec![funded, unfunded, 99], 1);
+            assert_noop!(
+                Crowdfunding::finalize_campaigns_unsigned(
+                    RuntimeOrigin::signed(CHARLIE),
+                    payload.clone(),
+                    signature.clone()
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Crowdfunding::finalize_campaigns_unsigned(
+                RuntimeOrigin::none(),
+                payload,
+                signature
+            ));
+            let status = |id| pallet::Campaigns::<Test>::get(id).unwrap().status;
+            assert!(matches!(status(funded), CampaignStatus::Succeeded));
+            assert!(matches!(status(unfunded), CampaignStatus::Failed));
+            assert_eq!(pallet::CampaignDeadlines::<Test>::iter().count(), 0);
+            System::assert_has_event(
+                Event::<Test>::CampaignFinalized {
+                    campaign_id: unfunded,
+                    status: CampaignStatus::Failed,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn worker_submits_finalizable_campaigns_once_per_interval() {
+        let (offchain, _) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = ExtBuilder::default().build();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+
+        ext.execute_with(|| {
+            UintAuthorityId::set_all_keys(vec![1u64]);
+            let (funded, unfunded) = setup_past_deadline();
+            Crowdfunding::offchain_worker(21);
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert!(tx.signature.is_none());
+            let call = finalization_call(21, vec![funded, unfunded], 1);
+            assert_eq!(tx.call, RuntimeCall::Crowdfunding(call.clone()));
+            assert_ok!(validate(&call));
+
+            // Nothing is resubmitted before `UnsignedInterval` has passed.
+            Crowdfunding::offchain_worker(22);
+            assert!(pool_state.read().transactions.is_empty());
+            run_to_block(26);
+            Crowdfunding::offchain_worker(26);
+            assert_eq!(pool_state.read().transactions.len(), 1);
+        });
+    }
+
+    #[test]
+    fn worker_submits_nothing_before_any_deadline() {
+        let (offchain, _) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = ExtBuilder::default().build();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+
+        ext.execute_with(|| {
+            UintAuthorityId::set_all_keys(vec![1u64]);
+            create_funded_campaign(ALICE, default_aon_config(20, 1000));
+            Crowdfunding::offchain_worker(1);
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+
+    #[test]
+    fn worker_submits_nothing_without_an_allowed_key() {
+        let (offchain, _) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = ExtBuilder::default().build();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+
+        ext.execute_with(|| {
+            UintAuthorityId::set_all_keys(vec![2u64]);
+            setup_past_deadline();
+            Crowdfunding::offchain_worker(21);
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+}
+
+// ── licensed_campaigns index ────────────────────────────────────────────
+
+mod licensed_campaigns {
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    use super::*;
+    use crate::{
+        migrations::{stepped::StartSteppedMigration, v6::MigrateToV6},
+        SteppedMigration,
+    };
+
+    fn create_licensed(config: CampaignConfigOf, rwa_asset_id: u32, participation_id: u32) -> u32 {
+        MockLicenseVerifier::set_license(rwa_asset_id, participation_id, ALICE, true);
+        let id = pallet::NextCampaignId::<Test>::get();
+        assert_ok!(Crowdfunding::create_campaign(
+            RuntimeOrigin::signed(ALICE),
+            config,
+            None,
+            Some((rwa_asset_id, participation_id)),
+        ));
+        id
+    }
+
+    #[test]
+    fn indexes_licensed_campaigns_only() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_licensed(default_aon_config(20, 1000), 0, 1);
+            create_funded_campaign(ALICE, default_aon_config(20, 1000));
+            assert_eq!(
+                pallet::LicensedCampaigns::<Test>::iter_keys().collect::<Vec<_>>(),
+                vec![(0, 1, id)]
+            );
+            assert!(Crowdfunding::has_licensed_campaigns(0));
+            assert!(Crowdfunding::has_campaigns_under_license(0, 1));
+            assert!(!Crowdfunding::has_campaigns_under_license(0, 0));
+            assert!(!Crowdfunding::has_licensed_campaigns(1));
+        });
+    }
+
+    #[test]
+    fn failed_and_cancelled_campaigns_are_unlinked() {
+        ExtBuilder::default().build().execute_with(|| {
+            let failed = create_licensed(default_aon_config(20, 1000), 0, 0);
+            let cancelled = create_licensed(default_aon_config(20, 1000), 0, 1);
+            let succeeded = create_licensed(default_kwyr_config(20), 1, 0);
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), cancelled));
+            assert!(!Crowdfunding::has_campaigns_under_license(0, 1));
+
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(BOB), failed));
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(BOB), succeeded));
+            assert!(!Crowdfunding::has_licensed_campaigns(0));
+            // Succeeded is not terminal: the creator has yet to claim.
+            assert!(Crowdfunding::has_campaigns_under_license(1, 0));
+        });
+    }
+
+    #[test]
+    fn completed_campaigns_are_unlinked() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = create_licensed(default_kwyr_config(20), 0, 0);
+            assert_ok!(Crowdfunding::invest(RuntimeOrigin::signed(BOB), id, 500));
+            run_to_block(21);
+            assert_ok!(Crowdfunding::finalize_campaign(RuntimeOrigin::signed(BOB), id));
+            assert!(Crowdfunding::has_licensed_campaigns(0));
+            assert_ok!(Crowdfunding::claim_funds(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(
+                pallet::Campaigns::<Test>::get(id).unwrap().status,
+                CampaignStatus::Completed
+            );
+            assert!(!Crowdfunding::has_licensed_campaigns(0));
+        });
+    }
+
+    #[test]
+    fn migration_to_v6_indexes_non_terminal_campaigns_in_steps() {
+        ExtBuilder::default().build().execute_with(|| {
+            let active = create_licensed(default_aon_config(20, 1000), 0, 0);
+            let cancelled = create_licensed(default_aon_config(20, 1000), 0, 1);
+            let other = create_licensed(default_kwyr_config(20), 1, 0);
+            create_funded_campaign(ALICE, default_aon_config(20, 1000));
+            assert_ok!(Crowdfunding::cancel_campaign(RuntimeOrigin::root(), cancelled));
+            let _ = pallet::LicensedCampaigns::<Test>::clear(u32::MAX, None);
+
+            let first = MigrateToV6::<Test>::step(None, 3);
+            assert_eq!(first.migrated, 3);
+            let rest = MigrateToV6::<Test>::step(first.next, 3);
+            assert_eq!(rest.migrated, 1);
+            assert!(rest.next.is_none());
+
+            let mut indexed = pallet::LicensedCampaigns::<Test>::iter_keys().collect::<Vec<_>>();
+            indexed.sort();
+            assert_eq!(indexed, vec![(0, 0, active), (1, 0, other)]);
+        });
+    }
+
+    #[test]
+    fn guard_answers_true_while_migrating() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert!(!Crowdfunding::has_licensed_campaigns(0));
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+            assert!(Crowdfunding::has_licensed_campaigns(0));
+            assert!(Crowdfunding::has_campaigns_under_license(0, 0));
+        });
+    }
+}
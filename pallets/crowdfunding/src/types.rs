@@ -3,7 +3,8 @@ use frame_support::{
     pallet_prelude::*, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use scale_info::TypeInfo;
-use sp_runtime::{DispatchResult, RuntimeDebug};
+use sp_runtime::{DispatchResult, Perbill, RuntimeDebug};
+use sp_std::vec::Vec;
 
 /// Hook for verifying that a campaign creator holds an active RWA license
 /// (participation) before campaign creation and fund claiming.
@@ -29,6 +30,10 @@ pub trait LicenseVerifier<AccountId, BlockNumber> {
     /// `create_campaign` to ensure the campaign deadline does not exceed
     /// the license expiry (V2 fix).
     fn license_expiry(_rwa_asset_id: u32, _participation_id: u32) -> Option<BlockNumber> { None }
+
+    /// Check whether `who` holds an active participation in the given RWA
+    /// asset.  Used by the `RwaParticipation` eligibility rule.
+    fn has_active_participation(_rwa_asset_id: u32, _who: &AccountId) -> bool { false }
 }
 
 /// Blanket no-op: no license required.
@@ -36,6 +41,99 @@ impl<AccountId, BlockNumber> LicenseVerifier<AccountId, BlockNumber> for () {
     fn ensure_active_license(_: u32, _: u32, _: &AccountId) -> DispatchResult { Ok(()) }
 }
 
+/// Hook for account facts used by the `Attestation` and `MinAccountAge`
+/// eligibility rules.
+///
+/// Implemented at the runtime level on top of whichever identity or
+/// attestation source the chain trusts.
+pub trait EligibilityProvider<AccountId, BlockNumber> {
+    /// Check whether a trusted issuer has attested `claim` for `who`.
+    fn has_attestation(_who: &AccountId, _claim: &[u8; 32]) -> bool { false }
+
+    /// Block at which `who` was first seen, or `None` if unknown.
+    fn account_created_at(_who: &AccountId) -> Option<BlockNumber> { None }
+}
+
+/// Blanket no-op: no attestations and no known account ages.
+impl<AccountId, BlockNumber> EligibilityProvider<AccountId, BlockNumber> for () {}
+
+/// Hook for minting the NFT reward attached to a reward tier.
+///
+/// Implemented at the runtime level on top of `pallet_nfts`.  `serial` is a
+/// per-collection counter maintained by this pallet and is used as the item
+/// ID by the runtime implementation.
+pub trait RewardMinter<AccountId, CollectionId> {
+    fn mint_reward(collection: &CollectionId, serial: u32, who: &AccountId) -> DispatchResult;
+
+    /// Whether the item `serial` already exists in `collection`, e.g. because
+    /// the collection owner minted it directly.
+    fn is_minted(collection: &CollectionId, serial: u32) -> bool;
+}
+
+/// Blanket no-op: no NFT rewards are minted.
+impl<AccountId, CollectionId> RewardMinter<AccountId, CollectionId> for () {
+    fn mint_reward(_: &CollectionId, _: u32, _: &AccountId) -> DispatchResult { Ok(()) }
+
+    fn is_minted(_: &CollectionId, _: u32) -> bool { false }
+}
+
+/// Conversion rates between payment currencies, used to normalize
+/// contributions made in a campaign's alternative currencies.
+///
+/// Implemented at the runtime level on top of whichever price oracle the
+/// chain trusts.
+pub trait PriceFeed<AssetId, Balance> {
+    /// Value of `amount` of `from` expressed in `to`, or `None` if no rate
+    /// is available.
+    fn convert(
+        from: &PaymentCurrency<AssetId>,
+        to: &PaymentCurrency<AssetId>,
+        amount: Balance,
+    ) -> Option<Balance>;
+}
+
+/// Blanket no-op: no rates, so alternative currencies cannot be used.
+impl<AssetId, Balance> PriceFeed<AssetId, Balance> for () {
+    fn convert(
+        _: &PaymentCurrency<AssetId>,
+        _: &PaymentCurrency<AssetId>,
+        _: Balance,
+    ) -> Option<Balance> {
+        None
+    }
+}
+
+/// Appraised value of the RWA assets campaigns can be linked to, used to
+/// size asset-backed offerings.
+///
+/// Implemented at the runtime level on top of `pallet_rwa` valuations.
+pub trait ValuationProvider<AssetId, Balance> {
+    /// Currency and amount the asset is valued at, or `None` if it has no
+    /// valuation.
+    fn asset_valuation(_rwa_asset_id: u32) -> Option<(PaymentCurrency<AssetId>, Balance)> { None }
+}
+
+/// Blanket no-op: no valuations, so asset-backed offerings cannot be
+/// configured.
+impl<AssetId, Balance> ValuationProvider<AssetId, Balance> for () {}
+
+/// Sets up the state outside this pallet that the `claim_reward_nft`,
+/// `invest_in_currency` and `configure_asset_offering` benchmarks need.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, CollectionId, AssetId, Balance> {
+    /// Create a collection owned by `owner` that `RewardMinter` can mint
+    /// rewards from.
+    fn reward_collection(owner: &AccountId) -> CollectionId;
+
+    /// Create an asset that `PriceFeed` can convert into the native
+    /// currency.
+    fn payment_asset() -> AssetId;
+
+    /// Make `ValuationProvider` value `rwa_asset_id` at `amount` of
+    /// `currency`.
+    fn set_valuation(rwa_asset_id: u32, currency: PaymentCurrency<AssetId>, amount: Balance);
+}
+
 /// Payment currency: native token or a specific fungible asset.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum PaymentCurrency<AssetId> {
@@ -52,6 +150,68 @@ pub struct Milestone {
     pub description_hash: [u8; 32],
 }
 
+/// Early-bird bonus schedule.
+///
+/// Investments made within `full_bonus_blocks` of campaign creation receive
+/// `bonus_bps` of extra weight; the bonus then decays linearly to zero over
+/// the following `decay_blocks`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BonusSchedule<BlockNumber> {
+    pub bonus_bps: u16,
+    pub full_bonus_blocks: BlockNumber,
+    pub decay_blocks: BlockNumber,
+}
+
+/// A backer reward tier declared by the campaign creator.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RewardTier<Balance, CollectionId> {
+    /// Minimum net investment required to hold this tier.
+    pub min_amount: Balance,
+    /// Total number of backers this tier can be allocated to.
+    pub quantity: u32,
+    /// Number of allocations still available.  Set to `quantity` when the
+    /// tiers are declared; decremented on selection and restored on
+    /// withdrawal or refund.
+    pub remaining: u32,
+    /// SHA-256 digest or IPFS CIDv1 hash of the off-chain reward description.
+    pub description_hash: [u8; 32],
+    /// Collection to mint an NFT reward from once the campaign succeeds
+    /// (`None` = no NFT reward).
+    pub nft_collection: Option<CollectionId>,
+}
+
+/// A stretch goal announced by the campaign creator, reached once
+/// `total_raised` meets `threshold`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StretchGoal<Balance> {
+    pub threshold: Balance,
+    /// SHA-256 digest or IPFS CIDv1 hash of the off-chain stretch goal
+    /// description.
+    pub description_hash: [u8; 32],
+}
+
+/// One of a campaign's sequential funding rounds (e.g. seed, round A).
+///
+/// Rounds run back to back: a round is active from the end of the
+/// previous one until its `deadline`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FundingRound<Balance, BlockNumber> {
+    pub deadline: BlockNumber,
+    /// Cap on the campaign's `total_raised` while this round is active.
+    pub cap: Balance,
+    /// Minimum investment during this round, on top of the campaign's own.
+    pub min_investment: Option<Balance>,
+}
+
+/// An investor's reward tier allocation within a campaign.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct TierAllocation {
+    pub tier_index: u8,
+    /// Set once the tier's NFT reward has been minted to the investor.
+    pub reward_minted: bool,
+}
+
 /// Funding model for a campaign.
 #[derive(
     CloneNoBound,
@@ -101,6 +261,9 @@ pub struct CampaignConfig<
     /// SHA-256 digest or IPFS CIDv1 hash of the off-chain campaign metadata.
     pub metadata_hash: [u8; 32],
     pub early_withdrawal_penalty_bps: Option<u16>,
+    /// Optional early-bird bonus applied to the weighted allocation of
+    /// investments (`None` = every unit carries the same weight).
+    pub early_bird_bonus: Option<BonusSchedule<BlockNumber>>,
 }
 
 /// Eligibility rule for investors.
@@ -133,6 +296,32 @@ pub enum EligibilityRule<
     },
     #[codec(index = 3)]
     AccountWhitelist,
+    /// Holds an active participation in the given RWA asset.
+    #[codec(index = 4)]
+    RwaParticipation { rwa_asset_id: u32 },
+    /// Holds an attestation of `claim` from a trusted issuer.
+    #[codec(index = 5)]
+    Attestation { claim: [u8; 32] },
+    /// Account was first seen at least `blocks` blocks ago.
+    #[codec(index = 6)]
+    MinAccountAge { blocks: u32 },
+}
+
+/// Node of a campaign's eligibility expression.
+///
+/// An expression is a bounded list of nodes whose last entry is the root.
+/// Combinators refer to children by index; every child must precede its
+/// parent and be referenced exactly once, so the nodes always form a tree.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EligibilityNode<Rule> {
+    #[codec(index = 0)]
+    Rule(Rule),
+    #[codec(index = 1)]
+    And(u8, u8),
+    #[codec(index = 2)]
+    Or(u8, u8),
+    #[codec(index = 3)]
+    Not(u8),
 }
 
 /// Campaign lifecycle status.
@@ -219,12 +408,177 @@ pub struct Campaign<
     pub protocol_fee_bps: u16,
 }
 
+/// Balances recorded by `register_interest` during a campaign's
+/// registration phase.  Balance eligibility rules are checked against the
+/// lower of the snapshot and the balance at investment time.
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    Encode,
+    Decode,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxAssets))]
+pub struct RegistrationSnapshot<
+    Balance: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+    AssetId: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+    MaxAssets: Get<u32>,
+> {
+    pub native: Balance,
+    /// Balances of the assets referenced by the campaign's `AssetBalance`
+    /// rules.
+    pub assets: BoundedVec<(AssetId, Balance), MaxAssets>,
+}
+
+/// Compact record of a settled campaign, kept after `reap_campaign` removes
+/// the full campaign state.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ArchivedCampaign<AccountId, Balance, BlockNumber, AssetId> {
+    pub creator: AccountId,
+    /// Terminal status (Completed, Failed or Cancelled).
+    pub status: CampaignStatus,
+    pub funding_currency: PaymentCurrency<AssetId>,
+    pub total_raised: Balance,
+    pub total_disbursed: Balance,
+    pub created_at: BlockNumber,
+    pub reaped_at: BlockNumber,
+    pub rwa_asset_id: Option<u32>,
+    pub participation_id: Option<u32>,
+}
+
+/// Asset-backed offering terms of a campaign.
+///
+/// The campaign sells `fraction` of its linked RWA asset; its goal and hard
+/// cap are `fraction` of `valuation`, expressed in the funding currency.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AssetOffering<Balance> {
+    pub rwa_asset_id: u32,
+    pub fraction: Perbill,
+    /// Asset valuation in the funding currency when the offering was
+    /// configured.
+    pub valuation: Balance,
+    /// Part of `fraction` already issued to investors as asset shares.
+    pub issued: Perbill,
+}
+
+/// Creator bond locked when a milestone-based campaign is created.
+///
+/// The bond is released to the creator as milestones are approved; if the
+/// campaign is cancelled during `MilestonePhase`, whatever is still locked
+/// is slashed and paid out to investors with their refunds.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CreatorBond<Balance> {
+    pub amount: Balance,
+    /// Part of `amount` already returned to the creator.
+    pub released: Balance,
+    /// Part of `amount` slashed for investors.
+    pub slashed: Balance,
+    /// Part of `slashed` already paid out with investor refunds.
+    pub refunded: Balance,
+}
+
+/// Early-withdrawal penalty that applies once at most `within` blocks
+/// remain until the campaign deadline.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PenaltyStep<BlockNumber> {
+    pub within: BlockNumber,
+    pub penalty_bps: u16,
+}
+
+/// Time-dependent early-withdrawal penalty of a campaign.
+///
+/// Withdrawals are free for `cooling_off` blocks after the campaign is
+/// created.  After that, the step with the smallest `within` covering the
+/// blocks left until the deadline applies, and before the first step the
+/// campaign's flat penalty does.
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    Encode,
+    Decode,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxSteps))]
+pub struct PenaltySchedule<
+    BlockNumber: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+    MaxSteps: Get<u32>,
+> {
+    pub cooling_off: BlockNumber,
+    /// Steps in strictly descending `within` order.
+    pub steps: BoundedVec<PenaltyStep<BlockNumber>, MaxSteps>,
+}
+
+/// Where early-withdrawal penalties go.
+#[derive(
+    Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub enum PenaltyDestination {
+    #[default]
+    #[codec(index = 0)]
+    Burn,
+    /// Kept as campaign funds: counts towards the goal and is paid to the
+    /// creator, or shared among investors if the campaign fails.
+    #[codec(index = 1)]
+    CampaignPot,
+    /// Shared pro-rata among the investors still in the campaign when it
+    /// ends.
+    #[codec(index = 2)]
+    RemainingInvestors,
+    #[codec(index = 3)]
+    ProtocolFeeRecipient,
+}
+
+impl PenaltyDestination {
+    /// Whether penalties stay in the campaign sub-account.
+    pub fn is_retained(&self) -> bool {
+        matches!(self, Self::CampaignPot | Self::RemainingInvestors)
+    }
+}
+
+/// Early-withdrawal penalties retained by a campaign.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct PenaltyPot<Balance> {
+    pub amount: Balance,
+    /// Part of `amount` already paid out to investors.
+    pub paid: Balance,
+}
+
+/// Vesting of a successful campaign's funds to its creator.
+///
+/// Vesting starts at the campaign deadline and is linear over `duration`
+/// blocks, but nothing can be claimed before `cliff` blocks have passed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct VestingSchedule<BlockNumber> {
+    pub cliff: BlockNumber,
+    pub duration: BlockNumber,
+}
+
+/// Amount held in one of a campaign's alternative currencies, alongside
+/// its value in the funding currency at the time it was contributed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CurrencyHolding<AssetId, Balance> {
+    pub currency: PaymentCurrency<AssetId>,
+    pub amount: Balance,
+    pub normalized: Balance,
+}
+
 /// Individual investor's record for a campaign.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct Investment<Balance> {
     pub total_invested: Balance,
     pub total_withdrawn: Balance,
+    /// Weight of the current position including any early-bird bonus.
+    /// Reduced pro-rata on withdrawal, so withdrawn units take their bonus
+    /// with them.
+    pub weighted_allocation: Balance,
 }
 
 /// Preview of a withdrawal (returned by runtime API).
@@ -237,6 +591,19 @@ pub struct WithdrawalPreview<Balance> {
     pub penalty_bps: u16,
 }
 
+/// Vesting progress of a campaign's funds (returned by runtime API).
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct VestingStatus<Balance, BlockNumber> {
+    pub schedule: VestingSchedule<BlockNumber>,
+    pub start: BlockNumber,
+    /// Total amount vesting (gross, before protocol fees).
+    pub total: Balance,
+    pub vested: Balance,
+    pub claimed: Balance,
+    pub claimable: Balance,
+}
+
 /// Summary of a campaign (returned by runtime API).
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -253,6 +620,13 @@ pub struct CampaignSummary<Balance, BlockNumber> {
     pub milestones_total: Option<u8>,
     pub rwa_asset_id: Option<u32>,
     pub participation_id: Option<u32>,
+    /// Index of the funding round currently open (`None` = the campaign has
+    /// no rounds, or they are over).
+    pub active_round: Option<u32>,
+    pub stretch_goals_reached: u32,
+    pub stretch_goals_total: u32,
+    /// Threshold of the next stretch goal not yet reached.
+    pub next_stretch_goal: Option<Balance>,
 }
 
 /// Error type for check_eligibility runtime API.
@@ -269,4 +643,97 @@ pub enum EligibilityError {
     NftOwnershipNotMet,
     #[codec(index = 4)]
     NotWhitelisted,
+    #[codec(index = 5)]
+    NoRwaParticipation,
+    #[codec(index = 6)]
+    AttestationMissing,
+    #[codec(index = 7)]
+    AccountTooNew,
+    /// Neither branch of an `Or` node passed.
+    #[codec(index = 8)]
+    NoBranchPassed,
+    /// The condition under a `Not` node passed.
+    #[codec(index = 9)]
+    NegatedConditionMet,
+}
+
+/// Merkle proof that an account belongs to a campaign's whitelist root.
+///
+/// The leaf is `blake2_256((account, cap).encode())` and sibling pairs are
+/// hashed in sorted order, so no leaf index is needed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct WhitelistProof<Balance> {
+    /// Per-account investment cap committed to in the leaf.
+    pub cap: Option<Balance>,
+    pub siblings: Vec<[u8; 32]>,
+}
+
+/// Cached result of the first successful whitelist proof for an account.
+/// Only honoured while `root` is still the campaign's whitelist root.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct WhitelistAllowance<Balance> {
+    pub root: [u8; 32],
+    pub cap: Option<Balance>,
+}
+
+/// Failed branch returned by the check_eligibility runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum EligibilityFailure {
+    #[codec(index = 0)]
+    CampaignNotFound,
+    /// The flat rule at `index` in `eligibility_rules` failed.
+    #[codec(index = 1)]
+    Rule { index: u8, error: EligibilityError },
+    /// The expression failed; `path` lists node indices from the root to
+    /// the node that failed.
+    #[codec(index = 2)]
+    Expression { path: sp_std::vec::Vec<u8>, error: EligibilityError },
+    /// The campaign has a registration phase and the account did not
+    /// register interest.
+    #[codec(index = 3)]
+    NotRegistered,
+}
+
+/// Raw storage key a stepped migration resumes after.
+pub type MigrationKey = BoundedVec<u8, ConstU32<256>>;
+
+/// Progress of the multi-block migration currently running for the pallet.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MigrationCursor<BlockNumber> {
+    /// Storage version stamped once the migration finishes.
+    pub target_version: u16,
+    /// Key of the last migrated item; `None` until the first step.
+    pub last_key: Option<MigrationKey>,
+    /// Items migrated so far.
+    pub migrated: u32,
+    pub started_at: BlockNumber,
+}
+
+/// Transaction-fee sponsorship a campaign creator runs for its investors.
+/// The budget is the free balance of the campaign's sponsorship account.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeeSponsorship<AccountId> {
+    /// Account that funded the budget and is refunded when it ends.
+    pub sponsor: AccountId,
+    /// Sponsored transactions allowed per account per `SponsorshipPeriod`.
+    pub max_calls_per_period: u32,
+}
+
+/// Sponsored transactions an account has made in the current period.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SponsoredUsage<BlockNumber> {
+    pub period_start: BlockNumber,
+    pub calls: u32,
+}
+
+/// Past-deadline campaigns the offchain worker submits for finalization,
+/// signed by one of its keys.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct FinalizationPayload<Public, BlockNumber> {
+    /// Block the worker built the payload at.
+    pub block_number: BlockNumber,
+    pub campaign_ids: Vec<u32>,
+    pub public: Public,
 }
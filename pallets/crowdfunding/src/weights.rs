@@ -56,12 +56,44 @@ pub trait WeightInfo {
     fn set_protocol_config() -> Weight;
     fn report_license_revoked() -> Weight;
     fn force_finalize_campaign() -> Weight;
+    fn set_reward_tiers(t: u32) -> Weight;
+    fn invest_with_tier() -> Weight;
+    fn claim_reward_nft() -> Weight;
+    fn process_refunds(n: u32) -> Weight;
+    fn reap_campaign(n: u32) -> Weight;
+    fn set_eligibility_expression(n: u32) -> Weight;
+    fn set_registration_phase() -> Weight;
+    fn register_interest() -> Weight;
+    fn set_whitelist_root() -> Weight;
+    fn invest_with_proof(n: u32) -> Weight;
+    fn set_accepted_currencies() -> Weight;
+    fn invest_in_currency() -> Weight;
+    fn configure_asset_offering() -> Weight;
+    fn claim_asset_share() -> Weight;
+    fn surrender_asset_share() -> Weight;
+    fn set_stretch_goals() -> Weight;
+    fn set_funding_rounds() -> Weight;
+    fn set_creator_bond_bps() -> Weight;
+    fn set_vesting_schedule() -> Weight;
+    fn set_withdrawal_penalty() -> Weight;
+    fn claim_penalty_share() -> Weight;
+    fn transfer_investment() -> Weight;
+    fn set_transfer_lockup() -> Weight;
+    fn fund_fee_sponsorship() -> Weight;
+    fn end_fee_sponsorship() -> Weight;
+    fn set_finalization_key() -> Weight;
+    fn idle_cleanup(n: u32) -> Weight;
 }
 
 /// Weight functions for `pallet_crowdfunding`.
 ///
 /// Values below were generated by running frame-benchmarking CLI on local hardware
 /// (Apple Silicon, aarch64). For production, re-benchmark on reference hardware.
+///
+/// Functions marked `PLACEHOLDER` are hand-written from the storage pattern of
+/// a similar call and have not been measured yet.  All of them have a
+/// benchmark in `benchmarks.rs`; regenerate this file on reference hardware
+/// before a production runtime relies on them.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Crowdfunding CreatorCampaigns (r:1 w:1)
@@ -70,24 +102,35 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Crowdfunding DefaultEligibilityRules (r:1 w:0)
     /// Storage: Crowdfunding ProtocolFeeBpsOverride (r:1 w:0)
     /// Storage: Crowdfunding Campaigns (r:0 w:1)
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding ReapDeposits (r:0 w:1)
+    /// Storage: Crowdfunding CreatorBondBps (r:1 w:0)
+    /// Storage: Crowdfunding CreatorBonds (r:0 w:1)
     fn create_campaign() -> Weight {
         // Measured:  `4`
         // Estimated: `12684`
         // Minimum execution time: 42_000 nanoseconds.
         Weight::from_parts(42_000_000, 12684)
-            .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
     /// Storage: Crowdfunding CampaignWhitelistCount (r:0 w:1)
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
+    /// Storage: Crowdfunding VestingSchedules (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
     fn cancel_campaign() -> Weight {
         // Measured:  `262`
         // Estimated: `5080`
         // Minimum execution time: 17_000 nanoseconds.
         Weight::from_parts(17_000_000, 5080)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(9))
     }
 
     /// Storage: Crowdfunding DefaultEligibilityRules (r:0 w:1)
@@ -103,73 +146,102 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Crowdfunding Investments (r:1 w:1)
     /// Storage: Crowdfunding InvestorCampaigns (r:1 w:1)
     /// Storage: System Account (r:2 w:2)
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:1 w:0)
+    /// Storage: Crowdfunding Registrations (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistRoots (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistAllowances (r:1 w:0)
+    /// Storage: Crowdfunding FundingRounds (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoals (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoalsReached (r:1 w:1)
+    /// Storage: Crowdfunding TotalWeightedAllocations (r:1 w:1)
     fn invest() -> Weight {
         // Measured:  `505`
         // Estimated: `18555`
         // Minimum execution time: 41_000 nanoseconds.
         Weight::from_parts(43_000_000, 18555)
-            .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().reads(14))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
     /// Storage: Crowdfunding Investments (r:1 w:1)
     /// Storage: System Account (r:2 w:2)
+    /// Storage: Crowdfunding InvestorHoldings (r:1 w:1)
+    /// Storage: Crowdfunding CampaignHoldings (r:1 w:1)
+    /// Storage: Crowdfunding PenaltySchedules (r:1 w:0)
+    /// Storage: Crowdfunding PenaltyDestinations (r:1 w:0)
+    /// Storage: Crowdfunding PenaltyPots (r:1 w:1)
+    /// Storage: Crowdfunding FeeRecipientAccount (r:1 w:0)
+    /// Storage: System Account (r:0 w:1)
+    /// Storage: Crowdfunding TotalWeightedAllocations (r:1 w:1)
     fn withdraw_investment() -> Weight {
         // Measured:  `660`
         // Estimated: `14841`
         // Minimum execution time: 48_000 nanoseconds.
         Weight::from_parts(50_000_000, 14841)
-            .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(9))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
     /// Storage: Crowdfunding Investments (r:1 w:1)
     /// Storage: System Account (r:2 w:2)
     /// Storage: Crowdfunding InvestorCampaigns (r:1 w:1)
+    /// Storage: Crowdfunding InvestorHoldings (r:1 w:1)
+    /// Storage: Crowdfunding CampaignHoldings (r:1 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:1 w:1)
+    /// Storage: Crowdfunding PenaltyPots (r:1 w:1)
     fn claim_refund() -> Weight {
         // Measured:  `702`
         // Estimated: `18555`
         // Minimum execution time: 41_000 nanoseconds.
         Weight::from_parts(42_000_000, 18555)
-            .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().writes(10))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
     fn finalize_campaign() -> Weight {
         // Measured:  `328`
         // Estimated: `5080`
         // Minimum execution time: 13_000 nanoseconds.
         Weight::from_parts(15_000_000, 5080)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
     /// Storage: Crowdfunding ProtocolFeeRecipientOverride (r:1 w:0)
     /// Storage: System Account (r:2 w:2)
+    /// Storage: Crowdfunding CampaignHoldings (r:1 w:1)
+    /// Storage: Crowdfunding VestingSchedules (r:1 w:0)
     fn claim_funds() -> Weight {
         // Measured:  `431`
         // Estimated: `12793`
         // Minimum execution time: 59_000 nanoseconds.
         Weight::from_parts(61_000_000, 12793)
-            .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
     /// Storage: System Account (r:1 w:1)
     /// Storage: Crowdfunding CreatorCampaigns (r:1 w:1)
     /// Storage: Crowdfunding CampaignWhitelistCount (r:0 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
     fn claim_creation_deposit() -> Weight {
         // Measured:  `407`
         // Estimated: `12267`
         // Minimum execution time: 41_000 nanoseconds.
         Weight::from_parts(42_000_000, 12267)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:0)
@@ -185,13 +257,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 
     /// Storage: Crowdfunding Campaigns (r:1 w:0)
     /// Storage: Crowdfunding MilestoneStatuses (r:1 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
     fn approve_milestone() -> Weight {
         // Measured:  `486`
         // Estimated: `8583`
         // Minimum execution time: 16_000 nanoseconds.
         Weight::from_parts(17_000_000, 8583)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:0)
@@ -209,33 +283,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Crowdfunding MilestoneStatuses (r:2 w:1)
     /// Storage: Crowdfunding ProtocolFeeRecipientOverride (r:1 w:0)
     /// Storage: System Account (r:2 w:2)
+    /// Storage: Crowdfunding CampaignHoldings (r:1 w:1)
     fn claim_milestone_funds() -> Weight {
         // Measured:  `589`
         // Estimated: `18809`
         // Minimum execution time: 69_000 nanoseconds.
         Weight::from_parts(72_000_000, 18809)
-            .saturating_add(T::DbWeight::get().reads(6))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
     fn pause_campaign() -> Weight {
         // Measured:  `262`
         // Estimated: `5080`
         // Minimum execution time: 13_000 nanoseconds.
         Weight::from_parts(14_000_000, 5080)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding FundingRounds (r:1 w:1)
     fn resume_campaign() -> Weight {
         // Measured:  `266`
         // Estimated: `5080`
         // Minimum execution time: 13_000 nanoseconds.
         Weight::from_parts(14_000_000, 5080)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:0)
@@ -275,36 +354,406 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
     /// Storage: Rwa RwaAssets (r:1 w:0)
     /// Storage: Crowdfunding CampaignWhitelistCount (r:0 w:1)
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
+    /// Storage: Crowdfunding VestingSchedules (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
     fn report_license_revoked() -> Weight {
         // Measured:  `276`
         // Estimated: `8964`
         // Minimum execution time: 23_000 nanoseconds.
         Weight::from_parts(23_000_000, 8964)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().writes(9))
     }
 
     /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
     fn force_finalize_campaign() -> Weight {
         // Same storage pattern as finalize_campaign
+        Weight::from_parts(15_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding RewardTiers (r:0 w:1)
+    /// The range of component `t` is `[0, 10]`.
+    fn set_reward_tiers(t: u32) -> Weight {
+        // PLACEHOLDER: not yet benchmarked; one collection-owner lookup per tier
+        Weight::from_parts(14_000_000, 5080)
+            .saturating_add(Weight::from_parts(3_000_000, 2600).saturating_mul(t.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:1)
+    /// Storage: Crowdfunding InvestorCampaigns (r:1 w:1)
+    /// Storage: Crowdfunding InvestorTiers (r:1 w:1)
+    /// Storage: Crowdfunding RewardTiers (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:1 w:0)
+    /// Storage: Crowdfunding Registrations (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistRoots (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistAllowances (r:1 w:0)
+    /// Storage: Crowdfunding FundingRounds (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoals (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoalsReached (r:1 w:1)
+    /// Storage: Crowdfunding TotalWeightedAllocations (r:1 w:1)
+    fn invest_with_tier() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; same storage pattern as invest, plus the tier reads/writes
+        Weight::from_parts(52_000_000, 23555)
+            .saturating_add(T::DbWeight::get().reads(16))
+            .saturating_add(T::DbWeight::get().writes(9))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding InvestorTiers (r:1 w:1)
+    /// Storage: Crowdfunding RewardTiers (r:1 w:0)
+    /// Storage: Crowdfunding NextRewardSerial (r:1 w:1)
+    /// Storage: Nfts (r:4 w:4)
+    fn claim_reward_nft() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; dominated by the pallet_nfts mint
+        Weight::from_parts(60_000_000, 20000)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding RefundCursor (r:1 w:1)
+    /// Storage: Crowdfunding PendingRefunds (r:0 w:1)
+    /// Storage: Crowdfunding CampaignWhitelistCount (r:0 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:1)
+    /// Storage: Crowdfunding InvestorCampaigns (r:1 w:1)
+    /// Storage: Crowdfunding InvestorTiers (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    /// Storage: Crowdfunding InvestorHoldings (r:1 w:1)
+    /// Storage: Crowdfunding CampaignHoldings (r:1 w:1)
+    /// The range of component `n` is `[1, 50]`.
+    /// Storage: Crowdfunding CreatorBonds (r:n w:n)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:n w:n)
+    /// Storage: Crowdfunding PenaltyPots (r:n w:n)
+    fn process_refunds(n: u32) -> Weight {
+        // PLACEHOLDER: not yet benchmarked; per-investor cost follows the claim_refund storage pattern
+        Weight::from_parts(20_000_000, 5080)
+            .saturating_add(Weight::from_parts(45_000_000, 13475).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(n.into())))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding RewardTiers (r:1 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:1)
+    /// Storage: Crowdfunding InvestorTiers (r:1 w:1)
+    /// Storage: Crowdfunding InvestorCampaigns (r:1 w:1)
+    /// Storage: Crowdfunding NextRewardSerial (r:1 w:1)
+    /// Storage: Nfts (r:4 w:4)
+    /// Storage: Crowdfunding ReapDeposits (r:1 w:1)
+    /// Storage: Crowdfunding MilestoneStatuses (r:0 w:5)
+    /// Storage: Crowdfunding CampaignWhitelistCount (r:0 w:1)
+    /// Storage: Crowdfunding RefundCursor (r:0 w:1)
+    /// Storage: Crowdfunding PendingRefunds (r:0 w:1)
+    /// Storage: Crowdfunding ArchivedCampaigns (r:0 w:1)
+    /// Storage: System Account (r:2 w:2)
+    /// The range of component `n` is `[1, 50]`.
+    /// Storage: Crowdfunding AssetOfferings (r:n+1 w:n+1)
+    /// Storage: Crowdfunding OfferingShareClaims (r:n w:n)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:n+1 w:n+1)
+    /// Storage: Crowdfunding IssuedAssetFraction (r:n w:n)
+    /// Storage: Crowdfunding AssetShares (r:n w:n)
+    /// Storage: Crowdfunding StretchGoals (r:0 w:1)
+    /// Storage: Crowdfunding StretchGoalsReached (r:0 w:1)
+    /// Storage: Crowdfunding FundingRounds (r:0 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:0 w:1)
+    /// Storage: Crowdfunding VestingSchedules (r:0 w:1)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:n w:n)
+    /// Storage: Crowdfunding PenaltyPots (r:1 w:1)
+    /// Storage: Crowdfunding PenaltyDestinations (r:1 w:1)
+    /// Storage: Crowdfunding PenaltySchedules (r:0 w:1)
+    /// Storage: System Account (r:0 w:1)
+    /// Storage: Crowdfunding TransferLockups (r:0 w:1)
+    fn reap_campaign(n: u32) -> Weight {
+        // PLACEHOLDER: not yet benchmarked; per-record cost assumes an unclaimed NFT reward is minted
+        Weight::from_parts(40_000_000, 10160)
+            .saturating_add(Weight::from_parts(65_000_000, 20000).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().reads((15_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(26))
+            .saturating_add(T::DbWeight::get().writes((15_u64).saturating_mul(n.into())))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding EligibilityExpressions (r:0 w:1)
+    /// The range of component `n` is `[0, 15]`.
+    fn set_eligibility_expression(n: u32) -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_reward_tiers
+        Weight::from_parts(16_000_000, 5080)
+            .saturating_add(Weight::from_parts(600_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:0 w:1)
+    fn set_registration_phase() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_eligibility_expression
+        Weight::from_parts(16_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:1 w:0)
+    /// Storage: Crowdfunding Registrations (r:1 w:1)
+    /// Storage: Crowdfunding RegistrationCount (r:1 w:1)
+    /// Storage: Assets Account (r:10 w:0)
+    fn register_interest() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; one asset balance read per snapshot asset
+        Weight::from_parts(30_000_000, 10580)
+            .saturating_add(T::DbWeight::get().reads(15))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistRoots (r:0 w:1)
+    fn set_whitelist_root() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors add_to_whitelist
+        Weight::from_parts(14_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding WhitelistAllowances (r:1 w:1)
+    /// Storage: Crowdfunding WhitelistRoots (r:1 w:0)
+    /// The range of component `n` is `[0, 20]`.
+    fn invest_with_proof(n: u32) -> Weight {
+        // PLACEHOLDER: not yet benchmarked; invest plus one blake2_256 per proof sibling
+        Self::invest()
+            .saturating_add(Weight::from_parts(2_000_000, 0))
+            .saturating_add(Weight::from_parts(400_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding AcceptedCurrencies (r:0 w:1)
+    /// Storage: Crowdfunding PenaltyDestinations (r:1 w:0)
+    fn set_accepted_currencies() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_whitelist_root
+        Weight::from_parts(15_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding AcceptedCurrencies (r:1 w:0)
+    /// Storage: Crowdfunding CampaignHoldings (r:1 w:1)
+    /// Storage: Crowdfunding InvestorHoldings (r:1 w:1)
+    /// Storage: Assets Account (r:2 w:2)
+    fn invest_in_currency() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; invest plus the price feed lookup and holdings
+        Self::invest()
+            .saturating_add(Weight::from_parts(10_000_000, 0))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Rwa AssetValuations (r:1 w:0)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding IssuedAssetFraction (r:1 w:0)
+    fn configure_asset_offering() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_accepted_currencies plus the valuation lookup
+        Weight::from_parts(20_000_000, 8964)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding OfferingShareClaims (r:1 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:0)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding IssuedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding AssetShares (r:1 w:1)
+    fn claim_asset_share() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; storage-only, no transfers
+        Weight::from_parts(25_000_000, 12103)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    /// Storage: Crowdfunding AssetShares (r:1 w:1)
+    /// Storage: Crowdfunding IssuedAssetFraction (r:1 w:1)
+    fn surrender_asset_share() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; storage-only, no transfers
+        Weight::from_parts(12_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoals (r:1 w:1)
+    /// Storage: Crowdfunding StretchGoalsReached (r:1 w:0)
+    fn set_stretch_goals() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_accepted_currencies
+        Weight::from_parts(16_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding FundingRounds (r:0 w:1)
+    fn set_funding_rounds() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_accepted_currencies
+        Weight::from_parts(15_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding CreatorBondBps (r:0 w:1)
+    fn set_creator_bond_bps() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_default_eligibility
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding VestingSchedules (r:0 w:1)
+    fn set_vesting_schedule() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_accepted_currencies
         Weight::from_parts(15_000_000, 5080)
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding AcceptedCurrencies (r:1 w:0)
+    /// Storage: Crowdfunding PenaltySchedules (r:0 w:1)
+    /// Storage: Crowdfunding PenaltyDestinations (r:0 w:1)
+    fn set_withdrawal_penalty() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_accepted_currencies
+        Weight::from_parts(17_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:1 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:0)
+    /// Storage: Crowdfunding PenaltyPots (r:1 w:1)
+    /// Storage: Crowdfunding PenaltyDestinations (r:1 w:0)
+    /// Storage: System Account (r:1 w:2)
+    fn claim_penalty_share() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors claim_refund
+        Weight::from_parts(42_000_000, 18555)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding TransferLockups (r:1 w:0)
+    /// Storage: Crowdfunding OfferingShareClaims (r:2 w:0)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:2 w:0)
+    /// Storage: Crowdfunding Investments (r:2 w:2)
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:1 w:0)
+    /// Storage: Crowdfunding Registrations (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistRoots (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistAllowances (r:1 w:0)
+    /// Storage: Crowdfunding InvestorCampaigns (r:2 w:2)
+    /// Storage: Crowdfunding InvestorHoldings (r:2 w:2)
+    /// Storage: Crowdfunding InvestorTiers (r:2 w:2)
+    /// Storage: Crowdfunding RewardTiers (r:1 w:1)
+    fn transfer_investment() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors invest
+        Weight::from_parts(45_000_000, 18555)
+            .saturating_add(T::DbWeight::get().reads(21))
+            .saturating_add(T::DbWeight::get().writes(10))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding TransferLockups (r:0 w:1)
+    fn set_transfer_lockup() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_vesting_schedule
+        Weight::from_parts(15_000_000, 5080)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding FeeSponsorships (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    fn fund_fee_sponsorship() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; campaign and sponsorship reads plus a native transfer
+        Weight::from_parts(34_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: Crowdfunding FeeSponsorships (r:1 w:1)
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
+    fn end_fee_sponsorship() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors fund_fee_sponsorship
+        Weight::from_parts(34_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: Crowdfunding FinalizationKeys (r:0 w:1)
+    fn set_finalization_key() -> Weight {
+        // PLACEHOLDER: not yet benchmarked; mirrors set_creator_bond_bps
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: Crowdfunding CampaignWhitelist (r:n w:n)
+    /// Storage: Crowdfunding WhitelistAllowances (r:n w:n)
+    /// Storage: Crowdfunding Registrations (r:n w:n)
+    /// Storage: Crowdfunding SponsoredCalls (r:n w:n)
+    /// Storage: Crowdfunding PendingCleanups (r:0 w:1)
+    /// The range of component `n` is `[1, 500]`.
+    fn idle_cleanup(n: u32) -> Weight {
+        // PLACEHOLDER: not yet benchmarked; one key visit and removal per record
+        Weight::from_parts(3_000_000, 0)
+            .saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
 }
 
 /// Fallback weights for testing / development.
 impl WeightInfo for () {
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding ReapDeposits (r:0 w:1)
+    /// Storage: Crowdfunding CreatorBondBps (r:1 w:0)
+    /// Storage: Crowdfunding CreatorBonds (r:0 w:1)
     fn create_campaign() -> Weight {
         Weight::from_parts(42_000_000, 12684)
-            .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(7))
     }
 
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
+    /// Storage: Crowdfunding VestingSchedules (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
     fn cancel_campaign() -> Weight {
         Weight::from_parts(17_000_000, 5080)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().writes(9))
     }
 
     fn set_default_eligibility() -> Weight {
@@ -312,40 +761,64 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(1))
     }
 
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:1 w:0)
+    /// Storage: Crowdfunding Registrations (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistRoots (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistAllowances (r:1 w:0)
+    /// Storage: Crowdfunding FundingRounds (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoals (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoalsReached (r:1 w:1)
+    /// Storage: Crowdfunding TotalWeightedAllocations (r:1 w:1)
     fn invest() -> Weight {
         Weight::from_parts(43_000_000, 18555)
-            .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(5))
+            .saturating_add(RocksDbWeight::get().reads(14))
+            .saturating_add(RocksDbWeight::get().writes(7))
     }
 
+    /// Storage: Crowdfunding PenaltySchedules (r:1 w:0)
+    /// Storage: Crowdfunding PenaltyDestinations (r:1 w:0)
+    /// Storage: Crowdfunding PenaltyPots (r:1 w:1)
+    /// Storage: Crowdfunding FeeRecipientAccount (r:1 w:0)
+    /// Storage: System Account (r:0 w:1)
+    /// Storage: Crowdfunding TotalWeightedAllocations (r:1 w:1)
     fn withdraw_investment() -> Weight {
         Weight::from_parts(50_000_000, 14841)
-            .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(11))
+            .saturating_add(RocksDbWeight::get().writes(9))
     }
 
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:1 w:1)
+    /// Storage: Crowdfunding PenaltyPots (r:1 w:1)
     fn claim_refund() -> Weight {
         Weight::from_parts(42_000_000, 18555)
-            .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(5))
+            .saturating_add(RocksDbWeight::get().reads(10))
+            .saturating_add(RocksDbWeight::get().writes(10))
     }
 
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
     fn finalize_campaign() -> Weight {
         Weight::from_parts(15_000_000, 5080)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
 
+    /// Storage: Crowdfunding VestingSchedules (r:1 w:0)
     fn claim_funds() -> Weight {
         Weight::from_parts(61_000_000, 12793)
-            .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
     fn claim_creation_deposit() -> Weight {
         Weight::from_parts(42_000_000, 12267)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
 
     fn submit_milestone() -> Weight {
@@ -354,10 +827,12 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(1))
     }
 
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
     fn approve_milestone() -> Weight {
         Weight::from_parts(17_000_000, 8583)
-            .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn reject_milestone() -> Weight {
@@ -368,20 +843,24 @@ impl WeightInfo for () {
 
     fn claim_milestone_funds() -> Weight {
         Weight::from_parts(72_000_000, 18809)
-            .saturating_add(RocksDbWeight::get().reads(6))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
 
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
     fn pause_campaign() -> Weight {
         Weight::from_parts(14_000_000, 5080)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
     }
 
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding FundingRounds (r:1 w:1)
     fn resume_campaign() -> Weight {
         Weight::from_parts(14_000_000, 5080)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
     }
 
     fn add_to_whitelist() -> Weight {
@@ -401,15 +880,357 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(2))
     }
 
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:1 w:1)
+    /// Storage: Crowdfunding VestingSchedules (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
     fn report_license_revoked() -> Weight {
         Weight::from_parts(23_000_000, 8964)
+            .saturating_add(RocksDbWeight::get().reads(9))
+            .saturating_add(RocksDbWeight::get().writes(9))
+    }
+
+    /// Storage: Crowdfunding CampaignDeadlines (r:1 w:1)
+    /// Storage: Crowdfunding PendingFinalizations (r:0 w:1)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    fn force_finalize_campaign() -> Weight {
+        Weight::from_parts(15_000_000, 5080)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn set_reward_tiers(t: u32) -> Weight {
+        Weight::from_parts(14_000_000, 5080)
+            .saturating_add(Weight::from_parts(3_000_000, 2600).saturating_mul(t.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t.into())))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:1 w:0)
+    /// Storage: Crowdfunding Registrations (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistRoots (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistAllowances (r:1 w:0)
+    /// Storage: Crowdfunding FundingRounds (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoals (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoalsReached (r:1 w:1)
+    /// Storage: Crowdfunding TotalWeightedAllocations (r:1 w:1)
+    fn invest_with_tier() -> Weight {
+        Weight::from_parts(52_000_000, 23555)
+            .saturating_add(RocksDbWeight::get().reads(16))
+            .saturating_add(RocksDbWeight::get().writes(9))
+    }
+
+    fn claim_reward_nft() -> Weight {
+        Weight::from_parts(60_000_000, 20000)
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding RefundCursor (r:1 w:1)
+    /// Storage: Crowdfunding PendingRefunds (r:0 w:1)
+    /// Storage: Crowdfunding CampaignWhitelistCount (r:0 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:1)
+    /// Storage: Crowdfunding InvestorCampaigns (r:1 w:1)
+    /// Storage: Crowdfunding InvestorTiers (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    /// Storage: Crowdfunding InvestorHoldings (r:1 w:1)
+    /// Storage: Crowdfunding CampaignHoldings (r:1 w:1)
+    /// The range of component `n` is `[1, 50]`.
+    /// Storage: Crowdfunding CreatorBonds (r:n w:n)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:n w:n)
+    /// Storage: Crowdfunding PenaltyPots (r:n w:n)
+    fn process_refunds(n: u32) -> Weight {
+        // Estimated: per-investor cost follows the claim_refund storage pattern
+        Weight::from_parts(20_000_000, 5080)
+            .saturating_add(Weight::from_parts(45_000_000, 13475).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(n.into())))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding RewardTiers (r:1 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:1)
+    /// Storage: Crowdfunding InvestorTiers (r:1 w:1)
+    /// Storage: Crowdfunding InvestorCampaigns (r:1 w:1)
+    /// Storage: Crowdfunding NextRewardSerial (r:1 w:1)
+    /// Storage: Nfts (r:4 w:4)
+    /// Storage: Crowdfunding ReapDeposits (r:1 w:1)
+    /// Storage: Crowdfunding MilestoneStatuses (r:0 w:5)
+    /// Storage: Crowdfunding CampaignWhitelistCount (r:0 w:1)
+    /// Storage: Crowdfunding RefundCursor (r:0 w:1)
+    /// Storage: Crowdfunding PendingRefunds (r:0 w:1)
+    /// Storage: Crowdfunding ArchivedCampaigns (r:0 w:1)
+    /// Storage: System Account (r:2 w:2)
+    /// The range of component `n` is `[1, 50]`.
+    /// Storage: Crowdfunding AssetOfferings (r:n+1 w:n+1)
+    /// Storage: Crowdfunding OfferingShareClaims (r:n w:n)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:n+1 w:n+1)
+    /// Storage: Crowdfunding IssuedAssetFraction (r:n w:n)
+    /// Storage: Crowdfunding AssetShares (r:n w:n)
+    /// Storage: Crowdfunding StretchGoals (r:0 w:1)
+    /// Storage: Crowdfunding StretchGoalsReached (r:0 w:1)
+    /// Storage: Crowdfunding FundingRounds (r:0 w:1)
+    /// Storage: Crowdfunding CreatorBonds (r:0 w:1)
+    /// Storage: Crowdfunding VestingSchedules (r:0 w:1)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:n w:n)
+    /// Storage: Crowdfunding PenaltyPots (r:1 w:1)
+    /// Storage: Crowdfunding PenaltyDestinations (r:1 w:1)
+    /// Storage: Crowdfunding PenaltySchedules (r:0 w:1)
+    /// Storage: System Account (r:0 w:1)
+    /// Storage: Crowdfunding TransferLockups (r:0 w:1)
+    fn reap_campaign(n: u32) -> Weight {
+        // Estimated: per-record cost assumes an unclaimed NFT reward is minted
+        Weight::from_parts(40_000_000, 10160)
+            .saturating_add(Weight::from_parts(65_000_000, 20000).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(11))
+            .saturating_add(RocksDbWeight::get().reads((15_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(26))
+            .saturating_add(RocksDbWeight::get().writes((15_u64).saturating_mul(n.into())))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding EligibilityExpressions (r:0 w:1)
+    /// The range of component `n` is `[0, 15]`.
+    fn set_eligibility_expression(n: u32) -> Weight {
+        // Estimated: mirrors set_reward_tiers
+        Weight::from_parts(16_000_000, 5080)
+            .saturating_add(Weight::from_parts(600_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:0 w:1)
+    fn set_registration_phase() -> Weight {
+        // Estimated: mirrors set_eligibility_expression
+        Weight::from_parts(16_000_000, 5080)
             .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:1 w:0)
+    /// Storage: Crowdfunding Registrations (r:1 w:1)
+    /// Storage: Crowdfunding RegistrationCount (r:1 w:1)
+    /// Storage: Assets Account (r:10 w:0)
+    fn register_interest() -> Weight {
+        // Estimated: one asset balance read per snapshot asset
+        Weight::from_parts(30_000_000, 10580)
+            .saturating_add(RocksDbWeight::get().reads(15))
             .saturating_add(RocksDbWeight::get().writes(2))
     }
 
-    fn force_finalize_campaign() -> Weight {
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistRoots (r:0 w:1)
+    fn set_whitelist_root() -> Weight {
+        // Estimated: mirrors add_to_whitelist
+        Weight::from_parts(14_000_000, 5080)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding WhitelistAllowances (r:1 w:1)
+    /// Storage: Crowdfunding WhitelistRoots (r:1 w:0)
+    /// The range of component `n` is `[0, 20]`.
+    fn invest_with_proof(n: u32) -> Weight {
+        // Estimated: invest plus one blake2_256 per proof sibling
+        Self::invest()
+            .saturating_add(Weight::from_parts(2_000_000, 0))
+            .saturating_add(Weight::from_parts(400_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding AcceptedCurrencies (r:0 w:1)
+    /// Storage: Crowdfunding PenaltyDestinations (r:1 w:0)
+    fn set_accepted_currencies() -> Weight {
+        // Estimated: mirrors set_whitelist_root
+        Weight::from_parts(15_000_000, 5080)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding AcceptedCurrencies (r:1 w:0)
+    /// Storage: Crowdfunding CampaignHoldings (r:1 w:1)
+    /// Storage: Crowdfunding InvestorHoldings (r:1 w:1)
+    /// Storage: Assets Account (r:2 w:2)
+    fn invest_in_currency() -> Weight {
+        // Estimated: invest plus the price feed lookup and holdings
+        Self::invest()
+            .saturating_add(Weight::from_parts(10_000_000, 0))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Rwa AssetValuations (r:1 w:0)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding IssuedAssetFraction (r:1 w:0)
+    fn configure_asset_offering() -> Weight {
+        // Estimated: mirrors set_accepted_currencies plus the valuation lookup
+        Weight::from_parts(20_000_000, 8964)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding AssetOfferings (r:1 w:1)
+    /// Storage: Crowdfunding OfferingShareClaims (r:1 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:0)
+    /// Storage: Crowdfunding CommittedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding IssuedAssetFraction (r:1 w:1)
+    /// Storage: Crowdfunding AssetShares (r:1 w:1)
+    fn claim_asset_share() -> Weight {
+        // Estimated: storage-only, no transfers
+        Weight::from_parts(25_000_000, 12103)
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+
+    /// Storage: Crowdfunding AssetShares (r:1 w:1)
+    /// Storage: Crowdfunding IssuedAssetFraction (r:1 w:1)
+    fn surrender_asset_share() -> Weight {
+        // Estimated: storage-only, no transfers
+        Weight::from_parts(12_000_000, 5080)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding StretchGoals (r:1 w:1)
+    /// Storage: Crowdfunding StretchGoalsReached (r:1 w:0)
+    fn set_stretch_goals() -> Weight {
+        // Estimated: mirrors set_accepted_currencies
+        Weight::from_parts(16_000_000, 5080)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding FundingRounds (r:0 w:1)
+    fn set_funding_rounds() -> Weight {
+        // Estimated: mirrors set_accepted_currencies
         Weight::from_parts(15_000_000, 5080)
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
+
+    /// Storage: Crowdfunding CreatorBondBps (r:0 w:1)
+    fn set_creator_bond_bps() -> Weight {
+        // Estimated: mirrors set_default_eligibility
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding VestingSchedules (r:0 w:1)
+    fn set_vesting_schedule() -> Weight {
+        // Estimated: mirrors set_accepted_currencies
+        Weight::from_parts(15_000_000, 5080)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding AcceptedCurrencies (r:1 w:0)
+    /// Storage: Crowdfunding PenaltySchedules (r:0 w:1)
+    /// Storage: Crowdfunding PenaltyDestinations (r:0 w:1)
+    fn set_withdrawal_penalty() -> Weight {
+        // Estimated: mirrors set_accepted_currencies
+        Weight::from_parts(17_000_000, 5080)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:1 w:1)
+    /// Storage: Crowdfunding Investments (r:1 w:0)
+    /// Storage: Crowdfunding PenaltyPots (r:1 w:1)
+    /// Storage: Crowdfunding PenaltyDestinations (r:1 w:0)
+    /// Storage: System Account (r:1 w:2)
+    fn claim_penalty_share() -> Weight {
+        // Estimated: mirrors claim_refund
+        Weight::from_parts(42_000_000, 18555)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:1)
+    /// Storage: Crowdfunding TransferLockups (r:1 w:0)
+    /// Storage: Crowdfunding OfferingShareClaims (r:2 w:0)
+    /// Storage: Crowdfunding PenaltyShareClaims (r:2 w:0)
+    /// Storage: Crowdfunding Investments (r:2 w:2)
+    /// Storage: Crowdfunding EligibilityExpressions (r:1 w:0)
+    /// Storage: Crowdfunding RegistrationPhases (r:1 w:0)
+    /// Storage: Crowdfunding Registrations (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistRoots (r:1 w:0)
+    /// Storage: Crowdfunding WhitelistAllowances (r:1 w:0)
+    /// Storage: Crowdfunding InvestorCampaigns (r:2 w:2)
+    /// Storage: Crowdfunding InvestorHoldings (r:2 w:2)
+    /// Storage: Crowdfunding InvestorTiers (r:2 w:2)
+    /// Storage: Crowdfunding RewardTiers (r:1 w:1)
+    fn transfer_investment() -> Weight {
+        // Estimated: mirrors invest
+        Weight::from_parts(45_000_000, 18555)
+            .saturating_add(RocksDbWeight::get().reads(21))
+            .saturating_add(RocksDbWeight::get().writes(10))
+    }
+
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding TransferLockups (r:0 w:1)
+    fn set_transfer_lockup() -> Weight {
+        // Estimated: mirrors set_vesting_schedule
+        Weight::from_parts(15_000_000, 5080)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: Crowdfunding FeeSponsorships (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    fn fund_fee_sponsorship() -> Weight {
+        // Estimated: campaign and sponsorship reads plus a native transfer
+        Weight::from_parts(34_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    /// Storage: Crowdfunding FeeSponsorships (r:1 w:1)
+    /// Storage: Crowdfunding Campaigns (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
+    fn end_fee_sponsorship() -> Weight {
+        // Estimated: mirrors fund_fee_sponsorship
+        Weight::from_parts(34_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    /// Storage: Crowdfunding FinalizationKeys (r:0 w:1)
+    fn set_finalization_key() -> Weight {
+        // Estimated: mirrors set_creator_bond_bps
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    /// Storage: Crowdfunding CampaignWhitelist (r:n w:n)
+    /// Storage: Crowdfunding WhitelistAllowances (r:n w:n)
+    /// Storage: Crowdfunding Registrations (r:n w:n)
+    /// Storage: Crowdfunding SponsoredCalls (r:n w:n)
+    /// Storage: Crowdfunding PendingCleanups (r:0 w:1)
+    /// The range of component `n` is `[1, 500]`.
+    fn idle_cleanup(n: u32) -> Weight {
+        // Estimated: one key visit and removal per record
+        Weight::from_parts(3_000_000, 0)
+            .saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
 }
@@ -1,15 +1,45 @@
 use std::sync::Arc;
 
 use codec::Codec;
+use futures::{future, stream, FutureExt, StreamExt};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     proc_macros::rpc,
+    types::SubscriptionResult,
+    SubscriptionSink,
 };
 use pallet_rwa::{CanParticipateError, ParticipationStatus};
 use pallet_rwa_runtime_api::RwaApi as RwaRuntimeApi;
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+
+/// Which participation changes a `rwa_subscribeParticipation` subscriber
+/// receives.  Unset fields match everything.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ParticipationSubscription<AccountId> {
+    pub asset_id: Option<u32>,
+    /// Only participations this account holds, or held before the block.
+    pub holder: Option<AccountId>,
+    /// Follow finalized blocks instead of new best blocks.
+    pub finalized: bool,
+}
+
+/// A participation named by a block's events, with its status after the
+/// block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipationUpdate<BlockHash, BlockNumber> {
+    pub block_hash: BlockHash,
+    pub asset_id: u32,
+    pub participation_id: u32,
+    /// `None` once the participation has been removed (e.g. rejected).
+    pub status: Option<ParticipationStatus<BlockNumber>>,
+}
 
 #[rpc(client, server)]
 pub trait RwaRpc<BlockHash, AccountId, Balance, BlockNumber, AssetId> {
@@ -41,15 +71,106 @@ pub trait RwaRpc<BlockHash, AccountId, Balance, BlockNumber, AssetId> {
 
     #[method(name = "rwa_activeParticipantCount")]
     fn active_participant_count(&self, asset_id: u32, at: Option<BlockHash>) -> RpcResult<u32>;
+
+    /// Push the participation status changes of each new best (or
+    /// finalized) block that match `filter`.
+    #[subscription(
+        name = "rwa_subscribeParticipation" => "rwa_participation",
+        unsubscribe = "rwa_unsubscribeParticipation",
+        item = ParticipationUpdate<BlockHash, BlockNumber>,
+    )]
+    fn subscribe_participation(&self, filter: ParticipationSubscription<AccountId>);
 }
 
 pub struct RwaRpcHandler<C, Block> {
     client: Arc<C>,
+    executor: SubscriptionTaskExecutor,
     _marker: std::marker::PhantomData<Block>,
 }
 
 impl<C, Block> RwaRpcHandler<C, Block> {
-    pub fn new(client: Arc<C>) -> Self { Self { client, _marker: Default::default() } }
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+        Self { client, executor, _marker: Default::default() }
+    }
+}
+
+impl<C, Block> RwaRpcHandler<C, Block>
+where
+    Block: BlockT,
+    C: BlockchainEvents<Block>,
+{
+    /// Hashes of new best blocks, or of every newly finalized block in order.
+    fn block_hashes(&self, finalized: bool) -> stream::BoxStream<'static, Block::Hash> {
+        if finalized {
+            self.client
+                .finality_notification_stream()
+                .flat_map(|n| {
+                    let hashes: Vec<_> = n.tree_route.iter().copied().chain([n.hash]).collect();
+                    stream::iter(hashes)
+                })
+                .boxed()
+        } else {
+            self.client
+                .import_notification_stream()
+                .filter(|n| future::ready(n.is_new_best))
+                .map(|n| n.hash)
+                .boxed()
+        }
+    }
+}
+
+/// The participation changes in block `hash` that match `filter`.
+fn participation_updates<C, Block, AccountId, Balance, BlockNumber, AssetId>(
+    client: &C,
+    hash: Block::Hash,
+    filter: &ParticipationSubscription<AccountId>,
+) -> Vec<ParticipationUpdate<Block::Hash, BlockNumber>>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: RwaRuntimeApi<Block, AccountId, Balance, BlockNumber, AssetId>,
+    AccountId: Codec + Clone,
+    Balance: Codec,
+    BlockNumber: Codec,
+    AssetId: Codec,
+{
+    let api = client.runtime_api();
+    let touched = match api.participation_updates(hash) {
+        Ok(touched) => touched,
+        Err(_) => return Vec::new(),
+    };
+    let holdings = |at: Block::Hash| match &filter.holder {
+        Some(holder) => api.participations_by_holder(at, holder.clone()).unwrap_or_default(),
+        None => Vec::new(),
+    };
+    let (mut held_after, mut held_before) = (None, None);
+
+    let mut updates = Vec::new();
+    for (asset_id, participation_id) in touched {
+        if filter.asset_id.map_or(false, |id| id != asset_id) {
+            continue;
+        }
+        if filter.holder.is_some() {
+            let key = (asset_id, participation_id);
+            let after = held_after.get_or_insert_with(|| holdings(hash));
+            if !after.contains(&key) {
+                let before =
+                    held_before.get_or_insert_with(|| match client.header(hash).ok().flatten() {
+                        Some(header) => holdings(*header.parent_hash()),
+                        None => Vec::new(),
+                    });
+                if !before.contains(&key) {
+                    continue;
+                }
+            }
+        }
+        let status = match api.effective_participation_status(hash, asset_id, participation_id) {
+            Ok(status) => status,
+            Err(_) => continue,
+        };
+        updates.push(ParticipationUpdate { block_hash: hash, asset_id, participation_id, status });
+    }
+    updates
 }
 
 #[async_trait]
@@ -58,11 +179,16 @@ impl<C, Block, AccountId, Balance, BlockNumber, AssetId>
     for RwaRpcHandler<C, Block>
 where
     Block: BlockT,
-    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C: Send
+        + Sync
+        + 'static
+        + ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockchainEvents<Block>,
     C::Api: RwaRuntimeApi<Block, AccountId, Balance, BlockNumber, AssetId>,
-    AccountId: Codec + Send + Sync + 'static,
+    AccountId: Codec + Clone + Send + Sync + 'static,
     Balance: Codec + Send + Sync + 'static,
-    BlockNumber: Codec + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
     AssetId: Codec + Send + Sync + 'static,
 {
     fn effective_participation_status(
@@ -121,4 +247,21 @@ where
         api.active_participant_count(at_hash, asset_id)
             .map_err(|e| jsonrpsee::core::Error::Custom(format!("{:?}", e)))
     }
+
+    fn subscribe_participation(
+        &self,
+        sink: SubscriptionSink,
+        filter: ParticipationSubscription<AccountId>,
+    ) -> SubscriptionResult {
+        let client = self.client.clone();
+        let updates = self
+            .block_hashes(filter.finalized)
+            .flat_map(move |hash| stream::iter(participation_updates(&*client, hash, &filter)))
+            .boxed();
+        let fut = async move {
+            sink.pipe_from_stream(updates).await;
+        };
+        self.executor.spawn("rwa-participation-subscription", Some("rpc"), fut.boxed());
+        Ok(())
+    }
 }
@@ -5,6 +5,7 @@ use pallet_rwa::{CanParticipateError, ParticipationStatus};
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait RwaApi<AccountId, Balance, BlockNumber, AssetId>
     where
         AccountId: Codec,
@@ -27,5 +28,10 @@ sp_api::decl_runtime_apis! {
         fn participations_by_holder(holder: AccountId) -> Vec<(u32, u32)>;
 
         fn active_participant_count(asset_id: u32) -> u32;
+
+        /// Participations named by this block's events, as
+        /// `(asset_id, participation_id)` pairs without duplicates.
+        #[api_version(2)]
+        fn participation_updates() -> Vec<(u32, u32)>;
     }
 }
@@ -1339,7 +1339,7 @@ mod cat10_governance {
 
     #[test]
     fn attack_cat10_1_storage_version_exists() {
-        // CRIT-05 fix is now APPLIED: StorageVersion is declared as V5.
+        // CRIT-05 fix is now APPLIED: StorageVersion is declared as V6.
         // This test verifies the fix is in place and the version is
         // correctly set for future migration safety.
         ExtBuilder::default().build().execute_with(|| {
@@ -1347,11 +1347,11 @@ mod cat10_governance {
 
             let current = Rwa::current_storage_version();
 
-            // The in-code storage version should be 5 (V5 = MinParticipationDeposit)
+            // The in-code storage version should be 6 (V6 = ParticipationExpiries)
             assert_eq!(
                 current,
-                frame_support::traits::StorageVersion::new(5),
-                "DEFENDED: StorageVersion is declared as V5"
+                frame_support::traits::StorageVersion::new(6),
+                "DEFENDED: StorageVersion is declared as V6"
             );
         });
     }
@@ -1831,6 +1831,7 @@ mod cross_cutting {
 //   P8: guard scope isolation             (test 8)
 //   P9: full lifecycle simulation         (test 9)
 //   P10: per-participation slash granularity (test 10)
+//   P11: guard weight charged            (test 11)
 //
 // Each partition is mutually exclusive (tests exactly one unique
 // behavioral scenario) and collectively exhaustive (covers every
@@ -2349,4 +2350,32 @@ mod crit03_asset_lifecycle_guard {
                 assert_eq!(p1.deposit_held, 0);
             });
     }
+
+    // ── P11: guard weight charged ────────────────────────────────────
+    //
+    // A guard that scans storage must not run for free: its reported
+    // weight is part of the extrinsic's declared weight.
+
+    #[test]
+    fn attack_crit03_guard_weight_is_charged() {
+        use frame_support::dispatch::GetDispatchInfo;
+
+        let retire = pallet::Call::<Test>::force_retire_asset { rwa_asset_id: 0 };
+        assert_eq!(
+            retire.get_dispatch_info().weight,
+            <() as crate::WeightInfo>::force_retire_asset()
+                + MockLifecycleGuard::can_retire_asset_weight()
+        );
+        let slash = pallet::Call::<Test>::slash_participation {
+            rwa_asset_id: 0,
+            participation_id: 0,
+            amount: 10,
+            reporter: None,
+        };
+        assert_eq!(
+            slash.get_dispatch_info().weight,
+            <() as crate::WeightInfo>::slash_participation()
+                + MockLifecycleGuard::can_slash_participation_weight()
+        );
+    }
 }
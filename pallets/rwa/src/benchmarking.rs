@@ -392,5 +392,48 @@ benchmarks! {
         assert_eq!(pallet_rwa::PendingApprovals::<T>::get(aid).len(), 0);
     }
 
+    set_asset_valuation {
+        let caller = create_funded_account::<T>("caller", 0);
+        let beneficiary = create_funded_account::<T>("beneficiary", 0);
+        let aid = setup_asset::<T>(&caller, &beneficiary, 10);
+        let amount = T::MinParticipationDeposit::get().saturating_mul(1_000u32.into());
+    }: _(RawOrigin::Root, aid, PaymentCurrency::Native, amount)
+    verify {
+        assert!(pallet_rwa::AssetValuations::<T>::contains_key(aid));
+    }
+
+    fund_fee_sponsorship {
+        let caller = create_funded_account::<T>("caller", 0);
+        let beneficiary = create_funded_account::<T>("beneficiary", 0);
+        let aid = setup_asset::<T>(&caller, &beneficiary, 10);
+        let amount = T::NativeCurrency::minimum_balance().saturating_mul(100u32.into());
+    }: _(RawOrigin::Signed(caller), aid, amount, 10)
+    verify {
+        assert!(pallet_rwa::FeeSponsorships::<T>::contains_key(aid));
+    }
+
+    end_fee_sponsorship {
+        let caller = create_funded_account::<T>("caller", 0);
+        let beneficiary = create_funded_account::<T>("beneficiary", 0);
+        let aid = setup_asset::<T>(&caller, &beneficiary, 10);
+        let amount = T::NativeCurrency::minimum_balance().saturating_mul(100u32.into());
+        assert_ok!(Pallet::<T>::fund_fee_sponsorship(
+            RawOrigin::Signed(caller.clone()).into(),
+            aid,
+            amount,
+            10,
+        ));
+    }: _(RawOrigin::Signed(caller), aid)
+    verify {
+        assert!(!pallet_rwa::FeeSponsorships::<T>::contains_key(aid));
+    }
+
+    set_settlement_key {
+        let key: T::AccountId = account("key", 0, 0);
+    }: _(RawOrigin::Root, key.clone(), true)
+    verify {
+        assert!(pallet_rwa::SettlementKeys::<T>::contains_key(&key));
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
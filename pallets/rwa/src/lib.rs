@@ -5,6 +5,7 @@ pub mod migrations;
 pub mod types;
 pub mod weights;
 
+pub use migrations::stepped::SteppedMigration;
 pub use pallet::*;
 pub use types::*;
 pub use weights::WeightInfo;
@@ -19,19 +20,59 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+use frame_system::offchain::{SignedPayload, SigningTypes};
+use sp_core::crypto::KeyTypeId;
+
+/// Key type of the keys signing the offchain worker's settlement payloads.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"rwa!");
+
+/// sr25519 keys under [`KEY_TYPE`], for the offchain worker's signed payloads.
+pub mod crypto {
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    use super::KEY_TYPE;
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    pub struct SettlementAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for SettlementAuthId {
+        type GenericPublic = sp_core::sr25519::Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type RuntimeAppPublic = Public;
+    }
+}
+
+impl<T: SigningTypes> SignedPayload<T> for SettlementPayload<T::Public, T::BlockNumber> {
+    fn public(&self) -> T::Public { self.public.clone() }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         pallet_prelude::*,
+        storage,
         traits::{
-            tokens::fungibles, Currency, ExistenceRequirement, ReservableCurrency, WithdrawReasons,
+            tokens::fungibles, Currency, ExistenceRequirement, Imbalance, ReservableCurrency,
+            WithdrawReasons,
         },
         PalletId,
     };
-    use frame_system::pallet_prelude::*;
+    use frame_system::{
+        offchain::{AppCrypto, SendTransactionTypes, SendUnsignedTransaction, Signer},
+        pallet_prelude::*,
+    };
     use sp_runtime::{
-        traits::{AccountIdConversion, Saturating, Zero},
-        Permill,
+        offchain::storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+        traits::{AccountIdConversion, IdentifyAccount, Saturating, UniqueSaturatedInto, Zero},
+        transaction_validity::{
+            InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
+            ValidTransaction,
+        },
+        Permill, RuntimeAppPublic,
     };
     use sp_std::vec::Vec;
 
@@ -56,21 +97,36 @@ pub mod pallet {
         <T as Config>::MaxGroupSize,
     >;
 
+    pub type NegativeImbalanceOf<T> = <<T as Config>::NativeCurrency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+
     pub type SlashRecipientOf<T> = SlashRecipient<<T as frame_system::Config>::AccountId>;
 
+    pub type MigrationCursorOf<T> = MigrationCursor<<T as frame_system::Config>::BlockNumber>;
+
+    pub type AssetValuationOf<T> = AssetValuation<
+        <T as Config>::AssetId,
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+    >;
+
     /// Current storage version.
     ///
-    /// V5 corresponds to the `MinParticipationDeposit` policy addition.
-    /// Without a declared `StorageVersion`, future runtime upgrades cannot
-    /// detect which migration has been applied, risking data corruption.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+    /// V5 corresponds to the `MinParticipationDeposit` policy addition, V6
+    /// to the `ParticipationExpiries` index.  Without a declared
+    /// `StorageVersion`, future runtime upgrades cannot detect which
+    /// migration has been applied, risking data corruption.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config:
+        frame_system::Config + SendTransactionTypes<Call<Self>> + SigningTypes
+    {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type AssetId: Member + Parameter + Copy + MaxEncodedLen;
         type NativeCurrency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
@@ -79,6 +135,8 @@ pub mod pallet {
             + fungibles::Transfer<Self::AccountId>;
         type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to pause and unpause assets.
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         #[pallet::constant]
         type PalletId: Get<PalletId>;
         #[pallet::constant]
@@ -107,6 +165,29 @@ pub mod pallet {
         /// Optional guard that checks cross-pallet constraints before
         /// asset retirement or participation slashing.
         type AssetLifecycleGuard: AssetLifecycleGuard<Self::AccountId>;
+        /// Multi-block migration started on upgrade when its target version is
+        /// above the on-chain storage version.  `()` when none is pending.
+        type SteppedMigration: SteppedMigration;
+        /// Maximum number of items a stepped migration processes per block.
+        #[pallet::constant]
+        type MigrationItemsPerBlock: Get<u32>;
+        /// Window over which a fee sponsorship's per-account call limit
+        /// applies.
+        #[pallet::constant]
+        type SponsorshipPeriod: Get<Self::BlockNumber>;
+        /// Keys the offchain worker signs settlement payloads with.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+        /// Priority of the offchain worker's settlement transactions.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+        /// Maximum number of participations settled by one offchain worker
+        /// transaction.
+        #[pallet::constant]
+        type MaxSettlementsPerTx: Get<u32>;
+        /// Blocks the offchain worker waits between submissions, which is
+        /// also how long a submitted transaction stays valid.
+        #[pallet::constant]
+        type UnsignedInterval: Get<Self::BlockNumber>;
     }
 
     // ── Storage ──────────────────────────────────────────────────────────
@@ -171,6 +252,52 @@ pub mod pallet {
     pub type PendingOwnershipTransfer<T: Config> =
         StorageMap<_, Blake2_128Concat, u32, T::AccountId>;
 
+    /// Latest appraised value of each asset.  Used by asset-backed
+    /// crowdfunding offerings to size their goal.
+    #[pallet::storage]
+    pub type AssetValuations<T: Config> = StorageMap<_, Blake2_128Concat, u32, AssetValuationOf<T>>;
+
+    /// Cursor of the stepped migration in progress, if any.  While set, the
+    /// runtime call filter rejects the pallet's extrinsics.
+    #[pallet::storage]
+    pub type ActiveMigration<T: Config> = StorageValue<_, MigrationCursorOf<T>>;
+
+    /// Fee sponsorships by asset.
+    #[pallet::storage]
+    pub type FeeSponsorships<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, FeeSponsorship<T::AccountId>>;
+
+    /// Sponsored transactions per asset and account in the current period.
+    #[pallet::storage]
+    pub type SponsoredCalls<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        SponsoredUsage<T::BlockNumber>,
+        ValueQuery,
+    >;
+
+    /// Accounts of the keys allowed to sign the offchain worker's
+    /// settlement payloads.
+    #[pallet::storage]
+    pub type SettlementKeys<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Expiry index: active participations with an expiry, keyed by
+    /// `(expires_at, rwa_asset_id, participation_id)`, so the offchain
+    /// worker finds expired ones without scanning `Participations`.
+    #[pallet::storage]
+    pub type ParticipationExpiries<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, T::BlockNumber>,
+            NMapKey<Blake2_128Concat, u32>,
+            NMapKey<Blake2_128Concat, u32>,
+        ),
+        (),
+    >;
+
     // ── Events ───────────────────────────────────────────────────────────
 
     #[pallet::event]
@@ -267,6 +394,52 @@ pub mod pallet {
         /// CAT-7.2-R-S: all pending approvals were batch-rejected.
         #[codec(index = 26)]
         BatchPendingRejected { asset_id: u32, count: u32 },
+        #[codec(index = 27)]
+        AssetValuationSet {
+            asset_id: u32,
+            currency: PaymentCurrency<T::AssetId>,
+            amount: BalanceOf<T>,
+        },
+        #[codec(index = 28)]
+        MigrationStarted { target_version: u16 },
+        #[codec(index = 29)]
+        MigrationCompleted { target_version: u16, migrated: u32 },
+        #[codec(index = 30)]
+        FeeSponsorshipFunded {
+            asset_id: u32,
+            sponsor: T::AccountId,
+            amount: BalanceOf<T>,
+            max_calls_per_period: u32,
+        },
+        #[codec(index = 31)]
+        FeeSponsorshipEnded { asset_id: u32, sponsor: T::AccountId, refunded: BalanceOf<T> },
+        #[codec(index = 32)]
+        FeeSponsored { asset_id: u32, who: T::AccountId, fee: BalanceOf<T> },
+        #[codec(index = 33)]
+        SettlementKeySet { key: T::AccountId, allowed: bool },
+    }
+
+    impl<T: Config> Event<T> {
+        /// The `(asset_id, participation_id)` this event concerns, if any.
+        pub fn participation(&self) -> Option<(u32, u32)> {
+            match self {
+                Event::ParticipationRequested { asset_id, participation_id, .. }
+                | Event::ParticipationApproved { asset_id, participation_id, .. }
+                | Event::ParticipationRejected { asset_id, participation_id, .. }
+                | Event::ParticipationExited { asset_id, participation_id, .. }
+                | Event::ParticipationExpired { asset_id, participation_id, .. }
+                | Event::ParticipationRenewed { asset_id, participation_id, .. }
+                | Event::ParticipationSlashed { asset_id, participation_id, .. }
+                | Event::ParticipationRevoked { asset_id, participation_id, .. }
+                | Event::HolderAdded { asset_id, participation_id, .. }
+                | Event::HolderRemoved { asset_id, participation_id, .. }
+                | Event::HolderLeft { asset_id, participation_id, .. }
+                | Event::ParticipationTransferred { asset_id, participation_id, .. } => {
+                    Some((*asset_id, *participation_id))
+                }
+                _ => None,
+            }
+        }
     }
 
     // ── Errors ───────────────────────────────────────────────────────────
@@ -346,6 +519,17 @@ pub mod pallet {
         /// asset).
         #[codec(index = 34)]
         BlockedByLifecycleGuard,
+        /// Asset valuations must be non-zero.
+        #[codec(index = 35)]
+        InvalidValuation,
+        /// The asset's fee sponsorship was funded by another account.
+        #[codec(index = 36)]
+        SponsoredByAnother,
+        #[codec(index = 37)]
+        NoFeeSponsorship,
+        /// A fee sponsorship must allow at least one call per period.
+        #[codec(index = 38)]
+        InvalidSponsorshipLimit,
     }
 
     // ── Hooks ────────────────────────────────────────────────────────────
@@ -355,8 +539,16 @@ pub mod pallet {
         #[cfg(feature = "try-runtime")]
         fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> { Self::do_try_state() }
 
+        /// Retire the assets whose sunset expires at `n`.
+        ///
+        /// While a stepped migration is active only the migration advances;
+        /// assets expiring meanwhile stay `Sunsetting` and can be retired with
+        /// `retire_asset`.
         fn on_initialize(n: T::BlockNumber) -> Weight {
-            let mut weight = T::DbWeight::get().reads(1);
+            if let Some(cursor) = ActiveMigration::<T>::get() {
+                return Self::step_migration(cursor).saturating_add(T::DbWeight::get().reads(1));
+            }
+            let mut weight = T::DbWeight::get().reads(2);
             let asset_ids = SunsettingAssets::<T>::take(&n);
             if asset_ids.is_empty() {
                 return weight;
@@ -374,6 +566,7 @@ pub mod pallet {
                     Self::remove_from_owner_assets(&asset.owner, *asset_id);
                     PendingOwnershipTransfer::<T>::remove(asset_id);
                     AssetSlashDistribution::<T>::remove(asset_id);
+                    AssetValuations::<T>::remove(asset_id);
                     PendingApprovals::<T>::remove(asset_id);
                     Self::deposit_event(Event::AssetRetired {
                         asset_id: *asset_id,
@@ -383,6 +576,82 @@ pub mod pallet {
             }
             weight
         }
+
+        /// Submit the participations whose expiry has passed for settlement,
+        /// at most once every `UnsignedInterval` blocks.
+        fn offchain_worker(n: T::BlockNumber) {
+            if Self::migration_ongoing() {
+                return;
+            }
+            let participations = Self::expired_participations(T::MaxSettlementsPerTx::get());
+            if participations.is_empty() || !Self::acquire_offchain_slot(n) {
+                return;
+            }
+            let result = Signer::<T, T::AuthorityId>::any_account()
+                .with_filter(Self::local_settlement_keys())
+                .send_unsigned_transaction(
+                    |account| SettlementPayload {
+                        block_number: n,
+                        participations: participations.clone(),
+                        public: account.public.clone(),
+                    },
+                    |payload, signature| Call::settle_expired_participations_unsigned {
+                        payload,
+                        signature,
+                    },
+                );
+            match result {
+                Some((_, Ok(()))) => {}
+                Some((_, Err(()))) => {
+                    log::warn!(target: "runtime::rwa", "failed to submit settlement at {:?}", n)
+                }
+                None => {
+                    log::debug!(target: "runtime::rwa", "no allowed settlement key in keystore")
+                }
+            }
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// Accept a settlement payload signed by an allowed settlement key
+        /// whose participations are all due.  Every participation is
+        /// provided as a tag, so two transactions settling the same one
+        /// never both enter the pool.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let (payload, signature) = match call {
+                Call::settle_expired_participations_unsigned { payload, signature } => {
+                    (payload, signature)
+                }
+                _ => return InvalidTransaction::Call.into(),
+            };
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone())
+                || !Self::is_settlement_key(&payload.public)
+            {
+                return InvalidTransaction::BadProof.into();
+            }
+            if payload.participations.is_empty()
+                || payload.participations.len() > T::MaxSettlementsPerTx::get() as usize
+            {
+                return InvalidTransaction::Call.into();
+            }
+            if payload.block_number > frame_system::Pallet::<T>::block_number() {
+                return InvalidTransaction::Future.into();
+            }
+            let mut valid = ValidTransaction::with_tag_prefix("RwaSettlement")
+                .priority(T::UnsignedPriority::get())
+                .longevity(T::UnsignedInterval::get().unique_saturated_into())
+                .propagate(true);
+            for (rwa_asset_id, participation_id) in payload.participations.iter() {
+                if !Self::is_expired(*rwa_asset_id, *participation_id) {
+                    return InvalidTransaction::Stale.into();
+                }
+                valid = valid.and_provides((rwa_asset_id, participation_id));
+            }
+            valid.build()
+        }
     }
 
     // ── Dispatchables ────────────────────────────────────────────────────
@@ -600,7 +869,10 @@ pub mod pallet {
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::force_retire_asset())]
+        #[pallet::weight(
+            T::WeightInfo::force_retire_asset()
+                .saturating_add(T::AssetLifecycleGuard::can_retire_asset_weight())
+        )]
         pub fn force_retire_asset(origin: OriginFor<T>, rwa_asset_id: u32) -> DispatchResult {
             T::ForceOrigin::ensure_origin(origin)?;
             let asset = RwaAssets::<T>::get(rwa_asset_id).ok_or(Error::<T>::AssetNotFound)?;
@@ -623,6 +895,7 @@ pub mod pallet {
             Self::remove_from_owner_assets(&asset.owner, rwa_asset_id);
             PendingOwnershipTransfer::<T>::remove(rwa_asset_id);
             AssetSlashDistribution::<T>::remove(rwa_asset_id);
+            AssetValuations::<T>::remove(rwa_asset_id);
             PendingApprovals::<T>::remove(rwa_asset_id);
 
             RwaAssets::<T>::mutate(rwa_asset_id, |maybe| {
@@ -663,6 +936,7 @@ pub mod pallet {
             });
             // M-3: clean up slash distribution config
             AssetSlashDistribution::<T>::remove(rwa_asset_id);
+            AssetValuations::<T>::remove(rwa_asset_id);
             // M-4: clean up pending approvals queue
             PendingApprovals::<T>::remove(rwa_asset_id);
 
@@ -778,6 +1052,7 @@ pub mod pallet {
                 entry_fee_paid: fee_amount,
             };
             Participations::<T>::insert(rwa_asset_id, pid, &participation);
+            Self::index_expiry(rwa_asset_id, pid, &participation.status);
 
             // indexes
             for h in bounded_holders.iter() {
@@ -857,6 +1132,7 @@ pub mod pallet {
                     let now = frame_system::Pallet::<T>::block_number();
                     let expires_at = asset.policy.max_duration.map(|d| now.saturating_add(d));
                     p.status = ParticipationStatus::Active { started_at: now, expires_at };
+                    Self::index_expiry(rwa_asset_id, participation_id, &p.status);
                     Ok(())
                 },
             )?;
@@ -957,6 +1233,7 @@ pub mod pallet {
                 deposit,
             )?;
 
+            Self::unindex_expiry(rwa_asset_id, participation_id, &p.status);
             p.status = ParticipationStatus::Exited;
             p.deposit_held = Zero::zero();
             Participations::<T>::insert(rwa_asset_id, participation_id, &p);
@@ -1076,8 +1353,10 @@ pub mod pallet {
 
                     let now = frame_system::Pallet::<T>::block_number();
                     let new_expires_at = asset.policy.max_duration.map(|d| now.saturating_add(d));
+                    Self::unindex_expiry(rwa_asset_id, participation_id, &p.status);
                     p.status =
                         ParticipationStatus::Active { started_at: now, expires_at: new_expires_at };
+                    Self::index_expiry(rwa_asset_id, participation_id, &p.status);
 
                     Self::deposit_event(Event::ParticipationRenewed {
                         asset_id: rwa_asset_id,
@@ -1142,6 +1421,7 @@ pub mod pallet {
                 total,
             )?;
 
+            Self::unindex_expiry(rwa_asset_id, participation_id, &p.status);
             p.status = ParticipationStatus::Exited;
             p.deposit_held = Zero::zero();
             Participations::<T>::insert(rwa_asset_id, participation_id, &p);
@@ -1253,6 +1533,7 @@ pub mod pallet {
                     &p.payer,
                     deposit,
                 )?;
+                Self::unindex_expiry(rwa_asset_id, participation_id, &p.status);
                 p.status = ParticipationStatus::Exited;
                 p.deposit_held = Zero::zero();
                 Self::dec_participant_count(rwa_asset_id);
@@ -1306,6 +1587,7 @@ pub mod pallet {
                     &p.payer,
                     deposit,
                 )?;
+                Self::unindex_expiry(rwa_asset_id, participation_id, &p.status);
                 p.status = ParticipationStatus::Exited;
                 p.deposit_held = Zero::zero();
                 Self::dec_participant_count(rwa_asset_id);
@@ -1363,7 +1645,10 @@ pub mod pallet {
         // ─── Enforcement ─────────────────────────────────────────────
 
         #[pallet::call_index(18)]
-        #[pallet::weight(T::WeightInfo::slash_participation())]
+        #[pallet::weight(
+            T::WeightInfo::slash_participation()
+                .saturating_add(T::AssetLifecycleGuard::can_slash_participation_weight())
+        )]
         pub fn slash_participation(
             origin: OriginFor<T>,
             rwa_asset_id: u32,
@@ -1410,6 +1695,7 @@ pub mod pallet {
                 )?;
             }
 
+            Self::unindex_expiry(rwa_asset_id, participation_id, &p.status);
             p.status = ParticipationStatus::Slashed;
             p.deposit_held = Zero::zero();
             Participations::<T>::insert(rwa_asset_id, participation_id, &p);
@@ -1453,6 +1739,7 @@ pub mod pallet {
                 deposit,
             )?;
 
+            Self::unindex_expiry(rwa_asset_id, participation_id, &p.status);
             p.status = ParticipationStatus::Revoked;
             p.deposit_held = Zero::zero();
             Participations::<T>::insert(rwa_asset_id, participation_id, &p);
@@ -1678,7 +1965,7 @@ pub mod pallet {
         #[pallet::call_index(26)]
         #[pallet::weight(T::WeightInfo::pause_asset())]
         pub fn pause_asset(origin: OriginFor<T>, rwa_asset_id: u32) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
+            T::PauseOrigin::ensure_origin(origin)?;
             RwaAssets::<T>::try_mutate(rwa_asset_id, |maybe| -> DispatchResult {
                 let asset = maybe.as_mut().ok_or(Error::<T>::AssetNotFound)?;
                 ensure!(
@@ -1695,7 +1982,7 @@ pub mod pallet {
         #[pallet::call_index(27)]
         #[pallet::weight(T::WeightInfo::unpause_asset())]
         pub fn unpause_asset(origin: OriginFor<T>, rwa_asset_id: u32) -> DispatchResult {
-            T::AdminOrigin::ensure_origin(origin)?;
+            T::PauseOrigin::ensure_origin(origin)?;
             RwaAssets::<T>::try_mutate(rwa_asset_id, |maybe| -> DispatchResult {
                 let asset = maybe.as_mut().ok_or(Error::<T>::AssetNotFound)?;
                 ensure!(
@@ -1715,7 +2002,8 @@ pub mod pallet {
         /// the `PendingApprovals` queue, the owner/admin can clear it
         /// in a single extrinsic instead of issuing O(n) individual
         /// `reject_participation` calls (which may exceed block weight
-        /// limits).
+        /// limits).  Each rejection emits `ParticipationRejected`, followed by
+        /// a single `BatchPendingRejected`.
         #[pallet::call_index(28)]
         #[pallet::weight(T::WeightInfo::batch_reject_pending())]
         pub fn batch_reject_pending(origin: OriginFor<T>, rwa_asset_id: u32) -> DispatchResult {
@@ -1757,6 +2045,12 @@ pub mod pallet {
                             Self::remove_all_holder_indexes(rwa_asset_id, &p.holders);
                             Self::dec_participant_count(rwa_asset_id);
                             rejected += 1;
+                            Self::deposit_event(Event::ParticipationRejected {
+                                asset_id: rwa_asset_id,
+                                participation_id: pid,
+                                deposit_refunded: p.deposit_held,
+                                fee_refunded: p.entry_fee_paid,
+                            });
                         }
                         Err(e) => {
                             // Transfer failed — retain this entry so it can be
@@ -1792,6 +2086,189 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        // ─── Valuation ────────────────────────────────────────────────
+
+        /// Record the appraised value of an asset.  Requires `AdminOrigin`.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::set_asset_valuation())]
+        pub fn set_asset_valuation(
+            origin: OriginFor<T>,
+            rwa_asset_id: u32,
+            currency: PaymentCurrency<T::AssetId>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidValuation);
+            let asset = RwaAssets::<T>::get(rwa_asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(!matches!(asset.status, AssetStatus::Retired), Error::<T>::AssetAlreadyRetired);
+            AssetValuations::<T>::insert(
+                rwa_asset_id,
+                AssetValuation {
+                    currency: currency.clone(),
+                    amount,
+                    updated_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::AssetValuationSet {
+                asset_id: rwa_asset_id,
+                currency,
+                amount,
+            });
+            Ok(())
+        }
+
+        // ─── Fee sponsorship ──────────────────────────────────────────
+
+        /// Add `amount` to the budget paying the transaction fees of the
+        /// asset's participant calls (see `Call::sponsoring_asset`), and set
+        /// how many of them each account may have paid per
+        /// `SponsorshipPeriod`.  Owner only; a running sponsorship can only be
+        /// topped up by its sponsor.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::fund_fee_sponsorship())]
+        pub fn fund_fee_sponsorship(
+            origin: OriginFor<T>,
+            rwa_asset_id: u32,
+            amount: BalanceOf<T>,
+            max_calls_per_period: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let asset = RwaAssets::<T>::get(rwa_asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotAssetOwner);
+            ensure!(!matches!(asset.status, AssetStatus::Retired), Error::<T>::AssetAlreadyRetired);
+            ensure!(max_calls_per_period > 0, Error::<T>::InvalidSponsorshipLimit);
+            if let Some(existing) = FeeSponsorships::<T>::get(rwa_asset_id) {
+                ensure!(existing.sponsor == who, Error::<T>::SponsoredByAnother);
+            }
+            if !amount.is_zero() {
+                T::NativeCurrency::transfer(
+                    &who,
+                    &Self::sponsorship_account(rwa_asset_id),
+                    amount,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+            }
+            FeeSponsorships::<T>::insert(
+                rwa_asset_id,
+                FeeSponsorship { sponsor: who.clone(), max_calls_per_period },
+            );
+            Self::deposit_event(Event::FeeSponsorshipFunded {
+                asset_id: rwa_asset_id,
+                sponsor: who,
+                amount,
+                max_calls_per_period,
+            });
+            Ok(())
+        }
+
+        /// End the asset's fee sponsorship and return what is left of its
+        /// budget to the sponsor.  Callable by the sponsor or the current
+        /// asset owner.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::end_fee_sponsorship())]
+        pub fn end_fee_sponsorship(origin: OriginFor<T>, rwa_asset_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let sponsorship =
+                FeeSponsorships::<T>::get(rwa_asset_id).ok_or(Error::<T>::NoFeeSponsorship)?;
+            if sponsorship.sponsor != who {
+                let asset = RwaAssets::<T>::get(rwa_asset_id).ok_or(Error::<T>::AssetNotFound)?;
+                ensure!(asset.owner == who, Error::<T>::NotAssetOwner);
+            }
+            let account = Self::sponsorship_account(rwa_asset_id);
+            let refunded = T::NativeCurrency::free_balance(&account);
+            T::NativeCurrency::transfer(
+                &account,
+                &sponsorship.sponsor,
+                refunded,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            FeeSponsorships::<T>::remove(rwa_asset_id);
+            Self::deposit_event(Event::FeeSponsorshipEnded {
+                asset_id: rwa_asset_id,
+                sponsor: sponsorship.sponsor,
+                refunded,
+            });
+            Ok(())
+        }
+
+        // ─── Offchain settlement ─────────────────────────────────────
+
+        /// Settle a batch of expired participations found by the offchain
+        /// worker.  Participations that can no longer be settled are
+        /// skipped.
+        #[pallet::call_index(32)]
+        #[pallet::weight(
+            T::WeightInfo::settle_expired_participations_unsigned(
+                payload.participations.len() as u32
+            )
+        )]
+        pub fn settle_expired_participations_unsigned(
+            origin: OriginFor<T>,
+            payload: SettlementPayload<T::Public, T::BlockNumber>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            for (rwa_asset_id, participation_id) in payload.participations {
+                let asset = match RwaAssets::<T>::get(rwa_asset_id) {
+                    Some(asset) => asset,
+                    None => continue,
+                };
+                let mut p = match Participations::<T>::get(rwa_asset_id, participation_id) {
+                    Some(p) => p,
+                    None => continue,
+                };
+                // Each participation gets its own layer so a failure part-way
+                // through leaves no partial writes behind.
+                if let Err(e) = storage::with_storage_layer(|| {
+                    Self::try_settle_expiry(rwa_asset_id, participation_id, &mut p, &asset)
+                }) {
+                    log::warn!(
+                        target: "runtime::rwa",
+                        "settling participation {}/{} failed: {:?}",
+                        rwa_asset_id,
+                        participation_id,
+                        e,
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        /// Allow (`true`) or disallow (`false`) a key to sign the offchain
+        /// worker's settlement payloads.  Requires `AdminOrigin`.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::set_settlement_key())]
+        pub fn set_settlement_key(
+            origin: OriginFor<T>,
+            key: T::AccountId,
+            allowed: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if allowed {
+                SettlementKeys::<T>::insert(&key, ());
+            } else {
+                SettlementKeys::<T>::remove(&key);
+            }
+            Self::deposit_event(Event::SettlementKeySet { key, allowed });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Call<T> {
+        /// The asset whose fee sponsorship may pay for this call: the calls a
+        /// participant makes on an asset.
+        pub fn sponsoring_asset(&self) -> Option<u32> {
+            match self {
+                Call::request_participation { rwa_asset_id, .. }
+                | Call::exit_participation { rwa_asset_id, .. }
+                | Call::renew_participation { rwa_asset_id, .. }
+                | Call::leave_participation { rwa_asset_id, .. }
+                | Call::claim_retired_deposit { rwa_asset_id, .. }
+                | Call::transfer_participation { rwa_asset_id, .. } => Some(*rwa_asset_id),
+                _ => None,
+            }
+        }
     }
 
     // ── Helpers ──────────────────────────────────────────────────────────
@@ -1799,6 +2276,204 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         pub fn pallet_account() -> T::AccountId { T::PalletId::get().into_account_truncating() }
 
+        /// Whether a stepped migration is in progress.
+        pub fn migration_ongoing() -> bool { ActiveMigration::<T>::exists() }
+
+        /// Whether the participation is active with an expiry that has
+        /// passed, so `settle_expired_participation` would settle it.
+        pub fn is_expired(rwa_asset_id: u32, participation_id: u32) -> bool {
+            if !RwaAssets::<T>::contains_key(rwa_asset_id) {
+                return false;
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            matches!(
+                Participations::<T>::get(rwa_asset_id, participation_id).map(|p| p.status),
+                Some(ParticipationStatus::Active { expires_at: Some(expiry), .. }) if now >= expiry
+            )
+        }
+
+        /// Up to `limit` expired participations, for the offchain worker.
+        /// Scans the expiry index, which holds only active participations
+        /// with an expiry.
+        fn expired_participations(limit: u32) -> Vec<(u32, u32)> {
+            let now = frame_system::Pallet::<T>::block_number();
+            ParticipationExpiries::<T>::iter_keys()
+                .filter(|(expiry, ..)| now >= *expiry)
+                .map(|(_, rwa_asset_id, participation_id)| (rwa_asset_id, participation_id))
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// Add a participation with `status` to the expiry index if it is
+        /// active with an expiry.
+        fn index_expiry(
+            rwa_asset_id: u32,
+            participation_id: u32,
+            status: &ParticipationStatus<T::BlockNumber>,
+        ) {
+            if let ParticipationStatus::Active { expires_at: Some(expiry), .. } = status {
+                ParticipationExpiries::<T>::insert((*expiry, rwa_asset_id, participation_id), ());
+            }
+        }
+
+        /// Drop a participation leaving `status` from the expiry index.
+        fn unindex_expiry(
+            rwa_asset_id: u32,
+            participation_id: u32,
+            status: &ParticipationStatus<T::BlockNumber>,
+        ) {
+            if let ParticipationStatus::Active { expires_at: Some(expiry), .. } = status {
+                ParticipationExpiries::<T>::remove((*expiry, rwa_asset_id, participation_id));
+            }
+        }
+
+        /// Record in offchain storage that the worker submits at `n`, unless
+        /// it already did within the last `UnsignedInterval` blocks, so the
+        /// same batch is not resubmitted while it waits in the pool.
+        fn acquire_offchain_slot(n: T::BlockNumber) -> bool {
+            let last_submission = StorageValueRef::persistent(b"rwa::last_settlement");
+            let result = last_submission.mutate(
+                |last: Result<Option<T::BlockNumber>, StorageRetrievalError>| match last {
+                    Ok(Some(block)) if n < block.saturating_add(T::UnsignedInterval::get()) => {
+                        Err(())
+                    }
+                    _ => Ok(n),
+                },
+            );
+            match result {
+                Ok(_) => true,
+                Err(MutateStorageError::ValueFunctionFailed(())) => false,
+                Err(MutateStorageError::ConcurrentModification(_)) => false,
+            }
+        }
+
+        /// Whether `public` may sign settlement payloads.
+        fn is_settlement_key(public: &T::Public) -> bool {
+            SettlementKeys::<T>::contains_key(public.clone().into_account())
+        }
+
+        /// The settlement keys in the local keystore that may sign.
+        fn local_settlement_keys() -> Vec<T::Public> {
+            <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+                .into_iter()
+                .map(|key| {
+                    <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(key)
+                        .into()
+                })
+                .filter(Self::is_settlement_key)
+                .collect()
+        }
+
+        /// Account holding the fee sponsorship budget of `rwa_asset_id`.
+        pub fn sponsorship_account(rwa_asset_id: u32) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating((b"sponsor", rwa_asset_id))
+        }
+
+        /// `who`'s sponsored-call count, reset once `SponsorshipPeriod` has
+        /// passed since its period started.
+        fn sponsored_usage(
+            rwa_asset_id: u32,
+            who: &T::AccountId,
+        ) -> SponsoredUsage<T::BlockNumber> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let usage = SponsoredCalls::<T>::get(rwa_asset_id, who);
+            if now >= usage.period_start.saturating_add(T::SponsorshipPeriod::get()) {
+                SponsoredUsage { period_start: now, calls: 0 }
+            } else {
+                usage
+            }
+        }
+
+        /// Whether the fee sponsorship of `rwa_asset_id` can pay `fee` for
+        /// `who`: it exists, `who` holds a participation in the asset, `who`
+        /// is within its call limit, and the budget covers `fee` without
+        /// reaping the sponsorship account.
+        ///
+        /// Only holders are sponsored, so fresh accounts cannot drain the
+        /// budget with calls that fail.
+        pub fn can_sponsor_fee(rwa_asset_id: u32, who: &T::AccountId, fee: BalanceOf<T>) -> bool {
+            let sponsorship = match FeeSponsorships::<T>::get(rwa_asset_id) {
+                Some(sponsorship) => sponsorship,
+                None => return false,
+            };
+            if !HolderIndex::<T>::contains_key(rwa_asset_id, who) {
+                return false;
+            }
+            if Self::sponsored_usage(rwa_asset_id, who).calls >= sponsorship.max_calls_per_period {
+                return false;
+            }
+            let budget = T::NativeCurrency::free_balance(&Self::sponsorship_account(rwa_asset_id));
+            budget.saturating_sub(fee) >= T::NativeCurrency::minimum_balance()
+        }
+
+        /// Withdraw `fee` from the sponsorship budget of `rwa_asset_id` and
+        /// count the transaction against `who`'s limit.  `None` if the
+        /// sponsorship cannot pay.
+        pub fn withdraw_sponsored_fee(
+            rwa_asset_id: u32,
+            who: &T::AccountId,
+            fee: BalanceOf<T>,
+        ) -> Option<NegativeImbalanceOf<T>> {
+            if !Self::can_sponsor_fee(rwa_asset_id, who, fee) {
+                return None;
+            }
+            let paid = T::NativeCurrency::withdraw(
+                &Self::sponsorship_account(rwa_asset_id),
+                fee,
+                WithdrawReasons::TRANSACTION_PAYMENT,
+                ExistenceRequirement::KeepAlive,
+            )
+            .ok()?;
+            let mut usage = Self::sponsored_usage(rwa_asset_id, who);
+            usage.calls = usage.calls.saturating_add(1);
+            SponsoredCalls::<T>::insert(rwa_asset_id, who, usage);
+            Some(paid)
+        }
+
+        /// Return the part of `paid` above the actual `fee` to the budget of
+        /// `rwa_asset_id`, and hand back the fee itself.
+        pub fn settle_sponsored_fee(
+            rwa_asset_id: u32,
+            who: &T::AccountId,
+            paid: NegativeImbalanceOf<T>,
+            fee: BalanceOf<T>,
+        ) -> NegativeImbalanceOf<T> {
+            let (fee, refund) = paid.split(fee);
+            T::NativeCurrency::resolve_creating(&Self::sponsorship_account(rwa_asset_id), refund);
+            Self::deposit_event(Event::FeeSponsored {
+                asset_id: rwa_asset_id,
+                who: who.clone(),
+                fee: fee.peek(),
+            });
+            fee
+        }
+
+        /// Advance the active stepped migration by up to
+        /// `MigrationItemsPerBlock` items, stamping its target storage version
+        /// once it finishes.
+        pub(crate) fn step_migration(mut cursor: MigrationCursorOf<T>) -> Weight {
+            let outcome =
+                T::SteppedMigration::step(cursor.last_key.take(), T::MigrationItemsPerBlock::get());
+            cursor.migrated = cursor.migrated.saturating_add(outcome.migrated);
+            let weight = outcome.weight.saturating_add(T::DbWeight::get().writes(1));
+            match outcome.next {
+                Some(key) => {
+                    cursor.last_key = Some(key);
+                    ActiveMigration::<T>::put(cursor);
+                    weight
+                }
+                None => {
+                    ActiveMigration::<T>::kill();
+                    StorageVersion::new(cursor.target_version).put::<Pallet<T>>();
+                    Self::deposit_event(Event::MigrationCompleted {
+                        target_version: cursor.target_version,
+                        migrated: cursor.migrated,
+                    });
+                    weight.saturating_add(T::DbWeight::get().writes(1))
+                }
+            }
+        }
+
         fn do_transfer(
             currency: &PaymentCurrency<T::AssetId>,
             from: &T::AccountId,
@@ -1881,6 +2556,7 @@ pub mod pallet {
                         &p.payer,
                         deposit,
                     )?;
+                    Self::unindex_expiry(asset_id, participation_id, &p.status);
                     p.status = ParticipationStatus::Expired;
                     p.deposit_held = Zero::zero();
                     Self::remove_all_holder_indexes(asset_id, &p.holders);
@@ -2067,6 +2743,14 @@ pub mod pallet {
 
         #[cfg(feature = "try-runtime")]
         fn do_try_state() -> Result<(), &'static str> {
+            // Storage is only partly migrated while a stepped migration runs.
+            if let Some(cursor) = ActiveMigration::<T>::get() {
+                if Self::on_chain_storage_version() >= cursor.target_version {
+                    return Err("ActiveMigration target not above on-chain version");
+                }
+                return Ok(());
+            }
+
             let next_id = NextRwaAssetId::<T>::get();
 
             // 1. All asset_ids < NextRwaAssetId
@@ -2178,6 +2862,23 @@ pub mod pallet {
                 }
             }
 
+            // 10. ParticipationExpiries ↔ Participations consistency
+            for (expiry, asset_id, pid) in ParticipationExpiries::<T>::iter_keys() {
+                let p = Participations::<T>::get(asset_id, pid)
+                    .ok_or("ParticipationExpiries references non-existent participation")?;
+                match p.status {
+                    ParticipationStatus::Active { expires_at: Some(at), .. } if at == expiry => {}
+                    _ => return Err("ParticipationExpiries entry does not match participation"),
+                }
+            }
+            for (asset_id, pid, p) in Participations::<T>::iter() {
+                if let ParticipationStatus::Active { expires_at: Some(at), .. } = p.status {
+                    if !ParticipationExpiries::<T>::contains_key((at, asset_id, pid)) {
+                        return Err("expiring participation missing from ParticipationExpiries");
+                    }
+                }
+            }
+
             Ok(())
         }
     }
@@ -5,10 +5,12 @@
 /// - **V1–V4**: Implicit fixes and feature additions applied during early
 ///   development.  No on-chain `StorageVersion` marker existed at those stages,
 ///   so there are no migration modules for these versions.
-/// - **V5** (current): Added `MinParticipationDeposit` policy enforcement. This
-///   is the first version tracked by an explicit `StorageVersion`. No schema
-///   change — the storage layout itself is unchanged; only the extrinsic
-///   validation logic was tightened.
+/// - **V5**: Added `MinParticipationDeposit` policy enforcement. This is the
+///   first version tracked by an explicit `StorageVersion`. No schema change —
+///   the storage layout itself is unchanged; only the extrinsic validation
+///   logic was tightened.
+/// - **V6** (current): Added the `ParticipationExpiries` index, populated by
+///   the stepped [`v6::MigrateToV6`].
 ///
 /// # How to add a future migration (e.g. V6)
 ///
@@ -23,8 +25,14 @@
 ///    - In `pre_upgrade()` (behind `try-runtime`): snapshot any counters needed
 ///      for post-upgrade assertions.
 ///    - In `post_upgrade()` (behind `try-runtime`): assert invariants.
-/// 4. Wire the migration into the runtime's `Executive` type or
-///    `type Migrations` tuple so it runs on the next upgrade.
+/// 4. Add the migration to the runtime's `Migrations` tuple so `Executive`
+///    runs it on the next upgrade.
+///
+/// A migration touching more items than fit in one block (e.g. every
+/// `Participations` entry) should implement [`stepped::SteppedMigration`]
+/// instead and be set as the runtime's `SteppedMigration`; it then runs
+/// `MigrationItemsPerBlock` items per block while the pallet's extrinsics are
+/// paused.
 
 /// Noop migration that confirms the on-chain storage is already at V5.
 ///
@@ -100,3 +108,200 @@ pub mod v5 {
         }
     }
 }
+
+/// Populates `ParticipationExpiries` with the active participations that
+/// have an expiry.
+///
+/// Runs as a [`stepped`] migration over `Participations`: configure
+/// `MigrateToV6` as `T::SteppedMigration` and list `StartSteppedMigration`
+/// among the runtime's migrations.
+pub mod v6 {
+    use frame_support::{pallet_prelude::*, weights::Weight};
+    use sp_std::vec::Vec;
+
+    use super::stepped::{StepOutcome, SteppedMigration};
+    use crate::{
+        pallet::{self, Config},
+        types::*,
+    };
+
+    pub struct MigrateToV6<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for MigrateToV6<T> {
+        const TARGET_VERSION: u16 = 6;
+
+        fn step(cursor: Option<MigrationKey>, limit: u32) -> StepOutcome {
+            let mut iter = match cursor {
+                Some(key) => pallet::Participations::<T>::iter_from(key.into_inner()),
+                None => pallet::Participations::<T>::iter(),
+            };
+            let mut migrated: u32 = 0;
+            let mut indexed: u64 = 0;
+            let mut done = false;
+            while migrated < limit {
+                let (rwa_asset_id, participation_id, p) = match iter.next() {
+                    Some(entry) => entry,
+                    None => {
+                        done = true;
+                        break;
+                    }
+                };
+                migrated += 1;
+                if let ParticipationStatus::Active { expires_at: Some(expiry), .. } = p.status {
+                    pallet::ParticipationExpiries::<T>::insert(
+                        (expiry, rwa_asset_id, participation_id),
+                        (),
+                    );
+                    indexed += 1;
+                }
+            }
+
+            // Participation keys are 72 bytes, well within the cursor bound.
+            let next =
+                if done { None } else { BoundedVec::try_from(iter.last_raw_key().to_vec()).ok() };
+            // The extra read is the one that finds the map exhausted.
+            let weight = T::DbWeight::get().reads_writes(migrated as u64 + 1, indexed);
+            StepOutcome { next, migrated, weight }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let expiring = pallet::Participations::<T>::iter_values()
+                .filter(|p| {
+                    matches!(p.status, ParticipationStatus::Active { expires_at: Some(_), .. })
+                })
+                .count() as u32;
+            Ok(expiring.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let expiring = u32::decode(&mut &state[..]).map_err(|_| "decode failed")?;
+            let indexed = pallet::ParticipationExpiries::<T>::iter_keys().count() as u32;
+            frame_support::ensure!(indexed == expiring, "expiring participations not all indexed");
+            Ok(())
+        }
+    }
+}
+
+/// Cursor-based migrations that run over several blocks.
+///
+/// [`StartSteppedMigration`] records an [`ActiveMigration`] cursor on upgrade
+/// when `T::SteppedMigration` targets a version above the on-chain one.  Each
+/// `on_initialize` then calls [`SteppedMigration::step`] until it reports no
+/// further key, at which point the target version is stamped.  While the
+/// cursor exists the runtime call filter rejects the pallet's extrinsics.
+///
+/// A step over a map typically resumes with `iter_from(last_key)`, migrates up
+/// to `limit` entries and returns `iter.last_raw_key()` as the next cursor.
+///
+/// [`ActiveMigration`]: crate::pallet::ActiveMigration
+pub mod stepped {
+    use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+    use sp_std::vec::Vec;
+
+    use crate::{
+        pallet::{self, ActiveMigration, Config},
+        types::*,
+    };
+
+    /// Result of a single [`SteppedMigration::step`].
+    pub struct StepOutcome {
+        /// Key to resume after, or `None` once every item is migrated.
+        pub next: Option<MigrationKey>,
+        /// Items migrated in this step.
+        pub migrated: u32,
+        pub weight: Weight,
+    }
+
+    /// A migration processed in bounded steps across blocks.
+    pub trait SteppedMigration {
+        /// Storage version stamped once the migration finishes.
+        const TARGET_VERSION: u16;
+
+        /// Migrate at most `limit` items following `cursor`, or from the
+        /// first item when `cursor` is `None`.
+        fn step(cursor: Option<MigrationKey>, limit: u32) -> StepOutcome;
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> { Ok(Vec::new()) }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> { Ok(()) }
+    }
+
+    /// No stepped migration pending.
+    impl SteppedMigration for () {
+        const TARGET_VERSION: u16 = 0;
+
+        fn step(_cursor: Option<MigrationKey>, _limit: u32) -> StepOutcome {
+            StepOutcome { next: None, migrated: 0, weight: Weight::zero() }
+        }
+    }
+
+    /// Starts `T::SteppedMigration` if the chain is below its target version.
+    pub struct StartSteppedMigration<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> StartSteppedMigration<T> {
+        fn should_start() -> bool {
+            pallet::Pallet::<T>::on_chain_storage_version() < T::SteppedMigration::TARGET_VERSION
+                && !ActiveMigration::<T>::exists()
+        }
+    }
+
+    impl<T: Config> OnRuntimeUpgrade for StartSteppedMigration<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !Self::should_start() {
+                return T::DbWeight::get().reads(2);
+            }
+            let target_version = T::SteppedMigration::TARGET_VERSION;
+            ActiveMigration::<T>::put(MigrationCursor {
+                target_version,
+                last_key: None,
+                migrated: 0,
+                started_at: frame_system::Pallet::<T>::block_number(),
+            });
+            pallet::Pallet::<T>::deposit_event(pallet::Event::MigrationStarted { target_version });
+
+            frame_support::log::info!(
+                target: "pallet-rwa",
+                "StartSteppedMigration: migrating to V{} over {} items per block",
+                target_version,
+                T::MigrationItemsPerBlock::get(),
+            );
+
+            T::DbWeight::get().reads_writes(3, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let start = Self::should_start();
+            let state = if start { T::SteppedMigration::pre_upgrade()? } else { Vec::new() };
+            Ok((start, state).encode())
+        }
+
+        /// Drives the started migration to completion so its own
+        /// `post_upgrade` checks the fully migrated state.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let (started, inner) =
+                <(bool, Vec<u8>)>::decode(&mut &state[..]).map_err(|_| "decode failed")?;
+            if !started {
+                return Ok(());
+            }
+            frame_support::ensure!(
+                ActiveMigration::<T>::exists(),
+                "pallet-rwa: stepped migration should be active after upgrade"
+            );
+            while let Some(cursor) = ActiveMigration::<T>::get() {
+                pallet::Pallet::<T>::step_migration(cursor);
+            }
+            frame_support::ensure!(
+                pallet::Pallet::<T>::on_chain_storage_version()
+                    == T::SteppedMigration::TARGET_VERSION,
+                "pallet-rwa: on-chain version should match the stepped migration target"
+            );
+            T::SteppedMigration::post_upgrade(inner)
+        }
+    }
+}
@@ -3,12 +3,13 @@ use std::{cell::RefCell, collections::BTreeSet};
 use frame_support::{
     parameter_types,
     traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64},
-    PalletId,
+    weights::Weight,
+    BoundedVec, PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::H256;
 use sp_runtime::{
-    testing::Header,
+    testing::{Header, TestSignature, TestXt, UintAuthorityId},
     traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
 };
 
@@ -164,6 +165,62 @@ impl crate::AssetLifecycleGuard<u64> for MockLifecycleGuard {
             Ok(())
         }
     }
+
+    fn can_retire_asset_weight() -> Weight { Weight::from_parts(1_000, 0) }
+
+    fn can_slash_participation_weight() -> Weight { Weight::from_parts(2_000, 0) }
+}
+
+// ── TestMigration ───────────────────────────────────────────────────────
+//
+// Stepped migration over `RwaAssets` that rewrites each entry unchanged and
+// records the visited ids, so tests can check every asset is migrated once.
+
+pub struct TestMigration;
+
+thread_local! {
+    static MIGRATED: RefCell<Vec<u32>> = RefCell::new(Vec::new());
+}
+
+impl TestMigration {
+    /// Asset ids migrated so far, in visiting order.
+    pub fn migrated() -> Vec<u32> { MIGRATED.with(|m| m.borrow().clone()) }
+}
+
+impl crate::SteppedMigration for TestMigration {
+    const TARGET_VERSION: u16 = 7;
+
+    fn step(
+        cursor: Option<crate::MigrationKey>,
+        limit: u32,
+    ) -> crate::migrations::stepped::StepOutcome {
+        let mut iter = match cursor {
+            Some(key) => crate::pallet::RwaAssets::<Test>::iter_from(key.into_inner()),
+            None => crate::pallet::RwaAssets::<Test>::iter(),
+        };
+        let mut migrated = 0;
+        while migrated < limit {
+            let (id, asset) = match iter.next() {
+                Some(entry) => entry,
+                None => {
+                    return crate::migrations::stepped::StepOutcome {
+                        next: None,
+                        migrated,
+                        weight: Weight::zero(),
+                    }
+                }
+            };
+            crate::pallet::RwaAssets::<Test>::insert(id, asset);
+            MIGRATED.with(|m| m.borrow_mut().push(id));
+            migrated += 1;
+        }
+        let next = BoundedVec::try_from(iter.last_raw_key().to_vec()).expect("key fits cursor");
+        crate::migrations::stepped::StepOutcome {
+            next: Some(next),
+            migrated,
+            weight: Weight::zero(),
+        }
+    }
 }
 
 impl pallet_rwa::Config for Test {
@@ -171,6 +228,7 @@ impl pallet_rwa::Config for Test {
     type AssetId = u32;
     type AssetLifecycleGuard = MockLifecycleGuard;
     type AssetRegistrationDeposit = ConstU128<100>;
+    type AuthorityId = TestAuthId;
     type ForceOrigin = EnsureRoot<u64>;
     type Fungibles = Assets;
     type MaxAssetsPerOwner = ConstU32<5>;
@@ -178,17 +236,47 @@ impl pallet_rwa::Config for Test {
     type MaxMetadataLen = ConstU32<64>;
     type MaxParticipationsPerHolder = ConstU32<5>;
     type MaxPendingApprovals = ConstU32<5>;
+    type MaxSettlementsPerTx = ConstU32<3>;
     type MaxSlashRecipients = ConstU32<3>;
     type MaxSunsettingPerBlock = ConstU32<3>;
+    type MigrationItemsPerBlock = ConstU32<2>;
     /// V5: set to 1 so that zero-deposit policies are rejected.
     type MinParticipationDeposit = ConstU128<1>;
     type NativeCurrency = Balances;
     type PalletId = RwaPalletId;
     type ParticipationFilter = ();
+    type PauseOrigin = EnsureRoot<u64>;
     type RuntimeEvent = RuntimeEvent;
+    type SponsorshipPeriod = ConstU64<10>;
+    type SteppedMigration = TestMigration;
+    type UnsignedInterval = ConstU64<5>;
+    type UnsignedPriority = ConstU64<100>;
     type WeightInfo = ();
 }
 
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = TestXt<RuntimeCall, ()>;
+    type OverarchingCall = RuntimeCall;
+}
+
+/// Signs settlement payloads with the test keys set by
+/// `UintAuthorityId::set_all_keys`.
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type RuntimeAppPublic = UintAuthorityId;
+}
+
 pub const ALICE: u64 = 1;
 pub const BOB: u64 = 2;
 pub const CHARLIE: u64 = 3;
@@ -221,6 +309,7 @@ impl ExtBuilder {
 
     pub fn build(self) -> sp_io::TestExternalities {
         MockLifecycleGuard::clear();
+        MIGRATED.with(|m| m.borrow_mut().clear());
         let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
         let mut balances = self.balances;
         // Seed pallet account with ED so KeepAlive transfers don't fail on last exit
@@ -14837,3 +14837,665 @@ mod mece_toppan_policy_manipulation {
         });
     }
 }
+
+// ── set_asset_valuation ─────────────────────────────────────────────────
+
+mod set_asset_valuation {
+    use super::*;
+
+    #[test]
+    fn admin_sets_valuation() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = register_test_asset(ALICE, BOB, default_policy());
+            System::set_block_number(5);
+            assert_ok!(Rwa::set_asset_valuation(
+                RuntimeOrigin::root(),
+                aid,
+                PaymentCurrency::Native,
+                50_000
+            ));
+            let valuation = pallet::AssetValuations::<Test>::get(aid).unwrap();
+            assert_eq!(valuation.currency, PaymentCurrency::Native);
+            assert_eq!(valuation.amount, 50_000);
+            assert_eq!(valuation.updated_at, 5);
+            System::assert_last_event(RuntimeEvent::Rwa(Event::AssetValuationSet {
+                asset_id: aid,
+                currency: PaymentCurrency::Native,
+                amount: 50_000,
+            }));
+        });
+    }
+
+    #[test]
+    fn non_admin_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = register_test_asset(ALICE, BOB, default_policy());
+            assert_noop!(
+                Rwa::set_asset_valuation(
+                    RuntimeOrigin::signed(ALICE),
+                    aid,
+                    PaymentCurrency::Native,
+                    50_000
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn zero_valuation_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = register_test_asset(ALICE, BOB, default_policy());
+            assert_noop!(
+                Rwa::set_asset_valuation(RuntimeOrigin::root(), aid, PaymentCurrency::Native, 0),
+                Error::<Test>::InvalidValuation
+            );
+        });
+    }
+
+    #[test]
+    fn retired_asset_fails_and_retirement_clears_valuation() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = register_test_asset(ALICE, BOB, default_policy());
+            assert_ok!(Rwa::set_asset_valuation(
+                RuntimeOrigin::root(),
+                aid,
+                PaymentCurrency::Native,
+                50_000
+            ));
+            assert_ok!(Rwa::force_retire_asset(RuntimeOrigin::root(), aid));
+            assert!(pallet::AssetValuations::<Test>::get(aid).is_none());
+            assert_noop!(
+                Rwa::set_asset_valuation(
+                    RuntimeOrigin::root(),
+                    aid,
+                    PaymentCurrency::Native,
+                    50_000
+                ),
+                Error::<Test>::AssetAlreadyRetired
+            );
+        });
+    }
+
+    #[test]
+    fn unknown_asset_fails() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_noop!(
+                Rwa::set_asset_valuation(
+                    RuntimeOrigin::root(),
+                    99,
+                    PaymentCurrency::Native,
+                    50_000
+                ),
+                Error::<Test>::AssetNotFound
+            );
+        });
+    }
+}
+
+// ── stepped_migration ───────────────────────────────────────────────────
+
+mod stepped_migration {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    use super::*;
+    use crate::migrations::stepped::StartSteppedMigration;
+
+    #[test]
+    fn upgrade_starts_migration_below_target() {
+        ExtBuilder::default().build().execute_with(|| {
+            register_test_asset(ALICE, BOB, default_policy());
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+            assert_eq!(
+                pallet::ActiveMigration::<Test>::get(),
+                Some(MigrationCursor {
+                    target_version: 7,
+                    last_key: None,
+                    migrated: 0,
+                    started_at: 1
+                })
+            );
+            assert!(Rwa::migration_ongoing());
+            System::assert_last_event(Event::<Test>::MigrationStarted { target_version: 7 }.into());
+        });
+    }
+
+    #[test]
+    fn upgrade_skips_when_already_at_target() {
+        ExtBuilder::default().build().execute_with(|| {
+            StorageVersion::new(7).put::<Rwa>();
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+            assert!(!Rwa::migration_ongoing());
+        });
+    }
+
+    #[test]
+    fn repeated_upgrade_keeps_cursor() {
+        ExtBuilder::default().build().execute_with(|| {
+            for _ in 0..3 {
+                register_test_asset(ALICE, BOB, default_policy());
+            }
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+            run_to_block(2);
+            let cursor = pallet::ActiveMigration::<Test>::get().unwrap();
+            assert_eq!(cursor.migrated, 2);
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+            assert_eq!(pallet::ActiveMigration::<Test>::get(), Some(cursor));
+        });
+    }
+
+    #[test]
+    fn migrates_items_per_block_until_complete() {
+        ExtBuilder::default().build().execute_with(|| {
+            for _ in 0..5 {
+                register_test_asset(ALICE, BOB, default_policy());
+            }
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+
+            run_to_block(2);
+            assert_eq!(TestMigration::migrated().len(), 2);
+            run_to_block(3);
+            assert_eq!(TestMigration::migrated().len(), 4);
+            assert!(Rwa::migration_ongoing());
+            assert!(Rwa::on_chain_storage_version() < 7);
+
+            run_to_block(4);
+            assert!(!Rwa::migration_ongoing());
+            assert_eq!(Rwa::on_chain_storage_version(), 7);
+            let mut migrated = TestMigration::migrated();
+            migrated.sort();
+            assert_eq!(migrated, vec![0, 1, 2, 3, 4]);
+            System::assert_last_event(
+                Event::<Test>::MigrationCompleted { target_version: 7, migrated: 5 }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn sunset_is_deferred_while_migrating() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = register_test_asset(ALICE, BOB, default_policy());
+            register_test_asset(ALICE, BOB, default_policy());
+            register_test_asset(ALICE, BOB, default_policy());
+            assert_ok!(Rwa::sunset_asset(RuntimeOrigin::signed(ALICE), id, 2));
+            StartSteppedMigration::<Test>::on_runtime_upgrade();
+
+            run_to_block(4);
+            assert!(!Rwa::migration_ongoing());
+            assert!(matches!(
+                pallet::RwaAssets::<Test>::get(id).unwrap().status,
+                AssetStatus::Sunsetting { expiry_block: 2 }
+            ));
+            assert_eq!(pallet::SunsettingAssets::<Test>::get(2).into_inner(), vec![id]);
+
+            assert_ok!(Rwa::retire_asset(RuntimeOrigin::signed(EVE), id));
+            assert!(matches!(
+                pallet::RwaAssets::<Test>::get(id).unwrap().status,
+                AssetStatus::Retired
+            ));
+            assert!(pallet::SunsettingAssets::<Test>::get(2).is_empty());
+        });
+    }
+}
+
+// ── participation_events ────────────────────────────────────────────────
+
+mod participation_events {
+    use super::*;
+
+    #[test]
+    fn participation_events_name_their_participation() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = register_test_asset(ALICE, BOB, default_policy());
+            assert_ok!(Rwa::request_participation(
+                RuntimeOrigin::signed(CHARLIE),
+                id,
+                vec![CHARLIE]
+            ));
+            let touched: Vec<_> = System::events()
+                .into_iter()
+                .filter_map(|r| match r.event {
+                    RuntimeEvent::Rwa(e) => e.participation(),
+                    _ => None,
+                })
+                .collect();
+            // ParticipationRequested and the auto-approval.
+            assert_eq!(touched, vec![(id, 0), (id, 0)]);
+            assert_eq!(Event::<Test>::AssetPaused { asset_id: id }.participation(), None);
+        });
+    }
+
+    #[test]
+    fn batch_reject_emits_each_rejection() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = register_test_asset(ALICE, BOB, approval_policy());
+            assert_ok!(Rwa::request_participation(
+                RuntimeOrigin::signed(CHARLIE),
+                id,
+                vec![CHARLIE]
+            ));
+            assert_ok!(Rwa::request_participation(RuntimeOrigin::signed(DAVE), id, vec![DAVE]));
+            System::reset_events();
+            assert_ok!(Rwa::batch_reject_pending(RuntimeOrigin::signed(ALICE), id));
+            for pid in [0, 1] {
+                System::assert_has_event(
+                    Event::<Test>::ParticipationRejected {
+                        asset_id: id,
+                        participation_id: pid,
+                        deposit_refunded: 50,
+                        fee_refunded: 10,
+                    }
+                    .into(),
+                );
+            }
+            System::assert_last_event(
+                Event::<Test>::BatchPendingRejected { asset_id: id, count: 2 }.into(),
+            );
+        });
+    }
+}
+
+// ── fee_sponsorship ─────────────────────────────────────────────────────
+
+mod fee_sponsorship {
+    use frame_support::traits::Imbalance;
+
+    use super::*;
+
+    #[test]
+    fn owner_funds_and_ends_sponsorship() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = register_test_asset(ALICE, BOB, default_policy());
+            let account = Rwa::sponsorship_account(id);
+            let budget_before = Balances::free_balance(account);
+            assert_noop!(
+                Rwa::fund_fee_sponsorship(RuntimeOrigin::signed(BOB), id, 500, 2),
+                Error::<Test>::NotAssetOwner
+            );
+            assert_noop!(
+                Rwa::fund_fee_sponsorship(RuntimeOrigin::signed(ALICE), id, 500, 0),
+                Error::<Test>::InvalidSponsorshipLimit
+            );
+            assert_ok!(Rwa::fund_fee_sponsorship(RuntimeOrigin::signed(ALICE), id, 500, 2));
+            assert_eq!(Balances::free_balance(account), budget_before + 500);
+            assert_eq!(
+                pallet::FeeSponsorships::<Test>::get(id),
+                Some(FeeSponsorship { sponsor: ALICE, max_calls_per_period: 2 })
+            );
+
+            assert_noop!(
+                Rwa::end_fee_sponsorship(RuntimeOrigin::signed(CHARLIE), id),
+                Error::<Test>::NotAssetOwner
+            );
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(Rwa::end_fee_sponsorship(RuntimeOrigin::signed(ALICE), id));
+            assert!(Balances::free_balance(ALICE) > alice_before);
+            assert!(pallet::FeeSponsorships::<Test>::get(id).is_none());
+            assert_noop!(
+                Rwa::end_fee_sponsorship(RuntimeOrigin::signed(ALICE), id),
+                Error::<Test>::NoFeeSponsorship
+            );
+        });
+    }
+
+    #[test]
+    fn only_the_sponsor_tops_up() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = register_test_asset(ALICE, BOB, default_policy());
+            assert_ok!(Rwa::fund_fee_sponsorship(RuntimeOrigin::signed(ALICE), id, 500, 2));
+            assert_ok!(Rwa::transfer_ownership(RuntimeOrigin::signed(ALICE), id, BOB));
+            assert_ok!(Rwa::accept_ownership(RuntimeOrigin::signed(BOB), id));
+            assert_noop!(
+                Rwa::fund_fee_sponsorship(RuntimeOrigin::signed(BOB), id, 100, 2),
+                Error::<Test>::SponsoredByAnother
+            );
+            // The new owner can end it; the budget goes back to the sponsor.
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(Rwa::end_fee_sponsorship(RuntimeOrigin::signed(BOB), id));
+            assert!(Balances::free_balance(ALICE) > alice_before);
+            assert!(pallet::FeeSponsorships::<Test>::get(id).is_none());
+        });
+    }
+
+    #[test]
+    fn sponsored_fees_are_rate_limited_per_account() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = register_test_asset(ALICE, BOB, default_policy());
+            for who in [CHARLIE, DAVE] {
+                assert_ok!(Rwa::request_participation(RuntimeOrigin::signed(who), id, vec![who]));
+            }
+            assert!(!Rwa::can_sponsor_fee(id, &CHARLIE, 10));
+            assert_ok!(Rwa::fund_fee_sponsorship(RuntimeOrigin::signed(ALICE), id, 500, 2));
+            let account = Rwa::sponsorship_account(id);
+            let budget = Balances::free_balance(account);
+            assert!(!Rwa::can_sponsor_fee(id, &CHARLIE, budget));
+
+            let paid = Rwa::withdraw_sponsored_fee(id, &CHARLIE, 10).unwrap();
+            let fee = Rwa::settle_sponsored_fee(id, &CHARLIE, paid, 6);
+            assert_eq!(fee.peek(), 6);
+            drop(fee);
+            assert_eq!(Balances::free_balance(account), budget - 6);
+            System::assert_last_event(
+                Event::<Test>::FeeSponsored { asset_id: id, who: CHARLIE, fee: 6 }.into(),
+            );
+
+            assert!(Rwa::withdraw_sponsored_fee(id, &CHARLIE, 10).is_some());
+            assert!(Rwa::withdraw_sponsored_fee(id, &CHARLIE, 10).is_none());
+            assert!(Rwa::can_sponsor_fee(id, &DAVE, 10));
+
+            // The limit resets once `SponsorshipPeriod` has passed.
+            run_to_block(11);
+            assert!(Rwa::can_sponsor_fee(id, &CHARLIE, 10));
+        });
+    }
+
+    #[test]
+    fn only_holders_are_sponsored() {
+        ExtBuilder::default().build().execute_with(|| {
+            let id = register_test_asset(ALICE, BOB, default_policy());
+            assert_ok!(Rwa::fund_fee_sponsorship(RuntimeOrigin::signed(ALICE), id, 500, 2));
+            assert!(!Rwa::can_sponsor_fee(id, &CHARLIE, 10));
+            assert!(Rwa::withdraw_sponsored_fee(id, &CHARLIE, 10).is_none());
+
+            assert_ok!(Rwa::request_participation(
+                RuntimeOrigin::signed(CHARLIE),
+                id,
+                vec![CHARLIE]
+            ));
+            assert!(Rwa::can_sponsor_fee(id, &CHARLIE, 10));
+        });
+    }
+}
+
+// ── offchain settlement ─────────────────────────────────────────────────
+
+mod offchain_settlement {
+    use codec::{Decode, Encode};
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{
+        testing::{TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+    use sp_runtime::{
+        testing::{TestSignature, UintAuthorityId},
+        traits::ValidateUnsigned,
+        transaction_validity::{InvalidTransaction, TransactionSource},
+    };
+
+    use super::*;
+
+    type Extrinsic = <Test as frame_system::offchain::SendTransactionTypes<Call<Test>>>::Extrinsic;
+
+    fn signed_payload(
+        block_number: u64,
+        participations: Vec<(u32, u32)>,
+        signer: u64,
+    ) -> (SettlementPayload<UintAuthorityId, u64>, TestSignature) {
+        let payload =
+            SettlementPayload { block_number, participations, public: UintAuthorityId(1) };
+        let signature = TestSignature(signer, payload.encode());
+        (payload, signature)
+    }
+
+    fn settlement_call(
+        block_number: u64,
+        participations: Vec<(u32, u32)>,
+        signer: u64,
+    ) -> Call<Test> {
+        let (payload, signature) = signed_payload(block_number, participations, signer);
+        Call::settle_expired_participations_unsigned { payload, signature }
+    }
+
+    fn validate(call: &Call<Test>) -> sp_runtime::transaction_validity::TransactionValidity {
+        Rwa::validate_unsigned(TransactionSource::External, call)
+    }
+
+    fn allow_key(key: u64) {
+        assert_ok!(Rwa::set_settlement_key(RuntimeOrigin::root(), key, true));
+    }
+
+    /// Allows key 1, registers a 5-block policy asset with CHARLIE and DAVE
+    /// participating from block 1, and runs past their expiry.
+    fn setup_expired() -> u32 {
+        allow_key(1);
+        let aid = register_test_asset(ALICE, BOB, timed_policy(5));
+        assert_ok!(Rwa::request_participation(RuntimeOrigin::signed(CHARLIE), aid, vec![CHARLIE]));
+        assert_ok!(Rwa::request_participation(RuntimeOrigin::signed(DAVE), aid, vec![DAVE]));
+        run_to_block(7);
+        aid
+    }
+
+    #[test]
+    fn accepts_due_participations_with_a_tag_each() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = setup_expired();
+            let valid = validate(&settlement_call(7, vec![(aid, 0), (aid, 1)], 1)).unwrap();
+            assert_eq!(valid.priority, 100);
+            assert_eq!(valid.longevity, 5);
+            assert_eq!(valid.provides.len(), 2);
+            // A batch overlapping the first provides the same tag.
+            let overlapping = validate(&settlement_call(7, vec![(aid, 1)], 1)).unwrap();
+            assert!(valid.provides.contains(&overlapping.provides[0]));
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_payloads() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = setup_expired();
+            assert_eq!(
+                validate(&settlement_call(7, vec![(aid, 0)], 2)),
+                InvalidTransaction::BadProof.into()
+            );
+            assert_eq!(validate(&settlement_call(7, vec![], 1)), InvalidTransaction::Call.into());
+            assert_eq!(
+                validate(&settlement_call(7, vec![(aid, 0); 4], 1)),
+                InvalidTransaction::Call.into()
+            );
+            assert_eq!(
+                validate(&settlement_call(8, vec![(aid, 0)], 1)),
+                InvalidTransaction::Future.into()
+            );
+            assert_eq!(
+                validate(&settlement_call(7, vec![(aid, 0), (aid, 2)], 1)),
+                InvalidTransaction::Stale.into()
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_participations_not_yet_expired() {
+        ExtBuilder::default().build().execute_with(|| {
+            allow_key(1);
+            let aid = register_test_asset(ALICE, BOB, timed_policy(100));
+            assert_ok!(Rwa::request_participation(
+                RuntimeOrigin::signed(CHARLIE),
+                aid,
+                vec![CHARLIE]
+            ));
+            run_to_block(7);
+            assert_eq!(
+                validate(&settlement_call(7, vec![(aid, 0)], 1)),
+                InvalidTransaction::Stale.into()
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_keys_not_allowed() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = setup_expired();
+            // Correctly signed, but by a key governance never allowed.
+            let payload = SettlementPayload {
+                block_number: 7,
+                participations: vec![(aid, 0)],
+                public: UintAuthorityId(2),
+            };
+            let signature = TestSignature(2, payload.encode());
+            let call = Call::settle_expired_participations_unsigned { payload, signature };
+            assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+
+            assert_noop!(
+                Rwa::set_settlement_key(RuntimeOrigin::signed(ALICE), 2, true),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            allow_key(2);
+            System::assert_last_event(
+                Event::<Test>::SettlementKeySet { key: 2, allowed: true }.into(),
+            );
+            assert_ok!(validate(&call));
+
+            assert_ok!(Rwa::set_settlement_key(RuntimeOrigin::root(), 1, false));
+            assert_eq!(
+                validate(&settlement_call(7, vec![(aid, 0)], 1)),
+                InvalidTransaction::BadProof.into()
+            );
+        });
+    }
+
+    #[test]
+    fn unsigned_call_settles_and_skips_settled() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = setup_expired();
+            assert_ok!(Rwa::settle_expired_participation(RuntimeOrigin::signed(EVE), aid, 1));
+            let charlie_before = Balances::free_balance(CHARLIE);
+            let (payload, signature) = signed_payload(7, vec![(aid, 0), (aid, 1)], 1);
+            assert_noop!(
+                Rwa::settle_expired_participations_unsigned(
+                    RuntimeOrigin::signed(EVE),
+                    payload.clone(),
+                    signature.clone()
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Rwa::settle_expired_participations_unsigned(
+                RuntimeOrigin::none(),
+                payload,
+                signature
+            ));
+            assert_eq!(Balances::free_balance(CHARLIE), charlie_before + 50);
+            let p = pallet::Participations::<Test>::get(aid, 0).unwrap();
+            assert!(matches!(p.status, ParticipationStatus::Expired));
+        });
+    }
+
+    #[test]
+    fn worker_submits_expired_participations_once_per_interval() {
+        let (offchain, _) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = ExtBuilder::default().build();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+
+        ext.execute_with(|| {
+            UintAuthorityId::set_all_keys(vec![1u64]);
+            let aid = setup_expired();
+            Rwa::offchain_worker(7);
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert!(tx.signature.is_none());
+            let call = settlement_call(7, vec![(aid, 0), (aid, 1)], 1);
+            assert_eq!(tx.call, RuntimeCall::Rwa(call.clone()));
+            assert_ok!(validate(&call));
+
+            // Nothing is resubmitted before `UnsignedInterval` has passed.
+            Rwa::offchain_worker(8);
+            assert!(pool_state.read().transactions.is_empty());
+            run_to_block(12);
+            Rwa::offchain_worker(12);
+            assert_eq!(pool_state.read().transactions.len(), 1);
+        });
+    }
+
+    #[test]
+    fn worker_submits_nothing_without_due_participations() {
+        let (offchain, _) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = ExtBuilder::default().build();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+
+        ext.execute_with(|| {
+            UintAuthorityId::set_all_keys(vec![1u64]);
+            let aid = register_test_asset(ALICE, BOB, timed_policy(100));
+            assert_ok!(Rwa::request_participation(
+                RuntimeOrigin::signed(CHARLIE),
+                aid,
+                vec![CHARLIE]
+            ));
+            Rwa::offchain_worker(1);
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+
+    #[test]
+    fn worker_submits_nothing_without_an_allowed_key() {
+        let (offchain, _) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = ExtBuilder::default().build();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+
+        ext.execute_with(|| {
+            UintAuthorityId::set_all_keys(vec![2u64]);
+            setup_expired();
+            Rwa::offchain_worker(7);
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+    #[test]
+    fn expiry_index_follows_participation_lifecycle() {
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = setup_expired();
+            let indexed = || pallet::ParticipationExpiries::<Test>::iter_keys().collect::<Vec<_>>();
+            let mut keys = indexed();
+            keys.sort();
+            assert_eq!(keys, vec![(6, aid, 0), (6, aid, 1)]);
+
+            assert_ok!(Rwa::settle_expired_participation(RuntimeOrigin::signed(EVE), aid, 0));
+            assert_eq!(indexed(), vec![(6, aid, 1)]);
+            // Renewal settles the old term and indexes the new one.
+            assert_ok!(Rwa::renew_participation(RuntimeOrigin::signed(DAVE), aid, 1));
+            assert_eq!(indexed(), vec![(12, aid, 1)]);
+            assert_ok!(Rwa::exit_participation(RuntimeOrigin::signed(DAVE), aid, 1));
+            assert!(indexed().is_empty());
+
+            // Participations without an expiry are never indexed.
+            let open = register_test_asset(ALICE, BOB, default_policy());
+            assert_ok!(Rwa::request_participation(
+                RuntimeOrigin::signed(CHARLIE),
+                open,
+                vec![CHARLIE]
+            ));
+            assert!(indexed().is_empty());
+        });
+    }
+
+    #[test]
+    fn v6_migration_indexes_expiring_participations() {
+        use crate::{migrations::v6::MigrateToV6, SteppedMigration};
+
+        ExtBuilder::default().build().execute_with(|| {
+            let aid = setup_expired();
+            let open = register_test_asset(ALICE, BOB, default_policy());
+            assert_ok!(Rwa::request_participation(RuntimeOrigin::signed(EVE), open, vec![EVE]));
+            let _ = pallet::ParticipationExpiries::<Test>::clear(u32::MAX, None);
+
+            let first = MigrateToV6::<Test>::step(None, 2);
+            assert_eq!(first.migrated, 2);
+            let rest = MigrateToV6::<Test>::step(first.next, 2);
+            assert_eq!(rest.migrated, 1);
+            assert!(rest.next.is_none());
+
+            let mut indexed =
+                pallet::ParticipationExpiries::<Test>::iter_keys().collect::<Vec<_>>();
+            indexed.sort();
+            assert_eq!(indexed, vec![(6, aid, 0), (6, aid, 1)]);
+        });
+    }
+}
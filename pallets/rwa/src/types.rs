@@ -2,6 +2,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{pallet_prelude::*, BoundedVec};
 use scale_info::TypeInfo;
 use sp_runtime::{DispatchResult, Permill, RuntimeDebug};
+use sp_std::vec::Vec;
 
 /// Hook for KYC / whitelist eligibility checks before participation.
 pub trait ParticipationFilter<AccountId> {
@@ -25,6 +26,14 @@ pub trait AssetLifecycleGuard<AccountId> {
     fn can_slash_participation(_rwa_asset_id: u32, _participation_id: u32) -> DispatchResult {
         Ok(())
     }
+
+    /// Upper bound on the weight of `can_retire_asset`, charged by
+    /// `force_retire_asset` on top of its own weight.
+    fn can_retire_asset_weight() -> Weight { Weight::zero() }
+
+    /// Upper bound on the weight of `can_slash_participation`, charged by
+    /// `slash_participation` on top of its own weight.
+    fn can_slash_participation_weight() -> Weight { Weight::zero() }
 }
 
 /// Blanket no-op: no guard.
@@ -141,6 +150,14 @@ pub struct SlashRecipient<AccountId> {
     pub share: Permill,
 }
 
+/// Appraised value of an asset, set by `AdminOrigin`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AssetValuation<AssetId, Balance, BlockNumber> {
+    pub currency: PaymentCurrency<AssetId>,
+    pub amount: Balance,
+    pub updated_at: BlockNumber,
+}
+
 /// Error type for the `can_participate` runtime API.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -156,3 +173,46 @@ pub enum CanParticipateError {
     #[codec(index = 4)]
     NotEligible,
 }
+
+/// Raw storage key a stepped migration resumes after.
+pub type MigrationKey = BoundedVec<u8, ConstU32<256>>;
+
+/// Progress of the multi-block migration currently running for the pallet.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MigrationCursor<BlockNumber> {
+    /// Storage version stamped once the migration finishes.
+    pub target_version: u16,
+    /// Key of the last migrated item; `None` until the first step.
+    pub last_key: Option<MigrationKey>,
+    /// Items migrated so far.
+    pub migrated: u32,
+    pub started_at: BlockNumber,
+}
+
+/// Transaction-fee sponsorship an asset owner runs for its participants.
+/// The budget is the free balance of the asset's sponsorship account.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeeSponsorship<AccountId> {
+    /// Account that funded the budget and is refunded when it ends.
+    pub sponsor: AccountId,
+    /// Sponsored transactions allowed per account per `SponsorshipPeriod`.
+    pub max_calls_per_period: u32,
+}
+
+/// Sponsored transactions an account has made in the current period.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SponsoredUsage<BlockNumber> {
+    pub period_start: BlockNumber,
+    pub calls: u32,
+}
+
+/// Expired participations the offchain worker submits for settlement,
+/// signed by one of its keys.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SettlementPayload<Public, BlockNumber> {
+    /// Block the worker built the payload at.
+    pub block_number: BlockNumber,
+    /// `(rwa_asset_id, participation_id)` pairs to settle.
+    pub participations: Vec<(u32, u32)>,
+    pub public: Public,
+}
@@ -65,6 +65,11 @@ pub trait WeightInfo {
     fn pause_asset() -> Weight;
     fn unpause_asset() -> Weight;
     fn batch_reject_pending() -> Weight;
+    fn set_asset_valuation() -> Weight;
+    fn fund_fee_sponsorship() -> Weight;
+    fn end_fee_sponsorship() -> Weight;
+    fn set_settlement_key() -> Weight;
+    fn settle_expired_participations_unsigned(n: u32) -> Weight;
 }
 
 /// Weight functions for `pallet_rwa`.
@@ -136,13 +141,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Rwa PendingApprovals (r:0 w:1)
     /// Storage: Rwa PendingOwnershipTransfer (r:0 w:1)
     /// Storage: Rwa AssetSlashDistribution (r:0 w:1)
+    /// Storage: Rwa AssetValuations (r:0 w:1)
     fn force_retire_asset() -> Weight {
         // Measured:  `349`
         // Estimated: `16472`
         // Minimum execution time: 34_000 nanoseconds.
         Weight::from_parts(35_000_000, 16472)
             .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:1)
@@ -152,13 +158,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Rwa PendingApprovals (r:0 w:1)
     /// Storage: Rwa PendingOwnershipTransfer (r:0 w:1)
     /// Storage: Rwa AssetSlashDistribution (r:0 w:1)
+    /// Storage: Rwa AssetValuations (r:0 w:1)
     fn retire_asset() -> Weight {
         // Measured:  `392`
         // Estimated: `15078`
         // Minimum execution time: 35_000 nanoseconds.
         Weight::from_parts(35_000_000, 15078)
             .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(7))
+            .saturating_add(T::DbWeight::get().writes(8))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:1)
@@ -167,6 +174,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: System Account (r:2 w:2)
     /// Storage: Rwa NextParticipationId (r:1 w:1)
     /// Storage: Rwa Participations (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     /// Component `h` range: [1, 10]. Worst-case used here (h=10).
     fn request_participation() -> Weight {
         // Measured:  `494`
@@ -175,19 +183,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
         // Worst case (h=10): ~112_000 nanoseconds.
         Weight::from_parts(111_793_196, 68259)
             .saturating_add(T::DbWeight::get().reads(24))
-            .saturating_add(T::DbWeight::get().writes(25))
+            .saturating_add(T::DbWeight::get().writes(26))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:0)
     /// Storage: Rwa Participations (r:1 w:1)
     /// Storage: Rwa PendingApprovals (r:1 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     fn approve_participation() -> Weight {
         // Measured:  `531`
         // Estimated: `11675`
         // Minimum execution time: 24_000 nanoseconds.
         Weight::from_parts(26_000_000, 11675)
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes(3))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:1)
@@ -210,24 +219,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: System Account (r:2 w:2)
     /// Storage: Rwa HolderAssets (r:1 w:1)
     /// Storage: Rwa HolderIndex (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     fn exit_participation() -> Weight {
         // Measured:  `772`
         // Estimated: `17698`
         // Minimum execution time: 42_000 nanoseconds.
         Weight::from_parts(43_000_000, 17698)
             .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:0)
     /// Storage: Rwa Participations (r:1 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:2)
     fn renew_participation() -> Weight {
         // Measured:  `487`
         // Estimated: `7788`
         // Minimum execution time: 19_000 nanoseconds.
         Weight::from_parts(20_000_000, 7788)
             .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(3))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:1)
@@ -235,13 +246,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: System Account (r:2 w:2)
     /// Storage: Rwa HolderAssets (r:1 w:1)
     /// Storage: Rwa HolderIndex (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     fn settle_expired_participation() -> Weight {
         // Measured:  `780`
         // Estimated: `17698`
         // Minimum execution time: 42_000 nanoseconds.
         Weight::from_parts(43_000_000, 17698)
             .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:1)
@@ -249,13 +261,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: System Account (r:2 w:2)
     /// Storage: Rwa HolderAssets (r:1 w:1)
     /// Storage: Rwa HolderIndex (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     fn claim_retired_deposit() -> Weight {
         // Measured:  `772`
         // Estimated: `17698`
         // Minimum execution time: 43_000 nanoseconds.
         Weight::from_parts(43_000_000, 17698)
             .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:0)
@@ -275,26 +288,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: Rwa Participations (r:1 w:1)
     /// Storage: Rwa HolderAssets (r:1 w:1)
     /// Storage: Rwa HolderIndex (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     fn remove_holder() -> Weight {
         // Measured:  `635`
         // Estimated: `11502`
         // Minimum execution time: 24_000 nanoseconds.
         Weight::from_parts(25_000_000, 11502)
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:0)
     /// Storage: Rwa Participations (r:1 w:1)
     /// Storage: Rwa HolderAssets (r:1 w:1)
     /// Storage: Rwa HolderIndex (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     fn leave_participation() -> Weight {
         // Measured:  `635`
         // Estimated: `11502`
         // Minimum execution time: 24_000 nanoseconds.
         Weight::from_parts(24_000_000, 11502)
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:0)
@@ -315,13 +330,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: System Account (r:3 w:3)
     /// Storage: Rwa HolderAssets (r:1 w:1)
     /// Storage: Rwa HolderIndex (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     fn slash_participation() -> Weight {
         // Measured:  `885`
         // Estimated: `29052`
         // Minimum execution time: 62_000 nanoseconds.
         Weight::from_parts(63_000_000, 29052)
             .saturating_add(T::DbWeight::get().reads(8))
-            .saturating_add(T::DbWeight::get().writes(7))
+            .saturating_add(T::DbWeight::get().writes(8))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:1)
@@ -329,13 +345,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: System Account (r:2 w:2)
     /// Storage: Rwa HolderAssets (r:1 w:1)
     /// Storage: Rwa HolderIndex (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
     fn revoke_participation() -> Weight {
         // Measured:  `772`
         // Estimated: `17698`
         // Minimum execution time: 42_000 nanoseconds.
         Weight::from_parts(43_000_000, 17698)
             .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
 
     /// Storage: Rwa RwaAssets (r:1 w:0)
@@ -440,6 +457,54 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(303))
             .saturating_add(T::DbWeight::get().writes(403))
     }
+    /// Storage: Rwa RwaAssets (r:1 w:0)
+    /// Storage: Rwa AssetValuations (r:0 w:1)
+    fn set_asset_valuation() -> Weight {
+        // Estimated: mirrors pause_asset
+        Weight::from_parts(13_000_000, 3884)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: Rwa RwaAssets (r:1 w:0)
+    /// Storage: Rwa FeeSponsorships (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    fn fund_fee_sponsorship() -> Weight {
+        // Estimated: asset and sponsorship reads plus a native transfer
+        Weight::from_parts(32_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: Rwa FeeSponsorships (r:1 w:1)
+    /// Storage: Rwa RwaAssets (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
+    fn end_fee_sponsorship() -> Weight {
+        // Estimated: mirrors fund_fee_sponsorship
+        Weight::from_parts(32_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: Rwa SettlementKeys (r:0 w:1)
+    fn set_settlement_key() -> Weight {
+        // Estimated: a single write, like set_asset_valuation without the asset read
+        Weight::from_parts(9_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: Rwa SettlementKeys (r:1 w:0)
+    /// Storage: Rwa RwaAssets (r:1 w:1)
+    /// Storage: Rwa Participations (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    /// Storage: Rwa HolderAssets (r:1 w:1)
+    /// Storage: Rwa HolderIndex (r:0 w:1)
+    /// Storage: Rwa ParticipationExpiries (r:0 w:1)
+    fn settle_expired_participations_unsigned(n: u32) -> Weight {
+        // Estimated: the payload signature check and settlement key read,
+        // plus settle_expired_participation and the two `validate_unsigned`
+        // reads per participation
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(43_000_000, 17698).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+    }
 }
 
 /// Fallback weights for testing / development.
@@ -477,25 +542,25 @@ impl WeightInfo for () {
     fn force_retire_asset() -> Weight {
         Weight::from_parts(35_000_000, 16472)
             .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(6))
+            .saturating_add(RocksDbWeight::get().writes(7))
     }
 
     fn retire_asset() -> Weight {
         Weight::from_parts(35_000_000, 15078)
             .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(7))
+            .saturating_add(RocksDbWeight::get().writes(8))
     }
 
     fn request_participation() -> Weight {
         Weight::from_parts(111_793_196, 68259)
             .saturating_add(RocksDbWeight::get().reads(24))
-            .saturating_add(RocksDbWeight::get().writes(25))
+            .saturating_add(RocksDbWeight::get().writes(26))
     }
 
     fn approve_participation() -> Weight {
         Weight::from_parts(26_000_000, 11675)
             .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
     }
 
     fn reject_participation() -> Weight {
@@ -507,25 +572,25 @@ impl WeightInfo for () {
     fn exit_participation() -> Weight {
         Weight::from_parts(43_000_000, 17698)
             .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(6))
+            .saturating_add(RocksDbWeight::get().writes(7))
     }
 
     fn renew_participation() -> Weight {
         Weight::from_parts(20_000_000, 7788)
             .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes(3))
     }
 
     fn settle_expired_participation() -> Weight {
         Weight::from_parts(43_000_000, 17698)
             .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(6))
+            .saturating_add(RocksDbWeight::get().writes(7))
     }
 
     fn claim_retired_deposit() -> Weight {
         Weight::from_parts(43_000_000, 17698)
             .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(6))
+            .saturating_add(RocksDbWeight::get().writes(7))
     }
 
     fn add_holder() -> Weight {
@@ -537,13 +602,13 @@ impl WeightInfo for () {
     fn remove_holder() -> Weight {
         Weight::from_parts(25_000_000, 11502)
             .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn leave_participation() -> Weight {
         Weight::from_parts(24_000_000, 11502)
             .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
 
     fn set_slash_distribution() -> Weight {
@@ -555,13 +620,13 @@ impl WeightInfo for () {
     fn slash_participation() -> Weight {
         Weight::from_parts(63_000_000, 29052)
             .saturating_add(RocksDbWeight::get().reads(8))
-            .saturating_add(RocksDbWeight::get().writes(7))
+            .saturating_add(RocksDbWeight::get().writes(8))
     }
 
     fn revoke_participation() -> Weight {
         Weight::from_parts(43_000_000, 17698)
             .saturating_add(RocksDbWeight::get().reads(5))
-            .saturating_add(RocksDbWeight::get().writes(6))
+            .saturating_add(RocksDbWeight::get().writes(7))
     }
 
     fn transfer_ownership() -> Weight {
@@ -617,4 +682,29 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(303))
             .saturating_add(RocksDbWeight::get().writes(403))
     }
+    fn set_asset_valuation() -> Weight {
+        Weight::from_parts(13_000_000, 3884)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn fund_fee_sponsorship() -> Weight {
+        Weight::from_parts(32_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn end_fee_sponsorship() -> Weight {
+        Weight::from_parts(32_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn set_settlement_key() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn settle_expired_participations_unsigned(n: u32) -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(43_000_000, 17698).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
+    }
 }
@@ -0,0 +1,53 @@
+//! Runtime API definition for assets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Maximum number of entries returned by one `account_balances_paged` call.
+pub const MAX_PAGE_SIZE: u32 = 256;
+
+/// An account's holding of one asset, together with the asset's metadata.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AssetBalanceInfo<AssetId, Balance> {
+    pub asset_id: AssetId,
+    /// Symbol from the asset's metadata; empty when none is set.
+    #[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+    pub symbol: Vec<u8>,
+    /// Decimals from the asset's metadata; zero when none is set.
+    pub decimals: u8,
+    /// Total balance, including `frozen`.
+    pub balance: Balance,
+    /// Part of `balance` that cannot be withdrawn, because the account or
+    /// asset is frozen or the account must keep its minimum balance.
+    pub frozen: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    #[api_version(2)]
+    pub trait AssetsApi<AccountId, AssetBalance, AssetId>
+    where
+        AccountId: Codec,
+        AssetBalance: Codec,
+        AssetId: Codec,
+    {
+        /// Returns the list of `AssetId`s and corresponding balance that an `AccountId` has.
+        fn account_balances(account: AccountId) -> Vec<(AssetId, AssetBalance)>;
+
+        /// Returns the assets held by `account` with their metadata, in the
+        /// order `pallet-assets` stores them and starting after
+        /// `start_after`.  At most `limit` entries are returned, capped at
+        /// [`MAX_PAGE_SIZE`].
+        #[api_version(2)]
+        fn account_balances_paged(
+            account: AccountId,
+            start_after: Option<AssetId>,
+            limit: u32,
+        ) -> Vec<AssetBalanceInfo<AssetId, AssetBalance>>;
+    }
+}
@@ -0,0 +1,161 @@
+//! On-chain governance: a council and a technical committee, plus referenda
+//! voted with conviction on per-origin tracks.
+//!
+//! Authority over the custom pallets is split as follows:
+//! - `PauseOrigin` (pausing assets and campaigns): half of the technical
+//!   committee, so emergencies don't wait for a referendum.
+//! - `AdminOrigin` (protocol configuration): the `protocol_admin` track.
+//! - `ForceOrigin` (forced retirement, cancellation and finalization): the
+//!   slower `force_admin` track.
+//! - `MilestoneApprover`: a council majority.
+//!
+//! Each of these also accepts Root.  Root itself is reachable through the
+//! `root` track, so `Sudo` can be removed once governance is bootstrapped.
+
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, EitherOf, EitherOfDiverse, EqualPrivilegeOnly},
+    weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_runtime::Perbill;
+
+use super::{
+    AccountId, Balance, Balances, BlockNumber, OriginCaller, Preimage, Referenda, Runtime,
+    RuntimeBlockWeights, RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, DAYS, DOLLARS, HOURS,
+    MINUTES,
+};
+
+mod origins;
+pub use origins::{pallet_custom_origins, ForceAdmin, ProtocolAdmin};
+mod tracks;
+pub use tracks::TracksInfo;
+
+pub type CouncilCollective = pallet_collective::Instance1;
+pub type TechnicalCollective = pallet_collective::Instance2;
+
+/// Root, or the `protocol_admin` track.
+pub type AdminOrigin = EitherOf<EnsureRoot<AccountId>, ProtocolAdmin>;
+/// Root, or the `force_admin` track.
+pub type ForceOrigin = EitherOf<EnsureRoot<AccountId>, ForceAdmin>;
+/// The admin origin, or half of the technical committee.
+pub type PauseOrigin = EitherOfDiverse<
+    AdminOrigin,
+    pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>,
+>;
+/// Root, or a council majority.
+pub type MilestoneApprover = EitherOfDiverse<
+    EnsureRoot<AccountId>,
+    pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+>;
+
+parameter_types! {
+    pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+    pub const CouncilMaxProposals: u32 = 100;
+    pub const CouncilMaxMembers: u32 = 100;
+}
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type MaxMembers = CouncilMaxMembers;
+    type MaxProposals = CouncilMaxProposals;
+    type MotionDuration = CouncilMotionDuration;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    // Short enough for a pause to land within hours of a motion.
+    pub const TechnicalMotionDuration: BlockNumber = 3 * HOURS;
+    pub const TechnicalMaxProposals: u32 = 100;
+    pub const TechnicalMaxMembers: u32 = 100;
+}
+
+impl pallet_collective::Config<TechnicalCollective> for Runtime {
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type MaxMembers = TechnicalMaxMembers;
+    type MaxProposals = TechnicalMaxProposals;
+    type MotionDuration = TechnicalMotionDuration;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const PreimageBaseDeposit: Balance = DOLLARS;
+    pub const PreimageByteDeposit: Balance = DOLLARS / 100;
+}
+
+impl pallet_preimage::Config for Runtime {
+    type BaseDeposit = PreimageBaseDeposit;
+    type ByteDeposit = PreimageByteDeposit;
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight =
+        Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type PalletsOrigin = OriginCaller;
+    type Preimages = Preimage;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type ScheduleOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const VoteLockingPeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+    type Currency = Balances;
+    type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, AccountId>;
+    type MaxVotes = ConstU32<512>;
+    type Polls = Referenda;
+    type RuntimeEvent = RuntimeEvent;
+    type VoteLockingPeriod = VoteLockingPeriod;
+    type WeightInfo = pallet_conviction_voting::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const AlarmInterval: BlockNumber = 1;
+    pub const SubmissionDeposit: Balance = 100 * DOLLARS;
+    pub const UndecidingTimeout: BlockNumber = 14 * DAYS;
+}
+
+impl pallet_custom_origins::Config for Runtime {}
+
+impl pallet_referenda::Config for Runtime {
+    type AlarmInterval = AlarmInterval;
+    type CancelOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type KillOrigin = EnsureRoot<AccountId>;
+    type MaxQueued = ConstU32<100>;
+    type Preimages = Preimage;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type Slash = ();
+    type SubmissionDeposit = SubmissionDeposit;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type Tally = pallet_conviction_voting::TallyOf<Runtime>;
+    type Tracks = TracksInfo;
+    type UndecidingTimeout = UndecidingTimeout;
+    type Votes = pallet_conviction_voting::VotesOf<Runtime>;
+    type WeightInfo = pallet_referenda::weights::SubstrateWeight<Runtime>;
+}
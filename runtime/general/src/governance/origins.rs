@@ -0,0 +1,46 @@
+//! Custom origins for governance interventions.
+
+pub use pallet_custom_origins::*;
+
+#[frame_support::pallet]
+pub mod pallet_custom_origins {
+    use frame_support::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {}
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Origins dispatched by passing referenda on their own track.
+    #[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+    #[pallet::origin]
+    pub enum Origin {
+        /// Protocol configuration: fees, bonds, slashing and valuations.
+        ProtocolAdmin,
+        /// Forced interventions: retiring assets and cancelling or
+        /// finalizing campaigns.
+        ForceAdmin,
+    }
+
+    macro_rules! decl_unit_ensures {
+        ($name:ident) => {
+            pub struct $name;
+            impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for $name {
+                type Success = ();
+
+                fn try_origin(o: O) -> Result<Self::Success, O> {
+                    o.into().and_then(|o| match o {
+                        Origin::$name => Ok(()),
+                        r => Err(O::from(r)),
+                    })
+                }
+
+                #[cfg(feature = "runtime-benchmarks")]
+                fn try_successful_origin() -> Result<O, ()> { Ok(O::from(Origin::$name)) }
+            }
+        };
+    }
+    decl_unit_ensures!(ProtocolAdmin);
+    decl_unit_ensures!(ForceAdmin);
+}
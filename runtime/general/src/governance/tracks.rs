@@ -0,0 +1,85 @@
+//! Referenda tracks, one per origin a referendum can dispatch with.
+
+use sp_runtime::Perbill;
+
+use super::{origins, Balance, BlockNumber, RuntimeOrigin, DAYS, DOLLARS, HOURS, MINUTES};
+
+const fn linear(floor: u32, ceil: u32) -> pallet_referenda::Curve {
+    pallet_referenda::Curve::LinearDecreasing {
+        length: Perbill::from_percent(100),
+        floor: Perbill::from_percent(floor),
+        ceil: Perbill::from_percent(ceil),
+    }
+}
+
+const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 3] = [
+    (
+        0,
+        pallet_referenda::TrackInfo {
+            name: "root",
+            max_deciding: 1,
+            decision_deposit: 10_000 * DOLLARS,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: 1 * DAYS,
+            min_enactment_period: 1 * DAYS,
+            min_approval: linear(50, 100),
+            min_support: linear(5, 50),
+        },
+    ),
+    (
+        1,
+        pallet_referenda::TrackInfo {
+            name: "protocol_admin",
+            max_deciding: 10,
+            decision_deposit: 1_000 * DOLLARS,
+            prepare_period: 2 * HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: linear(50, 100),
+            min_support: linear(1, 25),
+        },
+    ),
+    (
+        2,
+        pallet_referenda::TrackInfo {
+            name: "force_admin",
+            max_deciding: 10,
+            decision_deposit: 5_000 * DOLLARS,
+            prepare_period: 4 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: 1 * DAYS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: linear(60, 100),
+            min_support: linear(5, 50),
+        },
+    ),
+];
+
+pub struct TracksInfo;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin;
+
+    fn tracks() -> &'static [(Self::Id, pallet_referenda::TrackInfo<Balance, BlockNumber>)] {
+        &TRACKS_DATA[..]
+    }
+
+    fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
+            match system_origin {
+                frame_system::RawOrigin::Root => Ok(0),
+                _ => Err(()),
+            }
+        } else if let Ok(custom_origin) = origins::Origin::try_from(id.clone()) {
+            match custom_origin {
+                origins::Origin::ProtocolAdmin => Ok(1),
+                origins::Origin::ForceAdmin => Ok(2),
+            }
+        } else {
+            Err(())
+        }
+    }
+}
+pallet_referenda::impl_tracksinfo_get!(TracksInfo, Balance, BlockNumber);
@@ -1,7 +1,18 @@
-use frame_support::traits::fungibles::{Balanced, CreditOf};
-use pallet_asset_tx_payment::HandleCredit;
+use codec::{Decode, Encode};
+use frame_support::traits::{
+    fungibles::{Balanced, CreditOf},
+    Contains,
+};
+use pallet_asset_tx_payment::{ChargeAssetTxPayment, HandleCredit};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension, Zero},
+    transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+    DispatchResult, RuntimeDebug,
+};
 
-use crate::{AccountId, Assets, Runtime};
+use crate::{AccountId, Assets, Balance, Crowdfunding, Runtime, RuntimeCall, Rwa};
 
 /// A `HandleCredit` implementation that naively transfers the fees to the block
 /// author. Will drop and burn the assets in case the transfer fails.
@@ -16,3 +27,218 @@ impl HandleCredit<AccountId, Assets> for CreditToBlockAuthor {
         }
     }
 }
+
+/// `pallet-assets`' private map of asset details, whose keys are read to
+/// page through an account's balances.
+#[frame_support::storage_alias]
+type Asset = StorageMap<
+    Assets,
+    frame_support::Blake2_128Concat,
+    u32,
+    pallet_assets::AssetDetails<Balance, AccountId, Balance>,
+>;
+
+/// `account`'s asset balances, in the order `pallet-assets` stores the assets
+/// and starting after `start_after`.  Assets are read lazily, so a page only
+/// costs the assets it visits.
+pub fn account_balances_from(
+    account: &AccountId,
+    start_after: Option<u32>,
+) -> impl Iterator<Item = (u32, Balance)> + '_ {
+    let asset_ids = match start_after {
+        Some(after) => Asset::iter_keys_from(Asset::hashed_key_for(after)),
+        None => Asset::iter_keys(),
+    };
+    asset_ids.filter_map(move |asset_id| {
+        Assets::maybe_balance(asset_id, account.clone()).map(|balance| (asset_id, balance))
+    })
+}
+
+/// Base call filter that pauses the RWA and crowdfunding pallets while either
+/// runs a stepped storage migration.  The pallets read each other's storage,
+/// so both are paused together.
+pub struct PalletMigrationFilter;
+
+impl Contains<RuntimeCall> for PalletMigrationFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::Rwa(_) | RuntimeCall::Crowdfunding(_) => {
+                !Rwa::migration_ongoing() && !Crowdfunding::migration_ongoing()
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Native fee withdrawn from a sponsorship budget.
+type SponsoredFee = pallet_rwa::NegativeImbalanceOf<Runtime>;
+
+/// The fee sponsorship that may pay for a call.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum FeeSponsor {
+    /// The sponsorship of an RWA asset, for its participants' calls.
+    Asset(u32),
+    /// The sponsorship of a campaign, for its investors' calls, with the
+    /// amount of an `invest` that may open a position.
+    Campaign(u32, Option<Balance>),
+}
+
+impl FeeSponsor {
+    fn of(call: &RuntimeCall) -> Option<Self> {
+        match call {
+            RuntimeCall::Rwa(call) => call.sponsoring_asset().map(FeeSponsor::Asset),
+            RuntimeCall::Crowdfunding(call) => call
+                .sponsoring_campaign()
+                .map(|id| FeeSponsor::Campaign(id, call.sponsored_investment())),
+            _ => None,
+        }
+    }
+
+    fn can_pay(self, who: &AccountId, fee: Balance) -> bool {
+        match self {
+            FeeSponsor::Asset(id) => Rwa::can_sponsor_fee(id, who, fee),
+            FeeSponsor::Campaign(id, investment) => {
+                Crowdfunding::can_sponsor_fee(id, who, fee, investment)
+            }
+        }
+    }
+
+    fn withdraw(self, who: &AccountId, fee: Balance) -> Option<SponsoredFee> {
+        match self {
+            FeeSponsor::Asset(id) => Rwa::withdraw_sponsored_fee(id, who, fee),
+            FeeSponsor::Campaign(id, investment) => {
+                Crowdfunding::withdraw_sponsored_fee(id, who, fee, investment)
+            }
+        }
+    }
+
+    fn settle(self, who: &AccountId, paid: SponsoredFee, fee: Balance) -> SponsoredFee {
+        match self {
+            FeeSponsor::Asset(id) => Rwa::settle_sponsored_fee(id, who, paid, fee),
+            FeeSponsor::Campaign(id, _) => Crowdfunding::settle_sponsored_fee(id, who, paid, fee),
+        }
+    }
+}
+
+/// `ChargeAssetTxPayment` that lets RWA asset owners and campaign creators
+/// pay the fees of calls on their asset or campaign.
+///
+/// An untipped call named by `Call::sponsoring_asset` or
+/// `Call::sponsoring_campaign` is paid from that sponsorship's budget while it
+/// can cover the fee and the sender already takes part in the asset or
+/// campaign, or makes a first `invest` it is eligible for and can pay, and is
+/// within its call limit; anything else, including calls the base filter
+/// rejects, is charged to the sender as before.
+///
+/// The extension encodes byte for byte like `ChargeAssetTxPayment`: a compact
+/// tip followed by an optional asset id, with no additional signed data.  It
+/// keeps the `"ChargeAssetTxPayment"` identifier on purpose, because wallets
+/// and signers pick the encoder for a signed extension by its identifier, so
+/// they keep building valid extrinsics without learning a new extension.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo, RuntimeDebug)]
+pub struct ChargeSponsoredTxPayment {
+    #[codec(compact)]
+    tip: Balance,
+    asset_id: Option<u32>,
+}
+
+impl ChargeSponsoredTxPayment {
+    /// Utility constructor, as `ChargeAssetTxPayment::from`.
+    pub fn from(tip: Balance, asset_id: Option<u32>) -> Self { Self { tip, asset_id } }
+
+    fn payer_charge(&self) -> ChargeAssetTxPayment<Runtime> {
+        ChargeAssetTxPayment::from(self.tip, self.asset_id)
+    }
+
+    /// The sponsorship that may pay for `call`, with the fee it would pay.
+    fn sponsored_fee(
+        &self,
+        call: &RuntimeCall,
+        info: &DispatchInfoOf<RuntimeCall>,
+        len: usize,
+    ) -> Option<(FeeSponsor, Balance)> {
+        // Sponsors never pay tips.
+        if !self.tip.is_zero() {
+            return None;
+        }
+        // Calls the base filter rejects fail at dispatch, so they are
+        // charged to the sender.
+        if !PalletMigrationFilter::contains(call) {
+            return None;
+        }
+        let sponsor = FeeSponsor::of(call)?;
+        let fee = pallet_transaction_payment::Pallet::<Runtime>::compute_fee(len as u32, info, 0);
+        Some((sponsor, fee))
+    }
+}
+
+/// What `ChargeSponsoredTxPayment` charged before dispatch.
+pub enum SponsoredPre {
+    Sponsored { who: AccountId, sponsor: FeeSponsor, paid: SponsoredFee },
+    Payer(<ChargeAssetTxPayment<Runtime> as SignedExtension>::Pre),
+}
+
+impl SignedExtension for ChargeSponsoredTxPayment {
+    type AccountId = AccountId;
+    type AdditionalSigned = ();
+    type Call = RuntimeCall;
+    type Pre = SponsoredPre;
+
+    const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> { Ok(()) }
+
+    fn validate(
+        &self,
+        who: &AccountId,
+        call: &RuntimeCall,
+        info: &DispatchInfoOf<RuntimeCall>,
+        len: usize,
+    ) -> TransactionValidity {
+        if let Some((sponsor, fee)) = self.sponsored_fee(call, info, len) {
+            if sponsor.can_pay(who, fee) {
+                let priority =
+                    ChargeTransactionPayment::<Runtime>::get_priority(info, len, self.tip, fee);
+                return Ok(ValidTransaction { priority, ..Default::default() });
+            }
+        }
+        self.payer_charge().validate(who, call, info, len)
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &AccountId,
+        call: &RuntimeCall,
+        info: &DispatchInfoOf<RuntimeCall>,
+        len: usize,
+    ) -> Result<SponsoredPre, TransactionValidityError> {
+        if let Some((sponsor, fee)) = self.sponsored_fee(call, info, len) {
+            if let Some(paid) = sponsor.withdraw(who, fee) {
+                return Ok(SponsoredPre::Sponsored { who: who.clone(), sponsor, paid });
+            }
+        }
+        self.payer_charge().pre_dispatch(who, call, info, len).map(SponsoredPre::Payer)
+    }
+
+    fn post_dispatch(
+        pre: Option<SponsoredPre>,
+        info: &DispatchInfoOf<RuntimeCall>,
+        post_info: &PostDispatchInfoOf<RuntimeCall>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        let pre = match pre {
+            Some(SponsoredPre::Sponsored { who, sponsor, paid }) => {
+                let fee = pallet_transaction_payment::Pallet::<Runtime>::compute_actual_fee(
+                    len as u32, info, post_info, 0,
+                );
+                // Burnt, like native fees paid by the sender.
+                drop(sponsor.settle(&who, paid, fee));
+                return Ok(());
+            }
+            Some(SponsoredPre::Payer(pre)) => Some(pre),
+            None => None,
+        };
+        ChargeAssetTxPayment::<Runtime>::post_dispatch(pre, info, post_info, len, result)
+    }
+}
@@ -6,6 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+pub mod governance;
 mod weights;
 pub mod xcm_config;
 
@@ -18,7 +19,7 @@ use frame_support::{
     parameter_types,
     traits::{
         tokens::nonfungibles_v2::Inspect, AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32,
-        ConstU64, Everything, InstanceFilter,
+        ConstU64, InstanceFilter,
     },
     weights::{constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight},
     PalletId, RuntimeDebug,
@@ -27,6 +28,7 @@ use frame_system::{
     limits::{BlockLength, BlockWeights},
     EnsureRoot, EnsureSigned,
 };
+use governance::pallet_custom_origins;
 use pallet_nfts::PalletFeatures;
 use polkadot_runtime_common::{BlockHashCount, SlowAdjustingFeeUpdate};
 use sp_api::impl_runtime_apis;
@@ -37,7 +39,7 @@ pub use sp_runtime::BuildStorage;
 use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, IdentifyAccount, Verify},
-    transaction_validity::{TransactionSource, TransactionValidity},
+    transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
     ApplyExtrinsicResult, MultiSignature,
 };
 pub use sp_runtime::{MultiAddress, Perbill, Permill};
@@ -51,9 +53,6 @@ use xcm::latest::prelude::BodyId;
 use xcm_config::{XcmConfig, XcmOriginToTransactDispatchOrigin};
 use xcm_executor::XcmExecutor;
 
-/// Runtime API definition for assets.
-pub mod assets_api;
-
 /// Constant values used within the runtime.
 pub mod constants;
 pub use constants::{currency::*, fee::*, time::*};
@@ -61,7 +60,7 @@ pub use constants::{currency::*, fee::*, time::*};
 /// Implementations of some helper traits passed into runtime modules as
 /// associated types.
 pub mod impls;
-use impls::CreditToBlockAuthor;
+use impls::{ChargeSponsoredTxPayment, CreditToBlockAuthor, PalletMigrationFilter};
 
 /// Alias to 512-bit hash when used in the context of a transaction signature on
 /// the chain.
@@ -107,7 +106,7 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
+    ChargeSponsoredTxPayment,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -117,6 +116,20 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+///
+/// Each migration checks the on-chain storage version and is a no-op once
+/// applied.  The `StartSteppedMigration` entries begin the pallets' configured
+/// multi-block migrations, if any.
+pub type Migrations = (
+    pallet_rwa::migrations::v5::MigrateToV5<Runtime>,
+    pallet_crowdfunding::migrations::v3::MigrateToV3<Runtime>,
+    pallet_crowdfunding::migrations::v4::MigrateToV4<Runtime>,
+    pallet_crowdfunding::migrations::v5::MigrateToV5<Runtime>,
+    pallet_rwa::migrations::stepped::StartSteppedMigration<Runtime>,
+    pallet_crowdfunding::migrations::stepped::StartSteppedMigration<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -124,6 +137,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
 /// Opaque types. These are used by the CLI to instantiate machinery that don't
@@ -155,10 +169,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("thxnet-general-runtime"),
     impl_name: create_runtime_str!("thxnet-general-runtime"),
     authoring_version: 1,
-    spec_version: 4,
+    spec_version: 5,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -221,7 +235,7 @@ impl frame_system::Config for Runtime {
     /// The identifier used to distinguish between accounts.
     type AccountId = AccountId;
     /// The basic call filter to use in dispatchable.
-    type BaseCallFilter = Everything;
+    type BaseCallFilter = PalletMigrationFilter;
     /// Maximum number of block number to block hash mappings to keep (oldest
     /// pruned first).
     type BlockHashCount = BlockHashCount;
@@ -462,7 +476,7 @@ impl pallet_assets::Config for Runtime {
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
     type CallbackHandle = ();
-    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type CreateOrigin = xcm_config::SignedForLocalAssetIds;
     type Currency = Balances;
     type Extra = ();
     type ForceOrigin = EnsureRoot<AccountId>;
@@ -475,6 +489,21 @@ impl pallet_assets::Config for Runtime {
     type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const AssetRegistryPalletId: PalletId = PalletId(*b"py/asreg");
+}
+
+impl pallet_asset_registry::Config for Runtime {
+    type AssetId = u32;
+    type ForeignAssetIds = xcm_config::ForeignAssetIds;
+    type Fungibles = Assets;
+    type PalletId = AssetRegistryPalletId;
+    type RegistryOrigin = governance::AdminOrigin;
+    type RuntimeEvent = RuntimeEvent;
+    type StringLimit = StringLimit;
+    type WeightInfo = pallet_asset_registry::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub Features: PalletFeatures = PalletFeatures::all_enabled();
     pub const MaxAttributesPerCall: u32 = 10;
@@ -551,10 +580,18 @@ pub enum ProxyType {
     IdentityJudgement = 5,
     CancelProxy = 6,
     // Auction = 7,
+    /// Participation management of the delegator's RWA assets.
+    RwaOperator = 8,
+    /// Day-to-day management of the delegator's campaigns.
+    CampaignManager = 9,
+    /// Investing in and withdrawing from campaigns.
+    Investor = 10,
 }
 
 #[cfg(test)]
 mod proxy_type_tests {
+    use frame_support::assert_ok;
+
     use super::*;
 
     #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
@@ -582,6 +619,255 @@ mod proxy_type_tests {
         }
         assert!(ProxyType::decode(&mut &OldProxyType::SudoBalances.encode()[..]).is_err());
     }
+
+    const ALICE: AccountId = sp_runtime::AccountId32::new([1u8; 32]);
+    const BOB: AccountId = sp_runtime::AccountId32::new([2u8; 32]);
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: vec![(ALICE, 1_000 * DOLLARS), (BOB, 1_000 * DOLLARS)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    fn rwa_call(call: pallet_rwa::Call<Runtime>) -> RuntimeCall { RuntimeCall::Rwa(call) }
+
+    fn crowdfunding_call(call: pallet_crowdfunding::Call<Runtime>) -> RuntimeCall {
+        RuntimeCall::Crowdfunding(call)
+    }
+
+    fn asset_transfer() -> RuntimeCall {
+        RuntimeCall::Assets(pallet_assets::Call::transfer {
+            id: 1.into(),
+            target: MultiAddress::Id(BOB),
+            amount: 1,
+        })
+    }
+
+    fn nft_transfer() -> RuntimeCall {
+        RuntimeCall::Nfts(pallet_nfts::Call::transfer {
+            collection: 0,
+            item: 0,
+            dest: MultiAddress::Id(BOB),
+        })
+    }
+
+    #[test]
+    fn new_proxy_types_keep_their_indices() {
+        assert_eq!(ProxyType::RwaOperator.encode(), vec![8]);
+        assert_eq!(ProxyType::CampaignManager.encode(), vec![9]);
+        assert_eq!(ProxyType::Investor.encode(), vec![10]);
+    }
+
+    #[test]
+    fn non_transfer_rejects_asset_and_nft_transfers() {
+        assert!(!ProxyType::NonTransfer.filter(&asset_transfer()));
+        assert!(!ProxyType::NonTransfer.filter(&nft_transfer()));
+        assert!(!ProxyType::NonTransfer.filter(&crowdfunding_call(
+            pallet_crowdfunding::Call::invest { campaign_id: 0, amount: DOLLARS }
+        )));
+        assert!(ProxyType::NonTransfer
+            .filter(&RuntimeCall::System(frame_system::Call::remark { remark: vec![] })));
+    }
+
+    #[test]
+    fn rwa_operator_filter() {
+        let allowed = [
+            rwa_call(pallet_rwa::Call::approve_participation {
+                rwa_asset_id: 0,
+                participation_id: 0,
+            }),
+            rwa_call(pallet_rwa::Call::reject_participation {
+                rwa_asset_id: 0,
+                participation_id: 0,
+            }),
+            rwa_call(pallet_rwa::Call::revoke_participation {
+                rwa_asset_id: 0,
+                participation_id: 0,
+            }),
+            rwa_call(pallet_rwa::Call::update_metadata { rwa_asset_id: 0, new_metadata: vec![] }),
+        ];
+        for call in &allowed {
+            assert!(ProxyType::RwaOperator.filter(call), "{:?}", call);
+        }
+
+        let denied = [
+            rwa_call(pallet_rwa::Call::request_participation { rwa_asset_id: 0, holders: vec![] }),
+            rwa_call(pallet_rwa::Call::transfer_ownership { rwa_asset_id: 0, new_owner: BOB }),
+            rwa_call(pallet_rwa::Call::update_beneficiary {
+                rwa_asset_id: 0,
+                new_beneficiary: BOB,
+            }),
+            crowdfunding_call(pallet_crowdfunding::Call::invest { campaign_id: 0, amount: 1 }),
+            asset_transfer(),
+        ];
+        for call in &denied {
+            assert!(!ProxyType::RwaOperator.filter(call), "{:?}", call);
+        }
+    }
+
+    #[test]
+    fn campaign_manager_filter() {
+        let allowed = [
+            crowdfunding_call(pallet_crowdfunding::Call::submit_milestone {
+                campaign_id: 0,
+                index: 0,
+            }),
+            crowdfunding_call(pallet_crowdfunding::Call::add_to_whitelist {
+                campaign_id: 0,
+                account: BOB,
+            }),
+            crowdfunding_call(pallet_crowdfunding::Call::remove_from_whitelist {
+                campaign_id: 0,
+                account: BOB,
+            }),
+        ];
+        for call in &allowed {
+            assert!(ProxyType::CampaignManager.filter(call), "{:?}", call);
+        }
+
+        let denied = [
+            crowdfunding_call(pallet_crowdfunding::Call::claim_funds { campaign_id: 0 }),
+            crowdfunding_call(pallet_crowdfunding::Call::cancel_campaign { campaign_id: 0 }),
+            crowdfunding_call(pallet_crowdfunding::Call::pause_campaign { campaign_id: 0 }),
+            crowdfunding_call(pallet_crowdfunding::Call::resume_campaign { campaign_id: 0 }),
+            crowdfunding_call(pallet_crowdfunding::Call::invest { campaign_id: 0, amount: 1 }),
+            rwa_call(pallet_rwa::Call::approve_participation {
+                rwa_asset_id: 0,
+                participation_id: 0,
+            }),
+        ];
+        for call in &denied {
+            assert!(!ProxyType::CampaignManager.filter(call), "{:?}", call);
+        }
+    }
+
+    #[test]
+    fn investor_filter() {
+        let allowed = [
+            crowdfunding_call(pallet_crowdfunding::Call::invest { campaign_id: 0, amount: 1 }),
+            crowdfunding_call(pallet_crowdfunding::Call::withdraw_investment {
+                campaign_id: 0,
+                amount: 1,
+            }),
+            crowdfunding_call(pallet_crowdfunding::Call::claim_refund { campaign_id: 0 }),
+        ];
+        for call in &allowed {
+            assert!(ProxyType::Investor.filter(call), "{:?}", call);
+        }
+
+        let denied = [
+            crowdfunding_call(pallet_crowdfunding::Call::claim_funds { campaign_id: 0 }),
+            crowdfunding_call(pallet_crowdfunding::Call::pause_campaign { campaign_id: 0 }),
+            crowdfunding_call(pallet_crowdfunding::Call::transfer_investment {
+                campaign_id: 0,
+                to: BOB,
+                amount: 1,
+            }),
+            RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+                dest: MultiAddress::Id(BOB),
+                value: 1,
+            }),
+        ];
+        for call in &denied {
+            assert!(!ProxyType::Investor.filter(call), "{:?}", call);
+        }
+    }
+
+    #[test]
+    fn superset_ordering() {
+        use ProxyType::*;
+        let all = [
+            Any,
+            NonTransfer,
+            Governance,
+            Staking,
+            IdentityJudgement,
+            CancelProxy,
+            RwaOperator,
+            CampaignManager,
+            Investor,
+        ];
+        for x in all {
+            assert!(Any.is_superset(&x));
+            assert!(x.is_superset(&x));
+        }
+        for x in [Governance, Staking, IdentityJudgement, CancelProxy] {
+            assert!(NonTransfer.is_superset(&x));
+        }
+        for x in [RwaOperator, CampaignManager, Investor] {
+            assert!(!NonTransfer.is_superset(&x));
+            assert!(!x.is_superset(&Any));
+            assert!(!x.is_superset(&NonTransfer));
+        }
+        assert!(!RwaOperator.is_superset(&CampaignManager));
+        assert!(!CampaignManager.is_superset(&Investor));
+        assert!(!Investor.is_superset(&RwaOperator));
+    }
+
+    #[test]
+    fn rwa_operator_proxy_dispatches_only_scoped_calls() {
+        new_test_ext().execute_with(|| {
+            let rwa_asset_id = pallet_rwa::NextRwaAssetId::<Runtime>::get();
+            assert_ok!(Rwa::register_asset(
+                RuntimeOrigin::signed(ALICE),
+                ALICE,
+                pallet_rwa::AssetPolicy {
+                    deposit_currency: pallet_rwa::PaymentCurrency::Native,
+                    entry_fee: 0,
+                    deposit: DOLLARS,
+                    max_duration: None,
+                    max_participants: None,
+                    requires_approval: true,
+                },
+                vec![],
+            ));
+            assert_ok!(Proxy::add_proxy(
+                RuntimeOrigin::signed(ALICE),
+                MultiAddress::Id(BOB),
+                ProxyType::RwaOperator,
+                0,
+            ));
+
+            assert_ok!(Proxy::proxy(
+                RuntimeOrigin::signed(BOB),
+                MultiAddress::Id(ALICE),
+                None,
+                Box::new(rwa_call(pallet_rwa::Call::update_metadata {
+                    rwa_asset_id,
+                    new_metadata: b"updated".to_vec(),
+                })),
+            ));
+            System::assert_last_event(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }.into());
+            assert_eq!(
+                pallet_rwa::RwaAssets::<Runtime>::get(rwa_asset_id).unwrap().metadata.to_vec(),
+                b"updated".to_vec()
+            );
+
+            let alice_free = Balances::free_balance(ALICE);
+            assert_ok!(Proxy::proxy(
+                RuntimeOrigin::signed(BOB),
+                MultiAddress::Id(ALICE),
+                None,
+                Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+                    dest: MultiAddress::Id(BOB),
+                    value: DOLLARS,
+                })),
+            ));
+            System::assert_last_event(
+                pallet_proxy::Event::ProxyExecuted {
+                    result: Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+                }
+                .into(),
+            );
+            assert_eq!(Balances::free_balance(ALICE), alice_free);
+        });
+    }
 }
 
 impl Default for ProxyType {
@@ -599,10 +885,21 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::Utility(..)
                     | RuntimeCall::Proxy(..)
                     | RuntimeCall::Multisig(..)
-                    | RuntimeCall::Assets(..)
-                    | RuntimeCall::Nfts(..)
+                    | RuntimeCall::Preimage(..)
+                    | RuntimeCall::Council(..)
+                    | RuntimeCall::TechnicalCommittee(..)
+                    | RuntimeCall::ConvictionVoting(..)
+                    | RuntimeCall::Referenda(..)
+            ),
+            ProxyType::Governance => matches!(
+                c,
+                RuntimeCall::Utility(..)
+                    | RuntimeCall::Preimage(..)
+                    | RuntimeCall::Council(..)
+                    | RuntimeCall::TechnicalCommittee(..)
+                    | RuntimeCall::ConvictionVoting(..)
+                    | RuntimeCall::Referenda(..)
             ),
-            ProxyType::Governance => matches!(c, RuntimeCall::Utility(..)),
             ProxyType::Staking => {
                 matches!(c, RuntimeCall::Session(..) | RuntimeCall::Utility(..))
             }
@@ -610,6 +907,39 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             ProxyType::CancelProxy => {
                 matches!(c, RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }))
             }
+            ProxyType::RwaOperator => matches!(
+                c,
+                RuntimeCall::Rwa(
+                    pallet_rwa::Call::approve_participation { .. }
+                        | pallet_rwa::Call::reject_participation { .. }
+                        | pallet_rwa::Call::batch_reject_pending { .. }
+                        | pallet_rwa::Call::slash_participation { .. }
+                        | pallet_rwa::Call::revoke_participation { .. }
+                        | pallet_rwa::Call::update_metadata { .. }
+                ) | RuntimeCall::Utility(..)
+            ),
+            // Pausing and resuming require `PauseOrigin`, which a signed
+            // proxy never is, so they are left out.
+            ProxyType::CampaignManager => matches!(
+                c,
+                RuntimeCall::Crowdfunding(
+                    pallet_crowdfunding::Call::submit_milestone { .. }
+                        | pallet_crowdfunding::Call::add_to_whitelist { .. }
+                        | pallet_crowdfunding::Call::remove_from_whitelist { .. }
+                        | pallet_crowdfunding::Call::set_whitelist_root { .. }
+                ) | RuntimeCall::Utility(..)
+            ),
+            ProxyType::Investor => matches!(
+                c,
+                RuntimeCall::Crowdfunding(
+                    pallet_crowdfunding::Call::invest { .. }
+                        | pallet_crowdfunding::Call::invest_with_tier { .. }
+                        | pallet_crowdfunding::Call::invest_with_proof { .. }
+                        | pallet_crowdfunding::Call::invest_in_currency { .. }
+                        | pallet_crowdfunding::Call::withdraw_investment { .. }
+                        | pallet_crowdfunding::Call::claim_refund { .. }
+                ) | RuntimeCall::Utility(..)
+            ),
             // ProxyType::Auction => matches!(
             // 	c,
             // 	RuntimeCall::Auctions(..) |
@@ -625,7 +955,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             (x, y) if x == y => true,
             (ProxyType::Any, _) => true,
             (_, ProxyType::Any) => false,
-            (ProxyType::NonTransfer, _) => true,
+            // RWA and campaign calls move deposits and investments, so the
+            // types scoped to them are not covered by `NonTransfer`.
+            (
+                ProxyType::NonTransfer,
+                ProxyType::Governance
+                | ProxyType::Staking
+                | ProxyType::IdentityJudgement
+                | ProxyType::CancelProxy,
+            ) => true,
             _ => false,
         }
     }
@@ -646,6 +984,29 @@ impl pallet_proxy::Config for Runtime {
     type WeightInfo = weights::pallet_proxy::WeightInfo<Runtime>;
 }
 
+// ── Offchain transactions ───────────────────────────────────────────────
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+parameter_types! {
+    /// Priority of the unsigned settlement and finalization transactions
+    /// submitted by the pallet-rwa and pallet-crowdfunding offchain workers.
+    pub const OffchainUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    /// Blocks between two submissions of the same offchain worker.
+    pub const OffchainUnsignedInterval: BlockNumber = 10;
+}
+
 // ── pallet-rwa ──────────────────────────────────────────────────────────
 
 parameter_types! {
@@ -660,35 +1021,30 @@ parameter_types! {
     pub const MaxParticipationsPerHolder: u32 = 50;
     /// V5: minimum deposit required for participation policies (1 DOLLAR).
     pub const MinParticipationDeposit: Balance = 1 * DOLLARS;
+    /// Items a stepped migration of pallet-rwa or pallet-crowdfunding
+    /// processes per block.
+    pub const MigrationItemsPerBlock: u32 = 100;
+    /// Window of the per-account call limit of RWA and campaign fee
+    /// sponsorships.
+    pub const SponsorshipPeriod: BlockNumber = DAYS;
+    pub const MaxSettlementsPerTx: u32 = 50;
 }
 
 /// CRIT-03: Lifecycle guard that prevents retiring an RWA asset or slashing
 /// a participation while non-terminal crowdfunding campaigns are linked to it.
+/// Retirement is also blocked while asset-backed offerings have shares of
+/// the asset outstanding.
 pub struct CrowdfundingLifecycleGuard;
 
-impl CrowdfundingLifecycleGuard {
-    /// Returns `true` if the campaign status is non-terminal, meaning the
-    /// campaign is still active and should block destructive RWA operations.
-    fn is_non_terminal(status: pallet_crowdfunding::CampaignStatus) -> bool {
-        matches!(
-            status,
-            pallet_crowdfunding::CampaignStatus::Funding
-                | pallet_crowdfunding::CampaignStatus::Paused
-                | pallet_crowdfunding::CampaignStatus::Succeeded
-                | pallet_crowdfunding::CampaignStatus::MilestonePhase
-        )
-    }
-}
-
 impl pallet_rwa::AssetLifecycleGuard<AccountId> for CrowdfundingLifecycleGuard {
     fn can_retire_asset(rwa_asset_id: u32) -> frame_support::dispatch::DispatchResult {
-        // Iterate all campaigns to check if any non-terminal campaign is
-        // linked to this RWA asset.
-        for (_id, campaign) in pallet_crowdfunding::Campaigns::<Runtime>::iter() {
-            if campaign.rwa_asset_id == Some(rwa_asset_id) && Self::is_non_terminal(campaign.status)
-            {
-                return Err(sp_runtime::DispatchError::Other("ActiveCampaignLinkedToAsset"));
-            }
+        // Shares sold by asset-backed offerings, issued or still promised,
+        // are obligations the asset must outlive.
+        if pallet_crowdfunding::Pallet::<Runtime>::has_asset_obligations(rwa_asset_id) {
+            return Err(sp_runtime::DispatchError::Other("AssetSharesOutstanding"));
+        }
+        if pallet_crowdfunding::Pallet::<Runtime>::has_licensed_campaigns(rwa_asset_id) {
+            return Err(sp_runtime::DispatchError::Other("ActiveCampaignLinkedToAsset"));
         }
         Ok(())
     }
@@ -697,41 +1053,55 @@ impl pallet_rwa::AssetLifecycleGuard<AccountId> for CrowdfundingLifecycleGuard {
         rwa_asset_id: u32,
         participation_id: u32,
     ) -> frame_support::dispatch::DispatchResult {
-        // Iterate all campaigns to check if any non-terminal campaign is
-        // linked to this specific RWA asset + participation pair.
-        for (_id, campaign) in pallet_crowdfunding::Campaigns::<Runtime>::iter() {
-            if campaign.rwa_asset_id == Some(rwa_asset_id)
-                && campaign.participation_id == Some(participation_id)
-                && Self::is_non_terminal(campaign.status)
-            {
-                return Err(sp_runtime::DispatchError::Other(
-                    "ActiveCampaignLinkedToParticipation",
-                ));
-            }
+        if pallet_crowdfunding::Pallet::<Runtime>::has_campaigns_under_license(
+            rwa_asset_id,
+            participation_id,
+        ) {
+            return Err(sp_runtime::DispatchError::Other("ActiveCampaignLinkedToParticipation"));
         }
         Ok(())
     }
+
+    /// `has_asset_obligations` and `has_licensed_campaigns`.
+    fn can_retire_asset_weight() -> Weight {
+        pallet_crowdfunding::Pallet::<Runtime>::asset_obligations_weight().saturating_add(
+            pallet_crowdfunding::Pallet::<Runtime>::licensed_campaigns_lookup_weight(),
+        )
+    }
+
+    /// `has_campaigns_under_license`.
+    fn can_slash_participation_weight() -> Weight {
+        pallet_crowdfunding::Pallet::<Runtime>::licensed_campaigns_lookup_weight()
+    }
 }
 
 impl pallet_rwa::Config for Runtime {
-    type AdminOrigin = EnsureRoot<AccountId>;
+    type AdminOrigin = governance::AdminOrigin;
     type AssetId = u32;
     type AssetLifecycleGuard = CrowdfundingLifecycleGuard;
     type AssetRegistrationDeposit = AssetRegistrationDeposit;
-    type ForceOrigin = EnsureRoot<AccountId>;
+    type AuthorityId = pallet_rwa::crypto::SettlementAuthId;
+    type ForceOrigin = governance::ForceOrigin;
     type Fungibles = Assets;
     type MaxAssetsPerOwner = MaxAssetsPerOwner;
     type MaxGroupSize = MaxGroupSize;
     type MaxMetadataLen = RwaMaxMetadataLen;
     type MaxParticipationsPerHolder = MaxParticipationsPerHolder;
     type MaxPendingApprovals = MaxPendingApprovals;
+    type MaxSettlementsPerTx = MaxSettlementsPerTx;
     type MaxSlashRecipients = MaxSlashRecipients;
     type MaxSunsettingPerBlock = MaxSunsettingPerBlock;
+    type MigrationItemsPerBlock = MigrationItemsPerBlock;
     type MinParticipationDeposit = MinParticipationDeposit;
     type NativeCurrency = Balances;
     type PalletId = RwaPalletId;
     type ParticipationFilter = ();
+    type PauseOrigin = governance::PauseOrigin;
     type RuntimeEvent = RuntimeEvent;
+    type SponsorshipPeriod = SponsorshipPeriod;
+    type SteppedMigration = pallet_rwa::migrations::v6::MigrateToV6<Runtime>;
+    type UnsignedInterval = OffchainUnsignedInterval;
+    type UnsignedPriority = OffchainUnsignedPriority;
     type WeightInfo = pallet_rwa::weights::SubstrateWeight<Runtime>;
 }
 
@@ -746,10 +1116,28 @@ parameter_types! {
     pub const EarlyWithdrawalPenaltyBps: u16 = 100; // 1%
     pub const CfMaxMilestones: u32 = 10;
     pub const MaxEligibilityRules: u32 = 5;
+    pub const MaxEligibilityNodes: u32 = 15;
+    pub const MaxSnapshotAssets: u32 = 10;
+    pub const MaxRegistrations: u32 = 5_000;
     pub const MaxNftSets: u32 = 5;
     pub const MaxNftsPerSet: u32 = 5;
     pub const MaxInvestmentsPerInvestor: u32 = 50;
     pub const MaxWhitelistSize: u32 = 500;
+    /// Enough for a whitelist tree of about a million accounts.
+    pub const MaxWhitelistProofDepth: u32 = 20;
+    pub const MaxRewardTiers: u32 = 10;
+    pub const MaxDeadlinesPerBlock: u32 = 50;
+    pub const MaxAutoFinalizationsPerBlock: u32 = 8;
+    pub const MaxRefundsPerCall: u32 = 50;
+    pub const MaxIdleRefunds: u32 = 20;
+    pub const MaxIdleCleanups: u32 = 500;
+    pub const ReapDeposit: Balance = DOLLARS;
+    pub const MaxReapsPerCall: u32 = 50;
+    pub const MaxAcceptedCurrencies: u32 = 4;
+    pub const MaxStretchGoals: u32 = 5;
+    pub const MaxFundingRounds: u32 = 5;
+    pub const MaxPenaltySteps: u32 = 5;
+    pub const MaxFinalizationsPerTx: u32 = 8;
     // TODO(BEFORE-MAINNET): Replace with actual treasury / multisig account.
     // The all-zeros address is an uncontrollable burn address — 2 % of all
     // campaign proceeds are irrecoverably lost until this is updated via
@@ -825,34 +1213,183 @@ impl pallet_crowdfunding::LicenseVerifier<AccountId, BlockNumber> for RwaLicense
             }
         })
     }
+
+    fn has_active_participation(rwa_asset_id: u32, who: &AccountId) -> bool {
+        pallet_rwa::HolderIndex::<Runtime>::get(rwa_asset_id, who)
+            .map_or(false, |pid| Self::is_license_active(rwa_asset_id, pid))
+    }
+}
+
+/// Values asset-backed offerings from `pallet_rwa` asset valuations.
+pub struct RwaValuationProvider;
+
+impl pallet_crowdfunding::ValuationProvider<u32, Balance> for RwaValuationProvider {
+    fn asset_valuation(
+        rwa_asset_id: u32,
+    ) -> Option<(pallet_crowdfunding::PaymentCurrency<u32>, Balance)> {
+        let valuation = pallet_rwa::AssetValuations::<Runtime>::get(rwa_asset_id)?;
+        let currency = match valuation.currency {
+            pallet_rwa::PaymentCurrency::Native => pallet_crowdfunding::PaymentCurrency::Native,
+            pallet_rwa::PaymentCurrency::Asset(id) => {
+                pallet_crowdfunding::PaymentCurrency::Asset(id)
+            }
+        };
+        Some((currency, valuation.amount))
+    }
+}
+
+/// Mints reward-tier NFTs through `pallet_nfts`, using the crowdfunding
+/// pallet's per-collection serial as the item ID.
+pub struct NftRewardMinter;
+
+impl pallet_crowdfunding::RewardMinter<AccountId, u32> for NftRewardMinter {
+    fn mint_reward(
+        collection: &u32,
+        serial: u32,
+        who: &AccountId,
+    ) -> frame_support::dispatch::DispatchResult {
+        <Nfts as frame_support::traits::tokens::nonfungibles_v2::Mutate<
+            AccountId,
+            pallet_nfts::ItemConfig,
+        >>::mint_into(collection, &serial, who, &pallet_nfts::ItemConfig::default(), true)
+    }
+
+    fn is_minted(collection: &u32, serial: u32) -> bool {
+        <Nfts as Inspect<AccountId>>::owner(collection, &serial).is_some()
+    }
+}
+
+/// Sets up NFT collections, assets, prices and valuations for the
+/// crowdfunding benchmarks.  Also stands in for the price feed while
+/// benchmarking, converting at par.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CrowdfundingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_crowdfunding::BenchmarkHelper<AccountId, u32, u32, Balance>
+    for CrowdfundingBenchmarkHelper
+{
+    fn reward_collection(owner: &AccountId) -> u32 {
+        <Nfts as frame_support::traits::tokens::nonfungibles_v2::Create<
+            AccountId,
+            pallet_nfts::CollectionConfigFor<Runtime>,
+        >>::create_collection(
+            owner,
+            owner,
+            &pallet_nfts::CollectionConfig {
+                settings: pallet_nfts::CollectionSettings::all_enabled(),
+                max_supply: None,
+                mint_settings: Default::default(),
+            },
+        )
+        .expect("owner is funded")
+    }
+
+    fn payment_asset() -> u32 {
+        // Local asset ids have the foreign asset flag cleared.
+        let id = 1;
+        <Assets as frame_support::traits::tokens::fungibles::Create<AccountId>>::create(
+            id,
+            ProtocolFeeRecipientAccount::get(),
+            true,
+            1,
+        )
+        .expect("asset id is free");
+        id
+    }
+
+    fn set_valuation(
+        rwa_asset_id: u32,
+        currency: pallet_crowdfunding::PaymentCurrency<u32>,
+        amount: Balance,
+    ) {
+        let currency = match currency {
+            pallet_crowdfunding::PaymentCurrency::Native => pallet_rwa::PaymentCurrency::Native,
+            pallet_crowdfunding::PaymentCurrency::Asset(id) => {
+                pallet_rwa::PaymentCurrency::Asset(id)
+            }
+        };
+        pallet_rwa::AssetValuations::<Runtime>::insert(
+            rwa_asset_id,
+            pallet_rwa::AssetValuation { currency, amount, updated_at: System::block_number() },
+        );
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_crowdfunding::PriceFeed<u32, Balance> for CrowdfundingBenchmarkHelper {
+    fn convert(
+        _: &pallet_crowdfunding::PaymentCurrency<u32>,
+        _: &pallet_crowdfunding::PaymentCurrency<u32>,
+        amount: Balance,
+    ) -> Option<Balance> {
+        Some(amount)
+    }
 }
 
 impl pallet_crowdfunding::Config for Runtime {
-    type AdminOrigin = EnsureRoot<AccountId>;
+    type AdminOrigin = governance::AdminOrigin;
     type AssetId = u32;
+    type AuthorityId = pallet_crowdfunding::crypto::FinalizationAuthId;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CrowdfundingBenchmarkHelper;
     type CampaignCreationDeposit = CampaignCreationDeposit;
     type CollectionId = u32;
     type EarlyWithdrawalPenaltyBps = EarlyWithdrawalPenaltyBps;
-    type ForceOrigin = EnsureRoot<AccountId>;
+    // TODO(BEFORE-MAINNET): no attestation or identity source is deployed
+    // yet, so `Attestation` and `MinAccountAge` rules never pass.
+    type EligibilityProvider = ();
+    type ForceOrigin = governance::ForceOrigin;
     type Fungibles = Assets;
     type ItemId = u32;
     type LicenseVerifier = RwaLicenseVerifier;
+    type MaxAcceptedCurrencies = MaxAcceptedCurrencies;
+    type MaxAutoFinalizationsPerBlock = MaxAutoFinalizationsPerBlock;
     type MaxCampaignDuration = MaxCampaignDuration;
     type MaxCampaignsPerCreator = MaxCampaignsPerCreator;
+    type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+    type MaxEligibilityNodes = MaxEligibilityNodes;
     type MaxEligibilityRules = MaxEligibilityRules;
+    type MaxFinalizationsPerTx = MaxFinalizationsPerTx;
+    type MaxFundingRounds = MaxFundingRounds;
+    type MaxIdleCleanups = MaxIdleCleanups;
+    type MaxIdleRefunds = MaxIdleRefunds;
     type MaxInvestmentsPerInvestor = MaxInvestmentsPerInvestor;
     type MaxMilestones = CfMaxMilestones;
     type MaxNftSets = MaxNftSets;
     type MaxNftsPerSet = MaxNftsPerSet;
+    type MaxPenaltySteps = MaxPenaltySteps;
+    type MaxReapsPerCall = MaxReapsPerCall;
+    type MaxRefundsPerCall = MaxRefundsPerCall;
+    type MaxRegistrations = MaxRegistrations;
+    type MaxRewardTiers = MaxRewardTiers;
+    type MaxSnapshotAssets = MaxSnapshotAssets;
+    type MaxStretchGoals = MaxStretchGoals;
+    type MaxWhitelistProofDepth = MaxWhitelistProofDepth;
     type MaxWhitelistSize = MaxWhitelistSize;
-    type MilestoneApprover = EnsureRoot<AccountId>;
+    type MigrationItemsPerBlock = MigrationItemsPerBlock;
+    type MilestoneApprover = governance::MilestoneApprover;
     type MinCampaignDuration = MinCampaignDuration;
     type NativeCurrency = Balances;
     type NftInspect = Nfts;
     type PalletId = CrowdfundingPalletId;
+    type PauseOrigin = governance::PauseOrigin;
+    // TODO(BEFORE-MAINNET): no price oracle is deployed yet, so
+    // `invest_in_currency` fails with `PriceUnavailable`.
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    type PriceFeed = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type PriceFeed = CrowdfundingBenchmarkHelper;
     type ProtocolFeeBps = ConstU16<200>;
     type ProtocolFeeRecipient = ProtocolFeeRecipientAccount;
+    type ReapDeposit = ReapDeposit;
+    type RewardMinter = NftRewardMinter;
     type RuntimeEvent = RuntimeEvent;
+    type SponsorshipPeriod = SponsorshipPeriod;
+    type SteppedMigration = pallet_crowdfunding::migrations::v6::MigrateToV6<Runtime>;
+    type UnsignedInterval = OffchainUnsignedInterval;
+    type UnsignedPriority = OffchainUnsignedPriority;
+    type ValuationProvider = RwaValuationProvider;
     type WeightInfo = pallet_crowdfunding::weights::SubstrateWeight<Runtime>;
 }
 
@@ -896,11 +1433,21 @@ construct_runtime!(
         PolkadotXcm: pallet_xcm = 31,
         CumulusXcm: cumulus_pallet_xcm = 32,
         DmpQueue: cumulus_pallet_dmp_queue = 33,
+        AssetRegistry: pallet_asset_registry = 34,
 
         // Custom pallets.
         Rwa: pallet_rwa = 40,
         Crowdfunding: pallet_crowdfunding = 41,
 
+        // Governance.
+        Scheduler: pallet_scheduler = 50,
+        Preimage: pallet_preimage = 51,
+        Council: pallet_collective::<Instance1> = 52,
+        TechnicalCommittee: pallet_collective::<Instance2> = 53,
+        ConvictionVoting: pallet_conviction_voting = 54,
+        Referenda: pallet_referenda = 55,
+        Origins: pallet_custom_origins::{Origin} = 56,
+
         Sudo: pallet_sudo = 255,
     }
 );
@@ -919,8 +1466,14 @@ mod benches {
         [pallet_timestamp, Timestamp]
         [pallet_collator_selection, CollatorSelection]
         [cumulus_pallet_xcmp_queue, XcmpQueue]
+        [pallet_asset_registry, AssetRegistry]
         [pallet_rwa, Rwa]
         [pallet_crowdfunding, Crowdfunding]
+        [pallet_collective, Council]
+        [pallet_conviction_voting, ConvictionVoting]
+        [pallet_preimage, Preimage]
+        [pallet_referenda, Referenda]
+        [pallet_scheduler, Scheduler]
     );
 }
 
@@ -1054,7 +1607,8 @@ impl_runtime_apis! {
         }
     }
 
-    impl assets_api::AssetsApi<
+    #[api_version(2)]
+    impl assets_runtime_api::AssetsApi<
         Block,
         AccountId,
         Balance,
@@ -1064,8 +1618,36 @@ impl_runtime_apis! {
         fn account_balances(account: AccountId) -> Vec<(u32, Balance)> {
             Assets::account_balances(account)
         }
+
+        fn account_balances_paged(
+            account: AccountId,
+            start_after: Option<u32>,
+            limit: u32,
+        ) -> Vec<assets_runtime_api::AssetBalanceInfo<u32, Balance>> {
+            use frame_support::traits::tokens::fungibles::{
+                metadata::Inspect as MetadataInspect, Inspect as FungiblesInspect,
+            };
+
+            impls::account_balances_from(&account, start_after)
+                .take(limit.min(assets_runtime_api::MAX_PAGE_SIZE) as usize)
+                .map(|(asset_id, balance)| {
+                    let withdrawable =
+                        <Assets as FungiblesInspect<AccountId>>::reducible_balance(
+                            asset_id, &account, false,
+                        );
+                    assets_runtime_api::AssetBalanceInfo {
+                        asset_id,
+                        symbol: <Assets as MetadataInspect<AccountId>>::symbol(asset_id),
+                        decimals: <Assets as MetadataInspect<AccountId>>::decimals(asset_id),
+                        balance,
+                        frozen: balance.saturating_sub(withdrawable),
+                    }
+                })
+                .collect()
+        }
     }
 
+    #[api_version(2)]
     impl pallet_rwa_runtime_api::RwaApi<Block, AccountId, Balance, BlockNumber, u32> for Runtime {
         fn effective_participation_status(
             asset_id: u32,
@@ -1132,46 +1714,28 @@ impl_runtime_apis! {
                 })
                 .count() as u32
         }
+
+        fn participation_updates() -> Vec<(u32, u32)> {
+            let mut touched: Vec<(u32, u32)> = System::read_events_no_consensus()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::Rwa(event) => event.participation(),
+                    _ => None,
+                })
+                .collect();
+            touched.sort_unstable();
+            touched.dedup();
+            touched
+        }
     }
 
+    #[api_version(2)]
     impl pallet_crowdfunding_runtime_api::CrowdfundingApi<Block, AccountId, Balance, BlockNumber, u32> for Runtime {
         fn check_eligibility(
             campaign_id: u32,
             who: AccountId,
-        ) -> Result<(), pallet_crowdfunding::EligibilityError> {
-            let campaign = pallet_crowdfunding::Campaigns::<Runtime>::get(campaign_id)
-                .ok_or(pallet_crowdfunding::EligibilityError::CampaignNotFound)?;
-            for rule in campaign.eligibility_rules.iter() {
-                match rule {
-                    pallet_crowdfunding::EligibilityRule::NativeBalance { min_balance } => {
-                        if Balances::free_balance(&who) < *min_balance {
-                            return Err(pallet_crowdfunding::EligibilityError::InsufficientNativeBalance);
-                        }
-                    },
-                    pallet_crowdfunding::EligibilityRule::AssetBalance { asset_id, min_balance } => {
-                        if Assets::balance(*asset_id, &who) < *min_balance {
-                            return Err(pallet_crowdfunding::EligibilityError::InsufficientAssetBalance);
-                        }
-                    },
-                    pallet_crowdfunding::EligibilityRule::NftOwnership { required_sets } => {
-                        let ok = required_sets.iter().any(|set| {
-                            set.iter().all(|(cid, iid)| {
-                                <Nfts as Inspect<AccountId>>::owner(cid, iid)
-                                    .map_or(false, |o| o == who)
-                            })
-                        });
-                        if !ok {
-                            return Err(pallet_crowdfunding::EligibilityError::NftOwnershipNotMet);
-                        }
-                    },
-                    pallet_crowdfunding::EligibilityRule::AccountWhitelist => {
-                        if !pallet_crowdfunding::CampaignWhitelist::<Runtime>::get(campaign_id, &who) {
-                            return Err(pallet_crowdfunding::EligibilityError::NotWhitelisted);
-                        }
-                    },
-                }
-            }
-            Ok(())
+            proof: Option<pallet_crowdfunding::WhitelistProof<Balance>>,
+        ) -> Result<(), pallet_crowdfunding::EligibilityFailure> {
+            Crowdfunding::check_eligibility_with_proof(campaign_id, &who, proof.as_ref())
         }
 
         fn preview_withdrawal(
@@ -1183,10 +1747,14 @@ impl_runtime_apis! {
             let inv = pallet_crowdfunding::Investments::<Runtime>::get(campaign_id, &investor)?;
             let current = inv.total_invested.saturating_sub(inv.total_withdrawn);
             if amount > current { return None; }
-            let penalty_bps = campaign.config.early_withdrawal_penalty_bps
-                .unwrap_or_else(|| EarlyWithdrawalPenaltyBps::get());
-            // CAT-4.3-C-B: use Crowdfunding::bps_of() which uses ceiling division
-            let penalty = Crowdfunding::bps_of(amount, penalty_bps);
+            // Rounded up per currency part, exactly as withdraw_investment charges it
+            let (penalty, penalty_bps) = Crowdfunding::withdrawal_penalty(
+                campaign_id,
+                &campaign,
+                &investor,
+                amount,
+                System::block_number(),
+            );
             let net = amount.saturating_sub(penalty);
             Some(pallet_crowdfunding::WithdrawalPreview {
                 gross_amount: amount,
@@ -1223,6 +1791,18 @@ impl_runtime_apis! {
                 }
             });
 
+            let active_round = matches!(c.status, pallet_crowdfunding::CampaignStatus::Funding)
+                .then(|| pallet_crowdfunding::Pallet::<Runtime>::active_funding_round(campaign_id, now))
+                .flatten()
+                .map(|(index, _)| index);
+            let stretch_goals = pallet_crowdfunding::StretchGoals::<Runtime>::get(campaign_id)
+                .unwrap_or_default();
+            let stretch_goals_reached =
+                pallet_crowdfunding::StretchGoalsReached::<Runtime>::get(campaign_id);
+            let next_stretch_goal = stretch_goals
+                .get(stretch_goals_reached as usize)
+                .map(|g| g.threshold);
+
             Some(pallet_crowdfunding::CampaignSummary {
                 status: c.status,
                 total_raised: c.total_raised,
@@ -1235,9 +1815,17 @@ impl_runtime_apis! {
                 milestones_total,
                 rwa_asset_id: c.rwa_asset_id,
                 participation_id: c.participation_id,
+                active_round,
+                stretch_goals_reached,
+                stretch_goals_total: stretch_goals.len() as u32,
+                next_stretch_goal,
             })
         }
 
+        fn vesting_status(campaign_id: u32) -> Option<pallet_crowdfunding::VestingStatus<Balance, BlockNumber>> {
+            Crowdfunding::vesting_status(campaign_id)
+        }
+
         fn campaigns_by_creator(creator: AccountId) -> Vec<u32> {
             pallet_crowdfunding::CreatorCampaigns::<Runtime>::get(&creator).into_inner()
         }
@@ -1256,6 +1844,18 @@ impl_runtime_apis! {
                 Crowdfunding::effective_protocol_fee_recipient(),
             )
         }
+
+        fn campaign_updates() -> Vec<u32> {
+            let mut touched: Vec<u32> = System::read_events_no_consensus()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::Crowdfunding(event) => event.campaign_id(),
+                    _ => None,
+                })
+                .collect();
+            touched.sort_unstable();
+            touched.dedup();
+            touched
+        }
     }
 
     impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
@@ -1,26 +1,37 @@
-use core::{marker::PhantomData, ops::ControlFlow};
+use core::{borrow::Borrow, marker::PhantomData, ops::ControlFlow};
 
+use codec::Compact;
+use cumulus_primitives_utility::XcmFeesTo32ByteAccount;
 use frame_support::{
     log, match_types, parameter_types,
-    traits::{ConstU32, Everything, Nothing},
+    traits::{
+        ConstU32, Contains, ContainsPair, EnsureOrigin, EnsureOriginWithArg, Everything, Get,
+        Nothing,
+    },
     weights::Weight,
 };
+use frame_system::EnsureSigned;
+use pallet_asset_registry::{AssetIdForLocation, RegistryTrader};
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
 use xcm::{latest::prelude::*, CreateMatcher, MatchXcm};
 use xcm_builder::{
-    AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
-    CurrencyAdapter, EnsureXcmOrigin, FixedWeightBounds, IsConcrete, NativeAsset, ParentIsPreset,
+    Account32Hash, AccountId32Aliases, AllowExplicitUnpaidExecutionFrom,
+    AllowTopLevelPaidExecutionFrom, ConvertedConcreteId, CurrencyAdapter, EnsureXcmOrigin,
+    FixedWeightBounds, FungiblesAdapter, IsConcrete, NativeAsset, NoChecking, ParentIsPreset,
     RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
     SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
     UsingComponents, WithComputedOrigin,
 };
-use xcm_executor::{traits::ShouldExecute, XcmExecutor};
+use xcm_executor::{
+    traits::{Convert, JustTry, ShouldExecute},
+    XcmExecutor,
+};
 
 use crate::{
-    AccountId, AllPalletsWithSystem, Balances, ParachainInfo, ParachainSystem, PolkadotXcm,
-    Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
+    AccountId, AllPalletsWithSystem, Assets, Balance, Balances, ParachainInfo, ParachainSystem,
+    PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
 };
 
 parameter_types! {
@@ -28,6 +39,26 @@ parameter_types! {
     pub const RelayNetwork: Option<NetworkId> = None;
     pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
     pub UniversalLocation: InteriorMultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
+    pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+}
+
+/// Converts the location of an account on a sibling parachain,
+/// `../Parachain(id)/AccountId32`, into a local account by hashing the
+/// location, so users of other chains get an account here that only their
+/// own XCM can control.
+pub struct SiblingAccountId32Hash;
+
+impl Convert<MultiLocation, AccountId> for SiblingAccountId32Hash {
+    fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<AccountId, ()> {
+        match location.borrow() {
+            MultiLocation { parents: 1, interior: X2(Parachain(_), AccountId32 { .. }) } => {
+                Account32Hash::<RelayNetwork, AccountId>::convert_ref(location)
+            }
+            _ => Err(()),
+        }
+    }
+
+    fn reverse_ref(_: impl Borrow<AccountId>) -> Result<MultiLocation, ()> { Err(()) }
 }
 
 /// Type for specifying how a `MultiLocation` can be converted into an
@@ -41,6 +72,8 @@ pub type LocationToAccountId = (
     SiblingParachainConvertsVia<Sibling, AccountId>,
     // Straight up local `AccountId32` origins just alias directly to `AccountId`.
     AccountId32Aliases<RelayNetwork, AccountId>,
+    // Accounts on sibling parachains convert to a hash of their location.
+    SiblingAccountId32Hash,
 );
 
 /// Means for transacting assets on this chain.
@@ -57,6 +90,86 @@ pub type LocalAssetTransactor = CurrencyAdapter<
     (),
 >;
 
+/// Asset ids with this bit set are reserved for assets created by governance
+/// for the asset registry, so signed accounts cannot squat on them.
+pub const FOREIGN_ASSET_ID_FLAG: u32 = 1 << 31;
+
+/// Ids under which the asset registry creates assets.
+pub struct ForeignAssetIds;
+
+impl Contains<u32> for ForeignAssetIds {
+    fn contains(id: &u32) -> bool { id & FOREIGN_ASSET_ID_FLAG != 0 }
+}
+
+/// Matches fungibles registered in `AssetRegistry` to their `pallet_assets`
+/// id.
+pub type RegisteredAssetsConvertedConcreteId =
+    ConvertedConcreteId<u32, Balance, AssetIdForLocation<Runtime>, JustTry>;
+
+/// Means for transacting registered fungibles held in `pallet_assets`.
+pub type RegisteredAssetsTransactor = FungiblesAdapter<
+    Assets,
+    RegisteredAssetsConvertedConcreteId,
+    LocationToAccountId,
+    AccountId,
+    // Reserve-backed assets are minted and burnt; nothing is teleported.
+    NoChecking,
+    CheckingAccount,
+>;
+
+/// Means for transacting assets on this chain: the native currency, then
+/// registered fungibles.
+pub type AssetTransactors = (LocalAssetTransactor, RegisteredAssetsTransactor);
+
+/// Accepts a sibling parachain's own fungibles as reserve deposits from that
+/// sibling.
+pub struct SiblingReserveAssets;
+
+impl ContainsPair<MultiAsset, MultiLocation> for SiblingReserveAssets {
+    fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+        let para_id = match origin {
+            MultiLocation { parents: 1, interior: X1(Parachain(id)) } => *id,
+            _ => return false,
+        };
+        matches!(
+            &asset.id,
+            Concrete(MultiLocation { parents: 1, interior })
+                if interior.first() == Some(&Parachain(para_id))
+        )
+    }
+}
+
+/// `CreateOrigin` of `pallet_assets`: any signed account may create an asset,
+/// except in the id range reserved for registry assets.
+pub struct SignedForLocalAssetIds;
+
+impl EnsureOriginWithArg<RuntimeOrigin, Compact<u32>> for SignedForLocalAssetIds {
+    type Success = AccountId;
+
+    fn try_origin(o: RuntimeOrigin, id: &Compact<u32>) -> Result<AccountId, RuntimeOrigin> {
+        if id.0 & FOREIGN_ASSET_ID_FLAG != 0 {
+            return Err(o);
+        }
+        <EnsureSigned<AccountId> as EnsureOrigin<RuntimeOrigin>>::try_origin(o)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(id: &Compact<u32>) -> Result<RuntimeOrigin, ()> {
+        if id.0 & FOREIGN_ASSET_ID_FLAG != 0 {
+            return Err(());
+        }
+        <EnsureSigned<AccountId> as EnsureOrigin<RuntimeOrigin>>::try_successful_origin()
+    }
+}
+
+/// The current block author, who receives execution fees paid in registered
+/// fungibles.
+pub struct BlockAuthor;
+
+impl Get<Option<AccountId>> for BlockAuthor {
+    fn get() -> Option<AccountId> { pallet_authorship::Pallet::<Runtime>::author() }
+}
+
 /// This is the type we use to convert an (incoming) XCM origin into a local
 /// `Origin` instance, ready for dispatching a transaction with Xcm's
 /// `Transact`. There is an `OriginKind` which can biases the kind of local
@@ -86,6 +199,21 @@ parameter_types! {
     pub const MaxAssetsIntoHolding: u32 = 64;
 }
 
+/// Calls an XCM `Transact` may dispatch: the entry points for users on
+/// sibling chains to invest in campaigns and request asset participation.
+/// The base call filter still applies.
+pub struct SafeCallFilter;
+
+impl Contains<RuntimeCall> for SafeCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::Crowdfunding(pallet_crowdfunding::Call::invest { .. })
+                | RuntimeCall::Rwa(pallet_rwa::Call::request_participation { .. })
+        )
+    }
+}
+
 match_types! {
     pub type ParentOrParentsExecutivePlurality: impl Contains<MultiLocation> = {
         MultiLocation { parents: 1, interior: Here } |
@@ -184,12 +312,12 @@ impl xcm_executor::Config for XcmConfig {
     type AssetExchanger = ();
     type AssetLocker = ();
     // How to withdraw and deposit an asset.
-    type AssetTransactor = LocalAssetTransactor;
+    type AssetTransactor = AssetTransactors;
     type AssetTrap = PolkadotXcm;
     type Barrier = Barrier;
     type CallDispatcher = RuntimeCall;
     type FeeManager = ();
-    type IsReserve = NativeAsset;
+    type IsReserve = (NativeAsset, SiblingReserveAssets);
     type IsTeleporter = ();
     type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
     type MessageExporter = ();
@@ -197,10 +325,15 @@ impl xcm_executor::Config for XcmConfig {
     type PalletInstancesInfo = AllPalletsWithSystem;
     type ResponseHandler = PolkadotXcm;
     type RuntimeCall = RuntimeCall;
-    type SafeCallFilter = Everything;
+    type SafeCallFilter = SafeCallFilter;
     type SubscriptionService = PolkadotXcm;
-    type Trader =
-        UsingComponents<WeightToFee, RelayLocation, AccountId, Balances, ToAuthor<Runtime>>;
+    type Trader = (
+        UsingComponents<WeightToFee, RelayLocation, AccountId, Balances, ToAuthor<Runtime>>,
+        RegistryTrader<
+            Runtime,
+            XcmFeesTo32ByteAccount<RegisteredAssetsTransactor, AccountId, BlockAuthor>,
+        >,
+    );
     type UniversalAliases = Nothing;
     // Teleporting is disabled.
     type UniversalLocation = UniversalLocation;
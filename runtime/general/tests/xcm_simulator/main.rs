@@ -0,0 +1,319 @@
+//! Cross-chain participation and investment, exercised on an xcm-simulator
+//! network made of a relay chain, the general runtime (para 2000) and a bare
+//! sibling parachain (para 2001) that only sends messages.
+
+mod relay_chain;
+mod sibling;
+
+use codec::Encode;
+use frame_support::{assert_ok, parameter_types, weights::Weight};
+use general_runtime::{
+    xcm_config::{SiblingAccountId32Hash, UnitWeightCost, FOREIGN_ASSET_ID_FLAG},
+    AccountId, AssetRegistry, Assets, Balance, Balances, BuildStorage, Crowdfunding, Runtime,
+    RuntimeCall, RuntimeOrigin, Rwa, System, DOLLARS,
+};
+use sp_runtime::MultiAddress;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::Convert;
+use xcm_simulator::{
+    decl_test_network, decl_test_parachain, decl_test_relay_chain, ParaId, TestExt,
+};
+
+pub const GENERAL_PARA_ID: u32 = 2000;
+pub const SIBLING_PARA_ID: u32 = 2001;
+
+pub const ALICE: AccountId = sp_runtime::AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = sp_runtime::AccountId32::new([2u8; 32]);
+/// Account of the user on the sibling chain, as seen by that chain.
+pub const CAROL: [u8; 32] = [3u8; 32];
+
+const INITIAL_BALANCE: Balance = 10_000 * DOLLARS;
+
+parameter_types! {
+    pub SiblingParaId: ParaId = SIBLING_PARA_ID.into();
+}
+
+decl_test_parachain! {
+    pub struct GeneralPara {
+        Runtime = general_runtime::Runtime,
+        XcmpMessageHandler = general_runtime::XcmpQueue,
+        DmpMessageHandler = general_runtime::DmpQueue,
+        new_ext = general_ext(),
+    }
+}
+
+decl_test_parachain! {
+    pub struct SiblingPara {
+        Runtime = sibling::Runtime,
+        XcmpMessageHandler = sibling::NoMessages,
+        DmpMessageHandler = sibling::NoMessages,
+        new_ext = sibling::ext(),
+    }
+}
+
+decl_test_relay_chain! {
+    pub struct Relay {
+        Runtime = relay_chain::Runtime,
+        XcmConfig = relay_chain::XcmConfig,
+        new_ext = relay_ext(),
+    }
+}
+
+decl_test_network! {
+    pub struct MockNet {
+        relay_chain = Relay,
+        parachains = vec![
+            (2000, GeneralPara),
+            (2001, SiblingPara),
+        ],
+    }
+}
+
+/// Local account that `CAROL` on the sibling chain dispatches as.
+fn carol_on_general() -> AccountId {
+    SiblingAccountId32Hash::convert_ref(MultiLocation::new(
+        1,
+        X2(Parachain(SIBLING_PARA_ID), AccountId32 { network: None, id: CAROL }),
+    ))
+    .expect("sibling AccountId32 locations are mapped")
+}
+
+fn general_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+    parachain_info::GenesisConfig { parachain_id: GENERAL_PARA_ID.into() }
+        .assimilate_storage(&mut t)
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![(ALICE, INITIAL_BALANCE), (carol_on_general(), INITIAL_BALANCE)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn relay_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default().build_storage::<relay_chain::Runtime>().unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| relay_chain::System::set_block_number(1));
+    ext
+}
+
+/// Message in which `CAROL` pays for execution with native tokens held by
+/// her derived account and dispatches `call` as that account.
+fn transact_as_carol(call: RuntimeCall) -> Xcm<()> {
+    let fees: MultiAsset = (Parent, 10 * DOLLARS).into();
+    Xcm(vec![
+        DescendOrigin(X1(AccountId32 { network: None, id: CAROL })),
+        WithdrawAsset(fees.clone().into()),
+        BuyExecution { fees, weight_limit: Unlimited },
+        Transact {
+            origin_kind: OriginKind::SovereignAccount,
+            require_weight_at_most: Weight::from_parts(10_000_000_000, 200_000),
+            call: call.encode().into(),
+        },
+        RefundSurplus,
+        DepositAsset {
+            assets: All.into(),
+            beneficiary: MultiLocation::new(
+                1,
+                X2(Parachain(SIBLING_PARA_ID), AccountId32 { network: None, id: CAROL }),
+            ),
+        },
+    ])
+}
+
+// ── Transact ──────────────────────────────────────────────────────────
+
+#[test]
+fn sibling_account_invests_in_campaign() {
+    MockNet::reset();
+
+    let campaign_id = GeneralPara::execute_with(|| {
+        let campaign_id = pallet_crowdfunding::NextCampaignId::<Runtime>::get();
+        assert_ok!(Crowdfunding::create_campaign(
+            RuntimeOrigin::signed(ALICE),
+            pallet_crowdfunding::CampaignConfig {
+                funding_model: pallet_crowdfunding::FundingModel::KeepWhatYouRaise {
+                    soft_cap: None,
+                },
+                funding_currency: pallet_crowdfunding::PaymentCurrency::Native,
+                deadline: 1_000,
+                hard_cap: None,
+                min_investment: None,
+                max_investment_per_investor: None,
+                metadata_hash: [0u8; 32],
+                early_withdrawal_penalty_bps: None,
+                early_bird_bonus: None,
+            },
+            None,
+            None,
+        ));
+        campaign_id
+    });
+
+    SiblingPara::execute_with(|| {
+        sibling::send_to_general(transact_as_carol(RuntimeCall::Crowdfunding(
+            pallet_crowdfunding::Call::invest { campaign_id, amount: 100 * DOLLARS },
+        )));
+    });
+
+    GeneralPara::execute_with(|| {
+        let investment =
+            pallet_crowdfunding::Investments::<Runtime>::get(campaign_id, carol_on_general())
+                .expect("the sibling account invested");
+        assert_eq!(investment.total_invested, 100 * DOLLARS);
+    });
+}
+
+#[test]
+fn sibling_account_requests_participation() {
+    MockNet::reset();
+
+    let rwa_asset_id = GeneralPara::execute_with(|| {
+        let rwa_asset_id = pallet_rwa::NextRwaAssetId::<Runtime>::get();
+        assert_ok!(Rwa::register_asset(
+            RuntimeOrigin::signed(ALICE),
+            ALICE,
+            pallet_rwa::AssetPolicy {
+                deposit_currency: pallet_rwa::PaymentCurrency::Native,
+                entry_fee: 0,
+                deposit: 5 * DOLLARS,
+                max_duration: None,
+                max_participants: None,
+                requires_approval: false,
+            },
+            vec![],
+        ));
+        rwa_asset_id
+    });
+
+    SiblingPara::execute_with(|| {
+        sibling::send_to_general(transact_as_carol(RuntimeCall::Rwa(
+            pallet_rwa::Call::request_participation {
+                rwa_asset_id,
+                holders: vec![carol_on_general()],
+            },
+        )));
+    });
+
+    GeneralPara::execute_with(|| {
+        let participation = pallet_rwa::Participations::<Runtime>::get(rwa_asset_id, 0)
+            .expect("the sibling account requested a participation");
+        assert_eq!(participation.payer, carol_on_general());
+        assert_eq!(participation.deposit_held, 5 * DOLLARS);
+    });
+}
+
+#[test]
+fn calls_outside_safe_call_filter_are_not_dispatched() {
+    MockNet::reset();
+
+    SiblingPara::execute_with(|| {
+        sibling::send_to_general(transact_as_carol(RuntimeCall::Balances(
+            pallet_balances::Call::transfer_keep_alive {
+                dest: MultiAddress::Id(BOB),
+                value: 100 * DOLLARS,
+            },
+        )));
+    });
+
+    GeneralPara::execute_with(|| {
+        assert_eq!(Balances::free_balance(BOB), 0);
+        assert_eq!(Balances::free_balance(carol_on_general()), INITIAL_BALANCE);
+    });
+}
+
+// ── Reserve transfers ─────────────────────────────────────────────────
+
+/// Execution fee rate of the registered sibling asset.
+const UNITS_PER_SECOND: u128 = 100_000;
+
+/// Register a sufficient asset for `location`.
+fn register_foreign_asset(location: MultiLocation) -> u32 {
+    let asset_id = FOREIGN_ASSET_ID_FLAG | 1;
+    assert_ok!(AssetRegistry::register_asset(
+        RuntimeOrigin::root(),
+        asset_id,
+        Box::new(location.into()),
+        b"Sibling token".to_vec(),
+        b"SIB".to_vec(),
+        12,
+        Some(UNITS_PER_SECOND),
+        1,
+    ));
+    asset_id
+}
+
+/// Reserve deposit of `amount` of the asset at `location` for `BOB`,
+/// paying execution in that same asset.
+fn reserve_deposit_to_bob(location: MultiLocation, amount: u128) -> Xcm<()> {
+    let asset: MultiAsset = (location, amount).into();
+    Xcm(vec![
+        ReserveAssetDeposited(asset.clone().into()),
+        ClearOrigin,
+        BuyExecution { fees: asset, weight_limit: Unlimited },
+        DepositAsset {
+            assets: All.into(),
+            beneficiary: X1(AccountId32 { network: None, id: BOB.into() }).into(),
+        },
+    ])
+}
+
+#[test]
+fn sibling_reserve_asset_is_deposited_into_assets() {
+    MockNet::reset();
+
+    let location = MultiLocation::new(1, X2(Parachain(SIBLING_PARA_ID), GeneralIndex(7)));
+    let asset_id = GeneralPara::execute_with(|| register_foreign_asset(location));
+
+    SiblingPara::execute_with(|| {
+        sibling::send_to_general(reserve_deposit_to_bob(location, 1_000));
+    });
+
+    GeneralPara::execute_with(|| {
+        // Four instructions at the registered rate.
+        let fee = UNITS_PER_SECOND * 4 * UnitWeightCost::get().ref_time() as u128
+            / frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND as u128;
+        assert_eq!(Assets::balance(asset_id, BOB), 1_000 - fee);
+    });
+}
+
+#[test]
+fn unregistered_sibling_asset_is_rejected() {
+    MockNet::reset();
+
+    let location = MultiLocation::new(1, X2(Parachain(SIBLING_PARA_ID), GeneralIndex(7)));
+    let asset_id = GeneralPara::execute_with(|| {
+        let asset_id = register_foreign_asset(location);
+        assert_ok!(AssetRegistry::deregister_asset(RuntimeOrigin::root(), asset_id));
+        asset_id
+    });
+
+    SiblingPara::execute_with(|| {
+        sibling::send_to_general(reserve_deposit_to_bob(location, 1_000));
+    });
+
+    GeneralPara::execute_with(|| {
+        assert_eq!(Assets::balance(asset_id, BOB), 0);
+    });
+}
+
+#[test]
+fn assets_reserved_elsewhere_are_rejected() {
+    MockNet::reset();
+
+    // The sibling claims to be the reserve of an asset native to another chain.
+    let location = MultiLocation::new(1, X2(Parachain(3000), GeneralIndex(7)));
+    let asset_id = GeneralPara::execute_with(|| register_foreign_asset(location));
+
+    SiblingPara::execute_with(|| {
+        sibling::send_to_general(reserve_deposit_to_bob(location, 1_000));
+    });
+
+    GeneralPara::execute_with(|| {
+        assert_eq!(Assets::balance(asset_id, BOB), 0);
+    });
+}
@@ -0,0 +1,113 @@
+//! Minimal relay chain for the simulated network: it only has to accept
+//! upward messages, which it never acts on.
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, Everything, Nothing},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use polkadot_runtime_parachains::{configuration, origin, shared, ump};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
+use xcm::latest::prelude::*;
+use xcm_builder::{AllowUnpaidExecutionFrom, FixedWeightBounds};
+use xcm_executor::XcmExecutor;
+
+pub type AccountId = AccountId32;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+    type AccountData = ();
+    type AccountId = AccountId;
+    type BaseCallFilter = Everything;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockNumber = u64;
+    type BlockWeights = ();
+    type DbWeight = ();
+    type Hash = H256;
+    type Hashing = sp_runtime::traits::BlakeTwo256;
+    type Header = Header;
+    type Index = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type MaxConsumers = ConstU32<16>;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type OnSetCode = ();
+    type PalletInfo = PalletInfo;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+}
+
+impl shared::Config for Runtime {}
+
+impl configuration::Config for Runtime {
+    type WeightInfo = configuration::TestWeightInfo;
+}
+
+impl origin::Config for Runtime {}
+
+parameter_types! {
+    pub const UnitWeightCost: Weight = Weight::from_parts(10, 10);
+    pub const MaxInstructions: u32 = 100;
+    pub UniversalLocation: InteriorMultiLocation = Here;
+    pub const FirstMessageFactorPercent: u64 = 100;
+}
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+    type AssetClaims = ();
+    type AssetExchanger = ();
+    type AssetLocker = ();
+    type AssetTransactor = ();
+    type AssetTrap = ();
+    type Barrier = AllowUnpaidExecutionFrom<Everything>;
+    type CallDispatcher = RuntimeCall;
+    type FeeManager = ();
+    type IsReserve = ();
+    type IsTeleporter = ();
+    type MaxAssetsIntoHolding = ConstU32<64>;
+    type MessageExporter = ();
+    type OriginConverter = ();
+    type PalletInstancesInfo = ();
+    type ResponseHandler = ();
+    type RuntimeCall = RuntimeCall;
+    type SafeCallFilter = Everything;
+    type SubscriptionService = ();
+    type Trader = ();
+    type UniversalAliases = Nothing;
+    type UniversalLocation = UniversalLocation;
+    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type XcmSender = ();
+}
+
+impl ump::Config for Runtime {
+    type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+    type FirstMessageFactorPercent = FirstMessageFactorPercent;
+    type RuntimeEvent = RuntimeEvent;
+    type UmpSink = ump::XcmSink<XcmExecutor<XcmConfig>, Runtime>;
+    type WeightInfo = ump::TestWeightInfo;
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+        ParasOrigin: origin::{Pallet, Origin},
+        ParasUmp: ump::{Pallet, Call, Storage, Event},
+    }
+);
@@ -0,0 +1,76 @@
+//! Sibling parachain for the simulated network.  It never executes anything
+//! itself; tests only use it as the origin of messages sent to the general
+//! runtime.
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, Everything},
+};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
+use xcm::latest::prelude::*;
+
+pub type AccountId = AccountId32;
+
+/// Drops every inbound message; the sibling only ever sends.
+pub type NoMessages = ();
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+    type AccountData = ();
+    type AccountId = AccountId;
+    type BaseCallFilter = Everything;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockNumber = u64;
+    type BlockWeights = ();
+    type DbWeight = ();
+    type Hash = H256;
+    type Hashing = sp_runtime::traits::BlakeTwo256;
+    type Header = Header;
+    type Index = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type MaxConsumers = ConstU32<16>;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type OnSetCode = ();
+    type PalletInfo = PalletInfo;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+    }
+);
+
+/// Sends `message` to the general runtime on behalf of the sibling chain.
+pub fn send_to_general(message: Xcm<()>) {
+    xcm::v3::send_xcm::<super::ParachainXcmRouter<super::SiblingParaId>>(
+        (Parent, Parachain(super::GENERAL_PARA_ID)).into(),
+        message,
+    )
+    .expect("the simulator router accepts sibling destinations");
+}
+
+pub fn ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}